@@ -0,0 +1,501 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Caseless matching as defined in [Unicode §3.13](https://www.unicode.org/versions/latest/core-spec/chapter-3/#G34145).
+
+use crate::internals::FullFold;
+use crate::provider::CaseMapV1;
+use crate::{CaseMapper, CaseMapperBorrowed};
+use core::cmp::Ordering;
+use core::hash::Hasher;
+#[cfg(feature = "icu_normalizer")]
+use icu_normalizer::provider::{
+    NormalizerNfdDataV1, NormalizerNfdTablesV1, NormalizerNfkdDataV1, NormalizerNfkdTablesV1,
+};
+#[cfg(feature = "icu_normalizer")]
+use icu_normalizer::{DecomposingNormalizer, DecomposingNormalizerBorrowed, Decomposition};
+use icu_provider::prelude::*;
+
+/// Options for caseless matching
+#[non_exhaustive]
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct CaselessOptions {
+    /// Whether to use the Turkic (T) case foldings for dotted and dotless I.
+    ///
+    /// With Turkic foldings, I matches ı and İ matches i. As with Turkish
+    /// lowercasing, a capital I followed by U+0307 COMBINING DOT ABOVE matches i.
+    ///
+    /// Default is `false`
+    pub turkic: bool,
+}
+
+/// The normalizers that are applied around case folding.
+#[cfg(feature = "icu_normalizer")]
+#[derive(Debug)]
+enum Decomposers<N> {
+    /// Default caseless matching (D145)
+    None,
+    /// Canonical caseless matching (D146)
+    Canonical { nfd: N },
+    /// Compatibility caseless matching (D147)
+    Compatibility { nfd: N, nfkd: N },
+}
+
+/// A caseless matcher, which compares and hashes strings without regard to case.
+///
+/// This implements the caseless matching variants of [Unicode §3.13](https://www.unicode.org/versions/latest/core-spec/chapter-3/#G34145):
+///
+/// - default caseless matching, which compares the full case foldings of the strings;
+/// - canonical caseless matching, which additionally makes canonically equivalent
+///   strings match, such as precomposed and decomposed accented letters;
+/// - compatibility caseless matching, which additionally makes compatibility
+///   equivalent strings match, such as ligatures and their components.
+///
+/// The latter two require the `icu_normalizer` Cargo feature.
+///
+/// Strings are folded and normalized character by character while they are compared or
+/// hashed, so no strings are allocated. Only the normalizers may allocate, for unusually
+/// long sequences of combining characters.
+///
+/// Most methods for this type live on [`CaselessMatcherBorrowed`], which you can obtain via
+/// [`CaselessMatcher::new()`] or [`CaselessMatcher::as_borrowed()`].
+///
+/// # Examples
+///
+/// ```rust
+/// use icu::casemap::CaselessMatcher;
+///
+/// let matcher = CaselessMatcher::new(Default::default());
+///
+/// assert!(matcher.caseless_eq("Straße", "STRASSE"));
+/// assert!(matcher.caseless_eq("ΣΊΣΥΦΟΣ", "σίσυφος"));
+/// assert!(!matcher.caseless_eq("Straße", "Strasse "));
+/// ```
+#[derive(Debug)]
+pub struct CaselessMatcher {
+    cm: CaseMapper,
+    options: CaselessOptions,
+    #[cfg(feature = "icu_normalizer")]
+    decomposers: Decomposers<DecomposingNormalizer>,
+}
+
+impl CaselessMatcher {
+    /// A constructor which creates a [`CaselessMatcherBorrowed`] for default caseless
+    /// matching using compiled data
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[expect(clippy::new_ret_no_self)] // Intentional
+    pub const fn new(options: CaselessOptions) -> CaselessMatcherBorrowed<'static> {
+        CaselessMatcherBorrowed::new(options)
+    }
+
+    icu_provider::gen_buffer_data_constructors!((options: CaselessOptions) -> error: DataError,
+    functions: [
+        new: skip,
+        try_new_with_buffer_provider,
+        try_new_unstable,
+        Self,
+    ]);
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<P>(provider: &P, options: CaselessOptions) -> Result<Self, DataError>
+    where
+        P: DataProvider<CaseMapV1> + ?Sized,
+    {
+        Ok(Self {
+            cm: CaseMapper::try_new_unstable(provider)?,
+            options,
+            #[cfg(feature = "icu_normalizer")]
+            decomposers: Decomposers::None,
+        })
+    }
+
+    /// A constructor which creates a [`CaselessMatcherBorrowed`] for canonical caseless
+    /// matching using compiled data
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `icu_normalizer` Cargo features.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(all(feature = "compiled_data", feature = "icu_normalizer"))]
+    pub const fn new_canonical(options: CaselessOptions) -> CaselessMatcherBorrowed<'static> {
+        CaselessMatcherBorrowed::new_canonical(options)
+    }
+
+    #[cfg(feature = "icu_normalizer")]
+    icu_provider::gen_buffer_data_constructors!((options: CaselessOptions) -> error: DataError,
+    functions: [
+        new_canonical: skip,
+        try_new_canonical_with_buffer_provider,
+        try_new_canonical_unstable,
+        Self,
+    ]);
+
+    #[cfg(feature = "icu_normalizer")]
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_canonical)]
+    pub fn try_new_canonical_unstable<P>(
+        provider: &P,
+        options: CaselessOptions,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<CaseMapV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            cm: CaseMapper::try_new_unstable(provider)?,
+            options,
+            decomposers: Decomposers::Canonical {
+                nfd: DecomposingNormalizer::try_new_nfd_unstable(provider)?,
+            },
+        })
+    }
+
+    /// A constructor which creates a [`CaselessMatcherBorrowed`] for compatibility caseless
+    /// matching using compiled data
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `icu_normalizer` Cargo features.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(all(feature = "compiled_data", feature = "icu_normalizer"))]
+    pub const fn new_compatibility(options: CaselessOptions) -> CaselessMatcherBorrowed<'static> {
+        CaselessMatcherBorrowed::new_compatibility(options)
+    }
+
+    #[cfg(feature = "icu_normalizer")]
+    icu_provider::gen_buffer_data_constructors!((options: CaselessOptions) -> error: DataError,
+    functions: [
+        new_compatibility: skip,
+        try_new_compatibility_with_buffer_provider,
+        try_new_compatibility_unstable,
+        Self,
+    ]);
+
+    #[cfg(feature = "icu_normalizer")]
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_compatibility)]
+    pub fn try_new_compatibility_unstable<P>(
+        provider: &P,
+        options: CaselessOptions,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<CaseMapV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfkdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkdTablesV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            cm: CaseMapper::try_new_unstable(provider)?,
+            options,
+            decomposers: Decomposers::Compatibility {
+                nfd: DecomposingNormalizer::try_new_nfd_unstable(provider)?,
+                nfkd: DecomposingNormalizer::try_new_nfkd_unstable(provider)?,
+            },
+        })
+    }
+
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> CaselessMatcherBorrowed<'_> {
+        CaselessMatcherBorrowed {
+            cm: self.cm.as_borrowed(),
+            options: self.options,
+            #[cfg(feature = "icu_normalizer")]
+            decomposers: match &self.decomposers {
+                Decomposers::None => Decomposers::None,
+                Decomposers::Canonical { nfd } => Decomposers::Canonical {
+                    nfd: nfd.as_borrowed(),
+                },
+                Decomposers::Compatibility { nfd, nfkd } => Decomposers::Compatibility {
+                    nfd: nfd.as_borrowed(),
+                    nfkd: nfkd.as_borrowed(),
+                },
+            },
+        }
+    }
+}
+
+/// A borrowed [`CaselessMatcher`].
+///
+/// See methods or [`CaselessMatcher`] for examples.
+#[derive(Debug)]
+pub struct CaselessMatcherBorrowed<'a> {
+    cm: CaseMapperBorrowed<'a>,
+    options: CaselessOptions,
+    #[cfg(feature = "icu_normalizer")]
+    decomposers: Decomposers<DecomposingNormalizerBorrowed<'a>>,
+}
+
+impl CaselessMatcherBorrowed<'static> {
+    /// A constructor which creates a [`CaselessMatcherBorrowed`] for default caseless
+    /// matching using compiled data
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new(options: CaselessOptions) -> Self {
+        Self {
+            cm: CaseMapper::new(),
+            options,
+            #[cfg(feature = "icu_normalizer")]
+            decomposers: Decomposers::None,
+        }
+    }
+
+    /// A constructor which creates a [`CaselessMatcherBorrowed`] for canonical caseless
+    /// matching using compiled data
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `icu_normalizer` Cargo features.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(all(feature = "compiled_data", feature = "icu_normalizer"))]
+    pub const fn new_canonical(options: CaselessOptions) -> Self {
+        Self {
+            cm: CaseMapper::new(),
+            options,
+            decomposers: Decomposers::Canonical {
+                nfd: DecomposingNormalizerBorrowed::new_nfd(),
+            },
+        }
+    }
+
+    /// A constructor which creates a [`CaselessMatcherBorrowed`] for compatibility caseless
+    /// matching using compiled data
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `icu_normalizer` Cargo features.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(all(feature = "compiled_data", feature = "icu_normalizer"))]
+    pub const fn new_compatibility(options: CaselessOptions) -> Self {
+        Self {
+            cm: CaseMapper::new(),
+            options,
+            decomposers: Decomposers::Compatibility {
+                nfd: DecomposingNormalizerBorrowed::new_nfd(),
+                nfkd: DecomposingNormalizerBorrowed::new_nfkd(),
+            },
+        }
+    }
+
+    /// Cheaply converts a [`CaselessMatcherBorrowed<'static>`] into a [`CaselessMatcher`].
+    ///
+    /// Note: Due to branching and indirection, using [`CaselessMatcher`] might inhibit some
+    /// compile-time optimizations that are possible with [`CaselessMatcherBorrowed`].
+    pub const fn static_to_owned(self) -> CaselessMatcher {
+        CaselessMatcher {
+            cm: self.cm.static_to_owned(),
+            options: self.options,
+            #[cfg(feature = "icu_normalizer")]
+            decomposers: match self.decomposers {
+                Decomposers::None => Decomposers::None,
+                Decomposers::Canonical { nfd } => Decomposers::Canonical {
+                    nfd: nfd.static_to_owned(),
+                },
+                Decomposers::Compatibility { nfd, nfkd } => Decomposers::Compatibility {
+                    nfd: nfd.static_to_owned(),
+                    nfkd: nfkd.static_to_owned(),
+                },
+            },
+        }
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for CaselessMatcherBorrowed<'static> {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl<'a> CaselessMatcherBorrowed<'a> {
+    /// Returns an iterator over the characters that the given characters are matched by.
+    ///
+    /// Two strings match if and only if these characters are the same for both of them.
+    /// This is the full case folding of the characters, normalized as required by the
+    /// kind of matching.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::casemap::CaselessMatcher;
+    ///
+    /// let matcher = CaselessMatcher::new(Default::default());
+    ///
+    /// assert!(matcher.caseless_chars("ǅungla".chars()).eq("ǆungla".chars()));
+    /// ```
+    pub fn caseless_chars<I: Iterator<Item = char>>(
+        &self,
+        iter: I,
+    ) -> impl Iterator<Item = char> + use<'_, 'a, I> {
+        self.chars(iter)
+    }
+
+    fn chars<I: Iterator<Item = char>>(&self, iter: I) -> CaselessChars<'_, I> {
+        let cm = self.cm.data;
+        let turkic = self.options.turkic;
+        #[cfg(feature = "icu_normalizer")]
+        match &self.decomposers {
+            Decomposers::None => CaselessChars::Default(FullFold::new(cm, turkic, iter)),
+            // NFD(toCasefold(NFD(X)))
+            Decomposers::Canonical { nfd } => CaselessChars::Canonical(
+                nfd.normalize_iter(FullFold::new(cm, turkic, nfd.normalize_iter(iter))),
+            ),
+            // NFKD(toCasefold(NFKD(toCasefold(NFD(X)))))
+            Decomposers::Compatibility { nfd, nfkd } => {
+                CaselessChars::Compatibility(nfkd.normalize_iter(FullFold::new(
+                    cm,
+                    turkic,
+                    nfkd.normalize_iter(FullFold::new(cm, turkic, nfd.normalize_iter(iter))),
+                )))
+            }
+        }
+        #[cfg(not(feature = "icu_normalizer"))]
+        CaselessChars::Default(FullFold::new(cm, turkic, iter))
+    }
+
+    /// Returns whether two strings match without regard to case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::casemap::options::CaselessOptions;
+    /// use icu::casemap::CaselessMatcher;
+    ///
+    /// let matcher = CaselessMatcher::new(Default::default());
+    ///
+    /// assert!(matcher.caseless_eq("hello", "HeLLo"));
+    /// assert!(matcher.caseless_eq("ﬃ", "FFI"));
+    /// assert!(!matcher.caseless_eq("DİYARBAKIR", "diyarbakır"));
+    ///
+    /// let mut options = CaselessOptions::default();
+    /// options.turkic = true;
+    /// let turkic = CaselessMatcher::new(options);
+    ///
+    /// assert!(turkic.caseless_eq("DİYARBAKIR", "diyarbakır"));
+    /// assert!(turkic.caseless_eq("İSTANBUL", "istanbul"));
+    /// ```
+    pub fn caseless_eq(&self, a: &str, b: &str) -> bool {
+        self.chars(a.chars()).eq(self.chars(b.chars()))
+    }
+
+    /// Returns whether two slices of potentially-invalid UTF-16 match without regard to case.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+    pub fn caseless_eq_utf16(&self, a: &[u16], b: &[u16]) -> bool {
+        self.chars(utf16_chars(a)).eq(self.chars(utf16_chars(b)))
+    }
+
+    /// Compares two strings without regard to case.
+    ///
+    /// Strings that match are equal. Other strings are ordered by the code points of
+    /// the characters they are matched by (see [`Self::caseless_chars()`]).
+    ///
+    /// This is a stable order that is consistent with [`Self::caseless_eq()`], for example
+    /// for sorting or searching, but it is not meaningful to users. Use a collator to
+    /// sort strings for display.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    /// use icu::casemap::CaselessMatcher;
+    ///
+    /// let matcher = CaselessMatcher::new(Default::default());
+    ///
+    /// assert_eq!(matcher.caseless_cmp("Readme.txt", "README.TXT"), Ordering::Equal);
+    /// assert_eq!(matcher.caseless_cmp("a.txt", "B.txt"), Ordering::Less);
+    /// ```
+    pub fn caseless_cmp(&self, a: &str, b: &str) -> Ordering {
+        self.chars(a.chars()).cmp(self.chars(b.chars()))
+    }
+
+    /// Compares two slices of potentially-invalid UTF-16 without regard to case.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+    ///
+    /// See [`Self::caseless_cmp()`] for details.
+    pub fn caseless_cmp_utf16(&self, a: &[u16], b: &[u16]) -> Ordering {
+        self.chars(utf16_chars(a)).cmp(self.chars(utf16_chars(b)))
+    }
+
+    /// Feeds a string into a [`Hasher`] without regard to case.
+    ///
+    /// Strings that match produce the same hash, so this can be used to implement
+    /// [`Hash`](core::hash::Hash) for a type whose equality is [`Self::caseless_eq()`].
+    /// A string and a slice of UTF-16 that match also produce the same hash.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::hash::{BuildHasher, Hasher};
+    /// use icu::casemap::CaselessMatcher;
+    /// use std::collections::hash_map::RandomState;
+    ///
+    /// let matcher = CaselessMatcher::new(Default::default());
+    /// let state = RandomState::new();
+    /// let hash = |s: &str| {
+    ///     let mut hasher = state.build_hasher();
+    ///     matcher.caseless_hash(s, &mut hasher);
+    ///     hasher.finish()
+    /// };
+    ///
+    /// assert_eq!(hash("Straße"), hash("STRASSE"));
+    /// ```
+    pub fn caseless_hash<H: Hasher + ?Sized>(&self, s: &str, state: &mut H) {
+        hash_chars(self.chars(s.chars()), state)
+    }
+
+    /// Feeds a slice of potentially-invalid UTF-16 into a [`Hasher`] without regard to case.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+    ///
+    /// See [`Self::caseless_hash()`] for details.
+    pub fn caseless_hash_utf16<H: Hasher + ?Sized>(&self, s: &[u16], state: &mut H) {
+        hash_chars(self.chars(utf16_chars(s)), state)
+    }
+}
+
+fn utf16_chars(s: &[u16]) -> impl Iterator<Item = char> + '_ {
+    char::decode_utf16(s.iter().copied()).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+fn hash_chars<H: Hasher + ?Sized>(chars: impl Iterator<Item = char>, state: &mut H) {
+    for c in chars {
+        state.write_u32(u32::from(c));
+    }
+    // Like `str`, end with a byte that can't occur in the text, so that
+    // hashing consecutive strings isn't ambiguous.
+    state.write_u8(0xFF);
+}
+
+/// The iterator behind [`CaselessMatcherBorrowed::caseless_chars()`].
+// The iterators aren't boxed so that matching doesn't allocate.
+#[cfg_attr(feature = "icu_normalizer", expect(clippy::large_enum_variant))]
+enum CaselessChars<'a, I: Iterator<Item = char>> {
+    Default(FullFold<'a, I>),
+    #[cfg(feature = "icu_normalizer")]
+    Canonical(Decomposition<'a, FullFold<'a, Decomposition<'a, I>>>),
+    #[cfg(feature = "icu_normalizer")]
+    Compatibility(
+        Decomposition<'a, FullFold<'a, Decomposition<'a, FullFold<'a, Decomposition<'a, I>>>>>,
+    ),
+}
+
+impl<I: Iterator<Item = char>> Iterator for CaselessChars<'_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            Self::Default(iter) => iter.next(),
+            #[cfg(feature = "icu_normalizer")]
+            Self::Canonical(iter) => iter.next(),
+            #[cfg(feature = "icu_normalizer")]
+            Self::Compatibility(iter) => iter.next(),
+        }
+    }
+}
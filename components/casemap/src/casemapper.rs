@@ -170,7 +170,12 @@ impl<'a> CaseMapperBorrowed<'a> {
         langid: &LanguageIdentifier,
         options: TitlecaseOptions,
     ) -> impl Writeable + 'a + use<'a> {
-        self.titlecase_segment_with_adjustment(src, langid, options, |data, ch| data.is_cased(ch))
+        self.titlecase_segment_with_adjustment(
+            src,
+            CaseMapLocale::from_langid(langid),
+            options,
+            |data, ch| data.is_cased(ch),
+        )
     }
 
     /// Helper to support different leading adjustment behaviors,
@@ -182,7 +187,7 @@ impl<'a> CaseMapperBorrowed<'a> {
     pub(crate) fn titlecase_segment_with_adjustment(
         self,
         src: &'a str,
-        langid: &LanguageIdentifier,
+        locale: CaseMapLocale,
         options: TitlecaseOptions,
         char_is_lead: impl Fn(&CaseMap, char) -> bool,
     ) -> StringAndWriteable<'a, FullCaseWriteable<'a, 'a, true>> {
@@ -204,7 +209,7 @@ impl<'a> CaseMapperBorrowed<'a> {
         };
         let writeable = self.data.full_helper_writeable::<true>(
             rest,
-            CaseMapLocale::from_langid(langid),
+            locale,
             MappingKind::Title,
             options.trailing_case.unwrap_or_default(),
         );
@@ -118,6 +118,137 @@ impl<'a, const IS_TITLE_CONTEXT: bool> Writeable for FullCaseWriteable<'a, '_, I
     }
 }
 
+/// A fixed-capacity queue of characters that is filled up before being read.
+struct CharBuffer<const N: usize> {
+    chars: [char; N],
+    len: usize,
+    pos: usize,
+}
+
+impl<const N: usize> CharBuffer<N> {
+    const fn new() -> Self {
+        Self {
+            chars: ['\0'; N],
+            len: 0,
+            pos: 0,
+        }
+    }
+
+    fn clear(&mut self) {
+        self.len = 0;
+        self.pos = 0;
+    }
+
+    fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    fn pop(&mut self) -> Option<char> {
+        if self.pos >= self.len {
+            return None;
+        }
+        let c = self.chars.get(self.pos).copied();
+        self.pos += 1;
+        c
+    }
+}
+
+impl<const N: usize> fmt::Write for CharBuffer<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars().try_for_each(|c| self.write_char(c))
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        *self.chars.get_mut(self.len).ok_or(fmt::Error)? = c;
+        self.len += 1;
+        Ok(())
+    }
+}
+
+/// An iterator adapter that yields the full case folding of the characters
+/// of a delegate iterator.
+///
+/// With Turkic mappings, a capital I followed by U+0307 COMBINING DOT ABOVE
+/// folds to a plain i, so that the result is the same for the precomposed and
+/// the decomposed capital I with dot above.
+pub(crate) struct FullFold<'data, I> {
+    data: &'data CaseMap<'data>,
+    locale: CaseMapLocale,
+    delegate: I,
+    /// Characters read from the delegate while looking for a dot above after a capital I.
+    lookahead: CharBuffer<8>,
+    /// The folding of the last character, which is at most three characters long.
+    folded: CharBuffer<3>,
+}
+
+impl<'data, I: Iterator<Item = char>> FullFold<'data, I> {
+    pub(crate) fn new(data: &'data CaseMap<'data>, turkic: bool, delegate: I) -> Self {
+        Self {
+            data,
+            locale: if turkic {
+                CaseMapLocale::Turkish
+            } else {
+                CaseMapLocale::Root
+            },
+            delegate,
+            lookahead: CharBuffer::new(),
+            folded: CharBuffer::new(),
+        }
+    }
+
+    /// Reads ahead past accents that aren't above the base character and removes
+    /// a U+0307 COMBINING DOT ABOVE that follows them.
+    ///
+    /// Only called when the lookahead buffer has been read completely.
+    fn take_dot_above(&mut self) -> bool {
+        self.lookahead.clear();
+        for c in self.delegate.by_ref() {
+            if c == '\u{307}' {
+                return true;
+            }
+            // The buffer has space since it's checked below.
+            let _ = fmt::Write::write_char(&mut self.lookahead, c);
+            if self.data.dot_type(c) != DotType::OtherAccent || self.lookahead.is_full() {
+                break;
+            }
+        }
+        false
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for FullFold<'_, I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(c) = self.folded.pop() {
+                return Some(c);
+            }
+            let c = self.lookahead.pop().or_else(|| self.delegate.next())?;
+            if self.locale == CaseMapLocale::Turkish && c == 'I' && self.take_dot_above() {
+                return Some('i');
+            }
+            self.folded.clear();
+            let context = ContextIterator::new("", "");
+            if self
+                .data
+                .full_helper::<false, _>(
+                    c,
+                    context,
+                    self.locale,
+                    MappingKind::Fold,
+                    &mut self.folded,
+                )
+                .is_err()
+            {
+                // GIGO: foldings are no longer than three characters
+                self.folded.clear();
+                return Some(c);
+            }
+        }
+    }
+}
+
 impl<'data> CaseMap<'data> {
     fn simple_helper(&self, c: char, kind: MappingKind) -> char {
         let data = self.lookup_data(c);
@@ -44,6 +44,7 @@
 
 extern crate alloc;
 
+mod caseless;
 mod casemapper;
 mod closer;
 pub mod provider;
@@ -55,12 +56,16 @@ pub(crate) mod titlecase;
 pub mod greek_to_me;
 mod internals;
 
+pub use caseless::{CaselessMatcher, CaselessMatcherBorrowed};
 pub use casemapper::{CaseMapper, CaseMapperBorrowed};
 pub use closer::{CaseMapCloser, CaseMapCloserBorrowed};
 pub use set::ClosureSink;
+#[cfg(feature = "icu_segmenter")]
+pub use titlecase::TitlecaseSegmenter;
 pub use titlecase::{TitlecaseMapper, TitlecaseMapperBorrowed};
 
 /// Options used by types in this crate
 pub mod options {
+    pub use crate::caseless::CaselessOptions;
     pub use crate::titlecase::{LeadingAdjustment, TitlecaseOptions, TrailingCase};
 }
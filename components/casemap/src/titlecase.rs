@@ -3,14 +3,19 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 //! Titlecasing-specific
+use crate::internals::{CaseMapLocale, FullCaseWriteable, StringAndWriteable};
 use crate::provider::CaseMapV1;
 use crate::{CaseMapper, CaseMapperBorrowed};
 use alloc::borrow::Cow;
+#[cfg(feature = "icu_segmenter")]
+use alloc::string::String;
 use icu_locale_core::LanguageIdentifier;
 use icu_properties::props::{GeneralCategory, GeneralCategoryGroup};
 use icu_properties::provider::PropertyEnumGeneralCategoryV1;
 use icu_properties::{CodePointMapData, CodePointMapDataBorrowed};
 use icu_provider::prelude::*;
+#[cfg(feature = "icu_segmenter")]
+use icu_segmenter::{SentenceSegmenterBorrowed, WordSegmenterBorrowed};
 use writeable::Writeable;
 
 /// How to handle the rest of the string once the beginning of the
@@ -333,7 +338,7 @@ impl<'a> TitlecaseMapperBorrowed<'a> {
     ///
     /// This should typically be used as a lower-level helper to construct the titlecasing operation desired
     /// by the application, for example one can titlecase on a per-word basis by mixing this with
-    /// a `WordSegmenter`.
+    /// a `WordSegmenter`, which is what `titlecase()` does with the `icu_segmenter` Cargo feature.
     ///
     /// This function is context and language sensitive. Callers should pass the text's language
     /// as a `LanguageIdentifier` (usually the `id` field of the `Locale`) if available, or
@@ -347,6 +352,15 @@ impl<'a> TitlecaseMapperBorrowed<'a> {
         langid: &LanguageIdentifier,
         options: TitlecaseOptions,
     ) -> impl Writeable + 'a + use<'a> {
+        self.titlecase_segment_with_locale(src, CaseMapLocale::from_langid(langid), options)
+    }
+
+    fn titlecase_segment_with_locale(
+        self,
+        src: &'a str,
+        locale: CaseMapLocale,
+        options: TitlecaseOptions,
+    ) -> StringAndWriteable<'a, FullCaseWriteable<'a, 'a, true>> {
         if options.leading_adjustment.unwrap_or_default() == LeadingAdjustment::Auto {
             // letter, number, symbol, or private use code point
             const HEAD_GROUPS: GeneralCategoryGroup = GeneralCategoryGroup::Letter
@@ -354,12 +368,12 @@ impl<'a> TitlecaseMapperBorrowed<'a> {
                 .union(GeneralCategoryGroup::Symbol)
                 .union(GeneralCategoryGroup::PrivateUse);
             self.cm
-                .titlecase_segment_with_adjustment(src, langid, options, |_data, ch| {
+                .titlecase_segment_with_adjustment(src, locale, options, |_data, ch| {
                     HEAD_GROUPS.contains(self.gc.get(ch))
                 })
         } else {
             self.cm
-                .titlecase_segment_with_adjustment(src, langid, options, |data, ch| {
+                .titlecase_segment_with_adjustment(src, locale, options, |data, ch| {
                     data.is_cased(ch)
                 })
         }
@@ -370,7 +384,7 @@ impl<'a> TitlecaseMapperBorrowed<'a> {
     ///
     /// This should typically be used as a lower-level helper to construct the titlecasing operation desired
     /// by the application, for example one can titlecase on a per-word basis by mixing this with
-    /// a `WordSegmenter`.
+    /// a `WordSegmenter`, which is what `titlecase()` does with the `icu_segmenter` Cargo feature.
     ///
     /// This function is context and language sensitive. Callers should pass the text's language
     /// as a `LanguageIdentifier` (usually the `id` field of the `Locale`) if available, or
@@ -482,4 +496,280 @@ impl<'a> TitlecaseMapperBorrowed<'a> {
             src.as_bytes(),
         )
     }
+
+    /// Returns the full titlecase mapping of the given string as a [`Writeable`], titlecasing
+    /// each segment that `segmenter` finds: each word, or each sentence.
+    ///
+    /// Every segment is titlecased like [`TitlecaseMapperBorrowed::titlecase_segment()`] with the
+    /// given options, so [`LeadingAdjustment`] applies to the start of each segment and
+    /// [`TrailingCase::Unchanged`] leaves the rest of each segment as it is instead of lowercasing it.
+    ///
+    /// This function is context and language sensitive. Callers should pass the text's language
+    /// as a `LanguageIdentifier` (usually the `id` field of the `Locale`) if available, or
+    /// `Default::default()` for the root locale. For example, Dutch titlecases both letters
+    /// of an initial "ij" in each segment.
+    ///
+    /// See [`TitlecaseMapperBorrowed::titlecase_to_string()`] for the equivalent convenience function that returns a String,
+    /// as well as for an example.
+    ///
+    /// ✨ *Enabled with the `icu_segmenter` Cargo feature.*
+    #[cfg(feature = "icu_segmenter")]
+    pub fn titlecase<'s, 'seg>(
+        self,
+        src: &'s str,
+        segmenter: TitlecaseSegmenter<'seg>,
+        langid: &LanguageIdentifier,
+        options: TitlecaseOptions,
+    ) -> impl Writeable + use<'a, 's, 'seg> {
+        SegmentedTitlecase {
+            mapper: self,
+            src,
+            segmenter,
+            locale: CaseMapLocale::from_langid(langid),
+            options,
+        }
+    }
+
+    /// Returns the full titlecase mapping of the given potentially-invalid UTF-8 string as a
+    /// [`Writeable`], titlecasing each segment that `segmenter` finds.
+    ///
+    /// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER.
+    ///
+    /// See [`TitlecaseMapperBorrowed::titlecase()`].
+    ///
+    /// ✨ *Enabled with the `icu_segmenter` Cargo feature.*
+    #[cfg(feature = "icu_segmenter")]
+    pub fn titlecase_utf8<'s, 'seg>(
+        self,
+        src: &'s [u8],
+        segmenter: TitlecaseSegmenter<'seg>,
+        langid: &LanguageIdentifier,
+        options: TitlecaseOptions,
+    ) -> impl Writeable + use<'a, 's, 'seg> {
+        SegmentedTitlecase {
+            mapper: self,
+            src,
+            segmenter,
+            locale: CaseMapLocale::from_langid(langid),
+            options,
+        }
+    }
+
+    /// Returns the full titlecase mapping of the given potentially-invalid UTF-16 string as a
+    /// [`Writeable`], titlecasing each segment that `segmenter` finds.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER.
+    ///
+    /// See [`TitlecaseMapperBorrowed::titlecase()`].
+    ///
+    /// ✨ *Enabled with the `icu_segmenter` Cargo feature.*
+    #[cfg(feature = "icu_segmenter")]
+    pub fn titlecase_utf16<'s, 'seg>(
+        self,
+        src: &'s [u16],
+        segmenter: TitlecaseSegmenter<'seg>,
+        langid: &LanguageIdentifier,
+        options: TitlecaseOptions,
+    ) -> impl Writeable + use<'a, 's, 'seg> {
+        SegmentedTitlecase {
+            mapper: self,
+            src,
+            segmenter,
+            locale: CaseMapLocale::from_langid(langid),
+            options,
+        }
+    }
+
+    /// Returns the full titlecase mapping of the given string as a String, titlecasing
+    /// each segment that `segmenter` finds: each word, or each sentence.
+    ///
+    /// See [`TitlecaseMapperBorrowed::titlecase()`] for the equivalent lower-level function that returns a [`Writeable`]
+    ///
+    /// ✨ *Enabled with the `icu_segmenter` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use icu::casemap::TitlecaseMapper;
+    /// use icu::casemap::options::{TitlecaseOptions, TrailingCase};
+    /// use icu::locale::langid;
+    /// use icu::segmenter::options::{SentenceBreakInvariantOptions, WordBreakInvariantOptions};
+    /// use icu::segmenter::{SentenceSegmenter, WordSegmenter};
+    ///
+    /// let cm = TitlecaseMapper::new();
+    /// let words = WordSegmenter::new_for_non_complex_scripts(WordBreakInvariantOptions::default());
+    /// let sentences = SentenceSegmenter::new(SentenceBreakInvariantOptions::default());
+    /// let root = langid!("und");
+    ///
+    /// let default_options = Default::default();
+    /// let mut preserve_case: TitlecaseOptions = Default::default();
+    /// preserve_case.trailing_case = Some(TrailingCase::Unchanged);
+    ///
+    /// assert_eq!(
+    ///     cm.titlecase_to_string("the «quick» BROWN fox's den", words.into(), &root, default_options),
+    ///     "The «Quick» Brown Fox's Den"
+    /// );
+    /// assert_eq!(
+    ///     cm.titlecase_to_string("the «quick» BROWN fox's den", words.into(), &root, preserve_case),
+    ///     "The «Quick» BROWN Fox's Den"
+    /// );
+    ///
+    /// assert_eq!(
+    ///     cm.titlecase_to_string("hello WORLD! «goodbye» world. e.g. this one.", sentences.into(), &root, default_options),
+    ///     "Hello world! «Goodbye» world. e.g. this one."
+    /// );
+    ///
+    /// // Dutch IJ digraph
+    /// assert_eq!(
+    ///     cm.titlecase_to_string("het ijsselmeer", words.into(), &langid!("nl"), default_options),
+    ///     "Het IJsselmeer"
+    /// );
+    /// ```
+    #[cfg(feature = "icu_segmenter")]
+    pub fn titlecase_to_string<'s>(
+        self,
+        src: &'s str,
+        segmenter: TitlecaseSegmenter<'_>,
+        langid: &LanguageIdentifier,
+        options: TitlecaseOptions,
+    ) -> Cow<'s, str> {
+        writeable::to_string_or_borrow(
+            &self.titlecase(src, segmenter, langid, options),
+            src.as_bytes(),
+        )
+    }
+}
+
+/// The segmenter that determines where [`TitlecaseMapperBorrowed::titlecase()`] starts
+/// titlecasing.
+///
+/// ✨ *Enabled with the `icu_segmenter` Cargo feature.*
+#[cfg(feature = "icu_segmenter")]
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug)]
+pub enum TitlecaseSegmenter<'a> {
+    /// Titlecase each word, like in a headline.
+    Word(WordSegmenterBorrowed<'a>),
+    /// Titlecase each sentence, like in running text.
+    Sentence(SentenceSegmenterBorrowed<'a>),
+}
+
+#[cfg(feature = "icu_segmenter")]
+impl<'a> From<WordSegmenterBorrowed<'a>> for TitlecaseSegmenter<'a> {
+    fn from(segmenter: WordSegmenterBorrowed<'a>) -> Self {
+        Self::Word(segmenter)
+    }
+}
+
+#[cfg(feature = "icu_segmenter")]
+impl<'a> From<SentenceSegmenterBorrowed<'a>> for TitlecaseSegmenter<'a> {
+    fn from(segmenter: SentenceSegmenterBorrowed<'a>) -> Self {
+        Self::Sentence(segmenter)
+    }
+}
+
+/// The [`Writeable`] behind [`TitlecaseMapperBorrowed::titlecase()`] and its
+/// UTF-8 and UTF-16 versions.
+#[cfg(feature = "icu_segmenter")]
+struct SegmentedTitlecase<'a, 's, 'seg, T: ?Sized> {
+    mapper: TitlecaseMapperBorrowed<'a>,
+    src: &'s T,
+    segmenter: TitlecaseSegmenter<'seg>,
+    locale: CaseMapLocale,
+    options: TitlecaseOptions,
+}
+
+#[cfg(feature = "icu_segmenter")]
+impl<T: ?Sized> SegmentedTitlecase<'_, '_, '_, T> {
+    fn write_segment<W: core::fmt::Write + ?Sized>(
+        &self,
+        segment: &str,
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        self.mapper
+            .titlecase_segment_with_locale(segment, self.locale, self.options)
+            .write_to(sink)
+    }
+}
+
+/// Calls `f` with the start and the end of each segment between `boundaries`.
+#[cfg(feature = "icu_segmenter")]
+fn for_each_segment(
+    boundaries: impl Iterator<Item = usize>,
+    mut f: impl FnMut(usize, usize) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let mut start = 0;
+    for end in boundaries.filter(|&end| end != 0) {
+        f(start, end)?;
+        start = end;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "icu_segmenter")]
+impl Writeable for SegmentedTitlecase<'_, '_, '_, str> {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        let write_segment =
+            |start, end| self.write_segment(self.src.get(start..end).unwrap_or_default(), sink);
+        match self.segmenter {
+            TitlecaseSegmenter::Word(s) => for_each_segment(s.segment_str(self.src), write_segment),
+            TitlecaseSegmenter::Sentence(s) => {
+                for_each_segment(s.segment_str(self.src), write_segment)
+            }
+        }
+    }
+    fn writeable_length_hint(&self) -> writeable::LengthHint {
+        writeable::LengthHint::at_least(self.src.len())
+    }
+}
+
+#[cfg(feature = "icu_segmenter")]
+impl Writeable for SegmentedTitlecase<'_, '_, '_, [u8]> {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        // Segments are only copied if they contain ill-formed byte sequences.
+        let write_segment = |start, end| {
+            let segment = self.src.get(start..end).unwrap_or_default();
+            self.write_segment(&String::from_utf8_lossy(segment), sink)
+        };
+        match self.segmenter {
+            TitlecaseSegmenter::Word(s) => {
+                for_each_segment(s.segment_utf8(self.src), write_segment)
+            }
+            TitlecaseSegmenter::Sentence(s) => {
+                for_each_segment(s.segment_utf8(self.src), write_segment)
+            }
+        }
+    }
+    fn writeable_length_hint(&self) -> writeable::LengthHint {
+        writeable::LengthHint::at_least(self.src.len())
+    }
+}
+
+#[cfg(feature = "icu_segmenter")]
+impl Writeable for SegmentedTitlecase<'_, '_, '_, [u16]> {
+    fn write_to<W: core::fmt::Write + ?Sized>(&self, sink: &mut W) -> core::fmt::Result {
+        // Case mapping works on UTF-8, so each segment is decoded into a buffer
+        // that is reused for the next segment.
+        let mut buffer = String::new();
+        let write_segment = |start, end| {
+            let segment = self.src.get(start..end).unwrap_or_default();
+            buffer.clear();
+            buffer.extend(
+                char::decode_utf16(segment.iter().copied())
+                    .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)),
+            );
+            self.write_segment(&buffer, sink)
+        };
+        match self.segmenter {
+            TitlecaseSegmenter::Word(s) => {
+                for_each_segment(s.segment_utf16(self.src), write_segment)
+            }
+            TitlecaseSegmenter::Sentence(s) => {
+                for_each_segment(s.segment_utf16(self.src), write_segment)
+            }
+        }
+    }
+    fn writeable_length_hint(&self) -> writeable::LengthHint {
+        writeable::LengthHint::at_least(self.src.len())
+    }
 }
@@ -2,8 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use icu_casemap::CaseMapper;
-use icu_casemap::options::{TitlecaseOptions, TrailingCase};
+use core::cmp::Ordering;
+use icu_casemap::options::{CaselessOptions, TitlecaseOptions, TrailingCase};
+use icu_casemap::{CaseMapper, CaselessMatcher};
 
 use icu_locale_core::langid;
 
@@ -429,3 +430,278 @@ fn test_greek_upper() {
     // Handle breathing marks on rho
     assert_greek_uppercase("ῥήματα ῤήματα", "ΡΗΜΑΤΑ ΡΗΜΑΤΑ");
 }
+
+#[test]
+#[cfg(feature = "icu_segmenter")]
+fn test_titlecase_words() {
+    use icu_casemap::TitlecaseMapper;
+    use icu_casemap::options::LeadingAdjustment;
+    use icu_segmenter::WordSegmenter;
+    use icu_segmenter::options::WordBreakInvariantOptions;
+
+    let cm = TitlecaseMapper::new();
+    let words = WordSegmenter::new_for_non_complex_scripts(WordBreakInvariantOptions::default());
+    let root = langid!("und");
+    let tr = langid!("tr");
+    let nl = langid!("nl");
+    let default_options = TitlecaseOptions::default();
+
+    let titlecase = |s: &str, langid, options| {
+        cm.titlecase_to_string(s, words.into(), langid, options)
+            .into_owned()
+    };
+
+    assert_eq!(titlecase("", &root, default_options), "");
+    assert_eq!(
+        titlecase("hEllO WorLd", &root, default_options),
+        "Hello World"
+    );
+    assert_eq!(
+        titlecase("ΟΔΟΣ ΑΘΗΝΩΝ", &root, default_options),
+        "Οδος Αθηνων"
+    );
+    assert_eq!(
+        titlecase("'twas «the» night", &root, default_options),
+        "'Twas «The» Night"
+    );
+    assert_eq!(
+        titlecase("istanbul izmir", &tr, default_options),
+        "İstanbul İzmir"
+    );
+    assert_eq!(
+        titlecase("ijssel en ijmuiden", &nl, default_options),
+        "IJssel En IJmuiden"
+    );
+
+    let mut no_lower = TitlecaseOptions::default();
+    no_lower.trailing_case = Some(TrailingCase::Unchanged);
+    assert_eq!(
+        titlecase("iPhone and the NASA", &root, no_lower),
+        "IPhone And The NASA"
+    );
+
+    let mut no_adjust = TitlecaseOptions::default();
+    no_adjust.leading_adjustment = Some(LeadingAdjustment::None);
+    let mut adjust_to_cased = TitlecaseOptions::default();
+    adjust_to_cased.leading_adjustment = Some(LeadingAdjustment::ToCased);
+    assert_eq!(
+        titlecase("49ers fans", &root, default_options),
+        "49ers Fans"
+    );
+    assert_eq!(
+        titlecase("49ers fans", &root, adjust_to_cased),
+        "49Ers Fans"
+    );
+    assert_eq!(titlecase("49ers fans", &root, no_adjust), "49ers Fans");
+}
+
+#[test]
+#[cfg(feature = "icu_segmenter")]
+fn test_titlecase_sentences() {
+    use icu_casemap::TitlecaseMapper;
+    use icu_segmenter::SentenceSegmenter;
+    use icu_segmenter::options::SentenceBreakInvariantOptions;
+
+    let cm = TitlecaseMapper::new();
+    let sentences = SentenceSegmenter::new(SentenceBreakInvariantOptions::default());
+    let root = langid!("und");
+    let default_options = TitlecaseOptions::default();
+
+    assert_eq!(
+        cm.titlecase_to_string(
+            "the END! is it? «yes», IT is. e.g. this one.",
+            sentences.into(),
+            &root,
+            default_options
+        ),
+        "The end! Is it? «Yes», it is. e.g. this one."
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_segmenter")]
+fn test_titlecase_utf8_utf16() {
+    use icu_casemap::TitlecaseMapper;
+    use icu_segmenter::WordSegmenter;
+    use icu_segmenter::options::WordBreakInvariantOptions;
+    use writeable::Writeable;
+
+    let cm = TitlecaseMapper::new();
+    let words = WordSegmenter::new_for_non_complex_scripts(WordBreakInvariantOptions::default());
+    let nl = langid!("nl");
+    let default_options = TitlecaseOptions::default();
+
+    assert_eq!(
+        cm.titlecase_utf8(b"het ijSSELmeer", words.into(), &nl, default_options)
+            .write_to_string(),
+        "Het IJsselmeer"
+    );
+    assert_eq!(
+        cm.titlecase_utf8(
+            b"caf\xC3\xA9 \xFF au lait",
+            words.into(),
+            &nl,
+            default_options
+        )
+        .write_to_string(),
+        "Café \u{FFFD} Au Lait"
+    );
+
+    let utf16: Vec<u16> = "het ijSSELmeer".encode_utf16().collect();
+    assert_eq!(
+        cm.titlecase_utf16(&utf16, words.into(), &nl, default_options)
+            .write_to_string(),
+        "Het IJsselmeer"
+    );
+    assert_eq!(
+        cm.titlecase_utf16(
+            &[0x0061, 0xD800, 0x0020, 0x0062],
+            words.into(),
+            &nl,
+            default_options
+        )
+        .write_to_string(),
+        "A\u{FFFD} B"
+    );
+}
+
+#[test]
+fn test_caseless_chars_match_fold() {
+    let cm = CaseMapper::new();
+    let mut turkic_options = CaselessOptions::default();
+    turkic_options.turkic = true;
+    let default = CaselessMatcher::new(Default::default());
+    let turkic = CaselessMatcher::new(turkic_options);
+
+    for c in (0..=0x10FFFF).filter_map(char::from_u32) {
+        let s = c.to_string();
+        assert!(
+            default
+                .caseless_chars(s.chars())
+                .eq(cm.fold_string(&s).chars()),
+            "{c:?}"
+        );
+        assert!(
+            turkic
+                .caseless_chars(s.chars())
+                .eq(cm.fold_turkic_string(&s).chars()),
+            "{c:?}"
+        );
+    }
+}
+
+#[test]
+fn test_caseless_default() {
+    let matcher = CaselessMatcher::new(Default::default());
+
+    assert!(matcher.caseless_eq("", ""));
+    assert!(matcher.caseless_eq("Maße", "MASSE"));
+    assert!(matcher.caseless_eq("ǅ", "ǆ"));
+    assert!(matcher.caseless_eq("ΌΣΟΣ", "όσος"));
+    assert!(matcher.caseless_eq("ΌΣΟΣ", "όσοσ"));
+    assert!(!matcher.caseless_eq("Maße", "Masse "));
+    assert!(!matcher.caseless_eq("I", "ı"));
+    // Default caseless matching doesn't decompose.
+    assert!(!matcher.caseless_eq("É", "e\u{301}"));
+
+    assert_eq!(matcher.caseless_cmp("Maße", "MASSE"), Ordering::Equal);
+    assert_eq!(matcher.caseless_cmp("Maße", "mast"), Ordering::Less);
+    assert_eq!(matcher.caseless_cmp("ab", "A"), Ordering::Greater);
+    assert_eq!(matcher.caseless_cmp("Z", "a"), Ordering::Greater);
+
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+    assert!(matcher.caseless_eq_utf16(&utf16("Maße"), &utf16("MASSE")));
+    assert!(matcher.caseless_eq_utf16(&[0x0041, 0xD800], &utf16("a\u{FFFD}")));
+    assert_eq!(
+        matcher.caseless_cmp_utf16(&utf16("Maße"), &utf16("MAST")),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn test_caseless_turkic() {
+    let mut options = CaselessOptions::default();
+    options.turkic = true;
+    let matcher = CaselessMatcher::new(options);
+
+    assert!(matcher.caseless_eq("KIRMIZI", "kırmızı"));
+    assert!(matcher.caseless_eq("İNCİR", "incir"));
+    assert!(!matcher.caseless_eq("I", "i"));
+    assert!(!matcher.caseless_eq("İ", "ı"));
+    // Capital I with a combining dot above matches i, also with other accents in between.
+    assert!(matcher.caseless_eq("I\u{307}NCI\u{307}R", "incir"));
+    assert!(matcher.caseless_eq("I\u{323}\u{307}", "i\u{323}"));
+    assert!(matcher.caseless_eq("II\u{307}", "ıi"));
+    assert!(!matcher.caseless_eq("I\u{301}\u{307}", "i\u{301}"));
+}
+
+#[test]
+fn test_caseless_hash() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+
+    let matcher = CaselessMatcher::new(Default::default());
+    let hash = |s: &str| {
+        let mut hasher = DefaultHasher::new();
+        matcher.caseless_hash(s, &mut hasher);
+        hasher.finish()
+    };
+    let hash_utf16 = |s: &str| {
+        let mut hasher = DefaultHasher::new();
+        matcher.caseless_hash_utf16(&s.encode_utf16().collect::<Vec<_>>(), &mut hasher);
+        hasher.finish()
+    };
+
+    assert_eq!(hash("Maße"), hash("MASSE"));
+    assert_eq!(hash("Maße"), hash_utf16("masse"));
+    assert_ne!(hash("Maße"), hash("Masse "));
+
+    // Hashing several strings one after the other isn't ambiguous.
+    let hash_pair = |a: &str, b: &str| {
+        let mut hasher = DefaultHasher::new();
+        matcher.caseless_hash(a, &mut hasher);
+        matcher.caseless_hash(b, &mut hasher);
+        hasher.finish()
+    };
+    assert_ne!(hash_pair("ab", "c"), hash_pair("a", "bc"));
+}
+
+#[test]
+#[cfg(feature = "icu_normalizer")]
+fn test_caseless_canonical() {
+    let matcher = CaselessMatcher::new_canonical(Default::default());
+
+    assert!(matcher.caseless_eq("É", "e\u{301}"));
+    assert!(matcher.caseless_eq("Å", "\u{212B}"));
+    // U+0345 COMBINING GREEK YPOGEGRAMMENI folds to ι, so the
+    // precomposed letter needs to be decomposed before folding.
+    assert!(matcher.caseless_eq("ᾳ", "α\u{345}"));
+    assert!(matcher.caseless_eq("ᾼ", "αι"));
+    assert!(matcher.caseless_eq("ǰ", "J\u{30C}"));
+    assert!(!matcher.caseless_eq("ﬁ", "F\u{307}"));
+    assert!(!matcher.caseless_eq("²", "2"));
+
+    let mut options = CaselessOptions::default();
+    options.turkic = true;
+    let turkic = CaselessMatcher::new_canonical(options);
+    assert!(turkic.caseless_eq("İ", "I\u{307}"));
+    assert!(turkic.caseless_eq("İ\u{323}", "i\u{323}"));
+    assert!(turkic.caseless_eq("Ị\u{307}", "i\u{323}"));
+
+    let owned = CaselessMatcher::new_canonical(Default::default()).static_to_owned();
+    assert!(owned.as_borrowed().caseless_eq("É", "e\u{301}"));
+}
+
+#[test]
+#[cfg(feature = "icu_normalizer")]
+fn test_caseless_compatibility() {
+    let matcher = CaselessMatcher::new_compatibility(Default::default());
+
+    assert!(matcher.caseless_eq("É", "e\u{301}"));
+    assert!(matcher.caseless_eq("ﬁ", "FI"));
+    assert!(matcher.caseless_eq("²", "2"));
+    assert!(matcher.caseless_eq("㎒", "MHZ"));
+    // U+2160 ROMAN NUMERAL ONE
+    assert!(matcher.caseless_eq("\u{2160}\u{2170}", "ii"));
+    assert!(!matcher.caseless_eq("½", "1/2 "));
+}
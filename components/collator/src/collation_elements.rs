@@ -0,0 +1,221 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Access to the collation elements that comparisons and sort keys are based on.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use utf16_iter::Utf16CharsEx;
+
+use crate::CollatorBorrowed;
+use crate::elements::{CASE_MASK, CollationElements, NO_CE_PRIMARY, TERTIARY_MASK};
+
+/// The weights of a collation element.
+///
+/// A string maps to a sequence of collation elements, which the collator compares
+/// level by level: First the primary weights of all the collation elements, then
+/// the secondary weights, and so on. Weights that are zero are ignored on their
+/// level.
+///
+/// Like `CollationElementIterator` in ICU4C, this exposes the collation elements
+/// of the collation data before applying the options of the collator, except for
+/// numeric ordering and reordering, which are reflected in the primary weights.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CollationElement(u64);
+
+impl CollationElement {
+    /// The primary weight, which distinguishes base letters.
+    ///
+    /// Zero if the collation element is ignorable on the primary level, e.g.
+    /// for a combining accent.
+    pub fn primary(self) -> u32 {
+        (self.0 >> 32) as u32
+    }
+
+    /// The secondary weight, which distinguishes accents.
+    pub fn secondary(self) -> u16 {
+        (self.0 >> 16) as u16
+    }
+
+    /// The tertiary weight, which distinguishes variants like case and width,
+    /// without the case bits.
+    pub fn tertiary(self) -> u16 {
+        (self.0 as u16) & TERTIARY_MASK
+    }
+
+    /// The case bits: 0 for lowercase or uncased, 1 for mixed case (in expansions),
+    /// and 2 for uppercase.
+    pub fn case(self) -> u8 {
+        ((self.0 as u16 & CASE_MASK) >> 14) as u8
+    }
+}
+
+impl<'a> CollatorBorrowed<'a> {
+    /// Returns the collation elements of `text` together with the byte ranges
+    /// of `text` that they come from.
+    ///
+    /// The ranges are computed while iterating, so they don't split contractions,
+    /// characters whose collation elements depend on the preceding character, or
+    /// a character and the combining marks after it, which are normalized together.
+    /// Several collation elements can share a range, e.g. for an expansion or
+    /// an accented letter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::collator::{options::*, *};
+    /// use icu::locale::locale;
+    ///
+    /// let collator =
+    ///     Collator::try_new(locale!("cs").into(), CollatorOptions::default())
+    ///         .unwrap();
+    ///
+    /// // "ch" is a single letter in Czech, and "é" has a separate collation
+    /// // element for the accent.
+    /// let elements = collator.collation_elements_str("ché").collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     elements.iter().map(|(range, _)| range.clone()).collect::<Vec<_>>(),
+    ///     [0..2, 2..4, 2..4]
+    /// );
+    /// assert_eq!(elements[2].1.primary(), 0);
+    /// assert_ne!(elements[2].1.secondary(), 0);
+    ///
+    /// // The primary weights are in the order of the collator.
+    /// let primary = |s| collator.collation_elements_str(s).next().unwrap().1.primary();
+    /// assert!(primary("h") < primary("ch"));
+    /// assert!(primary("ch") < primary("i"));
+    /// ```
+    pub fn collation_elements_str<'s>(
+        &'s self,
+        text: &'s str,
+    ) -> impl Iterator<Item = (Range<usize>, CollationElement)> + 's {
+        CollationElementsWithOffsets::new(self, text.char_indices(), text.len())
+    }
+
+    /// Returns the collation elements of potentially ill-formed UTF-16 `text`
+    /// together with the code unit ranges of `text` that they come from.
+    ///
+    /// Unpaired surrogates are treated as REPLACEMENT CHARACTERs. See
+    /// [`Self::collation_elements_str`] for the ranges.
+    pub fn collation_elements_utf16<'s>(
+        &'s self,
+        text: &'s [u16],
+    ) -> impl Iterator<Item = (Range<usize>, CollationElement)> + 's {
+        CollationElementsWithOffsets::new(self, text.char_indices(), text.len())
+    }
+}
+
+/// The characters of a text, remembering the last one together with its offset.
+struct OffsetChars<I> {
+    iter: I,
+    last: Option<(usize, char)>,
+}
+
+impl<I: Iterator<Item = (usize, char)>> Iterator for OffsetChars<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let (offset, c) = self.iter.next()?;
+        self.last = Some((offset, c));
+        Some(c)
+    }
+}
+
+/// The iterator returned by [`CollatorBorrowed::collation_elements_str`] and
+/// [`CollatorBorrowed::collation_elements_utf16`].
+///
+/// A range ends where the collation elements have been returned for all the
+/// characters read so far except for the last one, which starts the next range.
+/// A range is only returned once the range after it is complete, because it is
+/// merged with that range if that range matched a prefix.
+struct CollationElementsWithOffsets<'s, 'a, I: Iterator<Item = (usize, char)>> {
+    collator: &'s CollatorBorrowed<'a>,
+    elements: CollationElements<'a, OffsetChars<I>>,
+    /// The length of the text.
+    len: usize,
+    /// The range being returned, and the index of its next collation element.
+    ready: (Range<usize>, Vec<CollationElement>),
+    ready_pos: usize,
+    /// The last complete range, which hasn't been returned yet.
+    held: Option<(Range<usize>, Vec<CollationElement>)>,
+    /// The start of the range after `held`, `None` at the end of the text.
+    next_start: Option<usize>,
+}
+
+impl<'s, 'a, I: Iterator<Item = (usize, char)>> CollationElementsWithOffsets<'s, 'a, I> {
+    fn new(collator: &'s CollatorBorrowed<'a>, iter: I, len: usize) -> Self {
+        let mut elements = collator.collation_elements(OffsetChars { iter, last: None });
+        // Prefixes can't match at the start of the text.
+        elements.take_prefix_matched();
+        Self {
+            collator,
+            elements,
+            len,
+            ready: (0..0, Vec::new()),
+            ready_pos: 0,
+            held: None,
+            next_start: Some(0),
+        }
+    }
+
+    /// Reads the collation elements of the range after `held`, and returns its end
+    /// and whether they depend on the characters before it.
+    fn read_range(&mut self, elements: &mut Vec<CollationElement>) -> (usize, bool) {
+        loop {
+            let ce = self.elements.next();
+            let primary = ce.primary();
+            if primary == NO_CE_PRIMARY {
+                return (self.len, self.elements.take_prefix_matched());
+            }
+            elements.push(CollationElement(
+                (u64::from(self.collator.reorder_primary(primary)) << 32)
+                    | u64::from(ce.non_primary().bits()),
+            ));
+            if let Some((offset, c)) = self.elements.delegate().last
+                && self.elements.is_at_last_read(c)
+            {
+                return (offset, self.elements.take_prefix_matched());
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = (usize, char)>> Iterator for CollationElementsWithOffsets<'_, '_, I> {
+    type Item = (Range<usize>, CollationElement);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(&ce) = self.ready.1.get(self.ready_pos) {
+                self.ready_pos += 1;
+                return Some((self.ready.0.clone(), ce));
+            }
+            let Some(start) = self.next_start else {
+                self.ready = self.held.take()?;
+                self.ready_pos = 0;
+                continue;
+            };
+            // Reuse the allocation of the range that has been returned.
+            let mut elements = core::mem::take(&mut self.ready.1);
+            elements.clear();
+            let (end, prefix_matched) = self.read_range(&mut elements);
+            self.next_start = (end < self.len).then_some(end);
+            if elements.is_empty() {
+                continue;
+            }
+            match &mut self.held {
+                Some((range, held)) if prefix_matched => {
+                    range.end = end;
+                    held.append(&mut elements);
+                }
+                held => {
+                    if let Some(previous) = held.replace((start..end, elements)) {
+                        self.ready = previous;
+                        self.ready_pos = 0;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -9,6 +9,7 @@
 //! This module holds the `Collator` struct whose `compare_impl()` contains
 //! the comparison of collation element sequences.
 
+use alloc::boxed::Box;
 use alloc::collections::VecDeque;
 use alloc::vec::Vec;
 
@@ -26,7 +27,10 @@ use crate::elements::{
 };
 use crate::options::CollatorOptionsBitField;
 use crate::options::{AlternateHandling, CollatorOptions, ResolvedCollatorOptions, Strength};
-use crate::preferences::{CollationCaseFirst, CollationNumericOrdering, CollationType};
+use crate::preferences::{
+    CollationCaseFirst, CollationNumericOrdering, CollationReorderCode, CollationReorderCodes,
+    CollationType,
+};
 use crate::provider::CollationData;
 use crate::provider::CollationDiacritics;
 use crate::provider::CollationDiacriticsV1;
@@ -37,9 +41,12 @@ use crate::provider::CollationMetadataV1;
 use crate::provider::CollationReordering;
 use crate::provider::CollationReorderingV1;
 use crate::provider::CollationRootV1;
+use crate::provider::CollationScriptStarts;
+use crate::provider::CollationScriptStartsV1;
 use crate::provider::CollationSpecialPrimaries;
 use crate::provider::CollationSpecialPrimariesV1;
 use crate::provider::CollationTailoringV1;
+use crate::reordering::CustomReordering;
 use core::cmp::Ordering;
 use core::convert::Infallible;
 use icu_normalizer::DecomposingNormalizerBorrowed;
@@ -62,7 +69,7 @@ const LEVEL_SEPARATOR_BYTE: u8 = 1;
 /// be used as primary compression low terminator.  Otherwise usable.
 const MERGE_SEPARATOR: char = '\u{fffe}';
 const MERGE_SEPARATOR_BYTE: u8 = 2;
-const MERGE_SEPARATOR_PRIMARY: u32 = 0x02000000;
+pub(crate) const MERGE_SEPARATOR_PRIMARY: u32 = 0x02000000;
 
 /// Primary compression low terminator, must be greater than [`MERGE_SEPARATOR_BYTE`].
 ///
@@ -180,6 +187,34 @@ impl Reorder for &CollationReordering<'_> {
     }
 }
 
+impl Reorder for &CustomReordering {
+    #[inline(always)]
+    fn reorder(self, p: u32) -> u32 {
+        CustomReordering::reorder(self, p)
+    }
+}
+
+/// The reordering from the locale data or from explicit reorder codes.
+#[derive(Debug)]
+pub(crate) enum Reordering<'a> {
+    Data(&'a CollationReordering<'a>),
+    /// Borrowed from a [`Collator`].
+    Custom(&'a CustomReordering),
+    /// Owned by a [`CollatorBorrowed`] constructed with reorder codes.
+    OwnedCustom(Box<CustomReordering>),
+}
+
+impl Reordering<'_> {
+    #[inline(always)]
+    fn reorder(&self, p: u32) -> u32 {
+        match self {
+            Self::Data(reordering) => reordering.reorder(p),
+            Self::Custom(reordering) => reordering.reorder(p),
+            Self::OwnedCustom(reordering) => reordering.reorder(p),
+        }
+    }
+}
+
 struct AnyQuaternaryAccumulator(u32);
 
 impl AnyQuaternaryAccumulator {
@@ -395,11 +430,11 @@ fn split_prefix<'a, 'b>(left: &'a str, right: &'b str) -> (&'a str, &'a str, &'b
 /// Holder struct for payloads that are locale-dependent. (For code
 /// reuse between owned and borrowed cases.)
 #[derive(Debug)]
-struct LocaleSpecificDataHolder {
-    tailoring: Option<DataPayload<CollationTailoringV1>>,
-    diacritics: DataPayload<CollationDiacriticsV1>,
-    reordering: Option<DataPayload<CollationReorderingV1>>,
-    metadata: CollationMetadata,
+pub(crate) struct LocaleSpecificDataHolder {
+    pub(crate) tailoring: Option<DataPayload<CollationTailoringV1>>,
+    pub(crate) diacritics: DataPayload<CollationDiacriticsV1>,
+    pub(crate) reordering: Option<DataPayload<CollationReorderingV1>>,
+    pub(crate) metadata: CollationMetadata,
 }
 
 icu_locale_core::preferences::define_preferences!(
@@ -421,6 +456,14 @@ icu_locale_core::preferences::define_preferences!(
     /// This is the BCP47 key `kn`. When set to [`CollationNumericOrdering::True`], any sequence of decimal
     /// digits (General_Category = Nd) is sorted at the primary level according to the
     /// numeric value. The default is [`CollationNumericOrdering::False`].
+    ///
+    /// ## Reordering
+    ///
+    /// See the [spec](https://www.unicode.org/reports/tr35/tr35-collation.html#Script_Reordering).
+    /// This is the BCP47 key `kr`. The listed scripts and groups of characters, such as
+    /// [`CollationReorderCode::Digit`], sort before all the others, replacing the reordering
+    /// implied by the locale. The default is the reordering implied by the locale (e.g.
+    /// Cyrillic first for Russian). Reorder codes in [`CollatorOptions`] take precedence.
     [Copy]
     CollatorPreferences,
     {
@@ -432,13 +475,19 @@ icu_locale_core::preferences::define_preferences!(
         /// When set to `True`, any sequence of decimal digits is sorted at a primary level according
         /// to the numeric value.
         /// This corresponds to the `-u-kn` BPC-47 tag.
-        numeric_ordering: CollationNumericOrdering
+        numeric_ordering: CollationNumericOrdering,
+        /// The order of scripts and special groups of characters.
+        /// This corresponds to the `-u-kr` BCP-47 tag.
+        reorder_codes: CollationReorderCodes
     }
 );
 
 impl LocaleSpecificDataHolder {
     /// The constructor code reused between owned and borrowed cases.
-    fn try_new_unstable<D>(provider: &D, prefs: CollatorPreferences) -> Result<Self, DataError>
+    pub(crate) fn try_new_unstable<D>(
+        provider: &D,
+        prefs: CollatorPreferences,
+    ) -> Result<Self, DataError>
     where
         D: DataProvider<CollationTailoringV1>
             + DataProvider<CollationDiacriticsV1>
@@ -543,15 +592,17 @@ impl LocaleSpecificDataHolder {
 /// Compares strings according to culturally-relevant ordering.
 #[derive(Debug)]
 pub struct Collator {
-    special_primaries: DataPayload<CollationSpecialPrimariesV1>,
-    root: DataPayload<CollationRootV1>,
-    tailoring: Option<DataPayload<CollationTailoringV1>>,
-    jamo: DataPayload<CollationJamoV1>,
-    diacritics: DataPayload<CollationDiacriticsV1>,
-    options: CollatorOptionsBitField,
-    reordering: Option<DataPayload<CollationReorderingV1>>,
-    decompositions: DataPayload<NormalizerNfdDataV1>,
-    tables: DataPayload<NormalizerNfdTablesV1>,
+    pub(crate) special_primaries: DataPayload<CollationSpecialPrimariesV1>,
+    pub(crate) root: DataPayload<CollationRootV1>,
+    pub(crate) tailoring: Option<DataPayload<CollationTailoringV1>>,
+    pub(crate) jamo: DataPayload<CollationJamoV1>,
+    pub(crate) diacritics: DataPayload<CollationDiacriticsV1>,
+    pub(crate) options: CollatorOptionsBitField,
+    pub(crate) reordering: Option<DataPayload<CollationReorderingV1>>,
+    /// Takes precedence over `reordering` if present. Never `Reordering::Data`.
+    pub(crate) custom_reordering: Option<Reordering<'static>>,
+    pub(crate) decompositions: DataPayload<NormalizerNfdDataV1>,
+    pub(crate) tables: DataPayload<NormalizerNfdTablesV1>,
 }
 
 impl Collator {
@@ -564,7 +615,11 @@ impl Collator {
             jamo: self.jamo.get(),
             diacritics: self.diacritics.get(),
             options: self.options,
-            reordering: self.reordering.as_ref().map(|s| s.get()),
+            reordering: match &self.custom_reordering {
+                Some(Reordering::Custom(reordering)) => Some(Reordering::Custom(reordering)),
+                Some(Reordering::OwnedCustom(reordering)) => Some(Reordering::Custom(reordering)),
+                _ => self.reordering.as_ref().map(|s| Reordering::Data(s.get())),
+            },
             decompositions: self.decompositions.get(),
             tables: self.tables.get(),
         }
@@ -603,6 +658,7 @@ impl Collator {
             + DataProvider<CollationJamoV1>
             + DataProvider<CollationMetadataV1>
             + DataProvider<CollationReorderingV1>
+            + DataProvider<CollationScriptStartsV1>
             + DataProvider<NormalizerNfdDataV1>
             + DataProvider<NormalizerNfdTablesV1>
             + ?Sized,
@@ -620,7 +676,7 @@ impl Collator {
             metadata,
         } = LocaleSpecificDataHolder::try_new_unstable(provider, prefs)?;
 
-        Ok(Collator {
+        let mut collator = Collator {
             special_primaries,
             root,
             tailoring,
@@ -628,9 +684,29 @@ impl Collator {
             diacritics,
             options: options.resolve(metadata, prefs.case_first, prefs.numeric_ordering),
             reordering,
+            custom_reordering: None,
             decompositions,
             tables,
-        })
+        };
+        if let Some(codes) = options.reorder_codes.or(prefs.reorder_codes) {
+            let script_starts: DataPayload<CollationScriptStartsV1> =
+                provider.load(Default::default())?.payload;
+            collator.set_reorder_codes(script_starts.get(), &codes)?;
+        }
+        Ok(collator)
+    }
+
+    /// Replaces the reordering implied by the locale with the one for `codes`.
+    pub(crate) fn set_reorder_codes(
+        &mut self,
+        script_starts: &CollationScriptStarts,
+        codes: &[CollationReorderCode],
+    ) -> Result<(), DataError> {
+        self.custom_reordering =
+            CustomReordering::try_new(&self.as_borrowed(), script_starts, codes)?
+                .map(|reordering| Reordering::OwnedCustom(Box::new(reordering)));
+        self.reordering = None;
+        Ok(())
     }
 }
 
@@ -670,7 +746,7 @@ pub struct CollatorBorrowed<'a> {
     jamo: &'a CollationJamo<'a>,
     diacritics: &'a CollationDiacritics<'a>,
     options: CollatorOptionsBitField,
-    reordering: Option<&'a CollationReordering<'a>>,
+    reordering: Option<Reordering<'a>>,
     decompositions: &'a DecompositionData<'a>,
     tables: &'a DecompositionTables<'a>,
 }
@@ -699,17 +775,23 @@ impl CollatorBorrowed<'static> {
         let reordering = locale_dependent.reordering.map(|s| s.get_static().unwrap());
         let metadata = locale_dependent.metadata;
 
-        Ok(CollatorBorrowed {
+        let mut collator = CollatorBorrowed {
             special_primaries,
             root,
             tailoring,
             jamo,
             diacritics,
             options: options.resolve(metadata, prefs.case_first, prefs.numeric_ordering),
-            reordering,
+            reordering: reordering.map(Reordering::Data),
             decompositions,
             tables,
-        })
+        };
+        if let Some(codes) = options.reorder_codes.or(prefs.reorder_codes) {
+            let script_starts = crate::provider::Baked::SINGLETON_COLLATION_SCRIPT_STARTS_V1;
+            collator.reordering = CustomReordering::try_new(&collator, script_starts, &codes)?
+                .map(|reordering| Reordering::OwnedCustom(Box::new(reordering)));
+        }
+        Ok(collator)
     }
 
     /// This creates a root collator using baked data only.
@@ -757,7 +839,13 @@ impl CollatorBorrowed<'static> {
     /// Note: Due to branching and indirection, using [`Collator`] might inhibit some
     /// compile-time optimizations that are possible with [`CollatorBorrowed`].
     pub const fn static_to_owned(self) -> Collator {
-        Collator {
+        let custom_reordering = match &self.reordering {
+            Some(Reordering::Data(_)) | None => None,
+            // Safety: `self` is forgotten below without being dropped, so the reordering
+            // is moved rather than duplicated.
+            Some(reordering) => Some(unsafe { core::ptr::read(reordering) }),
+        };
+        let collator = Collator {
             special_primaries: DataPayload::from_static_ref(self.special_primaries),
             root: DataPayload::from_static_ref(self.root),
             tailoring: if let Some(s) = self.tailoring {
@@ -769,15 +857,19 @@ impl CollatorBorrowed<'static> {
             jamo: DataPayload::from_static_ref(self.jamo),
             diacritics: DataPayload::from_static_ref(self.diacritics),
             options: self.options,
-            reordering: if let Some(s) = self.reordering {
+            reordering: if let Some(Reordering::Data(s)) = self.reordering {
                 // `map` not available in const context
                 Some(DataPayload::from_static_ref(s))
             } else {
                 None
             },
+            custom_reordering,
             decompositions: DataPayload::from_static_ref(self.decompositions),
             tables: DataPayload::from_static_ref(self.tables),
-        }
+        };
+        // The owned reordering, if any, was moved into `collator`.
+        core::mem::forget(self);
+        collator
     }
 }
 
@@ -890,16 +982,72 @@ impl<'a> CollatorBorrowed<'a> {
 
     #[inline(always)]
     fn variable_top(&self) -> u32 {
-        if self.options.alternate_handling() == AlternateHandling::NonIgnorable {
+        self.variable_top_for(self.options)
+    }
+
+    /// The variable top for `options`, which may differ from the options
+    /// of this collator in alternate handling.
+    #[inline(always)]
+    pub(crate) fn variable_top_for(&self, options: CollatorOptionsBitField) -> u32 {
+        if options.alternate_handling() == AlternateHandling::NonIgnorable {
             0
         } else {
             // +1 so that we can use "<" and primary ignorables test out early.
             self.special_primaries
-                .last_primary_for_group(self.options.max_variable())
+                .last_primary_for_group(options.max_variable())
                 + 1
         }
     }
 
+    /// The special primaries of the root collation.
+    pub(crate) fn special_primaries(&self) -> &'a CollationSpecialPrimaries<'a> {
+        self.special_primaries
+    }
+
+    /// The root collation data.
+    pub(crate) fn root(&self) -> &'a CollationData<'a> {
+        self.root
+    }
+
+    /// `primary` after applying the script reordering of this collator, if any.
+    pub(crate) fn reorder_primary(&self, primary: u32) -> u32 {
+        match &self.reordering {
+            Some(reordering) => reordering.reorder(primary),
+            None => primary,
+        }
+    }
+
+    /// The resolved options of this collator as a bit field.
+    #[cfg(feature = "icu_segmenter")]
+    pub(crate) fn options_bit_field(&self) -> CollatorOptionsBitField {
+        self.options
+    }
+
+    /// An initialized iterator over the collation elements of `iter`.
+    pub(crate) fn collation_elements<I>(&self, iter: I) -> CollationElements<'a, I>
+    where
+        I: Iterator<Item = char>,
+    {
+        let mut elements = CollationElements::new(
+            iter,
+            self.root,
+            self.tailoring_or_root(),
+            self.jamo.as_array(),
+            &self.diacritics.secondaries,
+            self.decompositions,
+            self.tables,
+            self.numeric_primary(),
+            self.options.lithuanian_dot_above(),
+        );
+        elements.init();
+        elements
+    }
+
+    /// The NFD normalizer backed by the data of this collator.
+    pub(crate) fn nfd(&self) -> DecomposingNormalizerBorrowed<'a> {
+        DecomposingNormalizerBorrowed::new_with_data(self.decompositions, self.tables)
+    }
+
     /// The implementation of the comparison operation.
     ///
     /// `head_chars` is an iterator _backward_ over the identical
@@ -1799,17 +1947,23 @@ impl<'a> CollatorBorrowed<'a> {
         };
 
         let mut state = S::State::default();
-        match self.reordering {
-            Some(r) => self.write_sort_key_up_to_quaternary(iter, sink, &mut state, r)?,
+        match &self.reordering {
+            Some(Reordering::Data(r)) => {
+                self.write_sort_key_up_to_quaternary(iter, sink, &mut state, *r)?
+            }
+            Some(Reordering::Custom(r)) => {
+                self.write_sort_key_up_to_quaternary(iter, sink, &mut state, *r)?
+            }
+            Some(Reordering::OwnedCustom(r)) => {
+                self.write_sort_key_up_to_quaternary(iter, sink, &mut state, &**r)?
+            }
             None => self.write_sort_key_up_to_quaternary(iter, sink, &mut state, NoReorder)?,
         }
 
         if let Some(iter) = identical {
-            let nfd =
-                DecomposingNormalizerBorrowed::new_with_data(self.decompositions, self.tables);
             sink.write_byte(&mut state, LEVEL_SEPARATOR_BYTE)?;
 
-            let iter = nfd.normalize_iter(iter);
+            let iter = self.nfd().normalize_iter(iter);
             write_identical_level(iter, sink, &mut state)?;
         }
 
@@ -1835,18 +1989,7 @@ impl<'a> CollatorBorrowed<'a> {
         // This algorithm comes from `CollationKeys::writeSortKeyUpToQuaternary` in ICU4C.
         let levels = self.sort_key_levels();
 
-        let mut iter = CollationElements::new(
-            iter,
-            self.root,
-            self.tailoring_or_root(),
-            self.jamo.as_array(),
-            &self.diacritics.secondaries,
-            self.decompositions,
-            self.tables,
-            self.numeric_primary(),
-            self.options.lithuanian_dot_above(),
-        );
-        iter.init();
+        let mut iter = self.collation_elements(iter);
         let variable_top = self.variable_top();
 
         let tertiary_mask = self.options.tertiary_mask().unwrap_or_default();
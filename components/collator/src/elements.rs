@@ -143,7 +143,7 @@ fn decomposition_starts_with_non_starter(trie_value: u32) -> bool {
 /// Extracts a canonical combining class (possibly zero) from a trie value.
 ///
 /// See components/normalizer/trie-value-format.md
-fn ccc_from_trie_value(trie_value: u32) -> CanonicalCombiningClass {
+pub(crate) fn ccc_from_trie_value(trie_value: u32) -> CanonicalCombiningClass {
     if trie_value_has_ccc(trie_value) {
         CanonicalCombiningClass(trie_value as u8)
     } else {
@@ -182,7 +182,7 @@ const COMMON_SECONDARY_CE: u64 = 0x05000000;
 const COMMON_TERTIARY_CE: u64 = 0x0500;
 const COMMON_SEC_AND_TER_CE: u64 = COMMON_SECONDARY_CE | COMMON_TERTIARY_CE;
 
-const UNASSIGNED_IMPLICIT_BYTE: u8 = 0xFE;
+pub(crate) const UNASSIGNED_IMPLICIT_BYTE: u8 = 0xFE;
 
 // /// Set if there is no match for the single (no-suffix) character itself.
 // /// This is only possible if there is a prefix.
@@ -387,6 +387,11 @@ impl CollationElement32 {
         CollationElement32(bits)
     }
 
+    #[inline(always)]
+    pub fn bits(self) -> u32 {
+        self.0
+    }
+
     #[inline(always)]
     pub fn new_from_ule(ule: RawBytesULE<4>) -> Self {
         CollationElement32(u32::from_unaligned(ule))
@@ -907,6 +912,8 @@ where
     lithuanian_dot_above: bool,
     /// Whether `upcoming` (except the last item) has been normalized already
     upcoming_normalized: bool,
+    /// Whether a prefix has matched since `take_prefix_matched` was last called
+    prefix_matched: bool,
     #[cfg(debug_assertions)]
     /// Whether `iter` has been exhausted
     iter_exhausted: bool,
@@ -947,6 +954,7 @@ where
             numeric_primary,
             lithuanian_dot_above,
             upcoming_normalized: false,
+            prefix_matched: false,
             #[cfg(debug_assertions)]
             iter_exhausted: false,
             #[cfg(debug_assertions)]
@@ -1011,6 +1019,30 @@ where
         }
     }
 
+    /// The iterator that the characters are read from.
+    pub fn delegate(&self) -> &I {
+        &self.iter
+    }
+
+    /// Whether `c`, the character most recently read from `iter`, is the only
+    /// character read so far that hasn't been mapped to already returned
+    /// `CollationElement`s, i.e. whether the following collation elements
+    /// are those of `c` and the characters after it.
+    pub fn is_at_last_read(&self, c: char) -> bool {
+        self.pending.is_empty()
+            && matches!(
+                self.upcoming.as_slice(),
+                [upcoming] if upcoming.character() == c
+                    && !upcoming.decomposition_starts_with_non_starter()
+            )
+    }
+
+    /// Returns whether a prefix has matched since the last call, which
+    /// makes collation elements depend on the preceding characters.
+    pub fn take_prefix_matched(&mut self) -> bool {
+        core::mem::take(&mut self.prefix_matched)
+    }
+
     fn iter_next(&mut self) -> Option<CharacterAndClassAndTrieValue> {
         let c = self.iter.next()?;
         let trie_val = self.trie.get(c);
@@ -1862,9 +1894,11 @@ where
                                         }
                                         TrieResult::Intermediate(ce32_i) => {
                                             ce32 = CollationElement32::new(ce32_i as u32);
+                                            self.prefix_matched = true;
                                         }
                                         TrieResult::FinalValue(ce32_i) => {
                                             ce32 = CollationElement32::new(ce32_i as u32);
+                                            self.prefix_matched = true;
                                             continue 'ce32loop;
                                         }
                                     }
@@ -343,6 +343,7 @@
 
 extern crate alloc;
 
+mod collation_elements;
 mod comparison;
 #[cfg(doc)]
 pub mod docs;
@@ -355,10 +356,21 @@ mod elements;
 
 pub mod options;
 pub mod provider;
+mod reordering;
+#[cfg(feature = "icu_segmenter")]
+mod search;
+mod tailoring;
 
+pub use collation_elements::CollationElement;
 pub use comparison::Collator;
 pub use comparison::CollatorBorrowed;
 pub use comparison::CollatorPreferences;
+#[cfg(feature = "icu_segmenter")]
+pub use search::SearchOptions;
+#[cfg(feature = "icu_segmenter")]
+pub use search::StringSearcher;
+pub use tailoring::TailoringBuilder;
+pub use tailoring::TailoringError;
 
 #[cfg(feature = "unstable")]
 pub use comparison::CollationKeySink;
@@ -373,5 +385,11 @@ pub mod preferences {
     pub use icu_locale_core::preferences::extensions::unicode::keywords::CollationNumericOrdering;
     /// **This is a reexport of a type in [`icu::locale`](icu_locale_core::preferences::extensions::unicode::keywords)**.
     #[doc = "\n"] // prevent autoformatting
+    pub use icu_locale_core::preferences::extensions::unicode::keywords::CollationReorderCode;
+    /// **This is a reexport of a type in [`icu::locale`](icu_locale_core::preferences::extensions::unicode::keywords)**.
+    #[doc = "\n"] // prevent autoformatting
+    pub use icu_locale_core::preferences::extensions::unicode::keywords::CollationReorderCodes;
+    /// **This is a reexport of a type in [`icu::locale`](icu_locale_core::preferences::extensions::unicode::keywords)**.
+    #[doc = "\n"] // prevent autoformatting
     pub use icu_locale_core::preferences::extensions::unicode::keywords::CollationType;
 }
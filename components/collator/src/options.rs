@@ -11,7 +11,7 @@
 use crate::{
     CollatorPreferences,
     elements::{CASE_MASK, TERTIARY_MASK},
-    preferences::{CollationCaseFirst, CollationNumericOrdering},
+    preferences::{CollationCaseFirst, CollationNumericOrdering, CollationReorderCodes},
     provider::CollationMetadata,
 };
 
@@ -279,8 +279,8 @@ pub enum CaseLevel {
 
 /// Options settable by the user of the API.
 ///
-/// With the exception of reordering (BCP47 `kr`), options that can by implied by locale are
-/// set via [`CollatorPreferences`].
+/// With the exception of reordering (BCP47 `kr`), which can be set either way, options that
+/// can by implied by locale are set via [`CollatorPreferences`].
 ///
 /// See the [spec](https://www.unicode.org/reports/tr35/tr35-collation.html#Setting_Options).
 ///
@@ -321,10 +321,30 @@ pub enum CaseLevel {
 /// See the [spec](https://www.unicode.org/reports/tr35/tr35-collation.html#Case_Parameters).
 /// This is the BCP47 key `kc`. The default is [`CaseLevel::Off`].
 ///
-/// # Unsupported BCP47 options
+/// ## Reordering
+///
+/// See the [spec](https://www.unicode.org/reports/tr35/tr35-collation.html#Script_Reordering).
+/// This is the BCP47 key `kr`, which is prohibited by ECMA-402. The reorder codes set here
+/// take precedence over the ones in [`CollatorPreferences`], and both replace the reordering
+/// implied by the locale of the collation (e.g. Cyrillic first for Russian). The default
+/// is the reordering implied by the locale.
+///
+/// ```
+/// use icu::collator::{options::*, preferences::*, *};
+/// use icu::locale::subtags::script;
 ///
-/// Reordering (BCP47 `kr`) currently cannot be set via the API and is implied
-/// by the locale of the collation. `kr` is prohibited by ECMA-402.
+/// let mut options = CollatorOptions::default();
+/// options.reorder_codes = Some(
+///     CollationReorderCodes::try_from_slice(&[CollationReorderCode::Script(
+///         script!("Grek"),
+///     )])
+///     .unwrap(),
+/// );
+/// let collator = Collator::try_new(Default::default(), options).unwrap();
+/// assert_eq!(collator.compare("Ω", "A"), core::cmp::Ordering::Less);
+/// ```
+///
+/// # Unsupported BCP47 options
 ///
 /// Backward second level (BCP47 `kb`) cannot be set via the API and is implied
 /// by the locale of the collation (in practice only `fr-CA` turns it on and it's
@@ -343,9 +363,10 @@ pub enum CaseLevel {
 /// ## ECMA-402 Usage
 ///
 /// ECMA-402 `usage: "search"` is represented as `-u-co-search` as part of the
-/// locale in ICU4X. However, neither ECMA-402 nor ICU4X provides prefix matching
-/// or substring matching API surface. This makes the utility of search collations
-/// very narrow: With `-u-co-search`, [`Strength::Primary`], and observing whether
+/// locale in ICU4X. However, ECMA-402 provides no prefix matching or substring
+/// matching API surface. In ICU4X, substring matching is provided by
+/// `StringSearcher` (with the `icu_segmenter` Cargo feature). Otherwise, the utility of search
+/// collations is very narrow: With `-u-co-search`, [`Strength::Primary`], and observing whether
 /// comparison output is [`core::cmp::Ordering::Equal`] (making no distinction between
 /// [`core::cmp::Ordering::Less`] and [`core::cmp::Ordering::Greater`]), it is
 /// possible to check if a set of human-readable strings contains a full-string
@@ -366,6 +387,8 @@ pub struct CollatorOptions {
     pub max_variable: Option<MaxVariable>,
     /// User-specified case level collation option.
     pub case_level: Option<CaseLevel>,
+    /// User-specified reordering collation option.
+    pub reorder_codes: Option<CollationReorderCodes>,
 }
 
 impl CollatorOptions {
@@ -376,6 +399,7 @@ impl CollatorOptions {
             alternate_handling: None,
             max_variable: None,
             case_level: None,
+            reorder_codes: None,
         }
     }
 }
@@ -392,6 +416,7 @@ impl From<ResolvedCollatorOptions> for CollatorOptions {
             alternate_handling: Some(options.alternate_handling),
             max_variable: Some(options.max_variable),
             case_level: Some(options.case_level),
+            reorder_codes: None,
         }
     }
 }
@@ -533,7 +558,7 @@ impl CollatorOptionsBitField {
     }
 
     /// This is the BCP47 key `ks`. See the enum for examples.
-    const fn set_strength(&mut self, strength: Option<Strength>) {
+    pub(crate) const fn set_strength(&mut self, strength: Option<Strength>) {
         self.0 &= !CollatorOptionsBitField::STRENGTH_MASK;
         if let Some(strength) = strength {
             self.0 |= CollatorOptionsBitField::EXPLICIT_STRENGTH_MASK;
@@ -580,7 +605,10 @@ impl CollatorOptionsBitField {
 
     /// Whether certain characters are moved from the primary level to
     /// the quaternary level. See the enum for examples.
-    const fn set_alternate_handling(&mut self, alternate_handling: Option<AlternateHandling>) {
+    pub(crate) const fn set_alternate_handling(
+        &mut self,
+        alternate_handling: Option<AlternateHandling>,
+    ) {
         self.0 &= !CollatorOptionsBitField::ALTERNATE_HANDLING_MASK;
         if let Some(alternate_handling) = alternate_handling {
             self.0 |= CollatorOptionsBitField::EXPLICIT_ALTERNATE_HANDLING_MASK;
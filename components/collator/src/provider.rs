@@ -20,7 +20,10 @@
 
 use icu_collections::char16trie::Char16TrieIterator;
 use icu_collections::codepointtrie::CodePointTrie;
+use icu_locale_core::subtags::Script;
 use icu_provider::prelude::*;
+use tinystr::UnvalidatedTinyAsciiStr;
+use zerovec::ZeroMap;
 use zerovec::ZeroVec;
 use zerovec::ule::AsULE;
 use zerovec::{ZeroSlice, zeroslice};
@@ -38,6 +41,8 @@ use crate::preferences::CollationCaseFirst;
 
 use crate::options::MaxVariable;
 
+type UnvalidatedScript = UnvalidatedTinyAsciiStr<4>;
+
 #[cfg(feature = "compiled_data")]
 #[derive(Debug)]
 /// Baked data
@@ -65,6 +70,7 @@ const _: () = {
     impl_collation_metadata_v1!(Baked);
     impl_collation_special_primaries_v1!(Baked);
     impl_collation_reordering_v1!(Baked);
+    impl_collation_script_starts_v1!(Baked);
 };
 
 const SCRIPT_FALLBACK: icu_provider::fallback::LocaleFallbackConfig = {
@@ -116,6 +122,13 @@ icu_provider::data_marker!(
     #[cfg(feature = "datagen")]
     attributes_domain = "collator",
 );
+icu_provider::data_marker!(
+    /// Data marker for the script starts in the root collation.
+    CollationScriptStartsV1,
+    "collation/script/starts/v1",
+    CollationScriptStarts<'static>,
+    is_singleton = true,
+);
 icu_provider::data_marker!(
     /// Data marker for collation metadata.
     CollationMetadataV1,
@@ -145,6 +158,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     CollationMetadataV1::INFO,
     CollationReorderingV1::INFO,
     CollationSpecialPrimariesV1::INFO,
+    CollationScriptStartsV1::INFO,
 ];
 
 const SINGLE_U32: &ZeroSlice<u32> =
@@ -462,6 +476,43 @@ impl CollationReordering<'_> {
     }
 }
 
+/// The first primary weight of each script in the root collation, like
+/// `scriptStarts` in ICU4C.
+///
+/// Reorder codes move the primaries between the start of a script and the
+/// start of the next one together. Scripts that are sorted together, like
+/// Hiragana and Katakana, have the same start.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_collator::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct CollationScriptStarts<'data> {
+    /// Maps each script with characters in the root collation to the
+    /// primary weight of the `U+FDD1` contraction that marks its start.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub starts: ZeroMap<'data, UnvalidatedScript, u32>,
+}
+
+icu_provider::data_struct!(
+    CollationScriptStarts<'_>,
+    #[cfg(feature = "datagen")]
+);
+
+impl CollationScriptStarts<'_> {
+    /// The first primary of `script`, if it has characters in the root collation.
+    pub(crate) fn get(&self, script: Script) -> Option<u32> {
+        self.starts
+            .get_copied(&script.to_tinystr().to_unvalidated())
+    }
+}
+
 /// Each non-alias collation that the data provider knows
 /// about explicitly has an data entry at least for this
 /// struct.
@@ -494,15 +545,15 @@ icu_provider::data_struct!(
 );
 
 impl CollationMetadata {
-    const MAX_VARIABLE_MASK: u32 = 0b11;
-    const TAILORED_MASK: u32 = 1 << 3;
-    const TAILORED_DIACRITICS_MASK: u32 = 1 << 4;
+    pub(crate) const MAX_VARIABLE_MASK: u32 = 0b11;
+    pub(crate) const TAILORED_MASK: u32 = 1 << 3;
+    pub(crate) const TAILORED_DIACRITICS_MASK: u32 = 1 << 4;
     const REORDERING_MASK: u32 = 1 << 5;
     const LITHUANIAN_DOT_ABOVE_MASK: u32 = 1 << 6;
-    const BACWARD_SECOND_LEVEL_MASK: u32 = 1 << 7;
-    const ALTERNATE_SHIFTED_MASK: u32 = 1 << 8;
-    const CASE_FIRST_MASK: u32 = 1 << 9;
-    const UPPER_FIRST_MASK: u32 = 1 << 10;
+    pub(crate) const BACWARD_SECOND_LEVEL_MASK: u32 = 1 << 7;
+    pub(crate) const ALTERNATE_SHIFTED_MASK: u32 = 1 << 8;
+    pub(crate) const CASE_FIRST_MASK: u32 = 1 << 9;
+    pub(crate) const UPPER_FIRST_MASK: u32 = 1 << 10;
 
     #[inline(always)]
     pub(crate) const fn max_variable(self) -> MaxVariable {
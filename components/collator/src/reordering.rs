@@ -0,0 +1,423 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The computation of the reordering is adapted from ICU4C
+// (`CollationData::makeReorderRanges()` and `CollationSettings::setReordering()`)
+// and, therefore, is subject to the ICU license as described in LICENSE.
+
+//! Reordering of scripts and special groups of characters requested with
+//! reorder codes (BCP47 `kr`) instead of implied by the locale data.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use icu_locale_core::subtags::script;
+use icu_provider::prelude::*;
+
+use crate::CollatorBorrowed;
+use crate::elements::{CollationElement32, NO_CE_PRIMARY, UNASSIGNED_IMPLICIT_BYTE};
+use crate::preferences::CollationReorderCode;
+use crate::provider::{CollationReorderingV1, CollationScriptStarts};
+
+/// The start of the space group, right after the merge separator.
+const FIRST_GROUP_START: u16 = 0x0300;
+
+/// The start of the trail weights, which are never reordered.
+const TRAIL_WEIGHT_START: u16 = 0xFF00;
+
+/// The number of special groups: space, punctuation, symbols, currency
+/// symbols, and digits.
+const SPECIAL_GROUP_COUNT: usize = 5;
+
+/// The maximum number of reorder ranges. A list of reorder codes of the
+/// maximum length needs fewer.
+const MAX_REORDER_RANGES: usize = 48;
+
+/// A reordering computed from reorder codes, which works like
+/// [`CollationReordering`](crate::provider::CollationReordering) from
+/// locale data, but is computed at run time.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct CustomReordering {
+    min_high_no_reorder: u32,
+    reorder_table: [u8; 256],
+    reorder_ranges: [u32; MAX_REORDER_RANGES],
+    reorder_ranges_len: u8,
+}
+
+impl CustomReordering {
+    /// Computes the reordering for `codes` with the root collation of `collator`
+    /// and its `script_starts`.
+    ///
+    /// Returns `None` if the codes result in the root order.
+    pub(crate) fn try_new(
+        collator: &CollatorBorrowed,
+        script_starts: &CollationScriptStarts,
+        codes: &[CollationReorderCode],
+    ) -> Result<Option<Self>, DataError> {
+        let invalid =
+            || DataError::custom("reorder codes").with_marker(CollationReorderingV1::INFO);
+        let groups = ReorderGroups::try_new(collator, script_starts).ok_or_else(invalid)?;
+        // Scripts without characters in the root collation are ignored.
+        let groups_of_codes: Vec<Option<usize>> = codes
+            .iter()
+            .filter_map(|&code| match code {
+                CollationReorderCode::Others => Some(None),
+                code => groups.group(script_starts, code).map(Some),
+            })
+            .collect();
+        if groups_of_codes.is_empty() || groups_of_codes == [None] {
+            return Ok(None);
+        }
+        let ranges = groups
+            .reorder_ranges(&groups_of_codes, false)
+            .ok_or_else(invalid)?;
+        Self::try_from_ranges(&ranges).ok_or_else(invalid)
+    }
+
+    /// The port of `CollationSettings::setReordering()`.
+    fn try_from_ranges(ranges: &[u32]) -> Option<Option<Self>> {
+        let Some(&last) = ranges.last() else {
+            return Some(None);
+        };
+        let mut result = Self {
+            min_high_no_reorder: last & 0xFFFF0000,
+            reorder_table: [0; 256],
+            reorder_ranges: [0; MAX_REORDER_RANGES],
+            reorder_ranges_len: 0,
+        };
+        // Set a 0 for each lead byte that has a range boundary in the middle.
+        let mut b = 0;
+        let mut first_split_byte_range = None;
+        for (i, &range) in ranges.iter().enumerate() {
+            let limit_lead_byte = (range >> 24) as usize;
+            while b < limit_lead_byte {
+                *result.reorder_table.get_mut(b)? = (b as u32).wrapping_add(range) as u8;
+                b += 1;
+            }
+            if range & 0xFF0000 != 0 {
+                *result.reorder_table.get_mut(limit_lead_byte)? = 0;
+                b = limit_lead_byte + 1;
+                first_split_byte_range.get_or_insert(i);
+            }
+        }
+        for (i, entry) in result.reorder_table.iter_mut().enumerate().skip(b) {
+            *entry = i as u8;
+        }
+        // The lead byte table alone suffices before the first split byte.
+        if let Some(first) = first_split_byte_range {
+            let ranges = ranges.get(first..)?;
+            result
+                .reorder_ranges
+                .get_mut(..ranges.len())?
+                .copy_from_slice(ranges);
+            result.reorder_ranges_len = ranges.len() as u8;
+        }
+        Some(Some(result))
+    }
+
+    /// Moves `primary` to its reordered position.
+    pub(crate) fn reorder(&self, primary: u32) -> u32 {
+        let b = self
+            .reorder_table
+            .get((primary >> 24) as usize)
+            .copied()
+            .unwrap_or_default();
+        if b != 0 || primary <= NO_CE_PRIMARY {
+            return (u32::from(b) << 24) | (primary & 0x00FFFFFF);
+        }
+        if primary >= self.min_high_no_reorder {
+            return primary;
+        }
+        let q = primary | 0xFFFF;
+        self.reorder_ranges
+            .iter()
+            .take(usize::from(self.reorder_ranges_len))
+            .find(|&&range| q < range)
+            .map_or(primary, |&range| primary.wrapping_add(range << 24))
+    }
+}
+
+/// The groups of primary weights in the root collation that are reordered
+/// as a unit, like `scriptStarts` in ICU4C.
+#[derive(Debug)]
+struct ReorderGroups {
+    /// The upper 16 bits of the first primary of each group in ascending
+    /// order. The first group holds the terminators and separators, and the
+    /// last entry is the limit of the last group.
+    starts: Vec<u16>,
+    /// The special groups in the order of [`CollationReorderCode`].
+    specials: [usize; SPECIAL_GROUP_COUNT],
+    latin: usize,
+    /// Ranges without primaries right before Latin and right after it, which
+    /// make room for moving other groups without moving Latin.
+    reserved: [Option<usize>; 2],
+}
+
+impl ReorderGroups {
+    fn try_new(collator: &CollatorBorrowed, script_starts: &CollationScriptStarts) -> Option<Self> {
+        // The limits of the special groups before digits are the last primaries
+        // for max variable.
+        let mut special_starts = [FIRST_GROUP_START; SPECIAL_GROUP_COUNT];
+        for (start, limit) in special_starts
+            .iter_mut()
+            .skip(1)
+            .zip(collator.special_primaries().last_primaries.iter())
+        {
+            *start = limit;
+        }
+        let mut starts = vec![0];
+        starts.extend_from_slice(&special_starts);
+
+        let mut script_primaries: Vec<u32> = script_starts
+            .starts
+            .iter_copied_values()
+            .map(|(_, primary)| primary)
+            .collect();
+        script_primaries.sort_unstable();
+        script_primaries.dedup();
+        let mut previous_lead_byte = special_starts.last().map(|&s| (s >> 8) as u8);
+        for primary in script_primaries {
+            let lead_byte = (primary >> 24) as u8;
+            // A group starts at its lead byte unless it shares the lead byte with
+            // the previous group.
+            starts.push(if previous_lead_byte == Some(lead_byte) {
+                (primary >> 16) as u16
+            } else {
+                u16::from(lead_byte) << 8
+            });
+            previous_lead_byte = Some(lead_byte);
+        }
+        starts.push(u16::from(UNASSIGNED_IMPLICIT_BYTE) << 8);
+        starts.push(TRAIL_WEIGHT_START);
+        starts.sort_unstable();
+        starts.dedup();
+
+        let group_of = |starts: &[u16], start: u16| starts.binary_search(&start).ok();
+        let latin_primary = script_starts.get(script!("Latn"))?;
+        let latin = starts
+            .partition_point(|&s| s <= (latin_primary >> 16) as u16)
+            .checked_sub(1)?;
+        let before_latin = *starts.get(latin.checked_sub(1)?)?;
+        let latin_start = *starts.get(latin)?;
+        let after_latin = *starts.get(latin + 1)?;
+
+        let used = used_lead_bytes(collator);
+        let unused_before = |limit: u16, floor: u16| {
+            if limit & 0xFF != 0 {
+                return None;
+            }
+            let floor = (floor >> 8) as usize;
+            let mut lead_byte = usize::from(limit >> 8);
+            while lead_byte > floor + 1 && used.get(lead_byte - 1) == Some(&false) {
+                lead_byte -= 1;
+            }
+            let start = (lead_byte as u16) << 8;
+            (start < limit).then_some(start)
+        };
+        let reserved_starts = [
+            unused_before(latin_start, before_latin),
+            unused_before(after_latin, latin_start),
+        ];
+        starts.extend(reserved_starts.iter().flatten());
+        starts.sort_unstable();
+
+        let mut specials = [0; SPECIAL_GROUP_COUNT];
+        for (group, &start) in specials.iter_mut().zip(&special_starts) {
+            *group = group_of(&starts, start)?;
+        }
+        Some(Self {
+            specials,
+            latin: group_of(&starts, latin_start)?,
+            reserved: reserved_starts.map(|start| group_of(&starts, start?)),
+            starts,
+        })
+    }
+
+    /// The number of groups.
+    fn len(&self) -> usize {
+        self.starts.len() - 1
+    }
+
+    /// The upper 16 bits of the first primary of `group`.
+    fn start(&self, group: usize) -> i32 {
+        i32::from(
+            self.starts
+                .get(group)
+                .copied()
+                .unwrap_or(TRAIL_WEIGHT_START),
+        )
+    }
+
+    /// The group of `code`, if the root collation has characters for it.
+    fn group(
+        &self,
+        script_starts: &CollationScriptStarts,
+        code: CollationReorderCode,
+    ) -> Option<usize> {
+        let special = match code {
+            CollationReorderCode::Space => 0,
+            CollationReorderCode::Punctuation => 1,
+            CollationReorderCode::Symbol => 2,
+            CollationReorderCode::Currency => 3,
+            CollationReorderCode::Digit => 4,
+            CollationReorderCode::Script(script) => {
+                let primary = script_starts.get(script)?;
+                return self
+                    .starts
+                    .partition_point(|&s| s <= (primary >> 16) as u16)
+                    .checked_sub(1);
+            }
+            _ => return None,
+        };
+        self.specials.get(special).copied()
+    }
+
+    /// Assigns the lead byte at `low_start` to `group` and returns the start for the next one.
+    fn add_low_group(&self, table: &mut [u8], group: usize, mut low_start: i32) -> i32 {
+        let start = self.start(group);
+        if (start & 0xFF) < (low_start & 0xFF) {
+            low_start += 0x100;
+        }
+        if let Some(entry) = table.get_mut(group) {
+            *entry = (low_start >> 8) as u8;
+        }
+        let limit = self.start(group + 1);
+        ((low_start & 0xFF00) + ((limit & 0xFF00) - (start & 0xFF00))) | (limit & 0xFF)
+    }
+
+    /// Assigns the lead bytes below `high_limit` to `group` and returns the limit for the
+    /// previous one.
+    fn add_high_group(&self, table: &mut [u8], group: usize, mut high_limit: i32) -> i32 {
+        let limit = self.start(group + 1);
+        if (limit & 0xFF) > (high_limit & 0xFF) {
+            high_limit -= 0x100;
+        }
+        let start = self.start(group);
+        high_limit =
+            ((high_limit & 0xFF00) - ((limit & 0xFF00) - (start & 0xFF00))) | (start & 0xFF);
+        if let Some(entry) = table.get_mut(group) {
+            *entry = (high_limit >> 8) as u8;
+        }
+        high_limit
+    }
+
+    /// The port of `CollationData::makeReorderRanges()`.
+    ///
+    /// `codes` holds the groups in the requested order and `None` for
+    /// [`CollationReorderCode::Others`]. Each range has the upper 16 bits of its
+    /// primary limit in the upper 16 bits and the signed offset of its lead bytes
+    /// in the lower 16 bits.
+    ///
+    /// Returns `None` if the groups do not fit the primary lead bytes.
+    fn reorder_ranges(&self, codes: &[Option<usize>], latin_must_move: bool) -> Option<Vec<u32>> {
+        // The new lead byte of each group, 0 if not assigned yet, and 0xFF for
+        // "don't care".
+        let mut table = vec![0u8; self.len()];
+        for &group in self.reserved.iter().flatten() {
+            *table.get_mut(group)? = 0xFF;
+        }
+        let mut low_start = i32::from(FIRST_GROUP_START);
+        let mut high_limit = i32::from(TRAIL_WEIGHT_START);
+
+        // Start with the special groups that are not listed.
+        let has_specials = self.specials.iter().any(|&s| codes.contains(&Some(s)));
+        for &group in &self.specials {
+            if !codes.contains(&Some(group)) {
+                low_start = self.add_low_group(&mut table, group, low_start);
+            }
+        }
+
+        // Do not move Latin unnecessarily if it comes first.
+        let mut skipped_reserved = 0;
+        if !has_specials && codes.first() == Some(&Some(self.latin)) && !latin_must_move {
+            let start = self.start(self.latin);
+            skipped_reserved = start - low_start;
+            low_start = start;
+        }
+
+        let mut has_reorder_to_end = false;
+        for (i, &code) in codes.iter().enumerate() {
+            let Some(group) = code else {
+                // Move the groups after "others" to the end, starting with the last one.
+                has_reorder_to_end = true;
+                for &group in codes.get(i + 1..)?.iter().rev().flatten() {
+                    // Scripts that are sorted together are listed once.
+                    if table.get(group) == Some(&0) {
+                        high_limit = self.add_high_group(&mut table, group, high_limit);
+                    }
+                }
+                break;
+            };
+            if table.get(group) == Some(&0) {
+                low_start = self.add_low_group(&mut table, group, low_start);
+            }
+        }
+
+        // Put all the remaining groups into the middle.
+        for group in 1..self.len() {
+            if table.get(group) != Some(&0) {
+                continue;
+            }
+            let start = self.start(group);
+            if !has_reorder_to_end && start > low_start {
+                // No need to move this group.
+                low_start = start;
+            }
+            low_start = self.add_low_group(&mut table, group, low_start);
+        }
+        if low_start > high_limit {
+            if low_start - (skipped_reserved & 0xFF00) <= high_limit {
+                // Try not skipping the reserved range.
+                return self.reorder_ranges(codes, true);
+            }
+            return None;
+        }
+
+        // Turn the lead bytes into (limit, offset) pairs.
+        let mut ranges = Vec::new();
+        let mut offset = 0;
+        let mut group = 1;
+        loop {
+            let mut next_offset = offset;
+            while group < self.len() {
+                let new_lead_byte = *table.get(group)?;
+                // Continue with the current offset for "don't care".
+                if new_lead_byte != 0xFF {
+                    next_offset = i32::from(new_lead_byte) - (self.start(group) >> 8);
+                    if next_offset != offset {
+                        break;
+                    }
+                }
+                group += 1;
+            }
+            if offset != 0 || group < self.len() {
+                ranges.push(((self.start(group) as u32) << 16) | (offset as u32 & 0xFFFF));
+            }
+            if group == self.len() {
+                break;
+            }
+            offset = next_offset;
+        }
+        Some(ranges)
+    }
+}
+
+/// Whether each primary lead byte occurs in the root collation.
+fn used_lead_bytes(collator: &CollatorBorrowed) -> [bool; 256] {
+    let root = collator.root();
+    let mut used = [false; 256];
+    let mut mark = |primary: u32| {
+        if let Some(entry) = used.get_mut((primary >> 24) as usize) {
+            *entry = true;
+        }
+    };
+    root.trie
+        .iter_ranges()
+        .map(|range| range.value)
+        .chain(root.ce32s.iter())
+        .filter_map(|ce32| CollationElement32::new(ce32).to_ce_simple_or_long_primary())
+        .for_each(|ce| mark(ce.primary()));
+    root.ces.iter().for_each(|ce| mark((ce >> 32) as u32));
+    used
+}
@@ -0,0 +1,315 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The matching semantics follow ICU4C `usearch`: A match is a range of
+// the text delimited by grapheme cluster boundaries whose collation
+// elements, reduced to the search strength, are the same as those of the
+// pattern.
+
+//! Searching text for matches that are collation-equivalent to a pattern.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+#[cfg(feature = "compiled_data")]
+use icu_segmenter::GraphemeClusterSegmenter;
+use icu_segmenter::GraphemeClusterSegmenterBorrowed;
+use utf16_iter::Utf16CharsEx;
+
+use crate::CollatorBorrowed;
+use crate::comparison::MERGE_SEPARATOR_PRIMARY;
+use crate::elements::{NO_CE_PRIMARY, TERTIARY_MASK};
+use crate::options::{AlternateHandling, CollatorOptionsBitField, Strength};
+
+/// Options for [`StringSearcher`].
+///
+/// Options that are `None` are taken from the collator.
+///
+/// ✨ *Enabled with the `icu_segmenter` Cargo feature.*
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// The strength at which the pattern and the text are compared.
+    ///
+    /// For example, at [`Strength::Primary`], "cafe" matches "Café".
+    pub strength: Option<Strength>,
+    /// Whether characters such as spaces and punctuation are ignored.
+    ///
+    /// With [`AlternateHandling::Shifted`], "e-mail" matches "email"
+    /// below [`Strength::Quaternary`].
+    pub alternate_handling: Option<AlternateHandling>,
+}
+
+impl SearchOptions {
+    /// Create a new `SearchOptions` with the defaults.
+    pub const fn default() -> Self {
+        Self {
+            strength: None,
+            alternate_handling: None,
+        }
+    }
+}
+
+/// A collation element reduced to the weights that matter at the search strength.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Key {
+    /// The primary weight in the high half, lower levels in the low half.
+    Weights(u64),
+    /// The primary weight of a variable collation element that has been
+    /// shifted to the quaternary level.
+    Shifted(u32),
+}
+
+/// Finds the ranges of a text that are equal to a pattern according to a collator.
+///
+/// Matches start and end on grapheme cluster boundaries and never split a
+/// contraction of the collator, so "c" does not match the beginning of "chata"
+/// in Czech, and "e" does not match "é" at the secondary strength, even if the
+/// accent is a separate combining character. Matches do not overlap.
+///
+/// ✨ *Enabled with the `icu_segmenter` Cargo feature.*
+///
+/// # Example
+///
+/// ```
+/// use icu::collator::options::{CollatorOptions, Strength};
+/// use icu::collator::{Collator, SearchOptions, StringSearcher};
+/// use icu::locale::locale;
+///
+/// let mut options = CollatorOptions::default();
+/// options.strength = Some(Strength::Primary);
+/// let collator = Collator::try_new(locale!("fr").into(), options).unwrap();
+///
+/// let searcher =
+///     StringSearcher::new(&collator, "cafe", SearchOptions::default());
+/// let text = "Café au lait, CAFÉ crème";
+/// assert_eq!(
+///     searcher.find_iter_str(text).collect::<Vec<_>>(),
+///     [0..5, 15..20]
+/// );
+///
+/// // The same at the secondary strength only finds the unaccented letters.
+/// let mut search_options = SearchOptions::default();
+/// search_options.strength = Some(Strength::Secondary);
+/// let searcher = StringSearcher::new(&collator, "cafe", search_options);
+/// assert_eq!(searcher.find_str(text), None);
+/// assert_eq!(searcher.find_str("Cafe, café"), Some(0..4));
+/// ```
+#[derive(Debug)]
+pub struct StringSearcher<'a> {
+    collator: &'a CollatorBorrowed<'a>,
+    segmenter: GraphemeClusterSegmenterBorrowed<'a>,
+    options: CollatorOptionsBitField,
+    variable_top: u32,
+    tertiary_mask: u16,
+    pattern_keys: Vec<Key>,
+    /// The pattern itself for comparing the NFD forms at the identical strength.
+    pattern: Option<String>,
+}
+
+impl<'a> StringSearcher<'a> {
+    /// Creates a searcher for `pattern` with grapheme cluster boundaries from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    #[cfg(feature = "compiled_data")]
+    pub fn new(collator: &'a CollatorBorrowed<'a>, pattern: &str, options: SearchOptions) -> Self {
+        Self::new_with_segmenter(collator, GraphemeClusterSegmenter::new(), pattern, options)
+    }
+
+    /// Creates a searcher for `pattern` with the grapheme cluster boundaries of `segmenter`.
+    pub fn new_with_segmenter(
+        collator: &'a CollatorBorrowed<'a>,
+        segmenter: GraphemeClusterSegmenterBorrowed<'a>,
+        pattern: &str,
+        options: SearchOptions,
+    ) -> Self {
+        let mut merged = collator.options_bit_field();
+        if let Some(strength) = options.strength {
+            merged.set_strength(Some(strength));
+        }
+        if let Some(alternate_handling) = options.alternate_handling {
+            merged.set_alternate_handling(Some(alternate_handling));
+        }
+        let mut searcher = Self {
+            collator,
+            segmenter,
+            options: merged,
+            variable_top: collator.variable_top_for(merged),
+            tertiary_mask: merged.tertiary_mask().unwrap_or(TERTIARY_MASK),
+            pattern_keys: Vec::new(),
+            pattern: None,
+        };
+        searcher.pattern_keys = searcher.keys(pattern.chars()).collect();
+        if merged.strength() == Strength::Identical {
+            searcher.pattern = Some(pattern.into());
+        }
+        searcher
+    }
+
+    /// Returns the byte range of the first match in `haystack`, if any.
+    pub fn find_str(&self, haystack: &str) -> Option<Range<usize>> {
+        self.find_iter_str(haystack).next()
+    }
+
+    /// Returns an iterator over the byte ranges of the matches in `haystack`.
+    pub fn find_iter_str<'s>(
+        &'s self,
+        haystack: &'s str,
+    ) -> impl Iterator<Item = Range<usize>> + 's {
+        let boundaries = self.segmenter.segment_str(haystack).collect();
+        self.matches(boundaries, move |range| {
+            haystack.get(range).unwrap_or_default().chars()
+        })
+    }
+
+    /// Returns the code unit range of the first match in potentially ill-formed
+    /// UTF-16 `haystack`, if any.
+    ///
+    /// Unpaired surrogates are treated as REPLACEMENT CHARACTERs.
+    pub fn find_utf16(&self, haystack: &[u16]) -> Option<Range<usize>> {
+        self.find_iter_utf16(haystack).next()
+    }
+
+    /// Returns an iterator over the code unit ranges of the matches in potentially
+    /// ill-formed UTF-16 `haystack`.
+    ///
+    /// Unpaired surrogates are treated as REPLACEMENT CHARACTERs.
+    pub fn find_iter_utf16<'s>(
+        &'s self,
+        haystack: &'s [u16],
+    ) -> impl Iterator<Item = Range<usize>> + 's {
+        let boundaries = self.segmenter.segment_utf16(haystack).collect();
+        self.matches(boundaries, move |range| {
+            haystack.get(range).unwrap_or_default().chars()
+        })
+    }
+
+    /// The matches in a text with grapheme cluster `boundaries`, where `chars`
+    /// returns the characters of the text in a range between boundaries.
+    fn matches<'s, F, I>(
+        &'s self,
+        boundaries: Vec<usize>,
+        chars: F,
+    ) -> impl Iterator<Item = Range<usize>> + 's
+    where
+        F: Fn(Range<usize>) -> I + 's,
+        I: Iterator<Item = char>,
+    {
+        let len = boundaries.last().copied().unwrap_or_default();
+        let pattern_len = self.pattern_keys.len();
+        let mut candidate = Vec::new();
+        // The index of the boundary to try as the start of the next match
+        let mut next = 0;
+        core::iter::from_fn(move || {
+            if pattern_len == 0 {
+                return None;
+            }
+            while let Some((&from, rest)) = boundaries.get(next..).and_then(|b| b.split_first()) {
+                next += 1;
+                // Cheap rejection: the text from here on has to begin with the pattern.
+                // This also rejects the ends that would split a contraction.
+                if !self
+                    .keys(chars(from..len))
+                    .take(pattern_len)
+                    .eq(self.pattern_keys.iter().copied())
+                {
+                    continue;
+                }
+                for (i, &to) in rest.iter().enumerate() {
+                    candidate.clear();
+                    candidate.extend(self.keys(chars(from..to)).take(pattern_len + 1));
+                    if i == 0 && candidate.is_empty() {
+                        // Do not start a match with an ignorable cluster.
+                        break;
+                    }
+                    if candidate.len() > pattern_len {
+                        break;
+                    }
+                    if candidate == self.pattern_keys && self.is_identical(&chars, from..to) {
+                        next += i;
+                        return Some(from..to);
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    /// Whether the text in `range` is identical to the pattern in NFD if the
+    /// strength is identical, and `true` otherwise.
+    fn is_identical<F, I>(&self, chars: &F, range: Range<usize>) -> bool
+    where
+        F: Fn(Range<usize>) -> I,
+        I: Iterator<Item = char>,
+    {
+        let Some(pattern) = &self.pattern else {
+            return true;
+        };
+        let nfd = self.collator.nfd();
+        nfd.normalize_iter(pattern.chars())
+            .eq(nfd.normalize_iter(chars(range)))
+    }
+
+    /// The keys of the collation elements of `chars` that are not ignorable at the
+    /// search strength.
+    fn keys<I>(&self, chars: I) -> impl Iterator<Item = Key>
+    where
+        I: Iterator<Item = char>,
+    {
+        let mut elements = self.collator.collation_elements(chars);
+        let strength = self.options.strength();
+        let case_level = self.options.case_level();
+        let mut after_variable = false;
+        core::iter::from_fn(move || {
+            loop {
+                let ce = elements.next();
+                let primary = ce.primary();
+                if primary == NO_CE_PRIMARY {
+                    return None;
+                }
+                if primary < self.variable_top && primary > MERGE_SEPARATOR_PRIMARY {
+                    // Shifted like in the sort key: The variable collation element moves to
+                    // the quaternary level and the primary ignorables after it are ignored.
+                    after_variable = true;
+                    if strength >= Strength::Quaternary {
+                        return Some(Key::Shifted(primary));
+                    }
+                    continue;
+                }
+                if after_variable && primary == 0 {
+                    continue;
+                }
+                after_variable = false;
+                let non_primary = ce.non_primary();
+                let case = if case_level { non_primary.case() } else { 0 };
+                let lower = match strength {
+                    Strength::Primary => {
+                        if primary == 0 {
+                            continue;
+                        }
+                        u32::from(case)
+                    }
+                    Strength::Secondary => {
+                        let secondary = non_primary.secondary();
+                        if primary == 0 && secondary == 0 {
+                            continue;
+                        }
+                        (u32::from(secondary) << 16) | u32::from(case)
+                    }
+                    _ => {
+                        if non_primary.ignorable() {
+                            continue;
+                        }
+                        (u32::from(non_primary.secondary()) << 16)
+                            | u32::from(
+                                non_primary.tertiary_case_quarternary(self.tertiary_mask) | case,
+                            )
+                    }
+                };
+                return Some(Key::Weights((u64::from(primary) << 32) | u64::from(lower)));
+            }
+        })
+    }
+}
@@ -0,0 +1,529 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The trie formats written and read here are those of ICU4C `UCharsTrie`
+// and `UCPTrie`, and the CE32 encoding follows ICU4C `CollationDataBuilder`.
+
+//! Serialization of tailored mappings into the runtime data formats.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use icu_collections::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieType};
+use zerovec::ZeroVec;
+
+const MIN_LINEAR_MATCH: u16 = 0x30;
+const MAX_LINEAR_MATCH_LENGTH: usize = 0x10;
+const MIN_VALUE_LEAD: u16 = 0x40;
+const NODE_TYPE_MASK: u16 = 0x3f;
+const VALUE_IS_FINAL: u16 = 0x8000;
+const MIN_TWO_UNIT_VALUE_LEAD: u16 = 0x4000;
+const THREE_UNIT_VALUE_LEAD: u16 = 0x7fff;
+const MIN_TWO_UNIT_NODE_VALUE_LEAD: u16 = 0x4040;
+const THREE_UNIT_NODE_VALUE_LEAD: u16 = 0x7fc0;
+const MIN_TWO_UNIT_DELTA_LEAD: u16 = 0xfc00;
+const THREE_UNIT_DELTA_LEAD: u16 = 0xffff;
+const MAX_BRANCH_LINEAR_SUB_NODE_LENGTH: usize = 5;
+
+type Entry = (Vec<u16>, u32);
+
+/// Writes a lead unit followed by a 32-bit payload in two units.
+///
+/// The writer always uses the three-unit forms of values and deltas,
+/// which keeps node sizes independent of the jump distances.
+fn push_three_units(out: &mut Vec<u16>, lead: u16, payload: u32) {
+    out.extend([lead, (payload >> 16) as u16, payload as u16]);
+}
+
+/// Serializes a `Char16Trie` mapping the given keys to values.
+///
+/// The entries must be sorted by key, and the keys must be distinct and non-empty.
+pub(super) fn write_char16_trie(entries: &[Entry]) -> Vec<u16> {
+    let mut out = Vec::new();
+    write_node(entries, 0, &mut out);
+    out
+}
+
+/// Writes the node for `entries`, which all share the first `depth` units.
+fn write_node(entries: &[Entry], depth: usize, out: &mut Vec<u16>) {
+    let (value, rest) = match entries.split_first() {
+        Some(((key, value), rest)) if key.len() == depth => (Some(*value), rest),
+        _ => (None, entries),
+    };
+    let (Some((first, _)), Some((last, _))) = (rest.first(), rest.last()) else {
+        push_three_units(
+            out,
+            VALUE_IS_FINAL | THREE_UNIT_VALUE_LEAD,
+            value.unwrap_or_default(),
+        );
+        return;
+    };
+    let mut body = Vec::new();
+    // The keys are sorted, so the first and the last key bound the common prefix.
+    let common = first
+        .iter()
+        .zip(last)
+        .skip(depth)
+        .take_while(|(a, b)| a == b)
+        .count();
+    if common > 0 {
+        let length = common.min(MAX_LINEAR_MATCH_LENGTH);
+        body.push(MIN_LINEAR_MATCH + length as u16 - 1);
+        body.extend(first.iter().skip(depth).take(length));
+        write_node(rest, depth + length, &mut body);
+    } else {
+        let groups: Vec<&[Entry]> = rest
+            .chunk_by(|(a, _), (b, _)| a.get(depth) == b.get(depth))
+            .collect();
+        let count_minus_one = groups.len() - 1;
+        if count_minus_one < usize::from(MIN_LINEAR_MATCH) {
+            body.push(count_minus_one as u16);
+        } else {
+            body.extend([0, count_minus_one as u16]);
+        }
+        write_branch(&groups, depth, &mut body);
+    }
+    match (value, body.split_first()) {
+        (Some(value), Some((&lead, tail))) => {
+            push_three_units(out, THREE_UNIT_NODE_VALUE_LEAD | lead, value);
+            out.extend_from_slice(tail);
+        }
+        _ => out.extend(body),
+    }
+}
+
+/// Writes the body of a branch node over `groups`, each of which shares
+/// the unit at `depth`.
+fn write_branch(groups: &[&[Entry]], depth: usize, out: &mut Vec<u16>) {
+    let unit = |group: &[Entry]| {
+        group
+            .first()
+            .and_then(|(key, _)| key.get(depth))
+            .copied()
+            .unwrap_or_default()
+    };
+    if groups.len() > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        // Binary search: units below the split unit are reached by a jump,
+        // the others follow inline.
+        let (lower, upper) = groups.split_at(groups.len() / 2);
+        let mut upper_out = Vec::new();
+        write_branch(upper, depth, &mut upper_out);
+        out.push(upper.first().map(|g| unit(g)).unwrap_or_default());
+        push_three_units(out, THREE_UNIT_DELTA_LEAD, upper_out.len() as u32);
+        out.extend(upper_out);
+        write_branch(lower, depth, out);
+        return;
+    }
+    let Some((last, init)) = groups.split_last() else {
+        return;
+    };
+    let mut jumps = Vec::new();
+    for &group in init {
+        out.push(unit(group));
+        match group {
+            [(key, value)] if key.len() == depth + 1 => {
+                push_three_units(out, VALUE_IS_FINAL | THREE_UNIT_VALUE_LEAD, *value);
+            }
+            _ => {
+                jumps.push((out.len(), group));
+                push_three_units(out, THREE_UNIT_VALUE_LEAD, 0);
+            }
+        }
+    }
+    out.push(unit(last));
+    write_node(last, depth + 1, out);
+    for (position, group) in jumps {
+        let delta = (out.len() - position - 3) as u32;
+        if let Some(units) = out.get_mut(position + 1..position + 3) {
+            units.copy_from_slice(&[(delta >> 16) as u16, delta as u16]);
+        }
+        write_node(group, depth + 1, out);
+    }
+}
+
+/// Reads all key-value pairs of a serialized `Char16Trie`.
+///
+/// Returns `None` if the trie is malformed.
+pub(super) fn read_char16_trie(trie: &[u16]) -> Option<Vec<Entry>> {
+    let mut out = Vec::new();
+    read_node(trie, 0, &mut Vec::new(), &mut out)?;
+    Some(out)
+}
+
+fn read_value(trie: &[u16], pos: usize, lead: u16) -> Option<(u32, usize)> {
+    Some(if lead < MIN_TWO_UNIT_VALUE_LEAD {
+        (u32::from(lead), pos)
+    } else if lead < THREE_UNIT_VALUE_LEAD {
+        (
+            (u32::from(lead - MIN_TWO_UNIT_VALUE_LEAD) << 16) | u32::from(*trie.get(pos)?),
+            pos + 1,
+        )
+    } else {
+        (
+            (u32::from(*trie.get(pos)?) << 16) | u32::from(*trie.get(pos + 1)?),
+            pos + 2,
+        )
+    })
+}
+
+fn read_node_value(trie: &[u16], pos: usize, lead: u16) -> Option<(u32, usize)> {
+    Some(if lead < MIN_TWO_UNIT_NODE_VALUE_LEAD {
+        (u32::from(lead >> 6) - 1, pos)
+    } else if lead < THREE_UNIT_NODE_VALUE_LEAD {
+        (
+            (u32::from((lead & 0x7fc0) - MIN_TWO_UNIT_NODE_VALUE_LEAD) << 10)
+                | u32::from(*trie.get(pos)?),
+            pos + 1,
+        )
+    } else {
+        (
+            (u32::from(*trie.get(pos)?) << 16) | u32::from(*trie.get(pos + 1)?),
+            pos + 2,
+        )
+    })
+}
+
+/// Returns the target of the delta at `pos` and the position after the delta.
+fn read_delta(trie: &[u16], pos: usize) -> Option<(usize, usize)> {
+    let delta = *trie.get(pos)?;
+    let (delta, after) = if delta < MIN_TWO_UNIT_DELTA_LEAD {
+        (usize::from(delta), pos + 1)
+    } else if delta == THREE_UNIT_DELTA_LEAD {
+        (
+            (usize::from(*trie.get(pos + 1)?) << 16) | usize::from(*trie.get(pos + 2)?),
+            pos + 3,
+        )
+    } else {
+        (
+            (usize::from(delta - MIN_TWO_UNIT_DELTA_LEAD) << 16) | usize::from(*trie.get(pos + 1)?),
+            pos + 2,
+        )
+    };
+    Some((after + delta, after))
+}
+
+fn read_node(trie: &[u16], mut pos: usize, key: &mut Vec<u16>, out: &mut Vec<Entry>) -> Option<()> {
+    let key_length = key.len();
+    let mut node = *trie.get(pos)?;
+    pos += 1;
+    loop {
+        if node >= MIN_VALUE_LEAD {
+            if node & VALUE_IS_FINAL != 0 {
+                out.push((key.clone(), read_value(trie, pos, node & 0x7fff)?.0));
+                break;
+            }
+            let (value, after) = read_node_value(trie, pos, node)?;
+            out.push((key.clone(), value));
+            pos = after;
+            node &= NODE_TYPE_MASK;
+        }
+        if node < MIN_LINEAR_MATCH {
+            let mut length = usize::from(node);
+            if length == 0 {
+                length = usize::from(*trie.get(pos)?);
+                pos += 1;
+            }
+            read_branch(trie, pos, length + 1, key, out)?;
+            break;
+        }
+        let length = usize::from(node - MIN_LINEAR_MATCH) + 1;
+        key.extend_from_slice(trie.get(pos..pos + length)?);
+        pos += length;
+        node = *trie.get(pos)?;
+        pos += 1;
+    }
+    key.truncate(key_length);
+    Some(())
+}
+
+fn read_branch(
+    trie: &[u16],
+    mut pos: usize,
+    mut length: usize,
+    key: &mut Vec<u16>,
+    out: &mut Vec<Entry>,
+) -> Option<()> {
+    while length > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        let (lower, after) = read_delta(trie, pos + 1)?;
+        read_branch(trie, lower, length >> 1, key, out)?;
+        length -= length >> 1;
+        pos = after;
+    }
+    for i in 0..length {
+        key.push(*trie.get(pos)?);
+        pos += 1;
+        if i + 1 == length {
+            read_node(trie, pos, key, out)?;
+        } else {
+            let lead = *trie.get(pos)?;
+            let (value, after) = read_value(trie, pos + 1, lead & 0x7fff)?;
+            if lead & VALUE_IS_FINAL != 0 {
+                out.push((key.clone(), value));
+            } else {
+                read_node(trie, after + value as usize, key, out)?;
+            }
+            pos = after;
+        }
+        key.pop();
+    }
+    Some(())
+}
+
+const FAST_DATA_BLOCK_LENGTH: u32 = 64;
+const BMP_INDEX_LENGTH: u32 = 0x10000 / FAST_DATA_BLOCK_LENGTH;
+const SHIFT_1: u32 = 14;
+const SHIFT_2: u32 = 9;
+const SHIFT_3: u32 = 4;
+const INDEX_BLOCK_LENGTH: u32 = 32;
+const SMALL_DATA_BLOCK_LENGTH: u32 = 16;
+
+/// Incrementally builds the index and data arrays of a fast-type `CodePointTrie`.
+struct CodePointTrieWriter<'a> {
+    /// Sorted, non-overlapping ranges of code points and their non-default values
+    ranges: &'a [(u32, u32, u32)],
+    default: u32,
+    index: Vec<u16>,
+    data: Vec<u32>,
+    blocks: BTreeMap<Vec<u32>, u32>,
+}
+
+impl CodePointTrieWriter<'_> {
+    /// Whether any code point in `start..end` has a non-default value.
+    fn any_in(&self, start: u32, end: u32) -> bool {
+        let i = self.ranges.partition_point(|&(_, last, _)| last < start);
+        self.ranges.get(i).is_some_and(|&(first, _, _)| first < end)
+    }
+
+    /// Adds a data block for `start..start + length` and returns its offset.
+    fn data_block(&mut self, start: u32, length: u32) -> u32 {
+        if !self.any_in(start, start + length) {
+            return 0;
+        }
+        let mut i = self.ranges.partition_point(|&(_, last, _)| last < start);
+        let mut block = Vec::with_capacity(length as usize);
+        for c in start..start + length {
+            while self.ranges.get(i).is_some_and(|&(_, last, _)| last < c) {
+                i += 1;
+            }
+            block.push(match self.ranges.get(i) {
+                Some(&(first, _, value)) if first <= c => value,
+                _ => self.default,
+            });
+        }
+        if let Some(&offset) = self.blocks.get(&block) {
+            return offset;
+        }
+        let offset = self.data.len() as u32;
+        self.data.extend_from_slice(&block);
+        self.blocks.insert(block, offset);
+        offset
+    }
+
+    /// Adds an index-3 block for `start..start + 0x200` and returns its index-2 entry.
+    fn index3_block(&mut self, start: u32, null_block: u16) -> Option<u16> {
+        if !self.any_in(start, start + (1 << SHIFT_2)) {
+            return Some(null_block);
+        }
+        let offsets: Vec<u32> = (0..INDEX_BLOCK_LENGTH)
+            .map(|i| self.data_block(start + (i << SHIFT_3), SMALL_DATA_BLOCK_LENGTH))
+            .collect();
+        let position = u16::try_from(self.index.len()).ok()?;
+        if offsets.iter().all(|&o| o <= 0xffff) {
+            self.index.extend(offsets.iter().map(|&o| o as u16));
+            Some(position)
+        } else {
+            // 18-bit offsets in groups of nine units per eight entries: the high
+            // bits of all eight entries come first.
+            for group in offsets.chunks(8) {
+                let high_bits = group.iter().enumerate().fold(0u16, |acc, (i, &o)| {
+                    acc | (((o >> 16) as u16 & 3) << (14 - 2 * i))
+                });
+                self.index.push(high_bits);
+                self.index.extend(group.iter().map(|&o| o as u16));
+            }
+            (position < 0x8000).then_some(0x8000 | position)
+        }
+    }
+}
+
+/// Builds a fast-type `CodePointTrie` from sorted, non-overlapping inclusive
+/// ranges `(first, last, value)`. All other code points map to `default`.
+///
+/// Returns `None` if the data does not fit the trie format.
+pub(super) fn write_code_point_trie(
+    ranges: &[(u32, u32, u32)],
+    default: u32,
+) -> Option<CodePointTrie<'static, u32>> {
+    let mut writer = CodePointTrieWriter {
+        ranges,
+        default,
+        index: Vec::new(),
+        data: alloc::vec![default; FAST_DATA_BLOCK_LENGTH as usize],
+        blocks: BTreeMap::new(),
+    };
+    writer.blocks.insert(writer.data.clone(), 0);
+    for i in 0..BMP_INDEX_LENGTH {
+        let offset = writer.data_block(i * FAST_DATA_BLOCK_LENGTH, FAST_DATA_BLOCK_LENGTH);
+        writer.index.push(u16::try_from(offset).ok()?);
+    }
+
+    let max = ranges.last().map(|&(_, last, _)| last).unwrap_or_default();
+    let high_start = if max < 0x10000 {
+        0x10000
+    } else {
+        (max + (1 << SHIFT_1)) & !((1 << SHIFT_1) - 1)
+    };
+    if high_start > 0x10000 {
+        // Index-1 entries for the supplementary planes, filled in below
+        let index1_start = writer.index.len();
+        let index1_length = (high_start >> SHIFT_1) as usize - 4;
+        writer.index.resize(index1_start + index1_length, 0);
+        // Null index-3 block, pointing to the null data block
+        let null_index3 = u16::try_from(writer.index.len()).ok()?;
+        writer
+            .index
+            .resize(writer.index.len() + INDEX_BLOCK_LENGTH as usize, 0);
+        // Null index-2 block
+        let null_index2 = u16::try_from(writer.index.len()).ok()?;
+        writer.index.resize(
+            writer.index.len() + INDEX_BLOCK_LENGTH as usize,
+            null_index3,
+        );
+        for i in 0..index1_length {
+            let start = (i as u32 + 4) << SHIFT_1;
+            let entry = if writer.any_in(start, start + (1 << SHIFT_1)) {
+                let entries = (0..INDEX_BLOCK_LENGTH)
+                    .map(|j| writer.index3_block(start + (j << SHIFT_2), null_index3))
+                    .collect::<Option<Vec<u16>>>()?;
+                let position = u16::try_from(writer.index.len()).ok()?;
+                writer.index.extend(entries);
+                position
+            } else {
+                null_index2
+            };
+            *writer.index.get_mut(index1_start + i)? = entry;
+        }
+    }
+    if writer.index.len() > 0xffff {
+        return None;
+    }
+
+    // The value for code points from `high_start` and the error value
+    writer.data.extend([default, default]);
+    CodePointTrie::try_new(
+        CodePointTrieHeader {
+            high_start,
+            shifted12_high_start: (high_start >> 12) as u16,
+            // No dedicated null blocks, as far as range iteration is concerned
+            index3_null_offset: 0xffff,
+            data_null_offset: 0xfffff,
+            null_value: default,
+            trie_type: TrieType::Fast,
+        },
+        ZeroVec::alloc_from_slice(&writer.index),
+        ZeroVec::alloc_from_slice(&writer.data),
+    )
+    .ok()
+}
+
+/// Encodes a collation element as a self-contained CE32 if possible.
+pub(super) fn ce32_from_ce(ce: u64) -> Option<u32> {
+    let p = (ce >> 32) as u32;
+    let lower32 = ce as u32;
+    let t = lower32 & 0xffff;
+    if ce & 0xffff_00ff_00ff == 0 {
+        // Simple: pppp ss tt
+        Some(p | (lower32 >> 16) | (t >> 8))
+    } else if ce & 0xff_ffff_ffff == 0x0500_0500 && p & 0xff == 0 {
+        // Three-byte primary with common secondary and tertiary
+        Some(p | 0xC1)
+    } else if p == 0 && t & 0xff == 0 {
+        // Long secondary
+        Some(lower32 | 0xC2)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_collections::char16trie::{Char16Trie, TrieResult};
+
+    fn entries(keys: &[&str]) -> Vec<Entry> {
+        let mut entries: Vec<Entry> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| (k.encode_utf16().collect(), 0x1234_5678 + i as u32))
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    #[test]
+    fn char16_trie_round_trip() {
+        let keys = [
+            "a",
+            "ab",
+            "abc",
+            "b",
+            "c",
+            "d",
+            "e",
+            "f",
+            "g",
+            "h",
+            "xyzzy",
+            "xyzzz",
+            "\u{0308}",
+            "\u{0301}\u{0308}",
+            "abcdefghijklmnopqrstuvwxyz",
+        ];
+        let entries = entries(&keys);
+        let trie = write_char16_trie(&entries);
+        let reader = Char16Trie::new(ZeroVec::alloc_from_slice(&trie));
+        for (key, value) in &entries {
+            let mut iter = reader.iter();
+            let mut result = TrieResult::NoMatch;
+            for &unit in key {
+                result = iter.next16(unit);
+            }
+            match result {
+                TrieResult::FinalValue(v) | TrieResult::Intermediate(v) => {
+                    assert_eq!(v as u32, *value)
+                }
+                _ => panic!("{key:?} not found"),
+            }
+        }
+        let mut read = read_char16_trie(&trie).unwrap();
+        read.sort();
+        assert_eq!(read, entries);
+    }
+
+    #[test]
+    fn code_point_trie() {
+        let ranges = [
+            (0x41, 0x41, 1),
+            (0x300, 0x310, 2),
+            (0xFFFF, 0x10000, 3),
+            (0x20000, 0x2A6DF, 4),
+            (0x10FFFF, 0x10FFFF, 5),
+        ];
+        let trie = write_code_point_trie(&ranges, 0xC0).unwrap();
+        for c in [
+            0, 0x40, 0x42, 0x2FF, 0x311, 0xFFFE, 0x10001, 0x1FFFF, 0x2A6E0, 0x10FFFE,
+        ] {
+            assert_eq!(trie.get32(c), 0xC0, "{c:x}");
+        }
+        for &(first, last, value) in &ranges {
+            assert_eq!(trie.get32(first), value);
+            assert_eq!(trie.get32(last), value);
+        }
+        let collected: Vec<_> = trie
+            .iter_ranges()
+            .filter(|r| r.value != 0xC0)
+            .map(|r| (*r.range.start(), *r.range.end(), r.value))
+            .collect();
+        assert_eq!(collected, ranges);
+    }
+}
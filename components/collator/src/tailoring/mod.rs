@@ -0,0 +1,1511 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The weight allocation follows the approach of ICU4C `CollationBuilder`
+// in a simplified form: Each root weight that a rule resets to has chains
+// of tailored nodes hanging off it, and the weights of the nodes are spread
+// over the gap between the root weight and the next root weight.
+
+//! Building collation data at run time from ICU collation rules.
+
+mod data;
+mod parser;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use displaydoc::Display;
+use icu_locale_core::Locale;
+use icu_normalizer::DecomposingNormalizerBorrowed;
+use icu_normalizer::provider::{NormalizerNfdDataV1, NormalizerNfdTablesV1};
+use icu_properties::props::CanonicalCombiningClass;
+use icu_provider::prelude::*;
+use zerovec::ZeroVec;
+
+use crate::comparison::LocaleSpecificDataHolder;
+use crate::elements::{
+    CollationElement, CollationElement32, CollationElements, FALLBACK_CE32, NO_CE,
+    OPTIMIZED_DIACRITICS_MAX_COUNT, Tag, ccc_from_trie_value, char_from_u32,
+};
+use crate::options::{AlternateHandling, CaseLevel, CollatorOptions, MaxVariable};
+use crate::preferences::{CollationCaseFirst, CollationNumericOrdering};
+use crate::provider::{
+    CollationData, CollationDiacritics, CollationDiacriticsV1, CollationJamoV1, CollationMetadata,
+    CollationMetadataV1, CollationReorderingV1, CollationRootV1, CollationScriptStartsV1,
+    CollationSpecialPrimariesV1, CollationTailoringV1,
+};
+use crate::{Collator, CollatorPreferences};
+use parser::{Level, Rule, Setting};
+
+const COMMON_WEIGHT: u32 = 0x0500_0000;
+/// Weights with a high byte in `0x06..=0x45` are reserved for the compression
+/// of secondary common weights in sort keys.
+const SECONDARY_COMMON_RANGE: RangeInclusive<u32> = 0x0500_0000..=0x45FF_0000;
+const TERTIARY_COMMON_RANGE: RangeInclusive<u32> = 0x0500_0000..=0x05FF_0000;
+/// The exclusive lower bound for weights tailored before the lowest root weight
+const BEFORE_LOWEST: u32 = 0x02FF_0000;
+const PRIMARY_LIMIT: u64 = 0xFF00_0000;
+const SECONDARY_LIMIT: u64 = 1 << 32;
+const TERTIARY_LIMIT: u64 = 0x4000_0000;
+const MAX_EXPANSION_LENGTH: usize = 31;
+const MAX_INDEX: usize = 1 << 19;
+
+/// An error from building a [`Collator`] out of collation rules.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TailoringError {
+    /// The rules are malformed.
+    #[displaydoc("Syntax error in collation rules at byte {offset}")]
+    Syntax {
+        /// The byte offset into the rules
+        offset: usize,
+    },
+    /// The rules are well-formed but use a feature that is not supported.
+    #[displaydoc("Unsupported collation rule at byte {offset}")]
+    Unsupported {
+        /// The byte offset into the rules
+        offset: usize,
+    },
+    /// There is no room for the weights that the rules call for, or the
+    /// resulting data exceeds the limits of the data format.
+    #[displaydoc("Too many tailored weights at byte {offset}")]
+    TooManyWeights {
+        /// The byte offset into the rules
+        offset: usize,
+    },
+    /// The root data or the data of an imported collation failed to load.
+    #[displaydoc("{0}")]
+    Data(DataError),
+}
+
+impl core::error::Error for TailoringError {}
+
+impl From<DataError> for TailoringError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+/// Builds a [`Collator`] from ICU collation rules at run time, like
+/// `RuleBasedCollator` in ICU4C.
+///
+/// The rules tailor the root collation, or the collation named by an
+/// `[import]` setting ahead of the resets and relations. The following are supported:
+///
+/// * resets (`&`), including `&[before 1]`, `&[before 2]` and `&[before 3]`
+/// * primary, secondary, tertiary, quaternary and identical relations
+///   (`<`, `<<`, `<<<`, `<<<<`, `=`) and their starred list forms
+/// * contractions, expansions (`/`) and prefixes (`|`)
+/// * the settings `strength`, `alternate`, `maxVariable`, `caseFirst`,
+///   `caseLevel`, `numericOrdering`, `backwards 2` and `import`
+///
+/// Special reset positions such as `[first regular]`, `[reorder]`,
+/// `[suppressContractions]`, `[optimize]`, `[hiraganaQ]` and tailorings of
+/// conjoining jamo result in [`TailoringError::Unsupported`].
+///
+/// Explicitly set fields of [`options`](Self::options) take precedence over
+/// the settings in the rules.
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use icu::collator::TailoringBuilder;
+///
+/// let collator = TailoringBuilder::new("&c < ch <<< cH <<< Ch <<< CH")
+///     .try_new()
+///     .unwrap();
+/// let collator = collator.as_borrowed();
+///
+/// assert_eq!(collator.compare("cz", "ch"), Ordering::Less);
+/// assert_eq!(collator.compare("ch", "d"), Ordering::Less);
+/// assert_eq!(collator.compare("ch", "Ch"), Ordering::Less);
+/// ```
+#[derive(Debug, Copy, Clone)]
+#[non_exhaustive]
+pub struct TailoringBuilder<'a> {
+    /// The collation rules
+    pub rules: &'a str,
+    /// Options that override the settings in the rules
+    pub options: CollatorOptions,
+}
+
+/// The data that does not depend on the tailoring
+struct RootData {
+    special_primaries: DataPayload<CollationSpecialPrimariesV1>,
+    root: DataPayload<CollationRootV1>,
+    jamo: DataPayload<CollationJamoV1>,
+    decompositions: DataPayload<NormalizerNfdDataV1>,
+    tables: DataPayload<NormalizerNfdTablesV1>,
+    /// Only loaded for reorder codes.
+    script_starts: Option<DataPayload<CollationScriptStartsV1>>,
+}
+
+impl<'a> TailoringBuilder<'a> {
+    /// Creates a builder for the given rules with default options.
+    pub const fn new(rules: &'a str) -> Self {
+        Self {
+            rules,
+            options: CollatorOptions::default(),
+        }
+    }
+
+    /// Creates a [`Collator`] from the rules on top of compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(self) -> Result<Collator, TailoringError> {
+        let root = RootData {
+            special_primaries: DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_COLLATION_SPECIAL_PRIMARIES_V1,
+            ),
+            root: DataPayload::from_static_ref(crate::provider::Baked::SINGLETON_COLLATION_ROOT_V1),
+            jamo: DataPayload::from_static_ref(crate::provider::Baked::SINGLETON_COLLATION_JAMO_V1),
+            decompositions: DataPayload::from_static_ref(
+                icu_normalizer::provider::Baked::SINGLETON_NORMALIZER_NFD_DATA_V1,
+            ),
+            tables: DataPayload::from_static_ref(
+                icu_normalizer::provider::Baked::SINGLETON_NORMALIZER_NFD_TABLES_V1,
+            ),
+            script_starts: Some(DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_COLLATION_SCRIPT_STARTS_V1,
+            )),
+        };
+        self.try_new_impl(root, |prefs| {
+            LocaleSpecificDataHolder::try_new_unstable(&crate::provider::Baked, prefs)
+        })
+    }
+
+    #[cfg(feature = "serde")]
+    #[doc = icu_provider::gen_buffer_unstable_docs!(BUFFER, Self::try_new)]
+    pub fn try_new_with_buffer_provider(
+        self,
+        provider: &(impl BufferProvider + ?Sized),
+    ) -> Result<Collator, TailoringError> {
+        self.try_new_unstable(&provider.as_deserializing())
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(self, provider: &D) -> Result<Collator, TailoringError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<CollationScriptStartsV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        let root = RootData {
+            special_primaries: provider.load(Default::default())?.payload,
+            root: provider.load(Default::default())?.payload,
+            jamo: provider.load(Default::default())?.payload,
+            decompositions: provider.load(Default::default())?.payload,
+            tables: provider.load(Default::default())?.payload,
+            script_starts: match self.options.reorder_codes {
+                Some(_) => Some(provider.load(Default::default())?.payload),
+                None => None,
+            },
+        };
+        self.try_new_impl(root, |prefs| {
+            LocaleSpecificDataHolder::try_new_unstable(provider, prefs)
+        })
+    }
+
+    fn try_new_impl(
+        self,
+        root_data: RootData,
+        load: impl FnOnce(CollatorPreferences) -> Result<LocaleSpecificDataHolder, DataError>,
+    ) -> Result<Collator, TailoringError> {
+        let rules = parser::parse(self.rules)?;
+
+        let mut import = None;
+        let mut seen_relation = false;
+        for rule in &rules {
+            match rule {
+                Rule::Setting {
+                    setting: Setting::Import(tag),
+                    offset,
+                } => {
+                    let locale = Locale::try_from_str(tag)
+                        .map_err(|_| TailoringError::Syntax { offset: *offset })?;
+                    // Only a single import ahead of any resets and relations
+                    if seen_relation || import.is_some() {
+                        return Err(TailoringError::Unsupported { offset: *offset });
+                    }
+                    import = Some(CollatorPreferences::from(&locale));
+                }
+                Rule::Setting { .. } => {}
+                _ => seen_relation = true,
+            }
+        }
+        let holder = load(import.unwrap_or_default())?;
+
+        let mut options = self.options;
+        let mut settings = Settings::default();
+        for rule in &rules {
+            match rule {
+                Rule::Setting {
+                    setting: Setting::Import(_),
+                    ..
+                } => settings.import(holder.metadata),
+                Rule::Setting { setting, .. } => settings.apply(setting, &mut options),
+                _ => {}
+            }
+        }
+
+        let mut builder = Builder::new(&root_data, &holder);
+        for rule in &rules {
+            builder.apply(rule)?;
+        }
+        let (data, diacritics) = builder.finish()?;
+
+        let mut metadata = settings.metadata(holder.metadata);
+        let diacritics = match diacritics {
+            Some(secondaries) => {
+                metadata.bits |= CollationMetadata::TAILORED_DIACRITICS_MASK;
+                DataPayload::from_owned(CollationDiacritics {
+                    secondaries: ZeroVec::alloc_from_slice(&secondaries),
+                })
+            }
+            None => holder.diacritics,
+        };
+
+        let mut collator = Collator {
+            special_primaries: root_data.special_primaries,
+            root: root_data.root,
+            tailoring: Some(DataPayload::from_owned(data)),
+            jamo: root_data.jamo,
+            diacritics,
+            options: options.resolve(metadata, None, settings.numeric),
+            reordering: holder.reordering,
+            custom_reordering: None,
+            decompositions: root_data.decompositions,
+            tables: root_data.tables,
+        };
+        if let Some((codes, script_starts)) = options.reorder_codes.zip(root_data.script_starts) {
+            collator.set_reorder_codes(script_starts.get(), &codes)?;
+        }
+        Ok(collator)
+    }
+}
+
+/// The settings from the rules that end up in the metadata
+#[derive(Default)]
+struct Settings {
+    alternate_shifted: Option<bool>,
+    max_variable: Option<u32>,
+    case_first: Option<CollationCaseFirst>,
+    backward_second_level: bool,
+    numeric: Option<CollationNumericOrdering>,
+}
+
+impl Settings {
+    fn apply(&mut self, setting: &Setting, options: &mut CollatorOptions) {
+        match *setting {
+            Setting::Strength(strength) => {
+                options.strength.get_or_insert(strength);
+            }
+            Setting::CaseLevel(on) => {
+                options
+                    .case_level
+                    .get_or_insert(if on { CaseLevel::On } else { CaseLevel::Off });
+            }
+            Setting::Alternate(alternate) => {
+                self.alternate_shifted = Some(alternate == AlternateHandling::Shifted);
+            }
+            Setting::MaxVariable(max_variable) => self.max_variable = Some(max_variable as u32),
+            Setting::CaseFirst(case_first) => self.case_first = Some(case_first),
+            Setting::BackwardSecondLevel => self.backward_second_level = true,
+            Setting::Numeric(on) => {
+                self.numeric = Some(if on {
+                    CollationNumericOrdering::True
+                } else {
+                    CollationNumericOrdering::False
+                });
+            }
+            Setting::Import(_) => {}
+        }
+    }
+
+    /// Drops the settings that the imported collation overrides. ICU4C inserts
+    /// the rules of the imported collation in place of the `[import]`, so its
+    /// settings replace the ones before it. The settings that it changes from
+    /// the root are the ones in its rules.
+    fn import(&mut self, imported: CollationMetadata) {
+        if imported.alternate_shifted() {
+            self.alternate_shifted = None;
+        }
+        if imported.max_variable() != MaxVariable::Punctuation {
+            self.max_variable = None;
+        }
+        if imported.case_first() != CollationCaseFirst::False {
+            self.case_first = None;
+        }
+    }
+
+    fn metadata(&self, base: CollationMetadata) -> CollationMetadata {
+        let mut bits = base.bits | CollationMetadata::TAILORED_MASK;
+        if let Some(shifted) = self.alternate_shifted {
+            bits &= !CollationMetadata::ALTERNATE_SHIFTED_MASK;
+            if shifted {
+                bits |= CollationMetadata::ALTERNATE_SHIFTED_MASK;
+            }
+        }
+        if let Some(max_variable) = self.max_variable {
+            bits = (bits & !CollationMetadata::MAX_VARIABLE_MASK) | max_variable;
+        }
+        if let Some(case_first) = self.case_first {
+            bits &= !(CollationMetadata::CASE_FIRST_MASK | CollationMetadata::UPPER_FIRST_MASK);
+            match case_first {
+                CollationCaseFirst::Upper => {
+                    bits |= CollationMetadata::CASE_FIRST_MASK | CollationMetadata::UPPER_FIRST_MASK
+                }
+                CollationCaseFirst::Lower => bits |= CollationMetadata::CASE_FIRST_MASK,
+                _ => {}
+            }
+        }
+        if self.backward_second_level {
+            bits |= CollationMetadata::BACWARD_SECOND_LEVEL_MASK;
+        }
+        CollationMetadata { bits }
+    }
+}
+
+/// A collation element of a string during building
+#[derive(Debug, Copy, Clone)]
+enum Ce {
+    /// A collation element of the base collation
+    Base(u64),
+    /// The collation element of a tailored node
+    Node(usize),
+}
+
+/// A sequence of tailored nodes that share the root weights of the anchor
+/// up to `level`, ordered after the anchor (or before it if `before`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct ChainKey {
+    level: Level,
+    before: bool,
+    anchor: u64,
+}
+
+#[derive(Debug)]
+struct Node {
+    chain: ChainKey,
+    /// The level of the difference from the previous node in the chain, or
+    /// from the anchor for the first node
+    strength: Level,
+    case: u64,
+    offset: usize,
+}
+
+#[derive(Debug)]
+struct Mapping {
+    ces: Vec<Ce>,
+    offset: usize,
+}
+
+/// Truncates a collation element to the weights that matter at `level`,
+/// dropping the case and quaternary bits.
+fn anchor_at(ce: u64, level: Level) -> u64 {
+    match level {
+        Level::Primary => ce & 0xFFFF_FFFF_0000_0000,
+        Level::Secondary => ce & 0xFFFF_FFFF_FFFF_0000,
+        _ => ce & 0xFFFF_FFFF_FFFF_3F3F,
+    }
+}
+
+fn ce_strength(ce: u64) -> Level {
+    if ce >> 32 != 0 {
+        Level::Primary
+    } else if ce >> 16 != 0 {
+        Level::Secondary
+    } else if ce != 0 {
+        Level::Tertiary
+    } else {
+        Level::Identical
+    }
+}
+
+fn ce_bits(ce: CollationElement) -> u64 {
+    (u64::from(ce.primary()) << 32) | u64::from(ce.non_primary().bits())
+}
+
+/// The index of a weight level in `[primary, secondary, tertiary, quaternary]`
+fn level_index(level: Level) -> usize {
+    (level as usize).saturating_sub(1)
+}
+
+/// Returns the first `x` in `range` for which `f` holds, given that `f` is
+/// monotonic over the range, or `range.end` if there is none.
+fn partition_point(range: core::ops::Range<u32>, f: impl Fn(u32) -> bool) -> u32 {
+    let (mut low, mut high) = (range.start, range.end);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if f(mid) {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    low
+}
+
+/// The number of bytes of a left-aligned weight
+fn weight_length(weight: u32) -> usize {
+    4 - (weight.trailing_zeros() / 8) as usize
+}
+
+/// Whether `weight` is a proper prefix of `other`
+fn is_prefix(weight: u32, other: u64) -> bool {
+    let Ok(other) = u32::try_from(other) else {
+        return false;
+    };
+    let length = weight_length(weight);
+    length < 4 && other != weight && other & !(u32::MAX >> (8 * length)) == weight
+}
+
+/// The byte ranges of the weights of a level, as left-aligned `u32`s
+///
+/// Sort keys are compared byte by byte, so a weight must never be a prefix of
+/// another weight in the same gap.
+struct WeightBytes {
+    lengths: RangeInclusive<usize>,
+    bytes: [(u8, u8); 4],
+    /// Lead bytes that are reserved for common weight compression
+    reserved: RangeInclusive<u8>,
+}
+
+impl WeightBytes {
+    // The second byte avoids the bytes reserved for primary compression
+    // regardless of whether the lead byte is compressible.
+    const PRIMARY: Self = Self {
+        lengths: 1..=4,
+        bytes: [(3, 0xFE), (4, 0xFE), (2, 0xFF), (2, 0xFF)],
+        reserved: 0..=0,
+    };
+
+    const SECONDARY: Self = Self {
+        lengths: 1..=2,
+        bytes: [(3, 0xFF), (2, 0xFF), (0, 0), (0, 0)],
+        reserved: 0x06..=0x45,
+    };
+
+    const TERTIARY: Self = Self {
+        lengths: 1..=2,
+        bytes: [(3, 0x3F), (2, 0x3F), (0, 0), (0, 0)],
+        reserved: 0..=0,
+    };
+
+    /// The smallest weight of `length` bytes greater than `weight` that does
+    /// not extend it
+    fn next(&self, weight: u32, length: usize) -> Option<u32> {
+        let mut bytes = weight.to_be_bytes();
+        let position = weight_length(weight).min(length);
+        for i in (0..position).rev() {
+            let (&(min, max), byte) = (self.bytes.get(i)?, bytes.get_mut(i)?);
+            if *byte < max {
+                *byte = (*byte + 1).max(min);
+                if i == 0 && self.reserved.contains(byte) {
+                    *byte = self.reserved.end() + 1;
+                }
+                for (j, (byte, &(min, _))) in
+                    bytes.iter_mut().zip(&self.bytes).enumerate().skip(i + 1)
+                {
+                    *byte = if j < length { min } else { 0 };
+                }
+                return Some(u32::from_be_bytes(bytes));
+            }
+        }
+        None
+    }
+
+    /// Allocates `count` weights between `low` and `high`, both exclusive,
+    /// using the shortest length that fits.
+    fn allocate(&self, low: u32, high: u64, count: usize) -> Option<Vec<u32>> {
+        'lengths: for length in self.lengths.clone() {
+            let mut weights = Vec::with_capacity(count);
+            let mut weight = low;
+            for _ in 0..count {
+                match self.next(weight, length) {
+                    Some(next) if u64::from(next) < high && !is_prefix(next, high) => {
+                        weights.push(next);
+                        weight = next;
+                    }
+                    _ => continue 'lengths,
+                }
+            }
+            return Some(weights);
+        }
+        None
+    }
+}
+
+/// A range of code points whose primaries are computed from the code point
+/// and increase with it
+struct PrimaryRange<'a> {
+    first: u32,
+    last: u32,
+    /// The data and CE32 for offset primaries, or `None` for unassigned code points
+    offset: Option<(&'a CollationData<'a>, CollationElement32)>,
+}
+
+impl PrimaryRange<'_> {
+    fn primary_at(&self, c: u32) -> u32 {
+        let c = char_from_u32(c);
+        match self.offset {
+            Some((data, ce32)) => data.ce_from_offset_ce32(c, ce32).primary(),
+            None => CollationElement::new_implicit_from_char(c).primary(),
+        }
+    }
+}
+
+/// The weights of the base collation
+struct RootWeights<'a> {
+    /// The collation elements from the data, without case and quaternary bits
+    ces: BTreeSet<u64>,
+    ranges: Vec<PrimaryRange<'a>>,
+}
+
+impl RootWeights<'_> {
+    /// The smallest primary greater than `p`
+    fn next_primary(&self, p: u32) -> Option<u32> {
+        let mut next = self
+            .ces
+            .range((u64::from(p) + 1) << 32..)
+            .next()
+            .map(|ce| (ce >> 32) as u32);
+        for range in &self.ranges {
+            let c = partition_point(range.first..range.last + 1, |c| range.primary_at(c) > p);
+            if c <= range.last {
+                let candidate = range.primary_at(c);
+                next = Some(next.map_or(candidate, |n| n.min(candidate)));
+            }
+        }
+        next
+    }
+
+    /// The greatest non-zero primary less than `p`
+    fn prev_primary(&self, p: u32) -> Option<u32> {
+        let mut prev = self
+            .ces
+            .range(..u64::from(p) << 32)
+            .next_back()
+            .map(|ce| (ce >> 32) as u32)
+            .filter(|&q| q != 0);
+        for range in &self.ranges {
+            let c = partition_point(range.first..range.last + 1, |c| range.primary_at(c) >= p);
+            if c > range.first {
+                let candidate = range.primary_at(c - 1);
+                prev = Some(prev.map_or(candidate, |n| n.max(candidate)));
+            }
+        }
+        prev
+    }
+
+    /// The smallest weight at `level` greater than that of `anchor` among the
+    /// root collation elements that share the stronger weights of `anchor`
+    fn next(&self, anchor: u64, level: Level) -> Option<u32> {
+        let (shift, mask) = if level == Level::Secondary {
+            (16, 0xFFFF_FFFF_0000_0000)
+        } else {
+            (0, 0xFFFF_FFFF_FFFF_0000)
+        };
+        let start = (anchor & (mask | (0xFFFF << shift))) + (1 << shift);
+        self.ces
+            .range(start..=(anchor & mask) | 0xFFFF_FFFF)
+            .next()
+            .map(|ce| (((ce >> shift) & 0xFFFF) as u32) << 16)
+    }
+
+    /// The greatest non-zero weight at `level` less than that of `anchor`
+    /// among the root collation elements that share the stronger weights
+    fn prev(&self, anchor: u64, level: Level) -> Option<u32> {
+        let (shift, mask) = if level == Level::Secondary {
+            (16, 0xFFFF_FFFF_0000_0000)
+        } else {
+            (0, 0xFFFF_FFFF_FFFF_0000)
+        };
+        self.ces
+            .range(anchor & mask..anchor & (mask | (0xFFFF << shift)))
+            .map(|ce| (((ce >> shift) & 0xFFFF) as u32) << 16)
+            .rfind(|&w| w != 0)
+    }
+}
+
+/// The arrays of the tailored `CollationData` under construction
+#[derive(Default)]
+struct Tables {
+    ces: Vec<u64>,
+    ce32s: Vec<u32>,
+    contexts: Vec<u16>,
+}
+
+impl Tables {
+    fn encode(&mut self, ces: &[u64]) -> Option<u32> {
+        if let [ce] = ces
+            && let Some(ce32) = data::ce32_from_ce(*ce)
+        {
+            return Some(ce32);
+        }
+        if ces.is_empty() {
+            return Some(0);
+        }
+        if ces.len() > MAX_EXPANSION_LENGTH {
+            return None;
+        }
+        let ce32s = ces
+            .iter()
+            .map(|&ce| data::ce32_from_ce(ce))
+            .collect::<Option<Vec<u32>>>();
+        let (index, tag) = if let Some(ce32s) = ce32s {
+            let index = self.ce32s.len();
+            self.ce32s.extend(ce32s);
+            (index, Tag::Expansion32)
+        } else {
+            let index = self.ces.len();
+            self.ces.extend_from_slice(ces);
+            (index, Tag::Expansion)
+        };
+        if index >= MAX_INDEX {
+            return None;
+        }
+        Some(((index as u32) << 13) | ((ces.len() as u32) << 8) | 0xC0 | tag as u32)
+    }
+
+    /// Appends a default CE32 and a trie to the contexts.
+    fn push_context(&mut self, default: u32, entries: &[(Vec<u16>, u32)]) -> Option<u32> {
+        let index = self.contexts.len();
+        if index >= MAX_INDEX {
+            return None;
+        }
+        self.contexts
+            .extend([(default >> 16) as u16, default as u16]);
+        self.contexts.extend(data::write_char16_trie(entries));
+        Some((index as u32) << 13)
+    }
+}
+
+/// Mappings keyed by prefix and the string after the first character
+type Conditionals = BTreeMap<(String, String), Vec<u64>>;
+
+struct Builder<'a> {
+    root: &'a CollationData<'a>,
+    base: Option<&'a CollationData<'a>>,
+    root_contexts: Vec<u16>,
+    base_contexts: Vec<u16>,
+    root_data: &'a RootData,
+    holder: &'a LocaleSpecificDataHolder,
+    nfd: DecomposingNormalizerBorrowed<'a>,
+    weights: RootWeights<'a>,
+    nodes: Vec<Node>,
+    chains: BTreeMap<ChainKey, Vec<usize>>,
+    mappings: BTreeMap<(String, String), Mapping>,
+    /// The collation elements of the last reset or relation
+    current: Vec<Ce>,
+    /// The pending `[before n]` of the last reset
+    before: Option<Level>,
+}
+
+impl<'a> Builder<'a> {
+    fn new(root_data: &'a RootData, holder: &'a LocaleSpecificDataHolder) -> Self {
+        let root = root_data.root.get();
+        let base = holder.tailoring.as_ref().map(|t| t.get());
+        let mut builder = Builder {
+            root,
+            base,
+            root_contexts: root.contexts.iter().collect(),
+            base_contexts: base
+                .map(|b| b.contexts.iter().collect())
+                .unwrap_or_default(),
+            root_data,
+            holder,
+            nfd: DecomposingNormalizerBorrowed::new_with_data(
+                root_data.decompositions.get(),
+                root_data.tables.get(),
+            ),
+            weights: RootWeights {
+                ces: BTreeSet::new(),
+                ranges: Vec::new(),
+            },
+            nodes: Vec::new(),
+            chains: BTreeMap::new(),
+            mappings: BTreeMap::new(),
+            current: Vec::new(),
+            before: None,
+        };
+        builder.collect_weights(root);
+        if let Some(base) = base {
+            builder.collect_weights(base);
+        }
+        for ce32 in root_data.jamo.get().ce32s.iter() {
+            builder.collect_ce32(root, '\u{1100}', CollationElement32::new(ce32));
+        }
+        for secondary in holder.diacritics.get().secondaries.iter() {
+            builder
+                .weights
+                .ces
+                .insert((u64::from(secondary) << 16) | 0x0500);
+        }
+        builder
+    }
+
+    fn collect_weights(&mut self, data: &'a CollationData<'a>) {
+        for range in data.trie.iter_ranges() {
+            let ce32 = CollationElement32::new(range.value);
+            let (start, end) = (*range.range.start(), *range.range.end());
+            let offset = match ce32.tag_checked() {
+                Some(Tag::Offset) => Some(Some((data, ce32))),
+                Some(Tag::Implicit) => Some(None),
+                _ => None,
+            };
+            let Some(offset) = offset else {
+                if let Some(c) = char::from_u32(start) {
+                    self.collect_ce32(data, c, ce32);
+                }
+                continue;
+            };
+            // Surrogate code points have no primaries.
+            for (first, last) in [(start, end.min(0xD7FF)), (start.max(0xE000), end)] {
+                if first <= last {
+                    self.weights.ranges.push(PrimaryRange {
+                        first,
+                        last,
+                        offset,
+                    });
+                }
+            }
+        }
+    }
+
+    fn collect_ce32(&mut self, data: &'a CollationData<'a>, c: char, ce32: CollationElement32) {
+        match ce32.tag_checked() {
+            Some(Tag::Prefix | Tag::Contraction) => {
+                let (default, entries) = self.contexts_at(data, ce32.index());
+                self.collect_ce32(data, c, default);
+                for (_, value) in entries {
+                    self.collect_ce32(data, c, CollationElement32::new(value));
+                }
+            }
+            Some(Tag::Fallback) => {}
+            _ => {
+                for ce in self.ces_of(data, c, ce32) {
+                    self.weights.ces.insert(anchor_at(ce, Level::Tertiary));
+                }
+            }
+        }
+    }
+
+    /// The default CE32 and the trie entries of a prefix or contraction
+    fn contexts_at(
+        &self,
+        data: &CollationData,
+        index: usize,
+    ) -> (CollationElement32, Vec<(Vec<u16>, u32)>) {
+        let contexts = if core::ptr::eq(data, self.root) {
+            &self.root_contexts
+        } else {
+            &self.base_contexts
+        };
+        let default = data.get_default(index);
+        let entries = contexts
+            .get(index + 2..)
+            .and_then(data::read_char16_trie)
+            .unwrap_or_default();
+        (default, entries)
+    }
+
+    /// The collation elements of a CE32 without context
+    fn ces_of(&self, data: &CollationData, c: char, ce32: CollationElement32) -> Vec<u64> {
+        if let Some(ce) = ce32.to_ce_self_contained() {
+            return alloc::vec![ce_bits(ce)];
+        }
+        match ce32.tag() {
+            Tag::Expansion32 => data
+                .get_ce32s(ce32.index(), ce32.len())
+                .iter()
+                .map(|ce32| ce_bits(CollationElement32::new(ce32).to_ce_self_contained_or_gigo()))
+                .collect(),
+            Tag::Expansion => data.get_ces(ce32.index(), ce32.len()).iter().collect(),
+            Tag::Prefix | Tag::Contraction => self.ces_of(data, c, data.get_default(ce32.index())),
+            Tag::Digit => self.ces_of(data, c, data.get_ce32(ce32.index())),
+            Tag::Offset => alloc::vec![ce_bits(data.ce_from_offset_ce32(c, ce32))],
+            Tag::Implicit => alloc::vec![ce_bits(CollationElement::new_implicit_from_char(c))],
+            Tag::Fallback if !core::ptr::eq(data, self.root) => {
+                self.ces_of(self.root, c, self.root.ce32_for_char(c))
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// The collation elements of the base collation for an NFD string
+    fn base_ces(&self, s: &str) -> Vec<u64> {
+        let mut iter = CollationElements::new(
+            s.chars(),
+            self.root,
+            self.base.unwrap_or(self.root),
+            self.root_data.jamo.get().as_array(),
+            &self.holder.diacritics.get().secondaries,
+            self.root_data.decompositions.get(),
+            self.root_data.tables.get(),
+            None,
+            self.holder.metadata.lithuanian_dot_above(),
+        );
+        iter.init();
+        let mut ces = Vec::new();
+        loop {
+            let ce = iter.next();
+            if ce == NO_CE {
+                return ces;
+            }
+            if ce != CollationElement::new(0) {
+                ces.push(ce_bits(ce));
+            }
+        }
+    }
+
+    /// The collation elements for a reset position or an extension, taking
+    /// earlier rules into account
+    fn ces_for(&self, s: &str) -> Vec<Ce> {
+        if let Some(mapping) = self.mappings.get(&(String::new(), String::from(s))) {
+            return mapping.ces.clone();
+        }
+        let mut ces = Vec::new();
+        let mut untailored = String::new();
+        let mut rest = s;
+        while let Some(c) = rest.chars().next() {
+            // The longest tailored string at this position
+            let ends: Vec<usize> = rest
+                .char_indices()
+                .skip(1)
+                .map(|(i, _)| i)
+                .chain([rest.len()])
+                .collect();
+            let tailored = ends.into_iter().rev().find_map(|end| {
+                self.mappings
+                    .get(&(String::new(), String::from(rest.get(..end)?)))
+                    .map(|m| (end, m))
+            });
+            if let Some((end, mapping)) = tailored {
+                ces.extend(self.base_ces(&untailored).into_iter().map(Ce::Base));
+                untailored.clear();
+                ces.extend_from_slice(&mapping.ces);
+                rest = rest.get(end..).unwrap_or_default();
+            } else {
+                untailored.push(c);
+                rest = rest.get(c.len_utf8()..).unwrap_or_default();
+            }
+        }
+        ces.extend(self.base_ces(&untailored).into_iter().map(Ce::Base));
+        ces
+    }
+
+    fn strength(&self, ce: Ce) -> Level {
+        match ce {
+            Ce::Base(ce) => ce_strength(ce),
+            Ce::Node(n) => {
+                let Some(chain) = self.nodes.get(n).map(|node| node.chain) else {
+                    return Level::Identical;
+                };
+                chain.level.min(ce_strength(chain.anchor))
+            }
+        }
+    }
+
+    /// The case bits for a tailored string, from its base collation elements
+    fn case_bits(&self, s: &str) -> u64 {
+        let (mut lower, mut upper) = (false, false);
+        for ce in self.base_ces(s) {
+            if ce >> 32 != 0 {
+                match ce & 0xC000 {
+                    0 => lower = true,
+                    0x8000 => upper = true,
+                    _ => return 0x4000,
+                }
+            }
+        }
+        match (lower, upper) {
+            (true, true) => 0x4000,
+            (false, true) => 0x8000,
+            _ => 0,
+        }
+    }
+
+    fn new_node(&mut self, chain: ChainKey, strength: Level, case: u64, offset: usize) -> usize {
+        self.nodes.push(Node {
+            chain,
+            strength,
+            case,
+            offset,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Inserts a node that sorts after `anchor` with a difference at `level`.
+    fn insert_after(&mut self, anchor: Ce, level: Level, case: u64, offset: usize) -> usize {
+        let (key, position) = match anchor {
+            Ce::Node(m) => match self.nodes.get(m) {
+                Some(node) if level >= node.chain.level => {
+                    let key = node.chain;
+                    let chain = self.chains.get(&key).map(Vec::as_slice).unwrap_or_default();
+                    let mut position = chain.iter().position(|&n| n == m).unwrap_or_default() + 1;
+                    while chain
+                        .get(position)
+                        .and_then(|&n| self.nodes.get(n))
+                        .is_some_and(|n| n.strength > level)
+                    {
+                        position += 1;
+                    }
+                    (key, position)
+                }
+                node => {
+                    let anchor = node.map(|n| n.chain.anchor).unwrap_or_default();
+                    let key = ChainKey {
+                        level,
+                        before: false,
+                        anchor: anchor_at(anchor, level),
+                    };
+                    (key, 0)
+                }
+            },
+            Ce::Base(ce) => {
+                let key = ChainKey {
+                    level,
+                    before: false,
+                    anchor: anchor_at(ce, level),
+                };
+                (key, 0)
+            }
+        };
+        let n = self.new_node(key, level, case, offset);
+        let chain = self.chains.entry(key).or_default();
+        chain.insert(position.min(chain.len()), n);
+        n
+    }
+
+    /// Inserts a node that sorts before `anchor` with a difference at `level`.
+    fn insert_before(
+        &mut self,
+        anchor: Ce,
+        level: Level,
+        case: u64,
+        offset: usize,
+    ) -> Result<usize, TailoringError> {
+        let ce = match anchor {
+            Ce::Node(m) => {
+                let Some(node) = self.nodes.get(m) else {
+                    return Err(TailoringError::Unsupported { offset });
+                };
+                if node.chain.level <= level {
+                    if node.strength > level {
+                        return Err(TailoringError::Unsupported { offset });
+                    }
+                    let (key, strength) = (node.chain, node.strength);
+                    let n = self.new_node(key, strength, case, offset);
+                    if let Some(node) = self.nodes.get_mut(m) {
+                        node.strength = level;
+                    }
+                    let chain = self.chains.entry(key).or_default();
+                    let position = chain.iter().position(|&x| x == m).unwrap_or_default();
+                    chain.insert(position, n);
+                    return Ok(n);
+                }
+                node.chain.anchor
+            }
+            Ce::Base(ce) => ce,
+        };
+        let key = if level == Level::Primary {
+            let p = self
+                .weights
+                .prev_primary((ce >> 32) as u32)
+                .ok_or(TailoringError::Unsupported { offset })?;
+            ChainKey {
+                level,
+                before: false,
+                anchor: u64::from(p) << 32,
+            }
+        } else {
+            ChainKey {
+                level,
+                before: true,
+                anchor: anchor_at(ce, level),
+            }
+        };
+        let n = self.new_node(key, level, case, offset);
+        self.chains.entry(key).or_default().push(n);
+        Ok(n)
+    }
+
+    fn nfd(&self, s: &str) -> String {
+        self.nfd.normalize_iter(s.chars()).collect()
+    }
+
+    fn apply(&mut self, rule: &Rule) -> Result<(), TailoringError> {
+        match rule {
+            Rule::Reset {
+                position,
+                before,
+                offset,
+            } => {
+                let position = self.nfd(position);
+                self.current = self.ces_for(&position);
+                self.before = *before;
+                if let Some(level) = before {
+                    while self
+                        .current
+                        .last()
+                        .is_some_and(|&ce| self.strength(ce) > *level)
+                    {
+                        self.current.pop();
+                    }
+                }
+                if self.current.is_empty() {
+                    return Err(TailoringError::Unsupported { offset: *offset });
+                }
+            }
+            Rule::Relation {
+                level,
+                prefix,
+                string,
+                extension,
+                offset,
+            } => self.apply_relation(*level, prefix, string, extension, *offset)?,
+            Rule::Setting { .. } => {}
+        }
+        Ok(())
+    }
+
+    fn apply_relation(
+        &mut self,
+        level: Level,
+        prefix: &str,
+        string: &str,
+        extension: &str,
+        offset: usize,
+    ) -> Result<(), TailoringError> {
+        let prefix = self.nfd(prefix);
+        let string = self.nfd(string);
+        let Some(first) = string.chars().next() else {
+            return Err(TailoringError::Syntax { offset });
+        };
+        // Conjoining jamo are looked up in a separate table, and U+FFFE and
+        // U+FFFF have special meanings.
+        if ('\u{1100}'..='\u{11FF}').contains(&first)
+            || first == '\u{FFFE}'
+            || first == '\u{FFFF}'
+            || prefix.chars().count() > 2
+        {
+            return Err(TailoringError::Unsupported { offset });
+        }
+
+        if let Some(before) = self.before.take() {
+            if level != before {
+                return Err(TailoringError::Syntax { offset });
+            }
+            let anchor = self
+                .current
+                .pop()
+                .ok_or(TailoringError::Syntax { offset })?;
+            let case = self.case_bits(&string);
+            let n = self.insert_before(anchor, level, case, offset)?;
+            self.current.push(Ce::Node(n));
+        } else if level != Level::Identical {
+            while self
+                .current
+                .last()
+                .is_some_and(|&ce| self.strength(ce) > level)
+            {
+                self.current.pop();
+            }
+            let anchor = self
+                .current
+                .pop()
+                .ok_or(TailoringError::Unsupported { offset })?;
+            let case = self.case_bits(&string);
+            let n = self.insert_after(anchor, level, case, offset);
+            self.current.push(Ce::Node(n));
+        }
+        let mut ces = self.current.clone();
+        if !extension.is_empty() {
+            let extension = self.nfd(extension);
+            ces.extend(self.ces_for(&extension));
+        }
+        self.mappings
+            .insert((prefix, string), Mapping { ces, offset });
+        Ok(())
+    }
+
+    /// Computes the collation elements of the tailored nodes.
+    fn node_ces(&self) -> Result<Vec<u64>, TailoringError> {
+        let mut ces = alloc::vec![0; self.nodes.len()];
+        for (key, chain) in &self.chains {
+            let offset = chain
+                .first()
+                .and_then(|&n| self.nodes.get(n))
+                .map(|n| n.offset)
+                .unwrap_or_default();
+            let error = TailoringError::TooManyWeights { offset };
+            let count = chain
+                .iter()
+                .filter(|&&n| self.nodes.get(n).is_some_and(|n| n.strength == key.level))
+                .count();
+            let anchor = key.anchor;
+            let mut weights = [
+                (anchor >> 32) as u32,
+                ((anchor >> 16) as u32) << 16,
+                (anchor as u32) << 16,
+                0,
+            ];
+            let mut allocated = self.allocate(key, count).ok_or(error)?.into_iter();
+            for &n in chain {
+                let Some(node) = self.nodes.get(n) else {
+                    continue;
+                };
+                let i = level_index(node.strength);
+                let weight = if node.strength == key.level {
+                    allocated.next()
+                } else {
+                    weights
+                        .get(i)
+                        .and_then(|&w| self.next_free(node.strength, w))
+                };
+                if let Some(w) = weights.get_mut(i) {
+                    *w = weight.ok_or(error)?;
+                }
+                for (j, w) in weights.iter_mut().enumerate().skip(i + 1) {
+                    *w = if j < 3 { COMMON_WEIGHT } else { 0 };
+                }
+                let [p, s, t, q] = weights;
+                let case = if p != 0 { node.case } else { 0 };
+                if let Some(ce) = ces.get_mut(n) {
+                    *ce = (u64::from(p) << 32)
+                        | u64::from(s)
+                        | u64::from(t >> 16) & 0x3F3F
+                        | case
+                        | u64::from(q << 6);
+                }
+            }
+        }
+        Ok(ces)
+    }
+
+    /// Allocates the weights for the nodes at the level of a chain.
+    fn allocate(&self, key: &ChainKey, count: usize) -> Option<Vec<u32>> {
+        let anchor = key.anchor;
+        let p = (anchor >> 32) as u32;
+        let s = ((anchor >> 16) as u32) << 16;
+        let t = (anchor as u32 & 0x3F3F) << 16;
+        match (key.level, key.before) {
+            (Level::Primary, _) => {
+                let high = self
+                    .weights
+                    .next_primary(p)
+                    .map_or(PRIMARY_LIMIT, u64::from);
+                WeightBytes::PRIMARY.allocate(p, high, count)
+            }
+            (Level::Secondary, false) => {
+                let high = self
+                    .weights
+                    .next(anchor, Level::Secondary)
+                    .map_or(SECONDARY_LIMIT, u64::from);
+                WeightBytes::SECONDARY.allocate(skip_common(Level::Secondary, s), high, count)
+            }
+            (Level::Secondary, true) => {
+                let low = self
+                    .weights
+                    .prev(anchor, Level::Secondary)
+                    .map_or(BEFORE_LOWEST, |w| skip_common(Level::Secondary, w));
+                WeightBytes::SECONDARY.allocate(low, u64::from(s), count)
+            }
+            (Level::Tertiary, false) => {
+                let high = self
+                    .weights
+                    .next(anchor, Level::Tertiary)
+                    .map_or(TERTIARY_LIMIT, u64::from);
+                WeightBytes::TERTIARY.allocate(skip_common(Level::Tertiary, t), high, count)
+            }
+            (Level::Tertiary, true) => {
+                let low = self
+                    .weights
+                    .prev(anchor, Level::Tertiary)
+                    .map_or(BEFORE_LOWEST, |w| skip_common(Level::Tertiary, w));
+                WeightBytes::TERTIARY.allocate(low, u64::from(t), count)
+            }
+            (Level::Quaternary, _) => (count <= 3).then(|| (1..=count as u32).collect()),
+            (Level::Identical, _) => None,
+        }
+    }
+
+    /// The next weight at `level` after `weight` for a node weaker than its chain,
+    /// where there are no root weights to stay below.
+    fn next_free(&self, level: Level, weight: u32) -> Option<u32> {
+        match level {
+            Level::Secondary => {
+                WeightBytes::SECONDARY.allocate(skip_common(level, weight), SECONDARY_LIMIT, 1)
+            }
+            Level::Tertiary => {
+                WeightBytes::TERTIARY.allocate(skip_common(level, weight), TERTIARY_LIMIT, 1)
+            }
+            Level::Quaternary => return (weight < 3).then_some(weight + 1),
+            _ => None,
+        }?
+        .first()
+        .copied()
+    }
+
+    /// The base mappings of a character, including prefixes and contractions
+    fn conditionals(
+        &self,
+        data: &CollationData,
+        c: char,
+        ce32: CollationElement32,
+        prefix: &str,
+        out: &mut Conditionals,
+    ) {
+        match ce32.tag_checked() {
+            Some(Tag::Prefix) if prefix.is_empty() => {
+                let (default, entries) = self.contexts_at(data, ce32.index());
+                self.conditionals(data, c, default, "", out);
+                for (key, value) in entries {
+                    let prefix: String = char::decode_utf16(key)
+                        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                        .collect();
+                    let prefix: String = prefix.chars().rev().collect();
+                    self.conditionals(data, c, CollationElement32::new(value), &prefix, out);
+                }
+            }
+            Some(Tag::Contraction) => {
+                let (default, entries) = self.contexts_at(data, ce32.index());
+                out.insert(
+                    (String::from(prefix), String::new()),
+                    self.ces_of(data, c, default),
+                );
+                for (key, value) in entries {
+                    let suffix = char::decode_utf16(key)
+                        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                        .collect();
+                    out.insert(
+                        (String::from(prefix), suffix),
+                        self.ces_of(data, c, CollationElement32::new(value)),
+                    );
+                }
+            }
+            _ => {
+                out.insert(
+                    (String::from(prefix), String::new()),
+                    self.ces_of(data, c, ce32),
+                );
+            }
+        }
+    }
+
+    fn is_non_starter(&self, c: char) -> bool {
+        ccc_from_trie_value(self.root_data.decompositions.get().trie.get(c))
+            != CanonicalCombiningClass::NotReordered
+    }
+
+    /// Encodes the contractions of a character after one prefix.
+    fn encode_contractions(
+        &self,
+        tables: &mut Tables,
+        suffixes: &BTreeMap<String, Vec<u64>>,
+    ) -> Option<u32> {
+        let default = tables.encode(suffixes.get("").map(Vec::as_slice).unwrap_or_default())?;
+        if suffixes.len() <= 1 {
+            return Some(default);
+        }
+        let mut entries = Vec::new();
+        let mut flags = 0x200;
+        for (suffix, ces) in suffixes.iter().filter(|(s, _)| !s.is_empty()) {
+            entries.push((
+                suffix.encode_utf16().collect::<Vec<u16>>(),
+                tables.encode(ces)?,
+            ));
+            if !suffix
+                .chars()
+                .next()
+                .is_some_and(|c| self.is_non_starter(c))
+            {
+                flags &= !0x200;
+            }
+            if suffix
+                .chars()
+                .next_back()
+                .is_some_and(|c| self.is_non_starter(c))
+            {
+                flags |= 0x400;
+            }
+            if suffix.chars().any(|c| !self.is_non_starter(c)) {
+                flags |= 0x800;
+            }
+        }
+        entries.sort();
+        Some(tables.push_context(default, &entries)? | flags | 0xC0 | Tag::Contraction as u32)
+    }
+
+    /// Encodes all mappings of a character, returning its CE32.
+    fn encode_char(&self, tables: &mut Tables, conditionals: &Conditionals) -> Option<u32> {
+        let mut by_prefix: BTreeMap<&str, BTreeMap<String, Vec<u64>>> = BTreeMap::new();
+        for ((prefix, suffix), ces) in conditionals {
+            by_prefix
+                .entry(prefix.as_str())
+                .or_default()
+                .insert(suffix.clone(), ces.clone());
+        }
+        // A prefix match takes precedence over shorter prefixes, so each prefix
+        // gets the contractions of the shorter ones that it does not override.
+        let mut inherited = BTreeMap::new();
+        for (&prefix, suffixes) in &by_prefix {
+            let mut suffixes = suffixes.clone();
+            for (i, _) in prefix.char_indices().skip(1).chain([(prefix.len(), ' ')]) {
+                let shorter = prefix.get(i..).unwrap_or_default();
+                for (suffix, ces) in by_prefix.get(shorter).into_iter().flatten() {
+                    suffixes
+                        .entry(suffix.clone())
+                        .or_insert_with(|| ces.clone());
+                }
+            }
+            inherited.insert(prefix, suffixes);
+        }
+
+        let mut default = None;
+        let mut entries = Vec::new();
+        for (prefix, suffixes) in &inherited {
+            let ce32 = self.encode_contractions(tables, suffixes)?;
+            if prefix.is_empty() {
+                default = Some(ce32);
+            } else {
+                let key = prefix.chars().rev().collect::<String>();
+                entries.push((key.encode_utf16().collect::<Vec<u16>>(), ce32));
+            }
+        }
+        let default = default?;
+        if entries.is_empty() {
+            return Some(default);
+        }
+        entries.sort();
+        Some(tables.push_context(default, &entries)? | 0xC0 | Tag::Prefix as u32)
+    }
+
+    /// Assembles the tailoring data and, if the tailoring affects the
+    /// combining diacritics, their shortened secondaries table.
+    fn finish(self) -> Result<(CollationData<'static>, Option<Vec<u16>>), TailoringError> {
+        let node_ces = self.node_ces()?;
+        let mut by_char: BTreeMap<char, (Conditionals, usize)> = BTreeMap::new();
+        for ((prefix, string), mapping) in &self.mappings {
+            let mut chars = string.chars();
+            let Some(first) = chars.next() else {
+                continue;
+            };
+            let ces = mapping
+                .ces
+                .iter()
+                .map(|&ce| match ce {
+                    Ce::Base(ce) => ce,
+                    Ce::Node(n) => node_ces.get(n).copied().unwrap_or_default(),
+                })
+                .collect();
+            let (conditionals, offset) = by_char.entry(first).or_default();
+            conditionals.insert((prefix.clone(), String::from(chars.as_str())), ces);
+            *offset = (*offset).max(mapping.offset);
+        }
+
+        let mut tables = Tables::default();
+        if let Some(base) = self.base {
+            tables.ces = base.ces.to_vec();
+            tables.ce32s = base.ce32s.to_vec();
+            tables.contexts = self.base_contexts.clone();
+        }
+        let mut tailored = Vec::new();
+        for (&c, (conditionals, offset)) in &mut by_char {
+            let (data, ce32) = match self.base {
+                Some(base) if base.ce32_for_char(c) != FALLBACK_CE32 => {
+                    (base, base.ce32_for_char(c))
+                }
+                _ => (self.root, self.root.ce32_for_char(c)),
+            };
+            let mut base = Conditionals::new();
+            self.conditionals(data, c, ce32, "", &mut base);
+            for (key, ces) in base {
+                conditionals.entry(key).or_insert(ces);
+            }
+            let ce32 = self
+                .encode_char(&mut tables, conditionals)
+                .ok_or(TailoringError::TooManyWeights { offset: *offset })?;
+            tailored.push((u32::from(c), ce32));
+        }
+
+        let mut ranges = Vec::new();
+        if let Some(base) = self.base {
+            for range in base.trie.iter_ranges() {
+                if range.value == FALLBACK_CE32.bits() {
+                    continue;
+                }
+                let (mut start, end) = (*range.range.start(), *range.range.end());
+                let i = tailored.partition_point(|&(c, _)| c < start);
+                for &(c, _) in tailored.iter().skip(i).take_while(|&&(c, _)| c <= end) {
+                    if c > start {
+                        ranges.push((start, c - 1, range.value));
+                    }
+                    start = c + 1;
+                }
+                if start <= end {
+                    ranges.push((start, end, range.value));
+                }
+            }
+        }
+        ranges.extend(tailored.iter().map(|&(c, ce32)| (c, c, ce32)));
+        ranges.sort_unstable();
+        let offset = self
+            .mappings
+            .values()
+            .map(|m| m.offset)
+            .max()
+            .unwrap_or_default();
+        let trie = data::write_code_point_trie(&ranges, FALLBACK_CE32.bits())
+            .ok_or(TailoringError::TooManyWeights { offset })?;
+
+        // The table of combining diacritics is consulted before the trie, so it
+        // has to end before the first tailored one.
+        let diacritics = self.holder.diacritics.get().secondaries.to_vec();
+        let diacritics = tailored
+            .iter()
+            .map(|&(c, _)| c.wrapping_sub(0x300) as usize)
+            .find(|&i| i < diacritics.len().min(OPTIMIZED_DIACRITICS_MAX_COUNT))
+            .map(|i| diacritics.get(..i).unwrap_or_default().to_vec());
+
+        Ok((
+            CollationData {
+                trie,
+                ces: ZeroVec::alloc_from_slice(&tables.ces),
+                ce32s: ZeroVec::alloc_from_slice(&tables.ce32s),
+                contexts: ZeroVec::alloc_from_slice(&tables.contexts),
+            },
+            diacritics,
+        ))
+    }
+}
+
+/// Moves a weight past the weights that are reserved for sort key compression.
+fn skip_common(level: Level, weight: u32) -> u32 {
+    let range = if level == Level::Secondary {
+        SECONDARY_COMMON_RANGE
+    } else {
+        TERTIARY_COMMON_RANGE
+    };
+    if range.contains(&weight) {
+        *range.end()
+    } else {
+        weight
+    }
+}
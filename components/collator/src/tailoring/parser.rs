@@ -0,0 +1,474 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The rule syntax handled here is that of ICU4C `CollationRuleParser`.
+
+//! Parser for the ICU collation rule syntax.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::TailoringError;
+use crate::options::{AlternateHandling, MaxVariable, Strength};
+use crate::preferences::CollationCaseFirst;
+
+/// The difference between a relation string and the position before it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum Level {
+    Primary = 1,
+    Secondary = 2,
+    Tertiary = 3,
+    Quaternary = 4,
+    Identical = 5,
+}
+
+/// A setting in square brackets.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Setting {
+    Strength(Strength),
+    Alternate(AlternateHandling),
+    MaxVariable(MaxVariable),
+    CaseFirst(CollationCaseFirst),
+    CaseLevel(bool),
+    Numeric(bool),
+    BackwardSecondLevel,
+    Import(String),
+}
+
+/// A parsed rule.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Rule {
+    /// `&position` or `&[before n]position`
+    Reset {
+        position: String,
+        before: Option<Level>,
+        offset: usize,
+    },
+    /// `< prefix|string/extension` and the like
+    Relation {
+        level: Level,
+        prefix: String,
+        string: String,
+        extension: String,
+        offset: usize,
+    },
+    Setting {
+        setting: Setting,
+        offset: usize,
+    },
+}
+
+/// Whether the character is ASCII punctuation that has to be quoted or escaped
+/// to be used literally.
+fn is_syntax_char(c: char) -> bool {
+    matches!(c, '\u{21}'..='\u{2F}' | '\u{3A}'..='\u{40}' | '\u{5B}'..='\u{60}' | '\u{7B}'..='\u{7E}')
+}
+
+/// `Pattern_White_Space`
+fn is_white_space(c: char) -> bool {
+    matches!(
+        c,
+        '\t' | '\n'
+            | '\u{0B}'
+            | '\u{0C}'
+            | '\r'
+            | ' '
+            | '\u{85}'
+            | '\u{200E}'
+            | '\u{200F}'
+            | '\u{2028}'
+            | '\u{2029}'
+    )
+}
+
+struct Parser<'a> {
+    rules: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn rest(&self) -> &str {
+        self.rules.get(self.pos..).unwrap_or_default()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn syntax_error(&self) -> TailoringError {
+        TailoringError::Syntax { offset: self.pos }
+    }
+
+    /// Skips white space and `#` comments.
+    fn skip_white_space(&mut self) {
+        while let Some(c) = self.peek() {
+            if is_white_space(c) {
+                self.bump();
+            } else if c == '#' {
+                while let Some(c) = self.bump() {
+                    if matches!(c, '\n' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}') {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Parses the hex digits of an escape sequence.
+    fn parse_hex(&mut self, min: usize, max: usize) -> Result<char, TailoringError> {
+        let start = self.pos;
+        let mut value = 0u32;
+        let mut count = 0;
+        while count < max
+            && let Some(digit) = self.peek().and_then(|c| c.to_digit(16))
+        {
+            self.bump();
+            value = value * 16 + digit;
+            count += 1;
+        }
+        if count < min {
+            return Err(TailoringError::Syntax { offset: start });
+        }
+        char::from_u32(value).ok_or(TailoringError::Syntax { offset: start })
+    }
+
+    /// Parses the character after a backslash.
+    fn parse_escape(&mut self) -> Result<char, TailoringError> {
+        let c = self.bump().ok_or_else(|| self.syntax_error())?;
+        Ok(match c {
+            'u' => self.parse_hex(4, 4)?,
+            'U' => self.parse_hex(8, 8)?,
+            'x' => {
+                if self.eat('{') {
+                    let c = self.parse_hex(1, 8)?;
+                    if !self.eat('}') {
+                        return Err(self.syntax_error());
+                    }
+                    c
+                } else {
+                    self.parse_hex(1, 2)?
+                }
+            }
+            'a' => '\u{07}',
+            'b' => '\u{08}',
+            't' => '\t',
+            'n' => '\n',
+            'v' => '\u{0B}',
+            'f' => '\u{0C}',
+            'r' => '\r',
+            'e' => '\u{1B}',
+            other => other,
+        })
+    }
+
+    /// Parses a string of literal, quoted and escaped characters up to the
+    /// next white space or unquoted syntax character.
+    fn parse_string(&mut self) -> Result<String, TailoringError> {
+        let mut s = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\'' {
+                self.bump();
+                if self.eat('\'') {
+                    // Doubled apostrophe
+                    s.push('\'');
+                    continue;
+                }
+                loop {
+                    match self.bump() {
+                        None => return Err(self.syntax_error()),
+                        Some('\'') => {
+                            if self.eat('\'') {
+                                s.push('\'');
+                            } else {
+                                break;
+                            }
+                        }
+                        Some(c) => s.push(c),
+                    }
+                }
+            } else if c == '\\' {
+                self.bump();
+                s.push(self.parse_escape()?);
+            } else if is_white_space(c) || is_syntax_char(c) {
+                break;
+            } else {
+                self.bump();
+                s.push(c);
+            }
+        }
+        Ok(s)
+    }
+
+    fn parse_non_empty_string(&mut self) -> Result<String, TailoringError> {
+        let start = self.pos;
+        let s = self.parse_string()?;
+        // Like ICU4C, which uses U+0000 as a terminator, reject it in strings
+        if s.is_empty() || s.contains('\0') {
+            return Err(TailoringError::Syntax { offset: start });
+        }
+        Ok(s)
+    }
+
+    /// Returns the contents of a bracketed expression, after the opening bracket,
+    /// allowing for nested brackets.
+    fn bracket_contents(&mut self) -> Result<&str, TailoringError> {
+        let start = self.pos;
+        let mut depth = 1;
+        while let Some(c) = self.bump() {
+            match c {
+                '[' => depth += 1,
+                ']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(self
+                            .rules
+                            .get(start..self.pos - 1)
+                            .unwrap_or_default()
+                            .trim());
+                    }
+                }
+                '\\' => {
+                    self.bump();
+                }
+                _ => {}
+            }
+        }
+        Err(TailoringError::Syntax { offset: start })
+    }
+
+    fn parse_reset(&mut self, offset: usize) -> Result<Rule, TailoringError> {
+        self.skip_white_space();
+        let mut before = None;
+        if self.peek() == Some('[') {
+            let start = self.pos;
+            self.bump();
+            let contents = self.bracket_contents()?;
+            before = Some(
+                match contents.split_whitespace().collect::<Vec<_>>().as_slice() {
+                    ["before", "1"] => Level::Primary,
+                    ["before", "2"] => Level::Secondary,
+                    ["before", "3"] => Level::Tertiary,
+                    // Special reset positions like `[first regular]`
+                    [first, ..] if first.starts_with("first") || first.starts_with("last") => {
+                        return Err(TailoringError::Unsupported { offset: start });
+                    }
+                    _ => return Err(TailoringError::Syntax { offset: start }),
+                },
+            );
+            self.skip_white_space();
+            if self.peek() == Some('[') {
+                return Err(TailoringError::Unsupported { offset: self.pos });
+            }
+        }
+        let position = self.parse_non_empty_string()?;
+        Ok(Rule::Reset {
+            position,
+            before,
+            offset,
+        })
+    }
+
+    fn parse_relation(
+        &mut self,
+        offset: usize,
+        rules: &mut Vec<Rule>,
+    ) -> Result<(), TailoringError> {
+        let level = match self.bump() {
+            Some('<') => {
+                let mut level = Level::Primary;
+                for next in [Level::Secondary, Level::Tertiary, Level::Quaternary] {
+                    if self.eat('<') {
+                        level = next;
+                    } else {
+                        break;
+                    }
+                }
+                level
+            }
+            Some(';') => Level::Secondary,
+            Some(',') => Level::Tertiary,
+            _ => Level::Identical,
+        };
+        let starred = self.eat('*');
+        self.skip_white_space();
+        if starred {
+            // Each character is a relation string of its own, and `a-z` is a range.
+            let mut range_start: Option<char> = None;
+            loop {
+                let range_offset = self.pos;
+                let s = self.parse_non_empty_string()?;
+                let mut chars = s.chars();
+                if let Some(start) = range_start
+                    && let Some(end) = chars.next()
+                {
+                    if start >= end {
+                        return Err(TailoringError::Syntax {
+                            offset: range_offset,
+                        });
+                    }
+                    for c in (u32::from(start) + 1..=u32::from(end)).filter_map(char::from_u32) {
+                        rules.push(Rule::Relation {
+                            level,
+                            prefix: String::new(),
+                            string: c.into(),
+                            extension: String::new(),
+                            offset,
+                        });
+                    }
+                }
+                for c in chars {
+                    rules.push(Rule::Relation {
+                        level,
+                        prefix: String::new(),
+                        string: c.into(),
+                        extension: String::new(),
+                        offset,
+                    });
+                }
+                if self.eat('-') {
+                    range_start = s.chars().next_back();
+                } else {
+                    break;
+                }
+            }
+            return Ok(());
+        }
+        let mut prefix = String::new();
+        let mut string = self.parse_non_empty_string()?;
+        self.skip_white_space();
+        if self.eat('|') {
+            self.skip_white_space();
+            prefix = core::mem::replace(&mut string, self.parse_non_empty_string()?);
+            self.skip_white_space();
+        }
+        let mut extension = String::new();
+        if self.eat('/') {
+            self.skip_white_space();
+            extension = self.parse_non_empty_string()?;
+        }
+        rules.push(Rule::Relation {
+            level,
+            prefix,
+            string,
+            extension,
+            offset,
+        });
+        Ok(())
+    }
+
+    /// Parses a setting after its opening bracket, returning `None` for settings
+    /// that have no effect.
+    fn parse_setting(&mut self, offset: usize) -> Result<Option<Setting>, TailoringError> {
+        let contents = self.bracket_contents()?;
+        let unsupported = Err(TailoringError::Unsupported { offset });
+        let syntax = Err(TailoringError::Syntax { offset });
+        let mut words = contents.split_whitespace();
+        let (Some(key), value, None) = (words.next(), words.next(), words.next()) else {
+            // `[reorder]` lists several scripts, and `[suppressContractions]`
+            // and `[optimize]` take a set
+            return if contents.starts_with("reorder")
+                || contents.starts_with("suppressContractions")
+                || contents.starts_with("optimize")
+            {
+                unsupported
+            } else {
+                syntax
+            };
+        };
+        let on_off = match value {
+            Some("on") => Some(true),
+            Some("off") => Some(false),
+            _ => None,
+        };
+        Ok(Some(match (key, value) {
+            ("strength", Some("1")) => Setting::Strength(Strength::Primary),
+            ("strength", Some("2")) => Setting::Strength(Strength::Secondary),
+            ("strength", Some("3")) => Setting::Strength(Strength::Tertiary),
+            ("strength", Some("4")) => Setting::Strength(Strength::Quaternary),
+            ("strength", Some("I")) => Setting::Strength(Strength::Identical),
+            ("alternate", Some("non-ignorable")) => {
+                Setting::Alternate(AlternateHandling::NonIgnorable)
+            }
+            ("alternate", Some("shifted")) => Setting::Alternate(AlternateHandling::Shifted),
+            ("maxVariable", Some("space")) => Setting::MaxVariable(MaxVariable::Space),
+            ("maxVariable", Some("punct")) => Setting::MaxVariable(MaxVariable::Punctuation),
+            ("maxVariable", Some("symbol")) => Setting::MaxVariable(MaxVariable::Symbol),
+            ("maxVariable", Some("currency")) => Setting::MaxVariable(MaxVariable::Currency),
+            ("caseFirst", Some("off")) => Setting::CaseFirst(CollationCaseFirst::False),
+            ("caseFirst", Some("lower")) => Setting::CaseFirst(CollationCaseFirst::Lower),
+            ("caseFirst", Some("upper")) => Setting::CaseFirst(CollationCaseFirst::Upper),
+            ("caseLevel", _) if on_off.is_some() => Setting::CaseLevel(on_off == Some(true)),
+            ("numericOrdering", _) if on_off.is_some() => Setting::Numeric(on_off == Some(true)),
+            ("backwards", Some("2")) => Setting::BackwardSecondLevel,
+            ("import", Some(tag)) => Setting::Import(tag.into()),
+            // Input is always normalized to NFD
+            ("normalization", _) if on_off.is_some() => return Ok(None),
+            ("reorder" | "hiraganaQ", _) => return unsupported,
+            _ => return syntax,
+        }))
+    }
+}
+
+/// Parses collation rules into a list of resets, relations and settings.
+pub(super) fn parse(rules: &str) -> Result<Vec<Rule>, TailoringError> {
+    let mut parser = Parser { rules, pos: 0 };
+    let mut out = Vec::new();
+    let mut has_reset = false;
+    loop {
+        parser.skip_white_space();
+        let offset = parser.pos;
+        match parser.peek() {
+            None => break,
+            Some('&') => {
+                parser.bump();
+                out.push(parser.parse_reset(offset)?);
+                has_reset = true;
+            }
+            Some('<' | ';' | ',' | '=') => {
+                if !has_reset {
+                    return Err(parser.syntax_error());
+                }
+                parser.parse_relation(offset, &mut out)?;
+            }
+            Some('[') => {
+                parser.bump();
+                if let Some(setting) = parser.parse_setting(offset)? {
+                    out.push(Rule::Setting { setting, offset });
+                }
+            }
+            Some('@') => {
+                // Legacy syntax for `[backwards 2]`
+                parser.bump();
+                out.push(Rule::Setting {
+                    setting: Setting::BackwardSecondLevel,
+                    offset,
+                });
+            }
+            Some('!') => {
+                // Legacy syntax for Thai/Lao prevowel reordering, which is
+                // handled by contractions in the root collation
+                parser.bump();
+            }
+            Some(_) => return Err(parser.syntax_error()),
+        }
+    }
+    Ok(out)
+}
@@ -28,6 +28,7 @@ const _: () = {
     impl_collation_metadata_v1!(TestingProvider);
     impl_collation_special_primaries_v1!(TestingProvider);
     impl_collation_reordering_v1!(TestingProvider);
+    impl_collation_script_starts_v1!(TestingProvider);
 
     icu_normalizer_data::impl_normalizer_nfc_v1!(TestingProvider);
     icu_normalizer_data::impl_normalizer_nfd_data_v1!(TestingProvider);
@@ -2196,3 +2197,557 @@ fn test_shifted_max_variable_currency() {
     let collator = Collator::try_new(prefs, options).unwrap();
     assert_eq!(collator.compare("", " "), Ordering::Equal);
 }
+
+fn reorder_codes(codes: &[CollationReorderCode]) -> CollationReorderCodes {
+    CollationReorderCodes::try_from_slice(codes).unwrap()
+}
+
+#[test]
+fn test_reorder_codes() {
+    use CollationReorderCode::*;
+    use icu_locale_core::subtags::script;
+
+    let mut options = CollatorOptions::default();
+    options.reorder_codes = Some(reorder_codes(&[Script(script!("Grek"))]));
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    assert_all_comparisons(&collator, "Ω", "A", Ordering::Less);
+    assert_all_comparisons(&collator, "α", "я", Ordering::Less);
+    // Digits are not listed, so they stay before the scripts.
+    assert_all_comparisons(&collator, "1", "α", Ordering::Less);
+
+    let collator = Collator::try_new(locale!("en-u-kr-grek").into(), Default::default()).unwrap();
+    assert_all_comparisons(&collator, "Ω", "A", Ordering::Less);
+
+    // Digits after letters
+    let locale: Locale = "en-u-kr-others-digit".parse().unwrap();
+    let collator = Collator::try_new(locale.into(), Default::default()).unwrap();
+    assert_all_comparisons(&collator, "a", "1", Ordering::Less);
+    assert_all_comparisons(&collator, "ω", "1", Ordering::Less);
+    assert_all_comparisons(&collator, "!", "a", Ordering::Less);
+
+    // Currency symbols before punctuation, after the unlisted special groups
+    options.reorder_codes = Some(reorder_codes(&[Currency, Punctuation]));
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    for pair in [" ", "+", "1", "$", "!", "a"].windows(2) {
+        assert_all_comparisons(&collator, pair[0], pair[1], Ordering::Less);
+    }
+
+    // Scripts that are sorted together are moved together.
+    options.reorder_codes = Some(reorder_codes(&[
+        Script(script!("Kana")),
+        Script(script!("Hira")),
+    ]));
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    assert_all_comparisons(&collator, "カ", "a", Ordering::Less);
+    assert_all_comparisons(&collator, "か", "a", Ordering::Less);
+
+    // The maximum number of codes
+    options.reorder_codes = Some(reorder_codes(&[
+        Script(script!("Hani")),
+        Script(script!("Hang")),
+        Script(script!("Kana")),
+        Script(script!("Thai")),
+        Script(script!("Copt")),
+        Script(script!("Mong")),
+        Script(script!("Ethi")),
+        Script(script!("Cyrl")),
+        Digit,
+        Others,
+        Script(script!("Latn")),
+        Script(script!("Grek")),
+        Script(script!("Arab")),
+        Currency,
+        Symbol,
+        Space,
+    ]));
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    for pair in [
+        "漢", "한", "カ", "ก", "ⲁ", "ᠠ", "ሀ", "я", "1", "ա", "a", "α", "ا", "$", "+", " ",
+    ]
+    .windows(2)
+    {
+        assert_all_comparisons(&collator, pair[0], pair[1], Ordering::Less);
+    }
+}
+
+#[test]
+fn test_reorder_codes_replace_locale() {
+    use CollationReorderCode::*;
+    use icu_locale_core::subtags::script;
+
+    // Russian puts Cyrillic first.
+    let collator = Collator::try_new(locale!("ru").into(), Default::default()).unwrap();
+    assert_all_comparisons(&collator, "я", "a", Ordering::Less);
+
+    let collator = Collator::try_new(locale!("ru-u-kr-others").into(), Default::default()).unwrap();
+    assert_all_comparisons(&collator, "a", "я", Ordering::Less);
+
+    // The options take precedence over the preferences.
+    let mut options = CollatorOptions::default();
+    options.reorder_codes = Some(reorder_codes(&[Script(script!("Latn"))]));
+    let collator = Collator::try_new(locale!("ru-u-kr-grek").into(), options).unwrap();
+    assert_all_comparisons(&collator, "a", "α", Ordering::Less);
+    assert_all_comparisons(&collator, "α", "я", Ordering::Less);
+
+    // Explicit codes that are the same as the ones implied by the locale
+    // result in the same order.
+    for (locale, codes) in [
+        (locale!("ru"), &[Script(script!("Cyrl"))][..]),
+        (
+            locale!("ja"),
+            &[
+                Script(script!("Latn")),
+                Script(script!("Kana")),
+                Script(script!("Hani")),
+            ],
+        ),
+        (
+            locale!("ko"),
+            &[Script(script!("Hang")), Script(script!("Hani"))],
+        ),
+        (
+            locale!("mn"),
+            &[Script(script!("Cyrl")), Script(script!("Mong"))],
+        ),
+    ] {
+        let implied = Collator::try_new(locale.clone().into(), Default::default()).unwrap();
+        options.reorder_codes = Some(reorder_codes(codes));
+        let explicit = Collator::try_new(locale.into(), options).unwrap();
+        for s in ["a", "1", "!", "α", "я", "ᠠ", "カ", "漢", "한", "\u{10FFFF}"] {
+            let mut implied_key = Vec::new();
+            implied.write_sort_key_to(s, &mut implied_key).unwrap();
+            let mut explicit_key = Vec::new();
+            explicit.write_sort_key_to(s, &mut explicit_key).unwrap();
+            assert_eq!(implied_key, explicit_key, "{s}");
+        }
+    }
+
+    // Owned data and tailorings
+    let prefs = locale!("ru-u-kr-grek").into();
+    let collator = Collator::try_new_unstable(&TestingProvider, prefs, Default::default()).unwrap();
+    assert_all_comparisons(&collator.as_borrowed(), "α", "я", Ordering::Less);
+    let mut builder = TailoringBuilder::new("&a < b");
+    builder.options.reorder_codes = Some(reorder_codes(&[Script(script!("Grek"))]));
+    let collator = builder.try_new().unwrap();
+    assert_all_comparisons(&collator.as_borrowed(), "α", "b", Ordering::Less);
+    let collator = Collator::try_new(locale!("en-u-kr-grek").into(), Default::default())
+        .unwrap()
+        .static_to_owned();
+    assert_all_comparisons(&collator.as_borrowed(), "α", "a", Ordering::Less);
+}
+
+fn assert_tailored_order(rules: &str, strings: &[&str], expected: Ordering) {
+    let collator = TailoringBuilder::new(rules).try_new().unwrap();
+    let collator = collator.as_borrowed();
+    for pair in strings.windows(2) {
+        assert_all_comparisons(&collator, pair[0], pair[1], expected);
+    }
+}
+
+#[test]
+fn test_tailoring_relations() {
+    assert_tailored_order("&b < a", &["b", "bz", "a", "c"], Ordering::Less);
+    assert_tailored_order(
+        "&a < b < c < d < e",
+        &["a", "b", "c", "d", "e", "f"],
+        Ordering::Less,
+    );
+    assert_tailored_order("&o << ö <<< Ö", &["o", "ö", "Ö", "oa", "p"], Ordering::Less);
+    assert_tailored_order("&o <<< q", &["o", "q", "O", "oa", "p"], Ordering::Less);
+    assert_tailored_order(
+        "&o < q <<< Q << r",
+        &["oz", "q", "Q", "r", "qa", "p"],
+        Ordering::Less,
+    );
+    assert_tailored_order(
+        "&a < x <<< X &X < y",
+        &["a", "x", "X", "y", "b"],
+        Ordering::Less,
+    );
+    assert_tailored_order("&a = q", &["a", "q"], Ordering::Equal);
+    assert_tailored_order("&a < \\u0301", &["aa", "á", "ab"], Ordering::Less);
+    assert_tailored_order(
+        "&\\u4E00 < a",
+        &["\u{4E00}", "a", "\u{4E01}"],
+        Ordering::Less,
+    );
+    assert_tailored_order("&z < \\U0001F600", &["z", "\u{1F600}", "α"], Ordering::Less);
+
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Quaternary);
+    let mut builder = TailoringBuilder::new("&a <<<< x");
+    builder.options = options;
+    let collator = builder.try_new().unwrap();
+    let collator = collator.as_borrowed();
+    assert_all_comparisons(&collator, "a", "x", Ordering::Less);
+    assert_all_comparisons(&collator, "x", "A", Ordering::Less);
+}
+
+#[test]
+fn test_tailoring_contractions_and_expansions() {
+    assert_tailored_order(
+        "&c < ch <<< cH <<< Ch <<< CH &l < ll",
+        &["cz", "ch", "cH", "Ch", "CH", "d", "lz", "ll", "m"],
+        Ordering::Less,
+    );
+    assert_tailored_order("&c < ch &ch < x", &["c", "ch", "x", "d"], Ordering::Less);
+    assert_tailored_order("&a < ä", &["az", "ä", "b"], Ordering::Less);
+    assert_tailored_order("&ae << æ <<< Æ", &["ae", "æ", "Æ", "af"], Ordering::Less);
+    assert_tailored_order("&x < y/e", &["x", "y", "ye"], Ordering::Less);
+    // Prefixes
+    assert_tailored_order("&z < a|b", &["az", "ab", "b"], Ordering::Less);
+    assert_tailored_order("&z < a|b", &["cb", "cz"], Ordering::Less);
+}
+
+#[test]
+fn test_tailoring_before() {
+    assert_tailored_order("&[before 1]b < z", &["a", "z", "b"], Ordering::Less);
+    assert_tailored_order("&[before 2]o << q", &["n", "q", "o", "ò"], Ordering::Less);
+    assert_tailored_order("&[before 3]o <<< q", &["n", "q", "o", "O"], Ordering::Less);
+    assert_tailored_order("&a < b &[before 1]b < x", &["a", "x", "b"], Ordering::Less);
+    assert_tailored_order(
+        "&a < x &[before 3]x <<< y",
+        &["a", "y", "x", "b"],
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_tailoring_settings() {
+    assert_tailored_order(
+        "[caseFirst upper]&z < q",
+        &["A", "a", "z", "q"],
+        Ordering::Less,
+    );
+    assert_tailored_order("[numericOrdering on]", &["2", "10"], Ordering::Less);
+    assert_tailored_order(
+        "[backwards 2]",
+        &["cote", "côte", "coté", "côté"],
+        Ordering::Less,
+    );
+    assert_tailored_order("[strength 1]&z < q", &["a", "A", "á"], Ordering::Equal);
+
+    // Options take precedence
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Tertiary);
+    let mut builder = TailoringBuilder::new("[strength 1]");
+    builder.options = options;
+    let collator = builder.try_new().unwrap();
+    assert_eq!(collator.as_borrowed().compare("a", "A"), Ordering::Less);
+}
+
+#[test]
+fn test_tailoring_import() {
+    assert_tailored_order(
+        "[import de-u-co-phonebk]&z < q",
+        &["ad", "ä", "af", "z", "q"],
+        Ordering::Less,
+    );
+    assert_tailored_order("[import sv]&z < q", &["z", "q", "å", "ä"], Ordering::Less);
+    assert_tailored_order(
+        "[import es-u-co-trad]&ch < q",
+        &["cz", "ch", "q", "d"],
+        Ordering::Less,
+    );
+
+    let collator = TailoringBuilder::new("[import sv]&z < q")
+        .try_new_unstable(&TestingProvider)
+        .unwrap();
+    assert_eq!(collator.as_borrowed().compare("q", "å"), Ordering::Less);
+
+    // Settings can come before the import, which overrides them.
+    assert_tailored_order(
+        "[strength 1][import sv]&z < q",
+        &["z", "q", "å", "ä"],
+        Ordering::Less,
+    );
+    let collator = TailoringBuilder::new("[strength 1][import sv]")
+        .try_new()
+        .unwrap();
+    assert_eq!(collator.as_borrowed().compare("a", "A"), Ordering::Equal);
+    let collator = TailoringBuilder::new("[caseFirst lower][import da]")
+        .try_new()
+        .unwrap();
+    assert_eq!(collator.as_borrowed().compare("A", "a"), Ordering::Less);
+    let collator = TailoringBuilder::new("[import da][caseFirst lower]")
+        .try_new()
+        .unwrap();
+    assert_eq!(collator.as_borrowed().compare("a", "A"), Ordering::Less);
+}
+
+#[test]
+fn test_tailoring_many_weights() {
+    let chars: Vec<char> = ('\u{4E00}'..'\u{5000}').collect();
+    let mut rules = String::from("&a");
+    for (i, c) in chars.iter().enumerate() {
+        rules.push_str(["<", "<<", "<<<"][i % 3]);
+        rules.push(*c);
+    }
+    let collator = TailoringBuilder::new(&rules).try_new().unwrap();
+    let collator = collator.as_borrowed();
+    let strings: Vec<String> = core::iter::once('a')
+        .chain(chars)
+        .chain(['b'])
+        .map(String::from)
+        .collect();
+    for pair in strings.windows(2) {
+        assert_all_comparisons(&collator, &pair[0], &pair[1], Ordering::Less);
+    }
+}
+
+#[test]
+fn test_tailoring_errors() {
+    let error = |rules| TailoringBuilder::new(rules).try_new().err();
+    assert_eq!(error("a < b"), Some(TailoringError::Syntax { offset: 0 }));
+    assert_eq!(error("&a < 'b"), Some(TailoringError::Syntax { offset: 7 }));
+    assert_eq!(
+        error("[import xx-!!]&a < b"),
+        Some(TailoringError::Syntax { offset: 0 })
+    );
+    assert_eq!(
+        error("&[first regular] < a"),
+        Some(TailoringError::Unsupported { offset: 1 })
+    );
+    assert_eq!(
+        error("&a < b [import de]"),
+        Some(TailoringError::Unsupported { offset: 7 })
+    );
+    assert_eq!(
+        error("&a < \\u0000"),
+        Some(TailoringError::Syntax { offset: 5 })
+    );
+    assert_eq!(error("&a < \0"), Some(TailoringError::Syntax { offset: 5 }));
+    assert_eq!(
+        error("[strength 2][import xx-!!]&a < b"),
+        Some(TailoringError::Syntax { offset: 12 })
+    );
+    assert_eq!(
+        error("[import de][import sv]&a < b"),
+        Some(TailoringError::Unsupported { offset: 11 })
+    );
+    assert_eq!(
+        error("&a < \\u1100"),
+        Some(TailoringError::Unsupported { offset: 3 })
+    );
+}
+
+#[cfg(feature = "icu_segmenter")]
+fn search_collator(locale: Locale, strength: Strength) -> CollatorBorrowed<'static> {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(strength);
+    Collator::try_new(locale.into(), options).unwrap()
+}
+
+#[cfg(feature = "icu_segmenter")]
+fn assert_matches(searcher: &StringSearcher, haystack: &str, expected: &[&str]) {
+    let matches: Vec<&str> = searcher
+        .find_iter_str(haystack)
+        .map(|range| &haystack[range])
+        .collect();
+    assert_eq!(matches, expected, "{haystack}");
+
+    let utf16: Vec<u16> = haystack.encode_utf16().collect();
+    let matches: Vec<String> = searcher
+        .find_iter_utf16(&utf16)
+        .map(|range| String::from_utf16(&utf16[range]).unwrap())
+        .collect();
+    assert_eq!(matches, expected, "{haystack}");
+}
+
+#[test]
+#[cfg(feature = "icu_segmenter")]
+fn test_search_strength() {
+    let collator = search_collator(locale!("fr"), Strength::Primary);
+    let searcher = StringSearcher::new(&collator, "cafe", SearchOptions::default());
+    assert_matches(&searcher, "Café au lait", &["Café"]);
+    assert_matches(
+        &searcher,
+        "cafe\u{301}, CAFE, caf",
+        &["cafe\u{301}", "CAFE"],
+    );
+    assert_eq!(searcher.find_str("Café au lait"), Some(0..5));
+    assert_eq!(
+        searcher.find_utf16(&"Café au lait".encode_utf16().collect::<Vec<_>>()),
+        Some(0..4)
+    );
+
+    let mut options = SearchOptions::default();
+    options.strength = Some(Strength::Secondary);
+    let searcher = StringSearcher::new(&collator, "cafe", options);
+    assert_matches(&searcher, "Café, café, CAFE, cafe\u{301}", &["CAFE"]);
+
+    options.strength = Some(Strength::Tertiary);
+    let searcher = StringSearcher::new(&collator, "cafe", options);
+    assert_matches(&searcher, "Cafe, CAFE, cafe", &["cafe"]);
+
+    // Canonically equivalent strings are equal even at the identical strength.
+    options.strength = Some(Strength::Identical);
+    let searcher = StringSearcher::new(&collator, "\u{C5}", options);
+    assert_matches(
+        &searcher,
+        "\u{212B}A\u{30A}\u{E5}",
+        &["\u{212B}", "A\u{30A}"],
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_segmenter")]
+fn test_search_case_level() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    options.case_level = Some(CaseLevel::On);
+    let collator = Collator::try_new(locale!("en").into(), options).unwrap();
+    let searcher = StringSearcher::new(&collator, "cafe", SearchOptions::default());
+    assert_matches(&searcher, "Café, café, CAFE", &["café"]);
+}
+
+#[test]
+#[cfg(feature = "icu_segmenter")]
+fn test_search_boundaries() {
+    let collator = search_collator(locale!("en"), Strength::Primary);
+
+    // A match extends over the whole grapheme cluster.
+    let searcher = StringSearcher::new(&collator, "e", SearchOptions::default());
+    assert_matches(
+        &searcher,
+        "e\u{301}e\u{328}\u{301}",
+        &["e\u{301}", "e\u{328}\u{301}"],
+    );
+
+    // Matches do not overlap.
+    let searcher = StringSearcher::new(&collator, "aa", SearchOptions::default());
+    assert_matches(&searcher, "aaaaa", &["aa", "aa"]);
+
+    // A pattern that is ignorable at the search strength matches nothing.
+    let searcher = StringSearcher::new(&collator, "\u{301}", SearchOptions::default());
+    assert_matches(&searcher, "e\u{301}\u{301}", &[]);
+    let searcher = StringSearcher::new(&collator, "", SearchOptions::default());
+    assert_matches(&searcher, "abc", &[]);
+
+    // Matches do not split contractions.
+    let collator = search_collator(locale!("cs"), Strength::Primary);
+    let searcher = StringSearcher::new(&collator, "c", SearchOptions::default());
+    assert_matches(&searcher, "chata cena", &["c"]);
+    assert_eq!(searcher.find_str("chata cena"), Some(6..7));
+    let searcher = StringSearcher::new(&collator, "ch", SearchOptions::default());
+    assert_matches(&searcher, "chata Chrudim cena", &["ch", "Ch"]);
+}
+
+#[test]
+#[cfg(feature = "icu_segmenter")]
+fn test_search_alternate_handling() {
+    let collator = search_collator(locale!("en"), Strength::Tertiary);
+    let searcher = StringSearcher::new(&collator, "email", SearchOptions::default());
+    assert_matches(&searcher, "e-mail, e mail, email", &["email"]);
+
+    let mut options = SearchOptions::default();
+    options.alternate_handling = Some(AlternateHandling::Shifted);
+    let searcher = StringSearcher::new(&collator, "email", options);
+    assert_matches(
+        &searcher,
+        "e-mail, e mail, email",
+        &["e-mail", "e mail", "email"],
+    );
+
+    // At the quaternary strength, the shifted characters have to match.
+    options.strength = Some(Strength::Quaternary);
+    let searcher = StringSearcher::new(&collator, "e-mail", options);
+    assert_matches(&searcher, "e-mail, e mail, email", &["e-mail"]);
+
+    let mut collator_options = CollatorOptions::default();
+    collator_options.alternate_handling = Some(AlternateHandling::Shifted);
+    let collator = Collator::try_new(locale!("en").into(), collator_options).unwrap();
+    let searcher = StringSearcher::new(&collator, "email", SearchOptions::default());
+    assert_matches(&searcher, "e.mail", &["e.mail"]);
+}
+
+fn element_ranges(collator: &CollatorBorrowed, s: &str) -> Vec<core::ops::Range<usize>> {
+    collator
+        .collation_elements_str(s)
+        .map(|(range, _)| range)
+        .collect()
+}
+
+#[test]
+fn test_collation_elements() {
+    let collator = Collator::try_new(locale!("en").into(), Default::default()).unwrap();
+    assert_eq!(element_ranges(&collator, "abc"), [0..1, 1..2, 2..3]);
+    assert_eq!(element_ranges(&collator, ""), []);
+    let primaries = collator
+        .collation_elements_str("abc")
+        .map(|(_, ce)| ce.primary())
+        .collect::<Vec<_>>();
+    assert!(primaries.is_sorted());
+
+    // The accent of a precomposed letter has its own collation element.
+    let precomposed = collator.collation_elements_str("é").collect::<Vec<_>>();
+    let decomposed = collator
+        .collation_elements_str("e\u{301}")
+        .collect::<Vec<_>>();
+    assert_eq!(
+        precomposed.iter().map(|(_, ce)| *ce).collect::<Vec<_>>(),
+        decomposed.iter().map(|(_, ce)| *ce).collect::<Vec<_>>()
+    );
+    assert_eq!(element_ranges(&collator, "é"), [0..2, 0..2]);
+    assert_eq!(element_ranges(&collator, "e\u{301}"), [0..3, 0..3]);
+    assert_eq!(decomposed[1].1.primary(), 0);
+
+    // Case
+    let upper = collator.collation_elements_str("A").next().unwrap().1;
+    let lower = collator.collation_elements_str("a").next().unwrap().1;
+    assert_eq!(upper.primary(), lower.primary());
+    assert_eq!(upper.secondary(), lower.secondary());
+    assert_eq!((lower.case(), upper.case()), (0, 2));
+
+    // UTF-16 offsets are in code units.
+    let text = "a😀b".encode_utf16().collect::<Vec<_>>();
+    let mut ranges = collator
+        .collation_elements_utf16(&text)
+        .map(|(range, _)| range)
+        .collect::<Vec<_>>();
+    ranges.dedup();
+    assert_eq!(ranges, [0..1, 1..3, 3..4]);
+    assert_eq!(
+        collator
+            .collation_elements_utf16(&[0xD800])
+            .map(|(_, ce)| ce)
+            .collect::<Vec<_>>(),
+        collator
+            .collation_elements_str("\u{FFFD}")
+            .map(|(_, ce)| ce)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_collation_elements_contexts() {
+    // Contractions are not split.
+    let collator = Collator::try_new(locale!("cs").into(), Default::default()).unwrap();
+    assert_eq!(element_ranges(&collator, "chata"), [0..2, 2..3, 3..4, 4..5]);
+
+    // Neither are characters from the preceding character that they depend on.
+    let collator = Collator::try_new(locale!("ja").into(), Default::default()).unwrap();
+    let mut ranges = element_ranges(&collator, "カーa");
+    ranges.dedup();
+    assert_eq!(ranges, [0..6, 6..7]);
+
+    // Numeric ordering makes a run of digits one unit.
+    let mut prefs = CollatorPreferences::from(locale!("en"));
+    prefs.numeric_ordering = Some(CollationNumericOrdering::True);
+    let collator = Collator::try_new(prefs, Default::default()).unwrap();
+    let mut ranges = element_ranges(&collator, "a123b");
+    ranges.dedup();
+    assert_eq!(ranges, [0..1, 1..4, 4..5]);
+
+    // The primary weights are reordered like for comparisons.
+    let collator = Collator::try_new(locale!("en-u-kr-grek").into(), Default::default()).unwrap();
+    let primary = |s| {
+        collator
+            .collation_elements_str(s)
+            .next()
+            .unwrap()
+            .1
+            .primary()
+    };
+    assert!(primary("α") < primary("a"));
+}
@@ -2,10 +2,11 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use fixed_decimal::UnsignedDecimal;
+use fixed_decimal::{Decimal, Sign, UnsignedDecimal};
 use icu_plurals::PluralOperands;
 use writeable::Writeable;
 
+use crate::options::PrecisionOptions;
 use crate::{
     CompactDecimalFormatter, DecimalFormatter, FormattedSign, FormattedUnsignedCompactDecimal,
     FormattedUnsignedDecimal,
@@ -30,12 +31,22 @@ pub trait AbstractFormatter: core::fmt::Debug + Sealed {
     #[doc(hidden)]
     fn format_unsigned<'a>(&'a self, value: UnsignedDecimal) -> Self::FormattedUnsigned<'a>;
 
+    /// Rounds `value` according to `precision` and formats its absolute value,
+    /// returning the sign of the rounded value.
+    ///
+    /// The defaults are used for the fraction digits when `precision` does not
+    /// set them; compact formatters use the compact notation defaults instead.
     #[doc(hidden)]
-    fn format_sign<'a, W: Writeable>(
+    fn format_with_precision<'a>(
         &'a self,
-        value: W,
-        sign: fixed_decimal::Sign,
-    ) -> FormattedSign<'a, W>;
+        value: &Decimal,
+        precision: &PrecisionOptions,
+        minimum_fraction_digits_default: u8,
+        maximum_fraction_digits_default: u8,
+    ) -> (Sign, Self::FormattedUnsigned<'a>);
+
+    #[doc(hidden)]
+    fn format_sign<'a, W: Writeable>(&'a self, value: W, sign: Sign) -> FormattedSign<'a, W>;
 
     #[doc(hidden)]
     fn plural_operands(value: &Self::FormattedUnsigned<'_>) -> PluralOperands;
@@ -49,11 +60,26 @@ impl AbstractFormatter for DecimalFormatter {
         self.format_unsigned(crate::Cow::Owned(value))
     }
 
-    fn format_sign<'a, W: Writeable>(
+    fn format_with_precision<'a>(
         &'a self,
-        value: W,
-        sign: fixed_decimal::Sign,
-    ) -> FormattedSign<'a, W> {
+        value: &Decimal,
+        precision: &PrecisionOptions,
+        minimum_fraction_digits_default: u8,
+        maximum_fraction_digits_default: u8,
+    ) -> (Sign, Self::FormattedUnsigned<'a>) {
+        let mut value = value.clone();
+        precision.round(
+            &mut value,
+            minimum_fraction_digits_default,
+            maximum_fraction_digits_default,
+        );
+        (
+            value.sign,
+            self.format_unsigned(crate::Cow::Owned(value.absolute)),
+        )
+    }
+
+    fn format_sign<'a, W: Writeable>(&'a self, value: W, sign: Sign) -> FormattedSign<'a, W> {
         self.format_sign(sign, value)
     }
 
@@ -70,11 +96,17 @@ impl AbstractFormatter for CompactDecimalFormatter {
         self.format_unsigned(&value)
     }
 
-    fn format_sign<'a, W: Writeable>(
+    fn format_with_precision<'a>(
         &'a self,
-        value: W,
-        sign: fixed_decimal::Sign,
-    ) -> FormattedSign<'a, W> {
+        value: &Decimal,
+        precision: &PrecisionOptions,
+        _minimum_fraction_digits_default: u8,
+        _maximum_fraction_digits_default: u8,
+    ) -> (Sign, Self::FormattedUnsigned<'a>) {
+        self.format_with_precision(value, precision)
+    }
+
+    fn format_sign<'a, W: Writeable>(&'a self, value: W, sign: Sign) -> FormattedSign<'a, W> {
         self.decimal_formatter.format_sign(sign, value)
     }
 
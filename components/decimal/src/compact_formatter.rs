@@ -9,7 +9,7 @@ use crate::input::Decimal;
 use crate::{
     DecimalFormatter,
     error::CompactExponentError,
-    options::CompactDecimalFormatterOptions,
+    options::{CompactDecimalFormatterOptions, PrecisionOptions},
     preferences::{CompactDecimalFormatterPreferences, DecimalFormatterPreferences},
     provider::*,
 };
@@ -347,9 +347,86 @@ impl CompactDecimalFormatter {
     ///     "999K"
     /// );
     /// ```
+    ///
+    /// If [`CompactDecimalFormatterOptions::precision`] is set, the significand is rounded
+    /// and padded according to it instead.
+    ///
+    /// ```
+    /// # use icu::decimal::CompactDecimalFormatter;
+    /// # use icu::decimal::options::{CompactDecimalFormatterOptions, PrecisionOptions};
+    /// # use icu::locale::locale;
+    /// # use writeable::assert_writeable_eq;
+    /// #
+    /// let mut precision = PrecisionOptions::default();
+    /// precision.maximum_fraction_digits = Some(2);
+    /// let short_english = CompactDecimalFormatter::try_new_short(
+    ///     locale!("en").into(),
+    ///     CompactDecimalFormatterOptions::from(precision),
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_writeable_eq!(short_english.format(&1_234_567i64.into()), "1.23M");
+    /// assert_writeable_eq!(short_english.format(&999_999i64.into()), "1M");
+    /// ```
     pub fn format<'a>(&'a self, value: &Decimal) -> impl Writeable + Display + 'a {
-        self.decimal_formatter
-            .format_sign(value.sign, self.format_unsigned(&value.absolute))
+        let (sign, formatted) =
+            self.format_with_precision(value, &self.decimal_formatter.options.precision);
+        self.decimal_formatter.format_sign(sign, formatted)
+    }
+
+    /// Formats a [`Decimal`] according to the given precision options,
+    /// returning the sign of the rounded value along with its unsigned formatting.
+    pub(crate) fn format_with_precision<'a>(
+        &'a self,
+        value: &Decimal,
+        precision: &PrecisionOptions,
+    ) -> (fixed_decimal::Sign, FormattedUnsignedCompactDecimal<'a>) {
+        if precision.is_empty() {
+            return (value.sign, self.format_unsigned(&value.absolute));
+        }
+
+        let round = |exponent: u8| {
+            let mut significand = value.clone();
+            significand.multiply_pow10(-i16::from(exponent));
+            precision.round_compact(&mut significand);
+            significand
+        };
+
+        let mut exponent =
+            self.compact_exponent_for_magnitude(value.absolute.nonzero_magnitude_start());
+        let mut significand = round(exponent);
+        // Rounding may bump the number up to the next compact exponent, e.g. 999,999 -> 1M.
+        let rounded_exponent = self.compact_exponent_for_magnitude(
+            significand.absolute.nonzero_magnitude_start() + i16::from(exponent),
+        );
+        if rounded_exponent != exponent {
+            exponent = rounded_exponent;
+            significand = round(exponent);
+        }
+
+        let log10_type = significand.absolute.nonzero_magnitude_start() + i16::from(exponent);
+        let pattern = self
+            .compact_data
+            .get()
+            .0
+            .iter()
+            .filter(|&t| log10_type >= i16::from(t.sized))
+            .last()
+            .map(|t| {
+                t.variable
+                    .get((&significand.absolute).into(), &self.plural_rules)
+                    .1
+            });
+
+        (
+            significand.sign,
+            FormattedUnsignedCompactDecimal {
+                pattern,
+                significand: significand.absolute,
+                decimal_formatter: &self.decimal_formatter,
+                exponent,
+            },
+        )
     }
 
     pub(crate) fn format_unsigned<'a>(
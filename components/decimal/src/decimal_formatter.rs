@@ -22,7 +22,7 @@ use icu_provider::prelude::*;
 use writeable::PartsWrite;
 use writeable::Writeable;
 
-size_test!(DecimalFormatter, decimal_formatter_size, 96);
+size_test!(DecimalFormatter, decimal_formatter_size, 112);
 
 /// A formatter for [`Decimal`], rendering decimal digits in an i18n-friendly way.
 ///
@@ -36,7 +36,7 @@ size_test!(DecimalFormatter, decimal_formatter_size, 96);
 #[doc = decimal_formatter_size!()]
 #[derive(Debug, Clone)]
 pub struct DecimalFormatter {
-    options: DecimalFormatterOptions,
+    pub(crate) options: DecimalFormatterOptions,
     symbols: DataPayload<DecimalSymbolsV1>,
     digits: DataPayload<DecimalDigitsV1>,
 }
@@ -113,11 +113,20 @@ impl DecimalFormatter {
     }
 
     /// Formats a [`Decimal`], returning a [`FormattedDecimal`].
+    ///
+    /// The number is rounded and padded according to [`DecimalFormatterOptions::precision`].
     pub fn format<'l>(&'l self, value: &'l Decimal) -> FormattedDecimal<'l> {
-        FormattedDecimal(self.format_sign(
-            value.sign,
-            self.format_unsigned(Cow::Borrowed(&value.absolute)),
-        ))
+        if self.options.precision.is_empty() {
+            return FormattedDecimal(self.format_sign(
+                value.sign,
+                self.format_unsigned(Cow::Borrowed(&value.absolute)),
+            ));
+        }
+        let mut value = value.clone();
+        self.options.precision.round(&mut value, 0, 3);
+        FormattedDecimal(
+            self.format_sign(value.sign, self.format_unsigned(Cow::Owned(value.absolute))),
+        )
     }
 
     pub(crate) fn format_unsigned<'l>(
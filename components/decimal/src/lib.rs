@@ -123,6 +123,7 @@ pub mod error;
 mod grouper;
 pub mod options;
 pub mod parts;
+mod precision;
 pub mod preferences;
 pub mod provider;
 mod size_test_macro;
@@ -4,6 +4,8 @@
 
 //! Options for [`DecimalFormatter`](crate::DecimalFormatter).
 
+pub use fixed_decimal::{RoundingIncrement, SignedRoundingMode, UnsignedRoundingMode};
+
 /// A bag of options defining how numbers will be formatted by
 /// [`DecimalFormatter`](crate::DecimalFormatter).
 #[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
@@ -13,12 +15,27 @@ pub struct DecimalFormatterOptions {
     ///
     /// Default is [`GroupingStrategy::Auto`]
     pub grouping_strategy: Option<GroupingStrategy>,
+
+    /// How many digits to display and how to round.
+    ///
+    /// By default, the number is displayed with exactly the digits it has.
+    pub precision: PrecisionOptions,
 }
 
 impl From<GroupingStrategy> for DecimalFormatterOptions {
     fn from(grouping_strategy: GroupingStrategy) -> Self {
         Self {
             grouping_strategy: Some(grouping_strategy),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PrecisionOptions> for DecimalFormatterOptions {
+    fn from(precision: PrecisionOptions) -> Self {
+        Self {
+            precision,
+            ..Default::default()
         }
     }
 }
@@ -67,6 +84,210 @@ pub enum GroupingStrategy {
     Min2,
 }
 
+/// Options controlling how many digits are displayed and how the number is rounded.
+///
+/// These options correspond to the digit options of ECMA-402 `Intl.NumberFormat`. If all
+/// of them are `None`, the formatters display the number with exactly the digits it has.
+/// Otherwise, the options are resolved as in ECMA-402, with the unset ones taking the defaults
+/// of the formatter: for example, a maximum of 3 fraction digits for decimals, 0 for percents,
+/// and the currency's digits for currencies.
+///
+/// Out-of-range values are clamped rather than rejected: fraction digits are clamped to
+/// 0–100, significant digits to 1–21, and a maximum that is smaller than the corresponding
+/// minimum is raised to the minimum.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::DecimalFormatter;
+/// use icu::decimal::input::Decimal;
+/// use icu::decimal::options::{
+///     DecimalFormatterOptions, PrecisionOptions, RoundingPriority,
+/// };
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut precision = PrecisionOptions::default();
+/// precision.maximum_fraction_digits = Some(2);
+/// precision.maximum_significant_digits = Some(2);
+/// precision.rounding_priority = Some(RoundingPriority::LessPrecision);
+///
+/// let formatter = DecimalFormatter::try_new(
+///     locale!("en").into(),
+///     DecimalFormatterOptions::from(precision),
+/// )
+/// .unwrap();
+///
+/// let value: Decimal = "1.2345".parse().unwrap();
+/// assert_writeable_eq!(formatter.format(&value), "1.2");
+///
+/// let value: Decimal = "12345.678".parse().unwrap();
+/// assert_writeable_eq!(formatter.format(&value), "12,000");
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrecisionOptions {
+    /// The minimum number of integer digits; the number is padded with leading zeros.
+    ///
+    /// Corresponds to ECMA-402 `minimumIntegerDigits`. Default is 1.
+    pub minimum_integer_digits: Option<u8>,
+
+    /// The maximum number of integer digits; higher digits are removed.
+    ///
+    /// Corresponds to the `integer-width` stem of ICU number skeletons. Default is unlimited.
+    pub maximum_integer_digits: Option<u8>,
+
+    /// The minimum number of fraction digits; the number is padded with trailing zeros.
+    ///
+    /// Corresponds to ECMA-402 `minimumFractionDigits`.
+    pub minimum_fraction_digits: Option<u8>,
+
+    /// The maximum number of fraction digits; the number is rounded after this digit.
+    ///
+    /// Corresponds to ECMA-402 `maximumFractionDigits`.
+    pub maximum_fraction_digits: Option<u8>,
+
+    /// The minimum number of significant digits; the number is padded with trailing zeros.
+    ///
+    /// Corresponds to ECMA-402 `minimumSignificantDigits`.
+    pub minimum_significant_digits: Option<u8>,
+
+    /// The maximum number of significant digits; the number is rounded after this digit.
+    ///
+    /// Corresponds to ECMA-402 `maximumSignificantDigits`.
+    pub maximum_significant_digits: Option<u8>,
+
+    /// How to resolve conflicts between fraction digits and significant digits.
+    ///
+    /// Default is [`RoundingPriority::Auto`].
+    pub rounding_priority: Option<RoundingPriority>,
+
+    /// The increment to round to, in units of the last fraction digit.
+    ///
+    /// Only used when rounding to fraction digits. Default is [`RoundingIncrement::MultiplesOf1`],
+    /// not scaled.
+    pub rounding_increment: Option<ScaledRoundingIncrement>,
+
+    /// How to round the number.
+    ///
+    /// Default is half expand, i.e. rounding halfway cases away from zero.
+    pub rounding_mode: Option<SignedRoundingMode>,
+
+    /// Whether to display trailing zeros of integers.
+    ///
+    /// Default is [`TrailingZeroDisplay::Auto`].
+    pub trailing_zero_display: Option<TrailingZeroDisplay>,
+}
+
+/// How to resolve conflicts between fraction digits and significant digits.
+///
+/// Corresponds to ECMA-402 `roundingPriority`.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundingPriority {
+    /// Use significant digits if any significant digit option is set, and fraction digits
+    /// otherwise.
+    #[default]
+    Auto,
+
+    /// Use whichever of fraction digits and significant digits keeps more precision.
+    MorePrecision,
+
+    /// Use whichever of fraction digits and significant digits keeps less precision.
+    LessPrecision,
+}
+
+/// The increment to round to, in units of the last displayed fraction digit.
+///
+/// Corresponds to ECMA-402 `roundingIncrement`, which allows the multiples of 1, 2, 5 and 25
+/// supported by [`RoundingIncrement`], scaled by a power of ten. For example, with 2 maximum
+/// fraction digits, an increment of 5 rounds to multiples of 0.05, and an increment of 50
+/// (5 scaled by 10<sup>1</sup>) rounds to multiples of 0.5.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options::{RoundingIncrement, ScaledRoundingIncrement};
+///
+/// assert_eq!(
+///     ScaledRoundingIncrement::try_from_ecma402(250),
+///     Some(ScaledRoundingIncrement::new(
+///         RoundingIncrement::MultiplesOf25,
+///         1
+///     ))
+/// );
+/// assert_eq!(ScaledRoundingIncrement::try_from_ecma402(30), None);
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaledRoundingIncrement {
+    /// The increment at the rounding position.
+    pub increment: RoundingIncrement,
+
+    /// The power of ten by which the rounding position is moved towards the integer digits.
+    pub power_of_ten: u8,
+}
+
+impl ScaledRoundingIncrement {
+    /// Creates an increment of `increment` scaled by 10<sup>`power_of_ten`</sup>.
+    pub const fn new(increment: RoundingIncrement, power_of_ten: u8) -> Self {
+        Self {
+            increment,
+            power_of_ten,
+        }
+    }
+
+    /// Returns the increment for one of the values allowed by ECMA-402 `roundingIncrement`
+    /// (1, 2, 5, 10, 20, 25, 50, 100, 200, 250, 500, 1000, 2000, 2500 and 5000), or `None`
+    /// for any other value.
+    pub const fn try_from_ecma402(value: u16) -> Option<Self> {
+        use RoundingIncrement::*;
+        let (increment, power_of_ten) = match value {
+            1 => (MultiplesOf1, 0),
+            2 => (MultiplesOf2, 0),
+            5 => (MultiplesOf5, 0),
+            10 => (MultiplesOf1, 1),
+            20 => (MultiplesOf2, 1),
+            25 => (MultiplesOf25, 0),
+            50 => (MultiplesOf5, 1),
+            100 => (MultiplesOf1, 2),
+            200 => (MultiplesOf2, 2),
+            250 => (MultiplesOf25, 1),
+            500 => (MultiplesOf5, 2),
+            1000 => (MultiplesOf1, 3),
+            2000 => (MultiplesOf2, 3),
+            2500 => (MultiplesOf25, 2),
+            5000 => (MultiplesOf5, 3),
+            _ => return None,
+        };
+        Some(Self::new(increment, power_of_ten))
+    }
+}
+
+impl From<RoundingIncrement> for ScaledRoundingIncrement {
+    fn from(increment: RoundingIncrement) -> Self {
+        Self::new(increment, 0)
+    }
+}
+
+/// Whether to display trailing zeros of integers.
+///
+/// Corresponds to ECMA-402 `trailingZeroDisplay`.
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrailingZeroDisplay {
+    /// Display trailing zeros according to the minimum fraction and significant digits.
+    #[default]
+    Auto,
+
+    /// Remove the fraction digits if they are all zero.
+    StripIfInteger,
+}
+
 /// A bag of options defining how numbers will be formatted by
 /// [`CompactDecimalFormatter`](super::CompactDecimalFormatter).
 ///
@@ -86,6 +307,12 @@ pub struct CompactDecimalFormatterOptions {
     ///
     /// Default is [`GroupingStrategy::Min2`]
     pub grouping_strategy: Option<GroupingStrategy>,
+
+    /// How many digits of the scaled number to display and how to round.
+    ///
+    /// By default, numbers are rounded to two significant digits, or to an integer if they
+    /// have more than one integer digit.
+    pub precision: PrecisionOptions,
 }
 
 #[cfg(feature = "unstable")]
@@ -100,6 +327,7 @@ impl From<DecimalFormatterOptions> for CompactDecimalFormatterOptions {
     fn from(decimal_formatter_options: DecimalFormatterOptions) -> Self {
         Self {
             grouping_strategy: decimal_formatter_options.grouping_strategy,
+            precision: decimal_formatter_options.precision,
         }
     }
 }
@@ -109,6 +337,7 @@ impl From<CompactDecimalFormatterOptions> for DecimalFormatterOptions {
     fn from(decimal_formatter_options: CompactDecimalFormatterOptions) -> Self {
         Self {
             grouping_strategy: decimal_formatter_options.grouping_strategy,
+            precision: decimal_formatter_options.precision,
         }
     }
 }
@@ -118,6 +347,17 @@ impl From<GroupingStrategy> for CompactDecimalFormatterOptions {
     fn from(grouping_strategy: GroupingStrategy) -> Self {
         Self {
             grouping_strategy: Some(grouping_strategy),
+            precision: Default::default(),
+        }
+    }
+}
+
+#[cfg(feature = "unstable")]
+impl From<PrecisionOptions> for CompactDecimalFormatterOptions {
+    fn from(precision: PrecisionOptions) -> Self {
+        Self {
+            precision,
+            ..Default::default()
         }
     }
 }
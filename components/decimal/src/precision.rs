@@ -0,0 +1,423 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Resolution and application of [`PrecisionOptions`], following the digit options of
+//! ECMA-402 `Intl.NumberFormat`.
+
+use crate::input::Decimal;
+use crate::options::*;
+
+/// The ECMA-402 limit on significant digits.
+const MAX_SIGNIFICANT_DIGITS: u8 = 21;
+
+/// The ECMA-402 limit on fraction digits.
+const MAX_FRACTION_DIGITS: u8 = 100;
+
+/// The ECMA-402 `[[RoundingType]]` of resolved digit options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RoundingType {
+    FractionDigits,
+    SignificantDigits,
+    MorePrecision,
+    LessPrecision,
+}
+
+/// Digit options with all defaults filled in.
+#[derive(Debug, Clone, Copy)]
+struct ResolvedPrecision {
+    minimum_integer_digits: u8,
+    maximum_integer_digits: Option<u8>,
+    minimum_fraction_digits: u8,
+    maximum_fraction_digits: u8,
+    minimum_significant_digits: u8,
+    maximum_significant_digits: u8,
+    rounding_type: RoundingType,
+    rounding_increment: ScaledRoundingIncrement,
+    rounding_mode: SignedRoundingMode,
+    strip_if_integer: bool,
+}
+
+impl PrecisionOptions {
+    /// Returns whether all options are unset, in which case numbers are displayed with exactly
+    /// the digits they have.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Rounds and pads a [`Decimal`] according to these options.
+    ///
+    /// `minimum_fraction_digits_default` and `maximum_fraction_digits_default` are the defaults
+    /// used for unset fraction digit options, like the `mnfdDefault` and `mxfdDefault`
+    /// arguments of the ECMA-402 `SetNumberFormatDigitOptions` operation. For example, decimal
+    /// formatting uses 0 and 3, and currency formatting uses the currency digits for both.
+    ///
+    /// If all options are unset, the value is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::input::Decimal;
+    /// use icu::decimal::options::{PrecisionOptions, TrailingZeroDisplay};
+    ///
+    /// let mut precision = PrecisionOptions::default();
+    /// precision.minimum_fraction_digits = Some(2);
+    /// precision.trailing_zero_display = Some(TrailingZeroDisplay::StripIfInteger);
+    ///
+    /// let mut value: Decimal = "3.14159".parse().unwrap();
+    /// precision.round(&mut value, 0, 3);
+    /// assert_eq!(value.to_string(), "3.142");
+    ///
+    /// let mut value: Decimal = "2.9999".parse().unwrap();
+    /// precision.round(&mut value, 0, 3);
+    /// assert_eq!(value.to_string(), "3");
+    ///
+    /// let mut value: Decimal = "2.5".parse().unwrap();
+    /// precision.round(&mut value, 0, 3);
+    /// assert_eq!(value.to_string(), "2.50");
+    /// ```
+    pub fn round(
+        &self,
+        value: &mut Decimal,
+        minimum_fraction_digits_default: u8,
+        maximum_fraction_digits_default: u8,
+    ) {
+        if self.is_empty() {
+            return;
+        }
+        self.resolve(
+            minimum_fraction_digits_default,
+            maximum_fraction_digits_default,
+            false,
+        )
+        .apply(value);
+    }
+
+    /// Rounds and pads the significand of a compact number according to these options.
+    ///
+    /// Unlike [`Self::round`], this uses the compact notation defaults: if neither fraction
+    /// nor significant digits are set, numbers are rounded to two significant digits or to an
+    /// integer, whichever is more precise.
+    #[cfg(feature = "unstable")]
+    pub(crate) fn round_compact(&self, value: &mut Decimal) {
+        self.resolve(0, 0, true).apply(value);
+    }
+
+    /// Fills in the defaults for unset options, following the ECMA-402
+    /// `SetNumberFormatDigitOptions` operation.
+    fn resolve(
+        &self,
+        minimum_fraction_digits_default: u8,
+        maximum_fraction_digits_default: u8,
+        compact: bool,
+    ) -> ResolvedPrecision {
+        let rounding_increment = self.rounding_increment.unwrap_or_default();
+        let maximum_fraction_digits_default =
+            if rounding_increment == ScaledRoundingIncrement::default() {
+                maximum_fraction_digits_default
+            } else {
+                minimum_fraction_digits_default
+            };
+        let rounding_priority = self.rounding_priority.unwrap_or_default();
+        let has_significant_digits =
+            self.minimum_significant_digits.is_some() || self.maximum_significant_digits.is_some();
+        let has_fraction_digits =
+            self.minimum_fraction_digits.is_some() || self.maximum_fraction_digits.is_some();
+
+        let (need_significant_digits, need_fraction_digits) =
+            if rounding_priority == RoundingPriority::Auto {
+                (
+                    has_significant_digits,
+                    !(has_significant_digits || (!has_fraction_digits && compact)),
+                )
+            } else {
+                (true, true)
+            };
+
+        let minimum_significant_digits = self
+            .minimum_significant_digits
+            .unwrap_or(1)
+            .clamp(1, MAX_SIGNIFICANT_DIGITS);
+        let maximum_significant_digits = self
+            .maximum_significant_digits
+            .unwrap_or(MAX_SIGNIFICANT_DIGITS)
+            .clamp(minimum_significant_digits, MAX_SIGNIFICANT_DIGITS);
+
+        let (minimum_fraction_digits, maximum_fraction_digits) =
+            match (self.minimum_fraction_digits, self.maximum_fraction_digits) {
+                (None, None) => (
+                    minimum_fraction_digits_default,
+                    maximum_fraction_digits_default,
+                ),
+                (None, Some(max)) => (minimum_fraction_digits_default.min(max), max),
+                (Some(min), None) => (min, maximum_fraction_digits_default.max(min)),
+                (Some(min), Some(max)) => (min, max.max(min)),
+            };
+        let minimum_fraction_digits = minimum_fraction_digits.min(MAX_FRACTION_DIGITS);
+        let maximum_fraction_digits = maximum_fraction_digits.min(MAX_FRACTION_DIGITS);
+
+        let mut resolved = ResolvedPrecision {
+            minimum_integer_digits: self
+                .minimum_integer_digits
+                .unwrap_or(1)
+                .clamp(1, MAX_SIGNIFICANT_DIGITS),
+            maximum_integer_digits: self.maximum_integer_digits,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            minimum_significant_digits,
+            maximum_significant_digits,
+            rounding_type: RoundingType::FractionDigits,
+            rounding_increment,
+            rounding_mode: self.rounding_mode.unwrap_or(SignedRoundingMode::Unsigned(
+                UnsignedRoundingMode::HalfExpand,
+            )),
+            strip_if_integer: self.trailing_zero_display
+                == Some(TrailingZeroDisplay::StripIfInteger),
+        };
+
+        resolved.rounding_type = if !need_significant_digits && !need_fraction_digits {
+            // Compact notation without digit options.
+            resolved.minimum_fraction_digits = 0;
+            resolved.maximum_fraction_digits = 0;
+            resolved.minimum_significant_digits = 1;
+            resolved.maximum_significant_digits = 2;
+            RoundingType::MorePrecision
+        } else {
+            match rounding_priority {
+                RoundingPriority::MorePrecision => RoundingType::MorePrecision,
+                RoundingPriority::LessPrecision => RoundingType::LessPrecision,
+                _ if has_significant_digits => RoundingType::SignificantDigits,
+                _ => RoundingType::FractionDigits,
+            }
+        };
+        if resolved.rounding_type != RoundingType::FractionDigits {
+            // Rounding increments only apply to fraction digits.
+            resolved.rounding_increment = ScaledRoundingIncrement::default();
+        }
+        resolved
+    }
+}
+
+impl ResolvedPrecision {
+    /// Rounds and pads `value`, following the ECMA-402 `FormatNumericToString` operation.
+    fn apply(&self, value: &mut Decimal) {
+        *value = match self.rounding_type {
+            RoundingType::FractionDigits => self.to_raw_fixed(value).0,
+            RoundingType::SignificantDigits => self.to_raw_precision(value).0,
+            RoundingType::MorePrecision | RoundingType::LessPrecision => {
+                let (significant, significant_magnitude) = self.to_raw_precision(value);
+                let (fixed, fixed_magnitude) = self.to_raw_fixed(value);
+                let prefer_significant = significant_magnitude <= fixed_magnitude;
+                if prefer_significant == (self.rounding_type == RoundingType::MorePrecision) {
+                    significant
+                } else {
+                    fixed
+                }
+            }
+        };
+
+        if self.strip_if_integer && value.absolute.nonzero_magnitude_end() >= 0 {
+            value.absolute.trim_end();
+        }
+        if let Some(maximum_integer_digits) = self.maximum_integer_digits {
+            value
+                .absolute
+                .set_max_position(i16::from(maximum_integer_digits));
+        }
+        value
+            .absolute
+            .pad_start(i16::from(self.minimum_integer_digits));
+    }
+
+    /// Rounds `value` to fraction digits, returning the result and the rounding magnitude.
+    fn to_raw_fixed(self, value: &Decimal) -> (Decimal, i16) {
+        let magnitude = -i16::from(self.maximum_fraction_digits);
+        let mut result = value.clone().rounded_with_mode_and_increment(
+            magnitude.saturating_add(i16::from(self.rounding_increment.power_of_ten)),
+            self.rounding_mode,
+            self.rounding_increment.increment,
+        );
+        result.absolute.trim_end();
+        result
+            .absolute
+            .pad_end(-i16::from(self.minimum_fraction_digits));
+        (result, magnitude)
+    }
+
+    /// Rounds `value` to significant digits, returning the result and the rounding magnitude.
+    fn to_raw_precision(self, value: &Decimal) -> (Decimal, i16) {
+        let magnitude = clamp_magnitude(
+            i32::from(value.absolute.nonzero_magnitude_start())
+                - i32::from(self.maximum_significant_digits)
+                + 1,
+        );
+        let mut result = value.clone().rounded_with_mode_and_increment(
+            magnitude,
+            self.rounding_mode,
+            RoundingIncrement::MultiplesOf1,
+        );
+        result.absolute.trim_end();
+        let minimum_magnitude = clamp_magnitude(
+            i32::from(result.absolute.nonzero_magnitude_start())
+                - i32::from(self.minimum_significant_digits)
+                + 1,
+        );
+        result.absolute.pad_end(minimum_magnitude);
+        (result, magnitude)
+    }
+}
+
+fn clamp_magnitude(magnitude: i32) -> i16 {
+    magnitude.clamp(i32::from(i16::MIN), i32::from(i16::MAX)) as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round(options: PrecisionOptions, input: &str, defaults: (u8, u8)) -> String {
+        let mut value: Decimal = input.parse().unwrap();
+        options.round(&mut value, defaults.0, defaults.1);
+        value.to_string()
+    }
+
+    #[test]
+    fn test_fraction_digits() {
+        let mut options = PrecisionOptions::default();
+        assert_eq!(round(options, "1.23456", (0, 3)), "1.23456");
+
+        options.maximum_fraction_digits = Some(2);
+        assert_eq!(round(options, "1.23456", (0, 3)), "1.23");
+        assert_eq!(round(options, "1.2", (0, 3)), "1.2");
+        assert_eq!(round(options, "-1.005", (0, 3)), "-1.01");
+        assert_eq!(round(options, "1.005", (2, 2)), "1.01");
+        // The default minimum is lowered to the maximum
+        options.maximum_fraction_digits = Some(1);
+        assert_eq!(round(options, "12345", (2, 2)), "12345.0");
+        assert_eq!(round(options, "12345.67", (2, 2)), "12345.7");
+        options.maximum_fraction_digits = Some(2);
+
+        options.minimum_fraction_digits = Some(2);
+        assert_eq!(round(options, "1.2", (0, 3)), "1.20");
+        assert_eq!(round(options, "7", (0, 3)), "7.00");
+
+        // The maximum is raised to the minimum
+        options.maximum_fraction_digits = Some(1);
+        assert_eq!(round(options, "1.2345", (0, 3)), "1.23");
+
+        // Only the minimum is set: the default maximum is raised to it
+        options.maximum_fraction_digits = None;
+        options.minimum_fraction_digits = Some(5);
+        assert_eq!(round(options, "1.2", (0, 3)), "1.20000");
+        options.minimum_fraction_digits = Some(1);
+        assert_eq!(round(options, "1.23456", (0, 3)), "1.235");
+    }
+
+    #[test]
+    fn test_significant_digits() {
+        let mut options = PrecisionOptions {
+            maximum_significant_digits: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(round(options, "123456", (0, 3)), "123000");
+        assert_eq!(round(options, "0.0012345", (0, 3)), "0.00123");
+        assert_eq!(round(options, "9.996", (0, 3)), "10");
+        assert_eq!(round(options, "1.5", (0, 3)), "1.5");
+
+        options.minimum_significant_digits = Some(3);
+        assert_eq!(round(options, "1.5", (0, 3)), "1.50");
+        assert_eq!(round(options, "9.996", (0, 3)), "10.0");
+        assert_eq!(round(options, "0", (0, 3)), "0.00");
+
+        // Significant digits take precedence over fraction digits with `Auto`
+        options.maximum_fraction_digits = Some(0);
+        assert_eq!(round(options, "1.5", (0, 3)), "1.50");
+    }
+
+    #[test]
+    fn test_rounding_priority() {
+        let mut options = PrecisionOptions {
+            maximum_fraction_digits: Some(2),
+            maximum_significant_digits: Some(2),
+            ..Default::default()
+        };
+
+        options.rounding_priority = Some(RoundingPriority::MorePrecision);
+        assert_eq!(round(options, "1.2345", (0, 3)), "1.23");
+        assert_eq!(round(options, "0.0012345", (0, 3)), "0.0012");
+        assert_eq!(round(options, "12345.678", (0, 3)), "12345.68");
+
+        options.rounding_priority = Some(RoundingPriority::LessPrecision);
+        assert_eq!(round(options, "1.2345", (0, 3)), "1.2");
+        assert_eq!(round(options, "0.0012345", (0, 3)), "0");
+        assert_eq!(round(options, "12345.678", (0, 3)), "12000");
+    }
+
+    #[test]
+    fn test_rounding_increment_and_mode() {
+        let mut options = PrecisionOptions {
+            maximum_fraction_digits: Some(2),
+            minimum_fraction_digits: Some(2),
+            ..Default::default()
+        };
+
+        options.rounding_increment = Some(RoundingIncrement::MultiplesOf5.into());
+        assert_eq!(round(options, "1.234", (0, 3)), "1.25");
+        options.rounding_increment = Some(RoundingIncrement::MultiplesOf25.into());
+        assert_eq!(round(options, "1.1", (0, 3)), "1.00");
+        assert_eq!(round(options, "1.13", (0, 3)), "1.25");
+        options.rounding_increment = ScaledRoundingIncrement::try_from_ecma402(50);
+        assert_eq!(round(options, "1.26", (0, 3)), "1.50");
+
+        // The increment sets the default maximum to the default minimum
+        let options = PrecisionOptions {
+            rounding_increment: Some(RoundingIncrement::MultiplesOf5.into()),
+            ..Default::default()
+        };
+        assert_eq!(round(options, "1.26", (2, 2)), "1.25");
+
+        let mut options = PrecisionOptions {
+            maximum_fraction_digits: Some(0),
+            rounding_mode: Some(SignedRoundingMode::Floor),
+            ..Default::default()
+        };
+        assert_eq!(round(options, "-1.2", (0, 3)), "-2");
+        options.rounding_mode = Some(SignedRoundingMode::Ceil);
+        assert_eq!(round(options, "-1.2", (0, 3)), "-1");
+    }
+
+    #[test]
+    fn test_integer_digits_and_trailing_zeros() {
+        let mut options = PrecisionOptions {
+            minimum_integer_digits: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(round(options, "4.5", (0, 3)), "004.5");
+        options.maximum_integer_digits = Some(3);
+        assert_eq!(round(options, "12345.5", (0, 3)), "345.5");
+
+        let options = PrecisionOptions {
+            minimum_fraction_digits: Some(2),
+            trailing_zero_display: Some(TrailingZeroDisplay::StripIfInteger),
+            ..Default::default()
+        };
+        assert_eq!(round(options, "5", (0, 3)), "5");
+        assert_eq!(round(options, "5.0001", (0, 3)), "5");
+        assert_eq!(round(options, "5.1", (0, 3)), "5.10");
+    }
+
+    #[test]
+    #[cfg(feature = "unstable")]
+    fn test_compact_defaults() {
+        let options = PrecisionOptions::default();
+        let compact = |input: &str| {
+            let mut value: Decimal = input.parse().unwrap();
+            options.round_compact(&mut value);
+            value.to_string()
+        };
+        assert_eq!(compact("1.234"), "1.2");
+        assert_eq!(compact("15.27"), "15");
+        assert_eq!(compact("999.5"), "1000");
+        assert_eq!(compact("1.05"), "1.1");
+    }
+}
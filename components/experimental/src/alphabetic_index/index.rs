@@ -0,0 +1,702 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The bucket construction follows ICU4C `AlphabeticIndex`, which relies on
+// special contractions in the collation data: U+FDD1 followed by a sample
+// character of a script sorts at the start of that script, and in Chinese
+// collations, U+FDD0 followed by a label sorts at the start of that label.
+
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use icu_collator::options::{CollatorOptions, Strength};
+use icu_collator::preferences::CollationType;
+use icu_collator::provider::{
+    CollationDiacriticsV1, CollationJamoV1, CollationMetadataV1, CollationReorderingV1,
+    CollationRootV1, CollationScriptStartsV1, CollationSpecialPrimariesV1, CollationTailoringV1,
+};
+use icu_collator::{Collator, CollatorBorrowed, CollatorPreferences};
+use icu_collections::codepointinvliststringlist::CodePointInversionListAndStringList;
+use icu_locale::exemplar_chars::ExemplarCharacters;
+use icu_locale::provider::LocaleExemplarCharactersIndexV1;
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_normalizer::provider::{NormalizerNfdDataV1, NormalizerNfdTablesV1};
+use icu_provider::prelude::*;
+
+define_preferences!(
+    /// The preferences for an alphabetic index.
+    [Copy]
+    AlphabeticIndexPreferences,
+    {
+        /// The collation type, which selects between the pinyin, stroke, and zhuyin
+        /// labels for Chinese, for example.
+        ///
+        /// This corresponds to the `-u-co` BCP-47 tag.
+        collation_type: CollationType
+    }
+);
+prefs_convert!(AlphabeticIndexPreferences, CollatorPreferences, {
+    collation_type
+});
+
+/// Options for [`AlphabeticIndex`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AlphabeticIndexOptions {
+    /// The maximum number of labels, not counting the underflow, inflow, and
+    /// overflow labels. If there are more labels, evenly spaced ones are dropped.
+    ///
+    /// The default is 99.
+    pub max_label_count: Option<usize>,
+}
+
+/// The kind of a [`Bucket`].
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BucketLabelType {
+    /// A bucket for the strings that start with the label.
+    Normal,
+    /// The bucket for the strings that sort before the first label, such as digits
+    /// and symbols.
+    Underflow,
+    /// A bucket for the strings in scripts that sort between the scripts of two labels.
+    Inflow,
+    /// The bucket for the strings that sort after the script of the last label.
+    Overflow,
+}
+
+/// A bucket of an [`AlphabeticIndex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bucket {
+    label: String,
+    label_type: BucketLabelType,
+}
+
+impl Bucket {
+    /// The label to display for this bucket.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// The kind of this bucket.
+    pub fn label_type(&self) -> BucketLabelType {
+        self.label_type
+    }
+}
+
+/// The default label of the underflow, inflow, and overflow buckets.
+const DEFAULT_LABEL: &str = "\u{2026}";
+const DEFAULT_MAX_LABEL_COUNT: usize = 99;
+
+/// The prefix of the contractions for the first primaries of scripts.
+const SCRIPT_BOUNDARY: char = '\u{FDD1}';
+/// The prefix of the contractions for the Chinese index labels.
+const CHINESE_LABEL: char = '\u{FDD0}';
+/// Appended to a label to form a boundary after all the strings starting with the label.
+const MAX_PRIMARY: char = '\u{FFFF}';
+/// Inserted between characters to prevent contractions.
+const CONTRACTION_BREAK: char = '\u{34F}';
+/// The character after the stroke count in the labels of the stroke collation: 劃
+const STROKES: char = '\u{5283}';
+
+/// The sample characters of the scripts for which the root collation has
+/// contractions with [`SCRIPT_BOUNDARY`], sorted by code point.
+///
+/// U+FDD0 marks the start of the unassigned code points, which is the
+/// lower boundary of the overflow bucket.
+#[rustfmt::skip]
+const SCRIPT_SAMPLES: &[char] = &[
+    '\u{4C}', '\u{3A9}', '\u{3E2}', '\u{42F}', '\u{531}', '\u{5D0}', '\u{628}', '\u{710}',
+    '\u{78C}', '\u{7CA}', '\u{800}', '\u{840}', '\u{905}', '\u{995}', '\u{A15}', '\u{A95}',
+    '\u{B15}', '\u{B95}', '\u{C15}', '\u{C95}', '\u{D15}', '\u{D85}', '\u{E17}', '\u{EA5}',
+    '\u{F40}', '\u{1000}', '\u{10D3}', '\u{12A0}', '\u{13C4}', '\u{14C0}', '\u{168F}', '\u{16A0}',
+    '\u{1703}', '\u{1723}', '\u{1743}', '\u{1763}', '\u{1780}', '\u{1826}', '\u{1900}', '\u{1950}',
+    '\u{1980}', '\u{1A00}', '\u{1A20}', '\u{1B05}', '\u{1B83}', '\u{1BC0}', '\u{1C00}', '\u{1C5A}',
+    '\u{2C00}', '\u{2D30}', '\u{304B}', '\u{30AB}', '\u{3105}', '\u{5B57}', '\u{A288}', '\u{A4D0}',
+    '\u{A549}', '\u{A6A0}', '\u{A800}', '\u{A840}', '\u{A882}', '\u{A90A}', '\u{A930}', '\u{A984}',
+    '\u{AA00}', '\u{AA80}', '\u{ABC0}', '\u{FDD0}', '\u{10000}', '\u{10280}', '\u{102A0}', '\u{10300}',
+    '\u{10330}', '\u{1036B}', '\u{10380}', '\u{103A0}', '\u{10414}', '\u{10450}', '\u{10480}', '\u{104B5}',
+    '\u{10500}', '\u{10537}', '\u{10582}', '\u{105C2}', '\u{10647}', '\u{10800}', '\u{10840}', '\u{10873}',
+    '\u{10896}', '\u{108F4}', '\u{10900}', '\u{10920}', '\u{10950}', '\u{10980}', '\u{109A0}', '\u{10A00}',
+    '\u{10A60}', '\u{10A95}', '\u{10AD8}', '\u{10B00}', '\u{10B40}', '\u{10B60}', '\u{10B8F}', '\u{10C00}',
+    '\u{10CA1}', '\u{10D12}', '\u{10D5D}', '\u{10E88}', '\u{10F19}', '\u{10F42}', '\u{10F7C}', '\u{10FBF}',
+    '\u{10FF1}', '\u{11005}', '\u{11083}', '\u{110D0}', '\u{11103}', '\u{11152}', '\u{11183}', '\u{11208}',
+    '\u{1128F}', '\u{112BE}', '\u{11315}', '\u{11392}', '\u{11412}', '\u{11484}', '\u{1158E}', '\u{1160E}',
+    '\u{11680}', '\u{11717}', '\u{1180B}', '\u{118B4}', '\u{1190C}', '\u{119CE}', '\u{11A0B}', '\u{11A5C}',
+    '\u{11AC0}', '\u{11BC4}', '\u{11C0E}', '\u{11C72}', '\u{11D10}', '\u{11D71}', '\u{11DC6}', '\u{11EE5}',
+    '\u{11F1B}', '\u{12000}', '\u{12FE5}', '\u{13153}', '\u{14400}', '\u{1611C}', '\u{16A4F}', '\u{16ABC}',
+    '\u{16AE6}', '\u{16B1C}', '\u{16D45}', '\u{16E40}', '\u{16EA1}', '\u{16F00}', '\u{18229}', '\u{18C65}',
+    '\u{1B1C4}', '\u{1BC20}', '\u{1E108}', '\u{1E290}', '\u{1E2E1}', '\u{1E4E6}', '\u{1E5D0}', '\u{1E6D5}',
+    '\u{1E802}', '\u{1E909}',
+];
+
+/// Computes locale-appropriate buckets for grouping a sorted list, such as the
+/// "A B C … Z" headings of a contact list.
+///
+/// The labels come from the index exemplar characters of the locale or, for Chinese,
+/// from the collation data. A string is assigned to the last bucket whose label it
+/// sorts after at the primary strength, except that strings in scripts without labels
+/// go to an underflow, inflow, or overflow bucket.
+///
+/// # Example
+///
+/// ```
+/// use icu::experimental::alphabetic_index::{AlphabeticIndex, BucketLabelType};
+/// use icu::locale::locale;
+///
+/// let index =
+///     AlphabeticIndex::try_new(locale!("en").into(), Default::default())
+///         .unwrap();
+///
+/// let labels: Vec<&str> = index.buckets().iter().map(|b| b.label()).collect();
+/// assert_eq!(labels.len(), 28);
+/// assert_eq!(labels[..4], ["…", "A", "B", "C"]);
+/// assert_eq!(labels[25..], ["Y", "Z", "…"]);
+///
+/// let bucket = &index.buckets()[index.bucket_index("Émile")];
+/// assert_eq!(bucket.label(), "E");
+///
+/// let bucket = &index.buckets()[index.bucket_index("42")];
+/// assert_eq!(bucket.label_type(), BucketLabelType::Underflow);
+///
+/// let bucket = &index.buckets()[index.bucket_index("Ωμέγα")];
+/// assert_eq!(bucket.label_type(), BucketLabelType::Overflow);
+/// ```
+#[derive(Debug)]
+pub struct AlphabeticIndex {
+    /// A collator at the primary strength
+    collator: Collator,
+    /// The lower boundaries of the scripts, sorted by the collator
+    script_boundaries: Vec<String>,
+    /// The labels before filtering
+    initial_labels: BTreeSet<String>,
+    max_label_count: usize,
+    underflow_label: String,
+    inflow_label: String,
+    overflow_label: String,
+    /// All the buckets by lower boundary, including invisible ones
+    boundaries: Vec<Boundary>,
+    /// The visible buckets
+    buckets: Vec<Bucket>,
+}
+
+/// A bucket by its lower boundary.
+#[derive(Debug)]
+struct Boundary {
+    lower: String,
+    label: String,
+    label_type: BucketLabelType,
+    /// The index of the bucket that this bucket is displayed as if it is invisible
+    display: Option<usize>,
+    /// The index into the visible buckets
+    visible_index: usize,
+}
+
+impl Boundary {
+    fn new(label: String, lower: String, label_type: BucketLabelType) -> Self {
+        Self {
+            lower,
+            label,
+            label_type,
+            display: None,
+            visible_index: 0,
+        }
+    }
+}
+
+impl AlphabeticIndex {
+    /// Creates an [`AlphabeticIndex`] for the given locale from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: AlphabeticIndexPreferences,
+        options: AlphabeticIndexOptions,
+    ) -> Result<Self, DataError> {
+        let collator = Collator::try_new((&prefs).into(), Self::collator_options())?;
+        let locale = LocaleExemplarCharactersIndexV1::make_locale(prefs.locale_preferences);
+        let exemplars = ExemplarCharacters::try_new_index(&locale)?;
+        Ok(Self::new_with_data(
+            collator.static_to_owned(),
+            &exemplars,
+            options,
+        ))
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: AlphabeticIndexPreferences, options: AlphabeticIndexOptions) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: AlphabeticIndexPreferences,
+        options: AlphabeticIndexOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<CollationScriptStartsV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<LocaleExemplarCharactersIndexV1>
+            + ?Sized,
+    {
+        let collator =
+            Collator::try_new_unstable(provider, (&prefs).into(), Self::collator_options())?;
+        let locale = LocaleExemplarCharactersIndexV1::make_locale(prefs.locale_preferences);
+        let exemplars = ExemplarCharacters::try_new_index_unstable(provider, &locale)?;
+        Ok(Self::new_with_data(
+            collator,
+            &exemplars.as_borrowed(),
+            options,
+        ))
+    }
+
+    fn collator_options() -> CollatorOptions {
+        let mut options = CollatorOptions::default();
+        options.strength = Some(Strength::Primary);
+        options
+    }
+
+    fn new_with_data(
+        collator: Collator,
+        exemplars: &CodePointInversionListAndStringList,
+        options: AlphabeticIndexOptions,
+    ) -> Self {
+        let borrowed = collator.as_borrowed();
+        let mut script_boundaries: Vec<String> = SCRIPT_SAMPLES
+            .iter()
+            .map(|&c| [SCRIPT_BOUNDARY, c].into_iter().collect::<String>())
+            .filter(|s| is_contraction(&borrowed, s, SCRIPT_BOUNDARY))
+            .collect();
+        script_boundaries.sort_by(|a, b| borrowed.compare(a, b));
+
+        let mut index = Self {
+            collator,
+            script_boundaries,
+            initial_labels: BTreeSet::new(),
+            max_label_count: options.max_label_count.unwrap_or(DEFAULT_MAX_LABEL_COUNT),
+            underflow_label: DEFAULT_LABEL.into(),
+            inflow_label: DEFAULT_LABEL.into(),
+            overflow_label: DEFAULT_LABEL.into(),
+            boundaries: Vec::new(),
+            buckets: Vec::new(),
+        };
+        if !index.add_chinese_labels() {
+            index.insert_labels(exemplars);
+        }
+        index.build();
+        index
+    }
+
+    /// Adds the labels for Chinese if the collation data has them.
+    fn add_chinese_labels(&mut self) -> bool {
+        let collator = self.collator.as_borrowed();
+        let candidates = ('A'..='Z')
+            // Stroke counts
+            .chain('\u{2801}'..='\u{28FF}')
+            // Bopomofo
+            .chain('\u{3105}'..='\u{312F}');
+        let mut has_pinyin = false;
+        for c in candidates {
+            let label: String = [CHINESE_LABEL, c].into_iter().collect();
+            if is_contraction(&collator, &label, CHINESE_LABEL) {
+                has_pinyin |= c.is_ascii_uppercase();
+                self.initial_labels.insert(label);
+            }
+        }
+        if has_pinyin {
+            // Latin strings go into the buckets of the pinyin labels.
+            for c in 'A'..='Z' {
+                self.initial_labels.insert(c.into());
+            }
+        }
+        !self.initial_labels.is_empty()
+    }
+
+    fn insert_labels(&mut self, labels: &CodePointInversionListAndStringList) {
+        self.initial_labels.extend(
+            labels
+                .code_points()
+                .iter_chars()
+                .map(String::from)
+                .chain(labels.strings().iter().map(String::from)),
+        );
+    }
+
+    /// Adds labels, for example the index exemplar characters of another locale
+    /// for a list with names in several scripts.
+    ///
+    /// A label that ends with a single `*` is used even if it is an expansion, with the
+    /// `*` removed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::experimental::alphabetic_index::{AlphabeticIndex, BucketLabelType};
+    /// use icu::locale::exemplar_chars::ExemplarCharacters;
+    /// use icu::locale::locale;
+    ///
+    /// let mut index =
+    ///     AlphabeticIndex::try_new(locale!("en").into(), Default::default())
+    ///         .unwrap();
+    /// index.add_labels(
+    ///     &ExemplarCharacters::try_new_index(&locale!("ru").into()).unwrap(),
+    /// );
+    ///
+    /// let bucket = &index.buckets()[index.bucket_index("Москва")];
+    /// assert_eq!(bucket.label(), "М");
+    ///
+    /// // Greek sorts between Latin and Cyrillic.
+    /// let bucket = &index.buckets()[index.bucket_index("Αθήνα")];
+    /// assert_eq!(bucket.label_type(), BucketLabelType::Inflow);
+    /// ```
+    pub fn add_labels(&mut self, labels: &CodePointInversionListAndStringList) {
+        self.insert_labels(labels);
+        self.build();
+    }
+
+    /// Sets the label of the underflow bucket. The default is "…".
+    pub fn set_underflow_label(&mut self, label: &str) {
+        self.underflow_label = label.into();
+        self.build();
+    }
+
+    /// Sets the label of the inflow buckets. The default is "…".
+    pub fn set_inflow_label(&mut self, label: &str) {
+        self.inflow_label = label.into();
+        self.build();
+    }
+
+    /// Sets the label of the overflow bucket. The default is "…".
+    pub fn set_overflow_label(&mut self, label: &str) {
+        self.overflow_label = label.into();
+        self.build();
+    }
+
+    /// The buckets in order.
+    ///
+    /// The first bucket is the underflow bucket, which is the only bucket if there are no labels.
+    pub fn buckets(&self) -> &[Bucket] {
+        &self.buckets
+    }
+
+    /// The index into [`Self::buckets()`] of the bucket for `name`.
+    pub fn bucket_index(&self, name: &str) -> usize {
+        let collator = self.collator.as_borrowed();
+        // The underflow bucket at 0 has an empty lower boundary.
+        let mut start = 0;
+        let mut limit = self.boundaries.len();
+        while start + 1 < limit {
+            let middle = (start + limit) / 2;
+            let Some(boundary) = self.boundaries.get(middle) else {
+                break;
+            };
+            if collator.compare(name, &boundary.lower) == Ordering::Less {
+                limit = middle;
+            } else {
+                start = middle;
+            }
+        }
+        self.boundaries
+            .get(start)
+            .map(|b| b.visible_index)
+            .unwrap_or_default()
+    }
+
+    /// The labels in order after filtering, deduplication, and reduction to the
+    /// maximum count.
+    fn labels(&self) -> Vec<&str> {
+        let collator = self.collator.as_borrowed();
+        let (Some(first_script), Some(overflow)) = (
+            self.script_boundaries.first(),
+            self.script_boundaries.last(),
+        ) else {
+            return Vec::new();
+        };
+        let mut labels: Vec<&str> = Vec::new();
+        for label in &self.initial_labels {
+            let mut label = label.as_str();
+            let check_distinct = if label.chars().nth(1).is_none() {
+                false
+            } else if let Some(starred) = label.strip_suffix('*')
+                && !starred.ends_with('*')
+            {
+                // Use a label marked with one trailing star even if it sorts like
+                // its separate characters.
+                label = starred;
+                false
+            } else {
+                true
+            };
+            if collator.compare(label, first_script) == Ordering::Less
+                || collator.compare(label, overflow) != Ordering::Less
+                || (check_distinct && collator.compare(label, &separated(label)) == Ordering::Equal)
+            {
+                // Ignore labels that are primary ignorable or not alphabetic, that
+                // would land in the overflow bucket, or that do not sort as a unit.
+                continue;
+            }
+            match labels.binary_search_by(|l| collator.compare(l, label)) {
+                Ok(i) => {
+                    if let Some(existing) = labels.get_mut(i)
+                        && is_better_label(label, existing)
+                    {
+                        *existing = label;
+                    }
+                }
+                Err(i) => labels.insert(i, label),
+            }
+        }
+
+        // Keep evenly spaced labels if there are too many.
+        let len = labels.len();
+        if len > self.max_label_count {
+            let mut i = 0;
+            let mut previous = None;
+            labels.retain(|_| {
+                let kept = i * self.max_label_count / len;
+                i += 1;
+                previous.replace(kept) != Some(kept)
+            });
+        }
+        labels
+    }
+
+    /// Recomputes the buckets.
+    fn build(&mut self) {
+        let collator = self.collator.as_borrowed();
+        let mut boundaries = Vec::new();
+        boundaries.push(Boundary::new(
+            self.underflow_label.clone(),
+            String::new(),
+            BucketLabelType::Underflow,
+        ));
+
+        let mut ascii_buckets = [None; 26];
+        let mut pinyin_buckets = [None; 26];
+        let mut has_invisible_buckets = false;
+
+        let mut script_index = None;
+        let mut script_upper: &str = "";
+        for label in self.labels() {
+            if collator.compare(label, script_upper) != Ordering::Less {
+                // The label is in a new script.
+                let inflow_lower = script_upper;
+                let mut skipped_script = false;
+                loop {
+                    let next = script_index.map_or(0, |i| i + 1);
+                    script_index = Some(next);
+                    script_upper = self
+                        .script_boundaries
+                        .get(next)
+                        .map(String::as_str)
+                        .unwrap_or_default();
+                    if script_upper.is_empty()
+                        || collator.compare(label, script_upper) == Ordering::Less
+                    {
+                        break;
+                    }
+                    skipped_script = true;
+                }
+                if skipped_script && boundaries.len() > 1 {
+                    // Not just getting out of the underflow bucket
+                    boundaries.push(Boundary::new(
+                        self.inflow_label.clone(),
+                        inflow_lower.into(),
+                        BucketLabelType::Inflow,
+                    ));
+                }
+            }
+
+            boundaries.push(Boundary::new(
+                display_label(label),
+                label.into(),
+                BucketLabelType::Normal,
+            ));
+            let mut chars = label.chars();
+            match (chars.next(), chars.next(), chars.next()) {
+                (Some(c @ 'A'..='Z'), None, _) => {
+                    if let Some(b) = ascii_buckets.get_mut(usize::from(c as u8 - b'A')) {
+                        *b = Some(boundaries.len() - 1);
+                    }
+                }
+                (Some(CHINESE_LABEL), Some(c @ 'A'..='Z'), None) => {
+                    if let Some(b) = pinyin_buckets.get_mut(usize::from(c as u8 - b'A')) {
+                        *b = Some(boundaries.len() - 1);
+                    }
+                }
+                _ => (),
+            }
+
+            if !label.starts_with(CHINESE_LABEL) && self.has_multiple_primaries(label) {
+                // For a label like "Sch", add an invisible bucket "Sch\u{FFFF}" that redirects
+                // the strings after all the "Sch" strings to the previous single-primary
+                // bucket, such as "S".
+                for (j, single) in boundaries.iter().enumerate().rev().skip(1) {
+                    if single.label_type != BucketLabelType::Normal {
+                        break;
+                    }
+                    if single.display.is_none() && !self.has_multiple_primaries(&single.lower) {
+                        let mut lower = String::from(label);
+                        lower.push(MAX_PRIMARY);
+                        let mut invisible =
+                            Boundary::new(String::new(), lower, BucketLabelType::Normal);
+                        invisible.display = Some(j);
+                        boundaries.push(invisible);
+                        has_invisible_buckets = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        if boundaries.len() > 1 {
+            boundaries.push(Boundary::new(
+                self.overflow_label.clone(),
+                script_upper.into(),
+                BucketLabelType::Overflow,
+            ));
+
+            // Show the pinyin buckets as the Latin buckets with the same or a preceding label.
+            let mut ascii = None;
+            for (a, p) in ascii_buckets.iter().zip(pinyin_buckets) {
+                if a.is_some() {
+                    ascii = *a;
+                }
+                if let (Some(p), Some(a)) = (p, ascii)
+                    && let Some(pinyin) = boundaries.get_mut(p)
+                {
+                    pinyin.display = Some(a);
+                    has_invisible_buckets = true;
+                }
+            }
+
+            if has_invisible_buckets {
+                // Merge the visible inflow buckets into the following overflow or inflow
+                // bucket if there is no normal bucket in between.
+                let mut next = boundaries.len() - 1;
+                for i in (1..boundaries.len() - 1).rev() {
+                    let Some(next_type) = boundaries.get(next).map(|b| b.label_type) else {
+                        break;
+                    };
+                    let Some(boundary) = boundaries.get_mut(i) else {
+                        break;
+                    };
+                    if boundary.display.is_some() {
+                        continue;
+                    }
+                    if boundary.label_type == BucketLabelType::Inflow
+                        && next_type != BucketLabelType::Normal
+                    {
+                        boundary.display = Some(next);
+                        continue;
+                    }
+                    next = i;
+                }
+            }
+        }
+
+        let mut buckets = Vec::new();
+        for boundary in &mut boundaries {
+            if boundary.display.is_none() {
+                boundary.visible_index = buckets.len();
+                buckets.push(Bucket {
+                    label: boundary.label.clone(),
+                    label_type: boundary.label_type,
+                });
+            }
+        }
+        for i in 0..boundaries.len() {
+            let Some(display) = boundaries.get(i).and_then(|b| b.display) else {
+                continue;
+            };
+            let visible_index = boundaries
+                .get(display)
+                .map(|b| b.visible_index)
+                .unwrap_or_default();
+            if let Some(boundary) = boundaries.get_mut(i) {
+                boundary.visible_index = visible_index;
+            }
+        }
+
+        self.boundaries = boundaries;
+        self.buckets = buckets;
+    }
+
+    /// Whether `label` sorts like a sequence of several characters.
+    ///
+    /// The collation elements are not available here, so a label that consists of
+    /// several characters that do not form a contraction is assumed to have several
+    /// primary weights, and a single character is assumed to have one.
+    fn has_multiple_primaries(&self, label: &str) -> bool {
+        label.chars().nth(1).is_some()
+            && self
+                .collator
+                .as_borrowed()
+                .compare(label, &separated(label))
+                == Ordering::Equal
+    }
+}
+
+/// Whether `s` is a contraction that starts with `prefix` and sorts lower than `prefix` alone.
+fn is_contraction(collator: &CollatorBorrowed, s: &str, prefix: char) -> bool {
+    let mut buffer = [0; 4];
+    collator.compare(s, prefix.encode_utf8(&mut buffer)) == Ordering::Less
+}
+
+/// The characters of `s` with contractions prevented between them.
+fn separated(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() * 3);
+    for (i, c) in s.chars().enumerate() {
+        if i != 0 {
+            result.push(CONTRACTION_BREAK);
+        }
+        result.push(c);
+    }
+    result
+}
+
+/// Whether `label` is preferable to the primary-equal `other`: Shorter labels and then
+/// labels with lower code points win.
+fn is_better_label(label: &str, other: &str) -> bool {
+    label
+        .chars()
+        .count()
+        .cmp(&other.chars().count())
+        .then_with(|| label.cmp(other))
+        == Ordering::Less
+}
+
+/// The label to display for a bucket with the lower boundary `label`.
+fn display_label(label: &str) -> String {
+    let Some(rest) = label.strip_prefix(CHINESE_LABEL) else {
+        return label.into();
+    };
+    match rest.chars().next() {
+        Some(c @ '\u{2801}'..='\u{28FF}') => {
+            // A stroke count
+            let mut label = (u32::from(c) - 0x2800).to_string();
+            label.push(STROKES);
+            label
+        }
+        _ => rest.into(),
+    }
+}
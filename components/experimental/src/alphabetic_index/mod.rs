@@ -0,0 +1,15 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Alphabetic indexes, the locale-dependent buckets of a sorted list
+//!
+//! See [`AlphabeticIndex`].
+//!
+//! ✨ *Enabled with the `alphabetic_index` Cargo feature.*
+
+mod index;
+
+pub use index::{
+    AlphabeticIndex, AlphabeticIndexOptions, AlphabeticIndexPreferences, Bucket, BucketLabelType,
+};
@@ -16,6 +16,7 @@ use super::CurrencyType;
 use fixed_decimal::{
     Decimal as FixedDecimal, RoundingIncrement, Sign, SignedRoundingMode, UnsignedRoundingMode,
 };
+use icu_decimal::options::PrecisionOptions;
 use icu_decimal::preferences::CompactDecimalFormatterPreferences;
 use icu_decimal::{
     AbstractFormatter, CompactDecimalFormatter, DecimalFormatter, DecimalFormatterPreferences,
@@ -88,6 +89,7 @@ pub struct CurrencyFormatter<V: AbstractFormatter> {
     value_formatter: V,
     currency_data: CurrencyFormatterData,
     usage: CurrencyUsage,
+    precision: PrecisionOptions,
     fraction_info: FractionInfo,
 }
 
@@ -139,6 +141,7 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
             value_formatter,
             currency_data,
             usage: options.usage,
+            precision: options.precision,
             fraction_info,
         })
     }
@@ -192,6 +195,7 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
             value_formatter,
             currency_data,
             usage: options.usage,
+            precision: options.precision,
             fraction_info,
         })
     }
@@ -223,6 +227,7 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
                 iso_code: currency.iso_code(),
             },
             usage: options.usage,
+            precision: options.precision,
             fraction_info,
         })
     }
@@ -255,6 +260,7 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
                 iso_code: currency.iso_code(),
             },
             usage: options.usage,
+            precision: options.precision,
             fraction_info,
         })
     }
@@ -310,6 +316,7 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
             value_formatter,
             currency_data,
             usage: CurrencyUsage::default(),
+            precision: Default::default(),
             fraction_info,
         })
     }
@@ -373,6 +380,7 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
             value_formatter,
             currency_data,
             usage: CurrencyUsage::default(),
+            precision: Default::default(),
             fraction_info,
         })
     }
@@ -401,6 +409,7 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
             value_formatter,
             currency_data: CurrencyFormatterData::NoCurrency { patterns },
             usage: options.usage,
+            precision: options.precision,
             fraction_info,
         })
     }
@@ -430,6 +439,7 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
             value_formatter,
             currency_data: CurrencyFormatterData::NoCurrency { patterns },
             usage: options.usage,
+            precision: options.precision,
             fraction_info,
         })
     }
@@ -1351,8 +1361,43 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
         //   (rendering e.g. "$12" instead of "$12.00").
         // * Currency fraction precision is applied uniformly without type-level switches, relying on the underlying
         //   formatter (such as `CompactDecimalFormatter`) to handle magnitude-based trailing zero trimming.
-        let rounded_value = apply_precision(value.clone(), self.fraction_info);
-        let formatted_value = V::format_unsigned(&self.value_formatter, rounded_value.absolute);
+        //
+        // Explicit precision options replace the currency rounding, with the currency digits
+        // as the default number of fraction digits, as in ECMA-402.
+        let (value_sign, formatted_value) = if self.precision.is_empty() {
+            let rounded_value = apply_precision(value.clone(), self.fraction_info);
+            (
+                rounded_value.sign,
+                V::format_unsigned(&self.value_formatter, rounded_value.absolute),
+            )
+        } else {
+            V::format_with_precision(
+                &self.value_formatter,
+                value,
+                &self.precision,
+                self.fraction_info.digits,
+                self.fraction_info.digits,
+            )
+        };
+        self.format_sign_and_unsigned(value_sign, formatted_value)
+    }
+
+    /// Formats a value that was already rounded with [`Self::round`].
+    pub(crate) fn format_rounded<'l>(
+        &'l self,
+        value: &FixedDecimal,
+    ) -> impl Writeable + Display + 'l {
+        self.format_sign_and_unsigned(
+            value.sign,
+            V::format_unsigned(&self.value_formatter, value.absolute.clone()),
+        )
+    }
+
+    fn format_sign_and_unsigned<'l>(
+        &'l self,
+        value_sign: Sign,
+        formatted_value: V::FormattedUnsigned<'l>,
+    ) -> impl Writeable + Display + 'l {
         let accounting = self.usage == CurrencyUsage::Accounting;
 
         let (pattern, currency_str, sign) = match &self.currency_data {
@@ -1360,25 +1405,20 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
                 essential,
                 iso_code,
             } => {
-                let (pattern, sign) = select_essentials_pattern(
-                    essential.get(),
-                    accounting,
-                    rounded_value.sign,
-                    true,
-                    true,
-                );
+                let (pattern, sign) =
+                    select_essentials_pattern(essential.get(), accounting, value_sign, true, true);
                 (pattern, iso_code.as_str(), sign)
             }
             CurrencyFormatterData::IsoName { patterns, iso_code } => {
                 let pattern = patterns.get().elements.get_default().1;
-                (pattern, iso_code.as_str(), rounded_value.sign)
+                (pattern, iso_code.as_str(), value_sign)
             }
             CurrencyFormatterData::Symbol { essential, symbol } => {
                 let symbol = symbol.get();
                 let (pattern, sign) = select_essentials_pattern(
                     essential.get(),
                     accounting,
-                    rounded_value.sign,
+                    value_sign,
                     symbol.starts_with_letter(),
                     symbol.ends_with_letter(),
                 );
@@ -1392,11 +1432,11 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
                 let operands = V::plural_operands(&formatted_value);
                 let currency_str = extended.get().get(operands, plural_rules);
                 let pattern = patterns.get().get(operands, plural_rules);
-                (pattern, currency_str, rounded_value.sign)
+                (pattern, currency_str, value_sign)
             }
             CurrencyFormatterData::NoCurrency { patterns } => {
                 let (pattern, sign) =
-                    select_no_currency_pattern(patterns.get(), accounting, rounded_value.sign);
+                    select_no_currency_pattern(patterns.get(), accounting, value_sign);
                 (pattern, "", sign)
             }
         };
@@ -1411,6 +1451,23 @@ impl<V: AbstractFormatter> CurrencyFormatter<V> {
             sign,
         )
     }
+
+    /// Replaces the usage and precision, e.g. for formatters created with full currency names.
+    pub(crate) fn with_options(mut self, options: CurrencyFormatterOptions) -> Self {
+        self.usage = options.usage;
+        self.precision = options.precision;
+        self
+    }
+
+    /// Rounds a value the way [`Self::format_fixed_decimal`] does for non-compact formatting.
+    pub(crate) fn round(&self, value: &mut FixedDecimal) {
+        if self.precision.is_empty() {
+            *value = apply_precision(core::mem::take(value), self.fraction_info);
+        } else {
+            self.precision
+                .round(value, self.fraction_info.digits, self.fraction_info.digits);
+        }
+    }
 }
 
 /// Selects the pattern for no-currency formatting for the given sign.
@@ -4,6 +4,8 @@
 
 pub mod formatter;
 pub mod options;
+pub mod parser;
+pub mod regions;
 
 pub use icu_locale_core::preferences::extensions::unicode::keywords::CurrencyType;
 
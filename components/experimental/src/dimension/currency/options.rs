@@ -4,6 +4,7 @@
 
 //! Options for [`CurrencyFormatter`](crate::dimension::currency::formatter::CurrencyFormatter).
 
+use icu_decimal::options::PrecisionOptions;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -15,11 +16,30 @@ use serde::{Deserialize, Serialize};
 pub struct CurrencyFormatterOptions {
     /// Whether to use standard or accounting currency patterns.
     pub usage: CurrencyUsage,
+
+    /// How many digits to display and how to round.
+    ///
+    /// By default, numbers are rounded according to the currency's digits and
+    /// rounding increment. If any option is set, the currency's digits are only used as the
+    /// default number of fraction digits.
+    pub precision: PrecisionOptions,
 }
 
 impl From<CurrencyUsage> for CurrencyFormatterOptions {
     fn from(usage: CurrencyUsage) -> Self {
-        Self { usage }
+        Self {
+            usage,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PrecisionOptions> for CurrencyFormatterOptions {
+    fn from(precision: PrecisionOptions) -> Self {
+        Self {
+            precision,
+            ..Default::default()
+        }
     }
 }
 
@@ -0,0 +1,365 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of localized currency amounts.
+
+use alloc::vec::Vec;
+
+use fixed_decimal::{Decimal, Sign};
+use icu_decimal::DecimalFormatterPreferences;
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_provider::prelude::*;
+use tinystr::TinyAsciiStr;
+
+use super::CurrencyType;
+use crate::dimension::parse::{
+    load_decimal_data, parse_number, prefix_len, strip_minus_sign, suffix_len, trim,
+};
+use crate::dimension::provider::currency::{
+    extended::CurrencyExtendedDataV1, symbols::CurrencySymbolsV1,
+};
+
+extern crate alloc;
+
+define_preferences!(
+    /// The preferences for currency parsing.
+    [Copy]
+    CurrencyParserPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: crate::dimension::preferences::NumberingSystem
+    }
+);
+
+prefs_convert!(CurrencyParserPreferences, DecimalFormatterPreferences, {
+    numbering_system
+});
+
+/// An error returned by [`CurrencyParser::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum CurrencyParseError {
+    /// The input does not contain the symbol, ISO code or name of any of the candidate currencies.
+    #[displaydoc("No currency symbol, code or name found")]
+    MissingCurrency,
+    /// The input does not contain a valid number in the locale's format.
+    #[displaydoc("Invalid amount")]
+    InvalidAmount,
+}
+
+impl core::error::Error for CurrencyParseError {}
+
+/// A currency amount returned by [`CurrencyParser::parse`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ParsedCurrencyAmount {
+    /// The parsed currency.
+    ///
+    /// If several currencies match the input, this is the first one in the order
+    /// given to the parser's constructor.
+    pub currency: CurrencyType,
+    /// The parsed amount.
+    pub value: Decimal,
+    /// All currencies that match the input, in the order given to the parser's constructor.
+    ///
+    /// This contains more than one currency if the input uses a symbol that is shared
+    /// by several currencies, such as "$".
+    pub candidates: Vec<CurrencyType>,
+}
+
+impl ParsedCurrencyAmount {
+    /// Returns whether the input matches more than one currency.
+    pub fn is_ambiguous(&self) -> bool {
+        self.candidates.len() > 1
+    }
+}
+
+#[derive(Debug)]
+struct CurrencyStrings {
+    currency: CurrencyType,
+    /// The short and narrow symbols, if the locale has them.
+    symbols: Vec<DataPayload<CurrencySymbolsV1>>,
+    /// The long names in all plural forms, if the locale has them.
+    names: Option<DataPayload<CurrencyExtendedDataV1>>,
+}
+
+impl CurrencyStrings {
+    /// Returns the length in bytes of the longest symbol, ISO code or name of this
+    /// currency that `input` starts with (or ends with, if `at_end` is set).
+    ///
+    /// Symbols are matched exactly, ISO codes and names ignoring case.
+    fn match_len(&self, input: &str, at_end: bool) -> Option<usize> {
+        let match_one = |token: &str, ignore_case| {
+            if at_end {
+                suffix_len(input, token, ignore_case)
+            } else {
+                prefix_len(input, token, ignore_case)
+            }
+        };
+        let iso_code = self.currency.iso_code();
+        let symbols = self
+            .symbols
+            .iter()
+            .filter_map(|symbol| match_one(symbol.get().as_str(), false));
+        let names = self
+            .names
+            .iter()
+            .flat_map(|names| names.get().elements.iter())
+            .filter_map(|(_, name)| match_one(name, true));
+        match_one(iso_code.as_str(), true)
+            .into_iter()
+            .chain(symbols)
+            .chain(names)
+            .max()
+    }
+}
+
+/// A parser for localized currency amounts, the inverse of
+/// [`CurrencyFormatter`](super::formatter::CurrencyFormatter).
+///
+/// The parser recognizes amounts written with the short or narrow symbol, the ISO code
+/// or the long name (in any plural form) of a fixed set of candidate currencies, on either
+/// side of a number in the locale's format. The number may use the locale's or ASCII digits,
+/// and can be negated with the locale's minus sign, an ASCII hyphen or accounting-style
+/// parentheses.
+///
+/// Parsing is lenient about whitespace and grouping separator positions. Symbols are
+/// matched exactly, while ISO codes and long names are matched ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::currency::parser::CurrencyParser;
+/// use icu::locale::locale;
+/// use icu::locale::preferences::extensions::unicode::keywords::currency;
+///
+/// let parser = CurrencyParser::try_new(
+///     locale!("en-CA").into(),
+///     &[currency!("CAD"), currency!("USD"), currency!("EUR")],
+/// )
+/// .unwrap();
+///
+/// let amount = parser.parse("US$1,234.50").unwrap();
+/// assert_eq!(amount.currency, currency!("USD"));
+/// assert_eq!(amount.value, "1234.50".parse().unwrap());
+/// assert!(!amount.is_ambiguous());
+///
+/// let amount = parser.parse("-12 euros").unwrap();
+/// assert_eq!(amount.currency, currency!("EUR"));
+/// assert_eq!(amount.value, "-12".parse().unwrap());
+///
+/// // "$" is the narrow symbol of several currencies
+/// let amount = parser.parse("($5.00)").unwrap();
+/// assert_eq!(amount.currency, currency!("CAD"));
+/// assert_eq!(amount.candidates, [currency!("CAD"), currency!("USD")]);
+/// assert!(amount.is_ambiguous());
+/// ```
+#[derive(Debug)]
+pub struct CurrencyParser {
+    currencies: Vec<CurrencyStrings>,
+    symbols: DataPayload<DecimalSymbolsV1>,
+    digits: DataPayload<DecimalDigitsV1>,
+}
+
+impl CurrencyParser {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: CurrencyParserPreferences, currencies: &[CurrencyType]) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`CurrencyParser`] from compiled locale data, recognizing the given currencies.
+    ///
+    /// The candidate currencies can for example be obtained from
+    /// [`RegionCurrencies`](super::regions::RegionCurrencies). Their order is used to
+    /// resolve ambiguous inputs.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: CurrencyParserPreferences,
+        currencies: &[CurrencyType],
+    ) -> Result<Self, DataError> {
+        let (symbols, digits) = load_decimal_data(&icu_decimal::provider::Baked, (&prefs).into())?;
+        Self::try_new_with_decimal_data(&crate::provider::Baked, prefs, currencies, symbols, digits)
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: CurrencyParserPreferences,
+        currencies: &[CurrencyType],
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<CurrencySymbolsV1>
+            + DataProvider<CurrencyExtendedDataV1>
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>,
+    {
+        let (symbols, digits) = load_decimal_data(provider, (&prefs).into())?;
+        Self::try_new_with_decimal_data(provider, prefs, currencies, symbols, digits)
+    }
+
+    fn try_new_with_decimal_data<D>(
+        provider: &D,
+        prefs: CurrencyParserPreferences,
+        currencies: &[CurrencyType],
+        symbols: DataPayload<DecimalSymbolsV1>,
+        digits: DataPayload<DecimalDigitsV1>,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized + DataProvider<CurrencySymbolsV1> + DataProvider<CurrencyExtendedDataV1>,
+    {
+        let locale = CurrencySymbolsV1::make_locale(prefs.locale_preferences);
+        let currencies = currencies
+            .iter()
+            .map(|&currency| {
+                let mut symbols = Vec::new();
+                for width in [CurrencySymbolsV1::SHORT, CurrencySymbolsV1::NARROW] {
+                    #[allow(const_item_mutation)]
+                    let symbol = DataProvider::<CurrencySymbolsV1>::load(
+                        provider,
+                        DataRequest {
+                            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                                CurrencySymbolsV1::make_attributes(
+                                    currency,
+                                    width,
+                                    &mut TinyAsciiStr::EMPTY,
+                                ),
+                                &locale,
+                            ),
+                            ..Default::default()
+                        },
+                    )
+                    .allow_identifier_not_found()?;
+                    symbols.extend(symbol.map(|res| res.payload));
+                }
+
+                let iso_code = currency.iso_code();
+                let names = match DataMarkerAttributes::try_from_str(iso_code.as_str()) {
+                    Ok(attributes) => DataProvider::<CurrencyExtendedDataV1>::load(
+                        provider,
+                        DataRequest {
+                            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                                attributes, &locale,
+                            ),
+                            ..Default::default()
+                        },
+                    )
+                    .allow_identifier_not_found()?
+                    .map(|res| res.payload),
+                    Err(_) => None,
+                };
+
+                Ok(CurrencyStrings {
+                    currency,
+                    symbols,
+                    names,
+                })
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+
+        Ok(Self {
+            currencies,
+            symbols,
+            digits,
+        })
+    }
+
+    /// Parses a localized currency amount, such as "US$1,234.50" or "1 234,50 dollars des États-Unis".
+    ///
+    /// See the [type-level documentation](Self) for the accepted inputs.
+    pub fn parse(&self, input: &str) -> Result<ParsedCurrencyAmount, CurrencyParseError> {
+        let mut rest = trim(input);
+        let mut negative = false;
+
+        // Accounting format, e.g. "($5.00)"
+        if let Some(inner) = rest.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            negative = true;
+            rest = trim(inner);
+        }
+        rest = self.strip_minus_sign(rest, &mut negative)?;
+
+        let (currency, candidates, rest) = self
+            .match_currency(rest, false)
+            .or_else(|| self.match_currency(rest, true))
+            .ok_or(CurrencyParseError::MissingCurrency)?;
+
+        let rest = self.strip_minus_sign(trim(rest), &mut negative)?;
+        let mut value = self.parse_number(rest)?;
+        if negative {
+            value.set_sign(Sign::Negative);
+        }
+
+        Ok(ParsedCurrencyAmount {
+            currency,
+            value,
+            candidates,
+        })
+    }
+
+    /// Finds the longest currency string at the start (or end) of the input, and returns
+    /// the first matching currency, all matching currencies, and the remaining input.
+    fn match_currency<'a>(
+        &self,
+        input: &'a str,
+        at_end: bool,
+    ) -> Option<(CurrencyType, Vec<CurrencyType>, &'a str)> {
+        let len = self
+            .currencies
+            .iter()
+            .filter_map(|c| c.match_len(input, at_end))
+            .max()?;
+        let mut candidates = Vec::new();
+        for c in self.currencies.iter() {
+            if c.match_len(input, at_end) == Some(len) && !candidates.contains(&c.currency) {
+                candidates.push(c.currency);
+            }
+        }
+        let rest = if at_end {
+            input.get(..input.len() - len)
+        } else {
+            input.get(len..)
+        }?;
+        Some((*candidates.first()?, candidates, rest))
+    }
+
+    /// Strips a minus sign from either end of the input, failing if the amount was already negated.
+    fn strip_minus_sign<'a>(
+        &self,
+        input: &'a str,
+        negative: &mut bool,
+    ) -> Result<&'a str, CurrencyParseError> {
+        match strip_minus_sign(input, &self.symbols.get().strings) {
+            Some(_) if *negative => Err(CurrencyParseError::InvalidAmount),
+            Some(s) => {
+                *negative = true;
+                Ok(s)
+            }
+            None => Ok(input),
+        }
+    }
+
+    /// Parses an unsigned number in the locale's format.
+    fn parse_number(&self, input: &str) -> Result<Decimal, CurrencyParseError> {
+        let strings = &self.symbols.get().strings;
+        parse_number(
+            input,
+            strings.decimal_separator(),
+            strings.grouping_separator(),
+            self.digits.get(),
+        )
+        .ok_or(CurrencyParseError::InvalidAmount)
+    }
+}
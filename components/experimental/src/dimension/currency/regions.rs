@@ -0,0 +1,178 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lookup of the currencies used in a region.
+
+use icu_calendar::{Date, Iso};
+use icu_locale_core::preferences::define_preferences;
+use icu_provider::prelude::*;
+
+use super::CurrencyType;
+use crate::dimension::provider::currency::regions::{
+    CurrencyDate, CurrencyRegionsV1, RegionCurrency,
+};
+
+define_preferences!(
+    /// The preferences for looking up the currencies of a region.
+    ///
+    /// The region is taken from the `-u-rg` keyword if present, or from the region subtag
+    /// of the locale otherwise. Locales without a region, such as `en`, use the region
+    /// of their likely subtags.
+    [Copy]
+    RegionCurrenciesPreferences,
+    {
+        /// The user's preferred currency.
+        ///
+        /// Corresponds to the `-u-cu` in Unicode Locale Identifier.
+        currency: CurrencyType
+    }
+);
+
+/// The current and historical currencies of a region, as recorded in CLDR.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::Date;
+/// use icu::experimental::dimension::currency::regions::RegionCurrencies;
+/// use icu::locale::locale;
+/// use icu::locale::preferences::extensions::unicode::keywords::currency;
+///
+/// let brazil = RegionCurrencies::try_new(locale!("pt-BR").into()).unwrap();
+///
+/// let today = Date::try_new_iso(2024, 1, 1).unwrap();
+/// assert_eq!(brazil.currency_at(today), Some(currency!("BRL")));
+///
+/// let before_real = Date::try_new_iso(1993, 1, 1).unwrap();
+/// assert_eq!(brazil.currency_at(before_real), Some(currency!("BRE")));
+///
+/// // The `-u-cu` keyword takes precedence
+/// let brazil_usd =
+///     RegionCurrencies::try_new(locale!("pt-BR-u-cu-usd").into()).unwrap();
+/// assert_eq!(brazil_usd.preferred_currency(today), Some(currency!("USD")));
+/// assert!(!brazil_usd.is_tender_at(currency!("USD"), today));
+///
+/// // The region can be overridden with `-u-rg`
+/// let germany =
+///     RegionCurrencies::try_new(locale!("en-US-u-rg-dezzzz").into()).unwrap();
+/// assert_eq!(germany.currency_at(today), Some(currency!("EUR")));
+/// ```
+#[derive(Debug)]
+pub struct RegionCurrencies {
+    data: DataPayload<CurrencyRegionsV1>,
+    preferred: Option<CurrencyType>,
+}
+
+/// A currency that is or was used in a region, returned by [`RegionCurrencies::history`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CurrencyUsagePeriod {
+    /// The currency.
+    pub currency: CurrencyType,
+    /// The first day on which the currency was used, if known.
+    pub from: Option<Date<Iso>>,
+    /// The first day on which the currency was no longer used, if it is no longer used.
+    pub to: Option<Date<Iso>>,
+    /// Whether the currency is legal tender.
+    ///
+    /// This is `false` for financial instruments such as `USN` (US Dollar, next day).
+    pub tender: bool,
+}
+
+impl RegionCurrencies {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: RegionCurrenciesPreferences) -> error: DataError,
+        /// Creates a new [`RegionCurrencies`] from compiled data.
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: RegionCurrenciesPreferences,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<CurrencyRegionsV1> + ?Sized,
+    {
+        let locale = CurrencyRegionsV1::make_locale(prefs.locale_preferences);
+        let data = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+        Ok(Self {
+            data,
+            preferred: prefs.currency,
+        })
+    }
+
+    /// Returns the tender currencies in use in the region on the given date,
+    /// starting with the most recently introduced one.
+    ///
+    /// Several currencies can be in use at the same time, for example during a transition.
+    pub fn currencies_at(&self, date: Date<Iso>) -> impl Iterator<Item = CurrencyType> + '_ {
+        let date = to_currency_date(date);
+        self.data
+            .get()
+            .currencies
+            .iter()
+            .filter(move |c| c.tender && is_in_use(c, date))
+            .filter_map(|c| to_currency_type(&c))
+    }
+
+    /// Returns the currency of the region on the given date, i.e. the most recently
+    /// introduced tender currency in use at that date.
+    ///
+    /// This ignores the `-u-cu` keyword; see [`Self::preferred_currency`].
+    pub fn currency_at(&self, date: Date<Iso>) -> Option<CurrencyType> {
+        self.currencies_at(date).next()
+    }
+
+    /// Returns the currency to use by default on the given date.
+    ///
+    /// This is the currency from the `-u-cu` keyword if it was set, and
+    /// [`Self::currency_at`] otherwise.
+    pub fn preferred_currency(&self, date: Date<Iso>) -> Option<CurrencyType> {
+        self.preferred.or_else(|| self.currency_at(date))
+    }
+
+    /// Returns whether the given currency is legal tender in the region on the given date.
+    pub fn is_tender_at(&self, currency: CurrencyType, date: Date<Iso>) -> bool {
+        self.currencies_at(date).any(|c| c == currency)
+    }
+
+    /// Returns all current and historical currencies of the region, including non-tender ones,
+    /// starting with the most recently introduced one.
+    pub fn history(&self) -> impl Iterator<Item = CurrencyUsagePeriod> + '_ {
+        self.data.get().currencies.iter().filter_map(|c| {
+            Some(CurrencyUsagePeriod {
+                currency: to_currency_type(&c)?,
+                from: c.from.and_then(from_currency_date),
+                to: c.to.and_then(from_currency_date),
+                tender: c.tender,
+            })
+        })
+    }
+}
+
+fn is_in_use(currency: &RegionCurrency, date: CurrencyDate) -> bool {
+    currency.from.is_none_or(|from| from <= date) && currency.to.is_none_or(|to| date < to)
+}
+
+fn to_currency_type(currency: &RegionCurrency) -> Option<CurrencyType> {
+    let iso_code = currency.iso_code.try_into_tinystr().ok()?;
+    CurrencyType::try_from_str(iso_code.as_str()).ok()
+}
+
+fn to_currency_date(date: Date<Iso>) -> CurrencyDate {
+    CurrencyDate {
+        year: date.year().extended_year(),
+        month: date.month().ordinal,
+        day: date.day_of_month().0,
+    }
+}
+
+fn from_currency_date(date: CurrencyDate) -> Option<Date<Iso>> {
+    Date::try_new_iso(date.year, date.month, date.day).ok()
+}
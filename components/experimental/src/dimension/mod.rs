@@ -10,6 +10,8 @@
 )]
 
 pub mod currency;
+pub mod number;
+pub(crate) mod parse;
 pub mod percent;
 pub mod provider;
 pub mod units;
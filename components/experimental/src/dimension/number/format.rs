@@ -0,0 +1,191 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{Decimal, Sign};
+use icu_decimal::AbstractFormatter;
+use icu_decimal::options::PrecisionOptions;
+use writeable::{PartsWrite, Writeable};
+
+use super::formatter::{NumberFormatter, NumberFormatterInner, UnitFormatter};
+
+/// A number formatted by [`NumberFormatter`].
+#[derive(Debug)]
+pub struct FormattedNumber<'l> {
+    /// The value, rounded for non-compact notation and with the sign display applied.
+    pub(crate) value: Decimal,
+    pub(crate) formatter: &'l NumberFormatter,
+}
+
+impl Writeable for FormattedNumber<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> Result<(), core::fmt::Error>
+    where
+        W: PartsWrite + ?Sized,
+    {
+        match self.formatter.inner() {
+            // The value was rounded in `NumberFormatter::format`.
+            NumberFormatterInner::Standard(unit) => write_unit(unit, &self.value, None, sink),
+            NumberFormatterInner::Compact(unit) => {
+                write_unit(unit, &self.value, Some(self.formatter.precision()), sink)
+            }
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedNumber<'_>);
+
+/// Formats the absolute value of `value`, rounding it with `precision` if it is not rounded yet.
+fn format_unsigned<'a, V: AbstractFormatter>(
+    formatter: &'a V,
+    value: &Decimal,
+    precision: Option<&PrecisionOptions>,
+    maximum_fraction_digits_default: u8,
+) -> (Sign, V::FormattedUnsigned<'a>) {
+    match precision {
+        Some(precision) => {
+            formatter.format_with_precision(value, precision, 0, maximum_fraction_digits_default)
+        }
+        None => (
+            value.sign,
+            formatter.format_unsigned(value.absolute.clone()),
+        ),
+    }
+}
+
+/// Writes `value` with the unit, rounding it with `precision` if it is not rounded yet.
+fn write_unit<V: AbstractFormatter, W: PartsWrite + ?Sized>(
+    unit: &UnitFormatter<V>,
+    value: &Decimal,
+    precision: Option<&PrecisionOptions>,
+    sink: &mut W,
+) -> Result<(), core::fmt::Error> {
+    match unit {
+        UnitFormatter::None(formatter) => {
+            let (sign, unsigned) = format_unsigned(formatter, value, precision, 3);
+            formatter.format_sign(unsigned, sign).write_to_parts(sink)
+        }
+        UnitFormatter::Percent(formatter, essential) => {
+            let essential = essential.get();
+            let (sign, unsigned) = format_unsigned(formatter, value, precision, 0);
+            match sign {
+                Sign::None => essential
+                    .unsigned_pattern
+                    .interpolate([unsigned])
+                    .write_to_parts(sink),
+                Sign::Negative => essential
+                    .signed_pattern
+                    .interpolate((unsigned, &essential.minus_sign))
+                    .write_to_parts(sink),
+                Sign::Positive => essential
+                    .signed_pattern
+                    .interpolate((unsigned, &essential.plus_sign))
+                    .write_to_parts(sink),
+            }
+        }
+        UnitFormatter::Currency(formatter) => match precision {
+            Some(_) => formatter.format_fixed_decimal(value).write_to_parts(sink),
+            None => formatter.format_rounded(value).write_to_parts(sink),
+        },
+        UnitFormatter::Measure(formatter, display_names, plural_rules) => {
+            let (sign, unsigned) = format_unsigned(formatter, value, precision, 3);
+            // The plural form is selected from the rounded, and for compact notation
+            // scaled, number.
+            let operands = V::plural_operands(&unsigned);
+            display_names
+                .get()
+                .get(operands, plural_rules)
+                .interpolate((formatter.format_sign(unsigned, sign),))
+                .write_to_parts(sink)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    use crate::dimension::number::formatter::NumberFormatter;
+    use crate::dimension::number::options::NumberFormatterOptions;
+
+    #[test]
+    fn test_format() {
+        let format = |skeleton: &str, value: &str| {
+            let options = NumberFormatterOptions::try_from_skeleton(skeleton).unwrap();
+            let formatter = NumberFormatter::try_new(locale!("en").into(), &options).unwrap();
+            formatter.format(&value.parse().unwrap()).to_string()
+        };
+
+        assert_eq!(format("", "1234.5678"), "1,234.5678");
+        assert_eq!(format(".00 +!", "1234.5678"), "+1,234.57");
+        assert_eq!(format("group-off precision-integer", "-1234.5"), "-1235");
+        assert_eq!(format("precision-integer +-", "-0.2"), "0");
+        assert_eq!(format("K", "1234567"), "1.2M");
+        assert_eq!(format("KK", "1234567"), "1.2 million");
+        assert_eq!(format("%", "12.5"), "12.5%");
+        assert_eq!(format("% precision-integer +?", "-12.5"), "-13%");
+        assert_eq!(format("currency/USD", "-12.345"), "-$12.35");
+        assert_eq!(format("currency/EUR ()", "-12"), "(€12.00)");
+        assert_eq!(
+            format("currency/USD unit-width-iso-code", "1"),
+            "USD\u{a0}1.00"
+        );
+        assert_eq!(format("currency/USD unit-width-hidden", "1"), "1.00");
+        assert_eq!(
+            format("currency/USD unit-width-full-name .0", "1"),
+            "1.0 US dollars"
+        );
+        assert_eq!(format("K currency/EUR", "-1234567"), "-€1.2M");
+        assert_eq!(format("unit/meter", "1"), "1 m");
+        assert_eq!(format("unit/meter unit-width-hidden", "1"), "1");
+        // Values are rounded once, so that ties stay ties for the rounding mode.
+        assert_eq!(
+            format("precision-increment/0.05 rounding-mode-half-even", "1.025"),
+            "1.00"
+        );
+        assert_eq!(
+            format("precision-increment/0.05 rounding-mode-half-even", "1.075"),
+            "1.10"
+        );
+        assert_eq!(
+            format(
+                "currency/USD precision-increment/0.05 rounding-mode-half-even",
+                "-1.025"
+            ),
+            "-$1.00"
+        );
+        assert_eq!(
+            format(
+                "unit/meter precision-integer rounding-mode-half-even",
+                "2.5"
+            ),
+            "2 m"
+        );
+
+        let format_fr = |skeleton: &str, value: &str| {
+            let options = NumberFormatterOptions::try_from_skeleton(skeleton).unwrap();
+            let formatter = NumberFormatter::try_new(locale!("fr").into(), &options).unwrap();
+            formatter.format(&value.parse().unwrap()).to_string()
+        };
+        assert_eq!(
+            format_fr("unit/meter unit-width-full-name", "1"),
+            "1\u{a0}mètre"
+        );
+        assert_eq!(
+            format_fr("unit/meter unit-width-full-name", "2.5"),
+            "2,5\u{a0}mètres"
+        );
+        assert_eq!(
+            format_fr("KK unit/meter unit-width-full-name", "2000000"),
+            "2 millions\u{a0}mètres"
+        );
+
+        let formatter =
+            NumberFormatter::try_new(locale!("ar-EG").into(), &"percent latin".parse().unwrap())
+                .unwrap();
+        assert_writeable_eq!(
+            formatter.format(&"5".parse().unwrap()),
+            "5\u{200e}%\u{200e}"
+        );
+    }
+}
@@ -0,0 +1,559 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::Decimal;
+use icu_decimal::options::{
+    CompactDecimalFormatterOptions, DecimalFormatterOptions, GroupingStrategy, PrecisionOptions,
+};
+use icu_decimal::preferences::CompactDecimalFormatterPreferences;
+use icu_decimal::provider::{
+    DecimalCompactLongV1, DecimalCompactShortV1, DecimalDigitsV1, DecimalSymbolsV1,
+};
+use icu_decimal::{
+    AbstractFormatter, CompactDecimalFormatter, DecimalFormatter, DecimalFormatterPreferences,
+};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_plurals::provider::PluralsCardinalV1;
+use icu_plurals::{PluralRules, PluralRulesPreferences};
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::*;
+
+use super::format::FormattedNumber;
+use super::options::{Notation, NumberFormatterOptions, NumberUnit, UnitWidth};
+use crate::dimension::currency::formatter::{CurrencyFormatter, CurrencyFormatterPreferences};
+use crate::dimension::currency::options::CurrencyFormatterOptions;
+use crate::dimension::provider::currency::essentials::CurrencyEssentialsV1;
+use crate::dimension::provider::currency::extended::CurrencyExtendedDataV1;
+use crate::dimension::provider::currency::fractions::CurrencyFractionsV1;
+use crate::dimension::provider::currency::no_currency::CurrencyPatternsNoCurrencyV1;
+use crate::dimension::provider::currency::patterns::CurrencyPatternsDataV1;
+use crate::dimension::provider::currency::symbols::CurrencySymbolsV1;
+use crate::dimension::provider::percent::PercentEssentialsV1;
+use crate::dimension::provider::units::categorized_display_names::*;
+use crate::dimension::provider::units::display_names::UnitsDisplayNames;
+use crate::dimension::units::names::load_unit_names;
+use crate::dimension::units::options::Width;
+
+define_preferences!(
+    /// The preferences for number formatting.
+    [Copy]
+    NumberFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: crate::dimension::preferences::NumberingSystem
+    }
+);
+
+prefs_convert!(NumberFormatterPreferences, DecimalFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(
+    NumberFormatterPreferences,
+    CompactDecimalFormatterPreferences,
+    { numbering_system }
+);
+prefs_convert!(NumberFormatterPreferences, CurrencyFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(NumberFormatterPreferences, PluralRulesPreferences);
+
+/// The formatter for the unit of the number, on top of a plain or compact value formatter.
+#[derive(Debug)]
+pub(crate) enum UnitFormatter<V: AbstractFormatter> {
+    None(V),
+    Percent(V, DataPayload<PercentEssentialsV1>),
+    Currency(CurrencyFormatter<V>),
+    Measure(
+        V,
+        DataPayload<ErasedMarker<UnitsDisplayNames<'static>>>,
+        PluralRules,
+    ),
+}
+
+#[derive(Debug)]
+pub(crate) enum NumberFormatterInner {
+    Standard(UnitFormatter<DecimalFormatter>),
+    Compact(UnitFormatter<CompactDecimalFormatter>),
+}
+
+/// A formatter for numbers with a notation, unit, precision and sign display.
+///
+/// [`NumberFormatter`] combines [`DecimalFormatter`], [`CompactDecimalFormatter`],
+/// currency, percent and measurement unit formatting behind a single type, configured with
+/// [`NumberFormatterOptions`] that map one-to-one to ICU number skeletons and to the
+/// ECMA-402 `Intl.NumberFormat` options.
+///
+/// Read more about the options in the [`super::options`] module.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::number::formatter::NumberFormatter;
+/// use icu::experimental::dimension::number::options::NumberFormatterOptions;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let options =
+///     NumberFormatterOptions::try_from_skeleton("compact-short currency/EUR")
+///         .unwrap();
+/// let formatter =
+///     NumberFormatter::try_new(locale!("en").into(), &options).unwrap();
+///
+/// assert_writeable_eq!(formatter.format(&"-1234567".parse().unwrap()), "-€1.2M");
+/// ```
+#[derive(Debug)]
+pub struct NumberFormatter {
+    inner: NumberFormatterInner,
+    precision: PrecisionOptions,
+    sign_display: fixed_decimal::SignDisplay,
+}
+
+impl NumberFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: NumberFormatterPreferences, options: &NumberFormatterOptions) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    // We manually implement the compiled constructor because of the cross-crate dependency
+
+    /// Creates a new [`NumberFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: NumberFormatterPreferences,
+        options: &NumberFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let prefs = Self::resolve_prefs(prefs, options);
+        let inner = match options.notation {
+            Notation::Simple => NumberFormatterInner::Standard(UnitFormatter::try_new(
+                DecimalFormatter::try_new((&prefs).into(), Self::decimal_options(options))?,
+                prefs,
+                options,
+            )?),
+            Notation::CompactShort => NumberFormatterInner::Compact(UnitFormatter::try_new(
+                CompactDecimalFormatter::try_new_short(
+                    (&prefs).into(),
+                    Self::compact_options(options),
+                )?,
+                prefs,
+                options,
+            )?),
+            Notation::CompactLong => NumberFormatterInner::Compact(UnitFormatter::try_new(
+                CompactDecimalFormatter::try_new_long(
+                    (&prefs).into(),
+                    Self::compact_options(options),
+                )?,
+                prefs,
+                options,
+            )?),
+        };
+        Ok(Self {
+            inner,
+            precision: options.precision,
+            sign_display: options.sign_display,
+        })
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: NumberFormatterPreferences,
+        options: &NumberFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<DecimalCompactShortV1>
+            + DataProvider<DecimalCompactLongV1>
+            + DataProvider<PluralsCardinalV1>
+            + DataProvider<PercentEssentialsV1>
+            + DataProvider<CurrencyEssentialsV1>
+            + DataProvider<CurrencySymbolsV1>
+            + DataProvider<CurrencyFractionsV1>
+            + DataProvider<CurrencyExtendedDataV1>
+            + DataProvider<CurrencyPatternsDataV1>
+            + DataProvider<CurrencyPatternsNoCurrencyV1>
+            + DataProvider<UnitsNamesAreaCoreV1>
+            + DataProvider<UnitsNamesAreaExtendedV1>
+            + DataProvider<UnitsNamesAreaOutlierV1>
+            + DataProvider<UnitsNamesDurationCoreV1>
+            + DataProvider<UnitsNamesDurationExtendedV1>
+            + DataProvider<UnitsNamesDurationOutlierV1>
+            + DataProvider<UnitsNamesLengthCoreV1>
+            + DataProvider<UnitsNamesLengthExtendedV1>
+            + DataProvider<UnitsNamesLengthOutlierV1>
+            + DataProvider<UnitsNamesMassCoreV1>
+            + DataProvider<UnitsNamesMassExtendedV1>
+            + DataProvider<UnitsNamesMassOutlierV1>
+            + DataProvider<UnitsNamesConsumptionCoreV1>
+            + DataProvider<UnitsNamesConsumptionExtendedV1>
+            + DataProvider<UnitsNamesConsumptionOutlierV1>
+            + DataProvider<UnitsNamesDigitalCoreV1>
+            + DataProvider<UnitsNamesDigitalExtendedV1>
+            + DataProvider<UnitsNamesDigitalOutlierV1>
+            + DataProvider<UnitsNamesEnergyCoreV1>
+            + DataProvider<UnitsNamesEnergyExtendedV1>
+            + DataProvider<UnitsNamesEnergyOutlierV1>
+            + DataProvider<UnitsNamesPowerCoreV1>
+            + DataProvider<UnitsNamesPowerExtendedV1>
+            + DataProvider<UnitsNamesPowerOutlierV1>
+            + DataProvider<UnitsNamesPressureCoreV1>
+            + DataProvider<UnitsNamesPressureExtendedV1>
+            + DataProvider<UnitsNamesPressureOutlierV1>
+            + DataProvider<UnitsNamesSpeedCoreV1>
+            + DataProvider<UnitsNamesSpeedExtendedV1>
+            + DataProvider<UnitsNamesSpeedOutlierV1>
+            + DataProvider<UnitsNamesTemperatureCoreV1>
+            + DataProvider<UnitsNamesTemperatureExtendedV1>
+            + DataProvider<UnitsNamesTemperatureOutlierV1>
+            + DataProvider<UnitsNamesOtherV1>
+            + DataProvider<UnitsNamesVolumeCoreV1>
+            + DataProvider<UnitsNamesVolumeExtendedV1>
+            + DataProvider<UnitsNamesVolumeOutlierV1>,
+    {
+        let prefs = Self::resolve_prefs(prefs, options);
+        let inner = match options.notation {
+            Notation::Simple => NumberFormatterInner::Standard(UnitFormatter::try_new_unstable(
+                provider,
+                DecimalFormatter::try_new_unstable(
+                    provider,
+                    (&prefs).into(),
+                    Self::decimal_options(options),
+                )?,
+                prefs,
+                options,
+            )?),
+            Notation::CompactShort => {
+                NumberFormatterInner::Compact(UnitFormatter::try_new_unstable(
+                    provider,
+                    CompactDecimalFormatter::try_new_short_unstable(
+                        provider,
+                        (&prefs).into(),
+                        Self::compact_options(options),
+                    )?,
+                    prefs,
+                    options,
+                )?)
+            }
+            Notation::CompactLong => {
+                NumberFormatterInner::Compact(UnitFormatter::try_new_unstable(
+                    provider,
+                    CompactDecimalFormatter::try_new_long_unstable(
+                        provider,
+                        (&prefs).into(),
+                        Self::compact_options(options),
+                    )?,
+                    prefs,
+                    options,
+                )?)
+            }
+        };
+        Ok(Self {
+            inner,
+            precision: options.precision,
+            sign_display: options.sign_display,
+        })
+    }
+
+    /// The numbering system of the options takes precedence over the one of the locale.
+    fn resolve_prefs(
+        mut prefs: NumberFormatterPreferences,
+        options: &NumberFormatterOptions,
+    ) -> NumberFormatterPreferences {
+        if options.numbering_system.is_some() {
+            prefs.numbering_system = options.numbering_system;
+        }
+        prefs
+    }
+
+    fn decimal_options(options: &NumberFormatterOptions) -> DecimalFormatterOptions {
+        let mut decimal_options = DecimalFormatterOptions::default();
+        decimal_options.grouping_strategy = options.grouping_strategy;
+        decimal_options
+    }
+
+    fn compact_options(options: &NumberFormatterOptions) -> CompactDecimalFormatterOptions {
+        options
+            .grouping_strategy
+            .unwrap_or(GroupingStrategy::Min2)
+            .into()
+    }
+
+    /// Formats a [`Decimal`] value.
+    ///
+    /// The number is rounded before the sign display is applied, so that, for example,
+    /// `-0.001` is formatted without a minus sign when rounded to an integer with
+    /// [`SignDisplay::Negative`](fixed_decimal::SignDisplay::Negative).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::dimension::number::formatter::NumberFormatter;
+    /// use icu::experimental::dimension::number::options::NumberFormatterOptions;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let options = NumberFormatterOptions::try_from_skeleton(
+    ///     "unit/kilometer .0 sign-always",
+    /// )
+    /// .unwrap();
+    /// let formatter =
+    ///     NumberFormatter::try_new(locale!("en").into(), &options).unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format(&"12.34".parse().unwrap()),
+    ///     "+12.3 km"
+    /// );
+    /// ```
+    pub fn format(&self, value: &Decimal) -> FormattedNumber<'_> {
+        let mut value = value.clone();
+        // Compact numbers are rounded after scaling, in the compact formatter.
+        if let NumberFormatterInner::Standard(unit) = &self.inner {
+            match unit {
+                UnitFormatter::None(_) | UnitFormatter::Measure(..) => {
+                    self.precision.round(&mut value, 0, 3)
+                }
+                UnitFormatter::Percent(..) => self.precision.round(&mut value, 0, 0),
+                UnitFormatter::Currency(currency) => currency.round(&mut value),
+            }
+        }
+        value.apply_sign_display(self.sign_display);
+        FormattedNumber {
+            value,
+            formatter: self,
+        }
+    }
+
+    pub(crate) fn inner(&self) -> &NumberFormatterInner {
+        &self.inner
+    }
+
+    pub(crate) fn precision(&self) -> &PrecisionOptions {
+        &self.precision
+    }
+}
+
+impl<V: AbstractFormatter> UnitFormatter<V> {
+    #[cfg(feature = "compiled_data")]
+    fn try_new(
+        value_formatter: V,
+        prefs: NumberFormatterPreferences,
+        options: &NumberFormatterOptions,
+    ) -> Result<Self, DataError> {
+        Ok(match &options.unit {
+            NumberUnit::None => Self::None(value_formatter),
+            NumberUnit::Percent => Self::Percent(
+                value_formatter,
+                load_percent(&crate::provider::Baked, prefs)?,
+            ),
+            &NumberUnit::Currency(currency) => {
+                let currency_prefs = CurrencyFormatterPreferences::from(&prefs);
+                let currency_options = currency_options(options);
+                Self::Currency(match options.unit_width {
+                    UnitWidth::Short => CurrencyFormatter::try_new_essential(
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                        CurrencySymbolsV1::SHORT,
+                        currency_options,
+                    )?,
+                    UnitWidth::Narrow => CurrencyFormatter::try_new_essential(
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                        CurrencySymbolsV1::NARROW,
+                        currency_options,
+                    )?,
+                    UnitWidth::IsoCode => CurrencyFormatter::try_new_code_internal(
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                        currency_options,
+                    )?,
+                    UnitWidth::FullName => CurrencyFormatter::try_new_name_internal(
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                    )?
+                    .with_options(currency_options),
+                    UnitWidth::Hidden => CurrencyFormatter::try_new_no_currency_internal(
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                        currency_options,
+                    )?,
+                })
+            }
+            NumberUnit::Measure(_) if options.unit_width == UnitWidth::Hidden => {
+                Self::None(value_formatter)
+            }
+            NumberUnit::Measure(unit) => Self::Measure(
+                value_formatter,
+                load_unit_names(
+                    &crate::provider::Baked,
+                    &UnitsNamesLengthCoreV1::make_locale(prefs.locale_preferences),
+                    names_width(options.unit_width),
+                    unit,
+                )?,
+                PluralRules::try_new_cardinal((&prefs).into())?,
+            ),
+        })
+    }
+
+    fn try_new_unstable<D>(
+        provider: &D,
+        value_formatter: V,
+        prefs: NumberFormatterPreferences,
+        options: &NumberFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<PluralsCardinalV1>
+            + DataProvider<PercentEssentialsV1>
+            + DataProvider<CurrencyEssentialsV1>
+            + DataProvider<CurrencySymbolsV1>
+            + DataProvider<CurrencyFractionsV1>
+            + DataProvider<CurrencyExtendedDataV1>
+            + DataProvider<CurrencyPatternsDataV1>
+            + DataProvider<CurrencyPatternsNoCurrencyV1>
+            + DataProvider<UnitsNamesAreaCoreV1>
+            + DataProvider<UnitsNamesAreaExtendedV1>
+            + DataProvider<UnitsNamesAreaOutlierV1>
+            + DataProvider<UnitsNamesDurationCoreV1>
+            + DataProvider<UnitsNamesDurationExtendedV1>
+            + DataProvider<UnitsNamesDurationOutlierV1>
+            + DataProvider<UnitsNamesLengthCoreV1>
+            + DataProvider<UnitsNamesLengthExtendedV1>
+            + DataProvider<UnitsNamesLengthOutlierV1>
+            + DataProvider<UnitsNamesMassCoreV1>
+            + DataProvider<UnitsNamesMassExtendedV1>
+            + DataProvider<UnitsNamesMassOutlierV1>
+            + DataProvider<UnitsNamesConsumptionCoreV1>
+            + DataProvider<UnitsNamesConsumptionExtendedV1>
+            + DataProvider<UnitsNamesConsumptionOutlierV1>
+            + DataProvider<UnitsNamesDigitalCoreV1>
+            + DataProvider<UnitsNamesDigitalExtendedV1>
+            + DataProvider<UnitsNamesDigitalOutlierV1>
+            + DataProvider<UnitsNamesEnergyCoreV1>
+            + DataProvider<UnitsNamesEnergyExtendedV1>
+            + DataProvider<UnitsNamesEnergyOutlierV1>
+            + DataProvider<UnitsNamesPowerCoreV1>
+            + DataProvider<UnitsNamesPowerExtendedV1>
+            + DataProvider<UnitsNamesPowerOutlierV1>
+            + DataProvider<UnitsNamesPressureCoreV1>
+            + DataProvider<UnitsNamesPressureExtendedV1>
+            + DataProvider<UnitsNamesPressureOutlierV1>
+            + DataProvider<UnitsNamesSpeedCoreV1>
+            + DataProvider<UnitsNamesSpeedExtendedV1>
+            + DataProvider<UnitsNamesSpeedOutlierV1>
+            + DataProvider<UnitsNamesTemperatureCoreV1>
+            + DataProvider<UnitsNamesTemperatureExtendedV1>
+            + DataProvider<UnitsNamesTemperatureOutlierV1>
+            + DataProvider<UnitsNamesOtherV1>
+            + DataProvider<UnitsNamesVolumeCoreV1>
+            + DataProvider<UnitsNamesVolumeExtendedV1>
+            + DataProvider<UnitsNamesVolumeOutlierV1>,
+    {
+        Ok(match &options.unit {
+            NumberUnit::None => Self::None(value_formatter),
+            NumberUnit::Percent => Self::Percent(value_formatter, load_percent(provider, prefs)?),
+            &NumberUnit::Currency(currency) => {
+                let currency_prefs = CurrencyFormatterPreferences::from(&prefs);
+                let currency_options = currency_options(options);
+                Self::Currency(match options.unit_width {
+                    UnitWidth::Short => CurrencyFormatter::try_new_essential_unstable(
+                        provider,
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                        CurrencySymbolsV1::SHORT,
+                        currency_options,
+                    )?,
+                    UnitWidth::Narrow => CurrencyFormatter::try_new_essential_unstable(
+                        provider,
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                        CurrencySymbolsV1::NARROW,
+                        currency_options,
+                    )?,
+                    UnitWidth::IsoCode => CurrencyFormatter::try_new_code_internal_unstable(
+                        provider,
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                        currency_options,
+                    )?,
+                    UnitWidth::FullName => CurrencyFormatter::try_new_name_internal_unstable(
+                        provider,
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                    )?
+                    .with_options(currency_options),
+                    UnitWidth::Hidden => CurrencyFormatter::try_new_no_currency_internal_unstable(
+                        provider,
+                        value_formatter,
+                        currency_prefs,
+                        currency,
+                        currency_options,
+                    )?,
+                })
+            }
+            NumberUnit::Measure(_) if options.unit_width == UnitWidth::Hidden => {
+                Self::None(value_formatter)
+            }
+            NumberUnit::Measure(unit) => Self::Measure(
+                value_formatter,
+                load_unit_names(
+                    provider,
+                    &UnitsNamesLengthCoreV1::make_locale(prefs.locale_preferences),
+                    names_width(options.unit_width),
+                    unit,
+                )?,
+                PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?,
+            ),
+        })
+    }
+}
+
+fn currency_options(options: &NumberFormatterOptions) -> CurrencyFormatterOptions {
+    CurrencyFormatterOptions {
+        usage: options.currency_usage,
+        precision: options.precision,
+    }
+}
+
+fn load_percent(
+    provider: &(impl DataProvider<PercentEssentialsV1> + ?Sized),
+    prefs: NumberFormatterPreferences,
+) -> Result<DataPayload<PercentEssentialsV1>, DataError> {
+    let locale = PercentEssentialsV1::make_locale(prefs.locale_preferences);
+    Ok(provider
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&locale),
+            ..Default::default()
+        })?
+        .payload)
+}
+
+/// The width of the unit display names for the given unit width.
+fn names_width(width: UnitWidth) -> Width {
+    match width {
+        UnitWidth::FullName => Width::Long,
+        UnitWidth::Narrow => Width::Narrow,
+        _ => Width::Short,
+    }
+}
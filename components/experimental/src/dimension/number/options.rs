@@ -0,0 +1,146 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`NumberFormatter`](crate::dimension::number::formatter::NumberFormatter).
+
+use alloc::borrow::Cow;
+
+use icu_decimal::input::SignDisplay;
+use icu_decimal::options::{GroupingStrategy, PrecisionOptions};
+
+use crate::dimension::currency::CurrencyType;
+use crate::dimension::currency::options::CurrencyUsage;
+use crate::dimension::preferences::NumberingSystem;
+
+/// A collection of configuration options that determine the formatting behavior of
+/// [`NumberFormatter`](crate::dimension::number::formatter::NumberFormatter).
+///
+/// The options correspond to the stems of ICU number skeletons, and can be
+/// created from a skeleton with [`NumberFormatterOptions::try_from_skeleton`].
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub struct NumberFormatterOptions {
+    /// Whether to display the number in full or in compact notation.
+    pub notation: Notation,
+
+    /// The unit of the number.
+    pub unit: NumberUnit,
+
+    /// How to display the unit.
+    pub unit_width: UnitWidth,
+
+    /// How many digits to display and how to round.
+    ///
+    /// By default, numbers are displayed with exactly the digits they have, except for
+    /// currencies, which are rounded according to the currency's digits. If any option is set,
+    /// unset fraction digits default to 0–3 for plain numbers and measurement units, to 0 for
+    /// percents, and to the currency's digits for currencies, as in ECMA-402. Compact notation
+    /// uses the defaults of [`CompactDecimalFormatter`](icu_decimal::CompactDecimalFormatter).
+    pub precision: PrecisionOptions,
+
+    /// When to display the sign of the number.
+    pub sign_display: SignDisplay,
+
+    /// Whether to use standard or accounting patterns for currencies.
+    pub currency_usage: CurrencyUsage,
+
+    /// When to render grouping separators.
+    ///
+    /// Default is [`GroupingStrategy::Auto`], or [`GroupingStrategy::Min2`] in compact notation.
+    pub grouping_strategy: Option<GroupingStrategy>,
+
+    /// The numbering system, overriding the one of the locale.
+    pub numbering_system: Option<NumberingSystem>,
+}
+
+impl From<Notation> for NumberFormatterOptions {
+    fn from(notation: Notation) -> Self {
+        Self {
+            notation,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<NumberUnit> for NumberFormatterOptions {
+    fn from(unit: NumberUnit) -> Self {
+        Self {
+            unit,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PrecisionOptions> for NumberFormatterOptions {
+    fn from(precision: PrecisionOptions) -> Self {
+        Self {
+            precision,
+            ..Default::default()
+        }
+    }
+}
+
+/// The notation of the number.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum Notation {
+    /// Display the number in full, e.g. "1,234,567".
+    #[default]
+    Simple,
+
+    /// Display the number in short compact notation, e.g. "1.2M".
+    CompactShort,
+
+    /// Display the number in long compact notation, e.g. "1.2 million".
+    CompactLong,
+}
+
+/// The unit of the number.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+#[non_exhaustive]
+pub enum NumberUnit {
+    /// A plain number.
+    #[default]
+    None,
+
+    /// A percentage, e.g. "12%".
+    ///
+    /// The number is not multiplied by 100.
+    Percent,
+
+    /// An amount of money in the given currency.
+    Currency(CurrencyType),
+
+    /// A measurement in the given unit.
+    ///
+    /// The unit is a CLDR unit identifier without the category, such as `meter` or `square-kilometer`.
+    Measure(Cow<'static, str>),
+}
+
+/// How to display the unit of the number.
+///
+/// This has no effect on plain numbers and percentages.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[non_exhaustive]
+pub enum UnitWidth {
+    /// Display the short form of the unit, e.g. "US$" or "m".
+    #[default]
+    Short,
+
+    /// Display the narrow form of the unit, e.g. "$" or "m".
+    Narrow,
+
+    /// Display the full name of the unit, e.g. "US dollars" or "meters".
+    FullName,
+
+    /// Display the ISO code of currencies, e.g. "USD".
+    ///
+    /// Measurement units are displayed in their short form.
+    IsoCode,
+
+    /// Do not display the unit.
+    ///
+    /// The number is still rounded as for the unit, e.g. to the digits of a currency.
+    Hidden,
+}
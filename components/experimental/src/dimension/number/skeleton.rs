@@ -0,0 +1,589 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of ICU number skeletons into [`NumberFormatterOptions`].
+
+use alloc::string::String;
+use core::str::FromStr;
+
+use fixed_decimal::{SignedRoundingMode, UnsignedRoundingMode};
+use icu_decimal::input::SignDisplay;
+use icu_decimal::options::{
+    GroupingStrategy, PrecisionOptions, RoundingPriority, ScaledRoundingIncrement,
+    TrailingZeroDisplay,
+};
+use icu_locale_core::extensions::unicode::Value;
+
+use super::options::{Notation, NumberFormatterOptions, NumberUnit, UnitWidth};
+use crate::dimension::currency::CurrencyType;
+use crate::dimension::currency::options::CurrencyUsage;
+use crate::dimension::preferences::NumberingSystem;
+
+/// An error returned when parsing an ICU number skeleton.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum SkeletonError {
+    /// A stem is not a valid number skeleton stem.
+    #[displaydoc("Invalid skeleton stem")]
+    InvalidStem,
+    /// A stem has a missing or invalid option.
+    #[displaydoc("Invalid skeleton option")]
+    InvalidOption,
+    /// A setting is specified by more than one stem.
+    #[displaydoc("Duplicate skeleton setting")]
+    DuplicateSetting,
+    /// A valid stem is not supported by
+    /// [`NumberFormatter`](crate::dimension::number::formatter::NumberFormatter), such as
+    /// scientific notation or scaling.
+    #[displaydoc("Unsupported skeleton stem")]
+    Unsupported,
+}
+
+impl core::error::Error for SkeletonError {}
+
+/// The settings that have already been set by a stem, to detect duplicates.
+#[derive(Default)]
+struct Seen {
+    notation: bool,
+    unit: bool,
+    unit_width: bool,
+    precision: bool,
+    integer_width: bool,
+    rounding_mode: bool,
+    sign: bool,
+    grouping: bool,
+    numbering_system: bool,
+    decimal: bool,
+}
+
+fn set_once(seen: &mut bool) -> Result<(), SkeletonError> {
+    if core::mem::replace(seen, true) {
+        Err(SkeletonError::DuplicateSetting)
+    } else {
+        Ok(())
+    }
+}
+
+impl NumberFormatterOptions {
+    /// Creates options from an ICU number skeleton, such as `compact-short currency/EUR precision-integer`.
+    ///
+    /// Both the long and the concise forms of the stems are supported. Stems that have no
+    /// equivalent in [`NumberFormatterOptions`], such as `scientific` or `scale/100`, return
+    /// [`SkeletonError::Unsupported`].
+    ///
+    /// See the [ICU documentation](https://unicode-org.github.io/icu/userguide/format_parse/numbers/skeletons.html)
+    /// for the skeleton syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::input::SignDisplay;
+    /// use icu::experimental::dimension::number::options::{
+    ///     Notation, NumberFormatterOptions, NumberUnit,
+    /// };
+    /// use icu::locale::preferences::extensions::unicode::keywords::currency;
+    ///
+    /// let options = NumberFormatterOptions::try_from_skeleton(
+    ///     "compact-short currency/EUR precision-integer sign-always",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(options.notation, Notation::CompactShort);
+    /// assert_eq!(options.unit, NumberUnit::Currency(currency!("EUR")));
+    /// assert_eq!(options.precision.maximum_fraction_digits, Some(0));
+    /// assert_eq!(options.sign_display, SignDisplay::Always);
+    ///
+    /// // Concise form
+    /// assert_eq!(
+    ///     NumberFormatterOptions::try_from_skeleton("K currency/EUR . +!"),
+    ///     Ok(options)
+    /// );
+    /// ```
+    pub fn try_from_skeleton(skeleton: &str) -> Result<Self, SkeletonError> {
+        let mut options = Self::default();
+        let mut seen = Seen::default();
+
+        for token in skeleton.split_ascii_whitespace() {
+            let (stem, option) = match token.split_once('/') {
+                Some((stem, option)) => (stem, Some(option)),
+                None => (token, None),
+            };
+            // Only a few stems take options
+            let no_option = || match option {
+                None => Ok(()),
+                Some(_) => Err(SkeletonError::InvalidOption),
+            };
+            let option = || option.ok_or(SkeletonError::InvalidOption);
+
+            match stem {
+                "notation-simple" | "compact-short" | "K" | "compact-long" | "KK" => {
+                    no_option()?;
+                    set_once(&mut seen.notation)?;
+                    options.notation = match stem {
+                        "compact-short" | "K" => Notation::CompactShort,
+                        "compact-long" | "KK" => Notation::CompactLong,
+                        _ => Notation::Simple,
+                    };
+                }
+                "scientific" | "engineering" => return Err(SkeletonError::Unsupported),
+                s if s.starts_with("EE") || s.starts_with("E0") => {
+                    return Err(SkeletonError::Unsupported);
+                }
+
+                "base-unit" | "percent" | "%" => {
+                    no_option()?;
+                    set_once(&mut seen.unit)?;
+                    if stem != "base-unit" {
+                        options.unit = NumberUnit::Percent;
+                    }
+                }
+                "currency" => {
+                    set_once(&mut seen.unit)?;
+                    let code = option()?;
+                    if code.len() != 3 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+                        return Err(SkeletonError::InvalidOption);
+                    }
+                    options.unit = NumberUnit::Currency(
+                        CurrencyType::try_from_str(code)
+                            .map_err(|_| SkeletonError::InvalidOption)?,
+                    );
+                }
+                "measure-unit" | "unit" => {
+                    set_once(&mut seen.unit)?;
+                    let unit = option()?;
+                    // The long form includes the unit's category, e.g. `length-meter`
+                    let unit = if stem == "measure-unit" {
+                        unit.split_once('-')
+                            .map(|(_, unit)| unit)
+                            .ok_or(SkeletonError::InvalidOption)?
+                    } else {
+                        unit
+                    };
+                    if unit.is_empty()
+                        || !unit
+                            .bytes()
+                            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+                    {
+                        return Err(SkeletonError::InvalidOption);
+                    }
+                    options.unit = NumberUnit::Measure(String::from(unit).into());
+                }
+                "permille" | "per-measure-unit" | "scale" | "%x100" => {
+                    return Err(SkeletonError::Unsupported);
+                }
+
+                "unit-width-short"
+                | "unit-width-narrow"
+                | "unit-width-full-name"
+                | "unit-width-iso-code"
+                | "unit-width-hidden" => {
+                    no_option()?;
+                    set_once(&mut seen.unit_width)?;
+                    options.unit_width = match stem {
+                        "unit-width-narrow" => UnitWidth::Narrow,
+                        "unit-width-full-name" => UnitWidth::FullName,
+                        "unit-width-iso-code" => UnitWidth::IsoCode,
+                        "unit-width-hidden" => UnitWidth::Hidden,
+                        _ => UnitWidth::Short,
+                    };
+                }
+                "unit-width-formal" | "unit-width-variant" => {
+                    return Err(SkeletonError::Unsupported);
+                }
+
+                "precision-integer" | "precision-unlimited" | "precision-currency-standard" => {
+                    no_option()?;
+                    set_once(&mut seen.precision)?;
+                    match stem {
+                        "precision-integer" => {
+                            options.precision.minimum_fraction_digits = Some(0);
+                            options.precision.maximum_fraction_digits = Some(0);
+                        }
+                        "precision-unlimited" => {
+                            options.precision.minimum_fraction_digits = Some(0);
+                            options.precision.maximum_fraction_digits = Some(100);
+                        }
+                        // The default for currencies
+                        _ => {}
+                    }
+                }
+                "precision-increment" => {
+                    set_once(&mut seen.precision)?;
+                    parse_increment(option()?, &mut options.precision)?;
+                }
+                "precision-currency-cash" => return Err(SkeletonError::Unsupported),
+                s if s.starts_with('.') => {
+                    set_once(&mut seen.precision)?;
+                    parse_fraction_precision(s, token.split_once('/'), &mut options.precision)?;
+                }
+                s if s.starts_with('@') => {
+                    no_option()?;
+                    set_once(&mut seen.precision)?;
+                    let (min, max) = parse_significant(s)?;
+                    options.precision.minimum_significant_digits = Some(min);
+                    options.precision.maximum_significant_digits = max;
+                }
+
+                "integer-width" => {
+                    set_once(&mut seen.integer_width)?;
+                    parse_integer_width(option()?, &mut options.precision)?;
+                }
+                "integer-width-trunc" => {
+                    no_option()?;
+                    set_once(&mut seen.integer_width)?;
+                    options.precision.maximum_integer_digits = Some(0);
+                }
+                s if !s.is_empty() && s.bytes().all(|b| b == b'0') => {
+                    no_option()?;
+                    set_once(&mut seen.integer_width)?;
+                    // The concise form of `integer-width/*000`
+                    options.precision.minimum_integer_digits = Some(to_digits(s.len())?);
+                }
+
+                s if s.starts_with("rounding-mode-") => {
+                    no_option()?;
+                    set_once(&mut seen.rounding_mode)?;
+                    options.precision.rounding_mode = Some(match s {
+                        "rounding-mode-ceiling" => SignedRoundingMode::Ceil,
+                        "rounding-mode-floor" => SignedRoundingMode::Floor,
+                        "rounding-mode-down" => {
+                            SignedRoundingMode::Unsigned(UnsignedRoundingMode::Trunc)
+                        }
+                        "rounding-mode-up" => {
+                            SignedRoundingMode::Unsigned(UnsignedRoundingMode::Expand)
+                        }
+                        "rounding-mode-half-even" => {
+                            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven)
+                        }
+                        "rounding-mode-half-down" => {
+                            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfTrunc)
+                        }
+                        "rounding-mode-half-up" => {
+                            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand)
+                        }
+                        "rounding-mode-half-ceiling" => SignedRoundingMode::HalfCeil,
+                        "rounding-mode-half-floor" => SignedRoundingMode::HalfFloor,
+                        "rounding-mode-half-odd" | "rounding-mode-unnecessary" => {
+                            return Err(SkeletonError::Unsupported);
+                        }
+                        _ => return Err(SkeletonError::InvalidStem),
+                    });
+                }
+
+                "group-off" | ",_" | "group-min2" | ",?" | "group-auto" | "group-on-aligned"
+                | ",!" => {
+                    no_option()?;
+                    set_once(&mut seen.grouping)?;
+                    options.grouping_strategy = Some(match stem {
+                        "group-off" | ",_" => GroupingStrategy::Never,
+                        "group-min2" | ",?" => GroupingStrategy::Min2,
+                        "group-on-aligned" | ",!" => GroupingStrategy::Always,
+                        _ => GroupingStrategy::Auto,
+                    });
+                }
+                "group-thousands" | ",=" => return Err(SkeletonError::Unsupported),
+
+                "sign-auto"
+                | "sign-always"
+                | "+!"
+                | "sign-never"
+                | "+_"
+                | "sign-except-zero"
+                | "+?"
+                | "sign-negative"
+                | "+-"
+                | "sign-accounting"
+                | "()"
+                | "sign-accounting-always"
+                | "()!"
+                | "sign-accounting-except-zero"
+                | "()?"
+                | "sign-accounting-negative"
+                | "()-" => {
+                    no_option()?;
+                    set_once(&mut seen.sign)?;
+                    let (sign_display, accounting) = match stem {
+                        "sign-always" | "+!" => (SignDisplay::Always, false),
+                        "sign-never" | "+_" => (SignDisplay::Never, false),
+                        "sign-except-zero" | "+?" => (SignDisplay::ExceptZero, false),
+                        "sign-negative" | "+-" => (SignDisplay::Negative, false),
+                        "sign-accounting" | "()" => (SignDisplay::Auto, true),
+                        "sign-accounting-always" | "()!" => (SignDisplay::Always, true),
+                        "sign-accounting-except-zero" | "()?" => (SignDisplay::ExceptZero, true),
+                        "sign-accounting-negative" | "()-" => (SignDisplay::Negative, true),
+                        _ => (SignDisplay::Auto, false),
+                    };
+                    options.sign_display = sign_display;
+                    if accounting {
+                        options.currency_usage = CurrencyUsage::Accounting;
+                    }
+                }
+
+                "numbering-system" | "latin" => {
+                    set_once(&mut seen.numbering_system)?;
+                    let nu = if stem == "latin" {
+                        no_option()?;
+                        "latn"
+                    } else {
+                        option()?
+                    };
+                    options.numbering_system = Some(
+                        Value::try_from_str(nu)
+                            .ok()
+                            .and_then(|v| NumberingSystem::try_from(v).ok())
+                            .ok_or(SkeletonError::InvalidOption)?,
+                    );
+                }
+
+                "decimal-auto" => {
+                    no_option()?;
+                    set_once(&mut seen.decimal)?;
+                }
+                "decimal-always" => return Err(SkeletonError::Unsupported),
+
+                _ => return Err(SkeletonError::InvalidStem),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+impl FromStr for NumberFormatterOptions {
+    type Err = SkeletonError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_skeleton(s)
+    }
+}
+
+/// Parses a fraction precision stem such as `.00#`, `.0*` or `.00/@@#r`.
+fn parse_fraction_precision(
+    stem: &str,
+    token: Option<(&str, &str)>,
+    precision: &mut PrecisionOptions,
+) -> Result<(), SkeletonError> {
+    let digits = stem.get(1..).unwrap_or_default();
+    let min = digits.bytes().take_while(|&b| b == b'0').count();
+    let rest = digits.get(min..).unwrap_or_default();
+    let max = match rest {
+        "*" | "+" => None,
+        _ if rest.bytes().all(|b| b == b'#') => Some(min + rest.len()),
+        _ => return Err(SkeletonError::InvalidStem),
+    };
+    precision.minimum_fraction_digits = Some(to_digits(min)?);
+    precision.maximum_fraction_digits = match max {
+        Some(max) => Some(to_digits(max)?),
+        None => Some(100),
+    };
+
+    match token.map(|(_, option)| option) {
+        None => {}
+        Some("w") => precision.trailing_zero_display = Some(TrailingZeroDisplay::StripIfInteger),
+        Some(option) => {
+            // Significant digits combined with the fraction digits, e.g. `@@#r`
+            let (significant, priority) = match option.as_bytes().last() {
+                Some(b'r') => (
+                    option.get(..option.len() - 1),
+                    RoundingPriority::MorePrecision,
+                ),
+                Some(b's') => (
+                    option.get(..option.len() - 1),
+                    RoundingPriority::LessPrecision,
+                ),
+                // `.00/@@*` is a legacy form of `.00/@@r`
+                _ => (Some(option), RoundingPriority::MorePrecision),
+            };
+            let significant = significant.ok_or(SkeletonError::InvalidOption)?;
+            if !significant.starts_with('@') {
+                return Err(SkeletonError::InvalidOption);
+            }
+            let (min, max) = parse_significant(significant)?;
+            precision.minimum_significant_digits = Some(min);
+            precision.maximum_significant_digits = max;
+            precision.rounding_priority = Some(priority);
+        }
+    }
+    Ok(())
+}
+
+/// Parses a significant digits stem such as `@@#` or `@@*`, returning the minimum and maximum.
+fn parse_significant(stem: &str) -> Result<(u8, Option<u8>), SkeletonError> {
+    let min = stem.bytes().take_while(|&b| b == b'@').count();
+    let rest = stem.get(min..).unwrap_or_default();
+    let max = match rest {
+        "*" | "+" => None,
+        _ if rest.bytes().all(|b| b == b'#') => Some(to_digits(min + rest.len())?),
+        _ => return Err(SkeletonError::InvalidStem),
+    };
+    if min == 0 {
+        return Err(SkeletonError::InvalidStem);
+    }
+    Ok((to_digits(min)?, max))
+}
+
+/// Parses an integer width option such as `*000`, `+00` or `##0`.
+fn parse_integer_width(
+    option: &str,
+    precision: &mut PrecisionOptions,
+) -> Result<(), SkeletonError> {
+    let (unlimited, digits) = match option.strip_prefix(['*', '+']) {
+        Some(digits) => (true, digits),
+        None => (false, option),
+    };
+    let optional = digits.bytes().take_while(|&b| b == b'#').count();
+    let required = digits.get(optional..).unwrap_or_default();
+    if !required.bytes().all(|b| b == b'0') || (unlimited && optional > 0) {
+        return Err(SkeletonError::InvalidOption);
+    }
+    precision.minimum_integer_digits = Some(to_digits(required.len())?);
+    precision.maximum_integer_digits = if unlimited {
+        None
+    } else {
+        Some(to_digits(optional + required.len())?)
+    };
+    Ok(())
+}
+
+/// Parses a rounding increment option such as `0.05` or `0.25`.
+fn parse_increment(option: &str, precision: &mut PrecisionOptions) -> Result<(), SkeletonError> {
+    let (integer, fraction) = option.split_once('.').unwrap_or((option, ""));
+    if integer.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(SkeletonError::InvalidOption);
+    }
+    // The increment in units of the last fraction digit
+    let mut increment = 0u32;
+    for b in integer.bytes().chain(fraction.bytes()) {
+        increment = increment
+            .checked_mul(10)
+            .and_then(|i| i.checked_add(u32::from(b - b'0')))
+            .ok_or(SkeletonError::Unsupported)?;
+    }
+    let increment = u16::try_from(increment)
+        .ok()
+        .and_then(ScaledRoundingIncrement::try_from_ecma402)
+        .ok_or(SkeletonError::Unsupported)?;
+    let digits = to_digits(fraction.len())?;
+    precision.rounding_increment = Some(increment);
+    precision.minimum_fraction_digits = Some(digits);
+    precision.maximum_fraction_digits = Some(digits);
+    Ok(())
+}
+
+fn to_digits(n: usize) -> Result<u8, SkeletonError> {
+    u8::try_from(n).map_err(|_| SkeletonError::InvalidStem)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_decimal::options::RoundingIncrement;
+
+    #[test]
+    fn test_precision() {
+        let precision = |skeleton| {
+            NumberFormatterOptions::try_from_skeleton(skeleton).map(|options| options.precision)
+        };
+
+        let p = precision(".00##").unwrap();
+        assert_eq!(p.minimum_fraction_digits, Some(2));
+        assert_eq!(p.maximum_fraction_digits, Some(4));
+
+        let p = precision(".0*").unwrap();
+        assert_eq!(p.minimum_fraction_digits, Some(1));
+        assert_eq!(p.maximum_fraction_digits, Some(100));
+
+        let p = precision(".00/w").unwrap();
+        assert_eq!(
+            p.trailing_zero_display,
+            Some(TrailingZeroDisplay::StripIfInteger)
+        );
+
+        let p = precision("@@#").unwrap();
+        assert_eq!(p.minimum_significant_digits, Some(2));
+        assert_eq!(p.maximum_significant_digits, Some(3));
+
+        let p = precision(".##/@@@s").unwrap();
+        assert_eq!(p.maximum_fraction_digits, Some(2));
+        assert_eq!(p.minimum_significant_digits, Some(3));
+        assert_eq!(p.rounding_priority, Some(RoundingPriority::LessPrecision));
+
+        let p = precision("precision-increment/0.05").unwrap();
+        assert_eq!(
+            p.rounding_increment,
+            Some(RoundingIncrement::MultiplesOf5.into())
+        );
+        assert_eq!(p.maximum_fraction_digits, Some(2));
+
+        let p = precision("integer-width/##0").unwrap();
+        assert_eq!(p.minimum_integer_digits, Some(1));
+        assert_eq!(p.maximum_integer_digits, Some(3));
+
+        let p = precision("000 rounding-mode-floor").unwrap();
+        assert_eq!(p.minimum_integer_digits, Some(3));
+        assert_eq!(p.maximum_integer_digits, None);
+        assert_eq!(p.rounding_mode, Some(SignedRoundingMode::Floor));
+
+        assert_eq!(precision(".0#0"), Err(SkeletonError::InvalidStem));
+        assert_eq!(precision("@#@"), Err(SkeletonError::InvalidStem));
+        assert_eq!(
+            precision("precision-increment/0.07"),
+            Err(SkeletonError::Unsupported)
+        );
+    }
+
+    #[test]
+    fn test_units() {
+        let unit = |skeleton| {
+            NumberFormatterOptions::try_from_skeleton(skeleton).map(|options| options.unit)
+        };
+
+        assert_eq!(unit("%"), Ok(NumberUnit::Percent));
+        assert_eq!(
+            unit("measure-unit/length-meter"),
+            Ok(NumberUnit::Measure("meter".into()))
+        );
+        assert_eq!(
+            unit("unit/square-kilometer"),
+            Ok(NumberUnit::Measure("square-kilometer".into()))
+        );
+        assert_eq!(unit("currency/usd"), unit("currency/USD"));
+        assert_eq!(unit("currency/US"), Err(SkeletonError::InvalidOption));
+        assert_eq!(unit("currency"), Err(SkeletonError::InvalidOption));
+        assert_eq!(unit("percent/x"), Err(SkeletonError::InvalidOption));
+        assert_eq!(unit("permille"), Err(SkeletonError::Unsupported));
+    }
+
+    #[test]
+    fn test_errors() {
+        let parse = NumberFormatterOptions::try_from_skeleton;
+
+        assert_eq!(parse(""), Ok(Default::default()));
+        assert_eq!(
+            parse("compact-short K"),
+            Err(SkeletonError::DuplicateSetting)
+        );
+        assert_eq!(
+            parse("% currency/EUR"),
+            Err(SkeletonError::DuplicateSetting)
+        );
+        assert_eq!(parse("scientific"), Err(SkeletonError::Unsupported));
+        assert_eq!(parse("foo"), Err(SkeletonError::InvalidStem));
+    }
+
+    #[test]
+    fn test_sign() {
+        let options = NumberFormatterOptions::try_from_skeleton("()? ,_ latin").unwrap();
+        assert_eq!(options.sign_display, SignDisplay::ExceptZero);
+        assert_eq!(options.currency_usage, CurrencyUsage::Accounting);
+        assert_eq!(options.grouping_strategy, Some(GroupingStrategy::Never));
+        assert_eq!(
+            options.numbering_system.map(|nu| nu.to_string()),
+            Some("latn".into())
+        );
+    }
+}
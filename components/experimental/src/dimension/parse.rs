@@ -0,0 +1,196 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Helpers shared by the parsers of localized amounts, such as
+//! [`CurrencyParser`](super::currency::parser::CurrencyParser) and
+//! [`UnitsParser`](super::units::parser::UnitsParser).
+
+use alloc::string::String;
+
+use fixed_decimal::Decimal;
+use icu_decimal::DecimalFormatterPreferences;
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsStrs, DecimalSymbolsV1};
+use icu_provider::prelude::*;
+
+use super::currency::formatter::load_with_fallback;
+
+/// Loads the decimal symbols and digits in the same way as [`icu_decimal::DecimalFormatter`].
+pub(crate) fn load_decimal_data<D>(
+    provider: &D,
+    prefs: DecimalFormatterPreferences,
+) -> Result<(DataPayload<DecimalSymbolsV1>, DataPayload<DecimalDigitsV1>), DataError>
+where
+    D: ?Sized + DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1>,
+{
+    let locale = DecimalSymbolsV1::make_locale(prefs.locale_preferences);
+    let symbols = load_with_fallback::<DecimalSymbolsV1>(
+        provider,
+        prefs
+            .nu_id(&locale)
+            .into_iter()
+            .chain([DataIdentifierBorrowed::for_locale(&locale)]),
+    )?
+    .payload;
+    let resolved_nu_id = DataIdentifierBorrowed::for_marker_attributes(
+        DataMarkerAttributes::from_str_or_panic(symbols.get().numsys()),
+    );
+    let digits = load_with_fallback::<DecimalDigitsV1>(
+        provider,
+        prefs.nu_id(&locale).into_iter().chain([resolved_nu_id]),
+    )?
+    .payload;
+    Ok((symbols, digits))
+}
+
+/// Whether a character is ignored around an amount and its currency or unit.
+fn is_ignorable(c: char) -> bool {
+    // Bidi marks are used in the minus sign, currency and unit patterns of some locales
+    c.is_whitespace() || matches!(c, '\u{200E}' | '\u{200F}' | '\u{061C}')
+}
+
+pub(crate) fn trim(s: &str) -> &str {
+    s.trim_matches(is_ignorable)
+}
+
+/// Strips the locale's minus sign, an ASCII hyphen or U+2212 MINUS SIGN from either end
+/// of the input, returning `None` if there is none.
+pub(crate) fn strip_minus_sign<'a>(
+    input: &'a str,
+    strings: &DecimalSymbolsStrs,
+) -> Option<&'a str> {
+    let prefix = trim(strings.minus_sign_prefix());
+    let suffix = trim(strings.minus_sign_suffix());
+    [prefix, "-", "\u{2212}"]
+        .into_iter()
+        .filter(|p| !p.is_empty())
+        .find_map(|p| input.strip_prefix(p))
+        .or_else(|| {
+            [suffix, "-", "\u{2212}"]
+                .into_iter()
+                .filter(|s| !s.is_empty())
+                .find_map(|s| input.strip_suffix(s))
+        })
+        .map(trim)
+}
+
+/// Returns the length in bytes of the prefix of `input` that is equal to `token`.
+pub(crate) fn prefix_len(input: &str, token: &str, ignore_case: bool) -> Option<usize> {
+    if token.is_empty() {
+        return None;
+    }
+    let mut chars = input.char_indices();
+    for t in token.chars() {
+        let (_, c) = chars.next()?;
+        if !chars_match(c, t, ignore_case) {
+            return None;
+        }
+    }
+    Some(chars.next().map(|(i, _)| i).unwrap_or(input.len()))
+}
+
+/// Returns the length in bytes of the suffix of `input` that is equal to `token`.
+pub(crate) fn suffix_len(input: &str, token: &str, ignore_case: bool) -> Option<usize> {
+    let mut chars = input.char_indices().rev();
+    let mut start = None;
+    for t in token.chars().rev() {
+        let (i, c) = chars.next()?;
+        if !chars_match(c, t, ignore_case) {
+            return None;
+        }
+        start = Some(i);
+    }
+    Some(input.len() - start?)
+}
+
+fn chars_match(a: char, b: char, ignore_case: bool) -> bool {
+    // Grouping, currency and unit patterns use a variety of spaces
+    a == b
+        || (ignore_case && a.to_lowercase().eq(b.to_lowercase()))
+        || (a.is_whitespace() && b.is_whitespace())
+}
+
+/// Parses an unsigned number with the given separators and digits.
+///
+/// ASCII digits are accepted in addition to the given digits. Grouping separators
+/// are accepted anywhere between digits of the integer part.
+pub(crate) fn parse_number(
+    input: &str,
+    decimal_separator: &str,
+    grouping_separator: &str,
+    digits: &[char; 10],
+) -> Option<Decimal> {
+    let mut ascii = String::with_capacity(input.len());
+    let mut seen_decimal_separator = false;
+    let mut rest = input;
+    while let Some(c) = rest.chars().next() {
+        if let Some(digit) = digits
+            .iter()
+            .position(|&d| d == c)
+            .and_then(|d| char::from_digit(d as u32, 10))
+            .or_else(|| c.is_ascii_digit().then_some(c))
+        {
+            ascii.push(digit);
+            rest = rest.get(c.len_utf8()..).unwrap_or_default();
+        } else if let Some(len) =
+            prefix_len(rest, decimal_separator, false).filter(|_| !seen_decimal_separator)
+        {
+            seen_decimal_separator = true;
+            ascii.push('.');
+            rest = rest.get(len..).unwrap_or_default();
+        } else {
+            let len = prefix_len(rest, grouping_separator, false).filter(|_| {
+                !seen_decimal_separator && ascii.ends_with(|c: char| c.is_ascii_digit())
+            })?;
+            rest = rest.get(len..).unwrap_or_default();
+            if !rest.starts_with(|c: char| c.is_ascii_digit() || digits.contains(&c)) {
+                return None;
+            }
+        }
+    }
+    if !ascii.contains(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    Decimal::try_from_str(&ascii).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LATN: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+    #[test]
+    fn test_parse_number() {
+        let parse = |input, decimal, grouping, digits| {
+            parse_number(input, decimal, grouping, digits).map(|d| d.to_string())
+        };
+
+        assert_eq!(parse("1,234.50", ".", ",", &LATN), Some("1234.50".into()));
+        assert_eq!(parse("1.234,50", ",", ".", &LATN), Some("1234.50".into()));
+        // Any space matches a space-like grouping separator
+        assert_eq!(
+            parse("1 234,50", ",", "\u{202F}", &LATN),
+            Some("1234.50".into())
+        );
+        assert_eq!(parse(".5", ".", ",", &LATN), Some("0.5".into()));
+
+        let arab = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+        assert_eq!(parse("١٬٢٣٤٫٥", "٫", "٬", &arab), Some("1234.5".into()));
+
+        for invalid in ["", ".", "1..2", "1.2.3", ",1", "1,", "1.2,3", "1a"] {
+            assert_eq!(parse(invalid, ".", ",", &LATN), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_affix_len() {
+        assert_eq!(prefix_len("US$12", "US$", false), Some(3));
+        assert_eq!(prefix_len("us$12", "US$", false), None);
+        assert_eq!(prefix_len("usd12", "USD", true), Some(3));
+        assert_eq!(prefix_len("US", "USD", true), None);
+        assert_eq!(suffix_len("12 Dollars US", "dollars US", true), Some(10));
+        assert_eq!(suffix_len("12 €", "€", false), Some(3));
+        assert_eq!(suffix_len("12", "", false), None);
+    }
+}
@@ -38,10 +38,22 @@ impl Writeable for FormattedPercent<'_> {
     where
         W: core::fmt::Write + ?Sized,
     {
+        // Unset fraction digits default to the fraction digits of the value, so that
+        // percentages are displayed with exactly the digits they have unless the options
+        // say otherwise
+        let mut rounded = self.value.to_owned();
+        let fraction_digits =
+            u8::try_from(rounded.absolute.magnitude_range().start().unsigned_abs())
+                .unwrap_or(u8::MAX);
+        self.options
+            .precision
+            .round(&mut rounded, fraction_digits, fraction_digits);
+        let sign = rounded.sign();
+
         // Removing the sign from the value
-        let abs_value = match self.value.sign() {
-            Sign::Negative => self.value.clone().with_sign(Sign::None),
-            _ => self.value.to_owned(),
+        let abs_value = match sign {
+            Sign::Negative => rounded.with_sign(Sign::None),
+            _ => rounded,
         };
 
         let value = self.decimal_formatter.format(&abs_value);
@@ -49,7 +61,7 @@ impl Writeable for FormattedPercent<'_> {
         match self.options.display {
             // In the Standard display, we take the unsigned pattern only when the value is positive.
             Display::Standard => {
-                if self.value.sign() == Sign::Negative {
+                if sign == Sign::Negative {
                     self.essential
                         .signed_pattern
                         .interpolate((value, &self.essential.minus_sign))
@@ -62,7 +74,7 @@ impl Writeable for FormattedPercent<'_> {
                 };
             }
             Display::Approximate => {
-                let sign = if self.value.sign() == Sign::Negative {
+                let sign = if sign == Sign::Negative {
                     // The approximate sign gets pre-pended
                     Append(
                         &self.essential.approximately_sign,
@@ -82,7 +94,7 @@ impl Writeable for FormattedPercent<'_> {
                 .signed_pattern
                 .interpolate((
                     value,
-                    if self.value.sign() == Sign::Negative {
+                    if sign == Sign::Negative {
                         &self.essential.minus_sign
                     } else {
                         &self.essential.plus_sign
@@ -106,6 +118,7 @@ mod tests {
         formatter::{PercentFormatter, PercentFormatterPreferences},
         options::{Display, PercentFormatterOptions},
     };
+    use icu_decimal::options::PrecisionOptions;
 
     #[test]
     pub fn test_en_us() {
@@ -127,6 +140,7 @@ mod tests {
             prefs,
             PercentFormatterOptions {
                 display: Display::Approximate,
+                ..Default::default()
             },
         )
         .unwrap();
@@ -138,6 +152,7 @@ mod tests {
             prefs,
             PercentFormatterOptions {
                 display: Display::ExplicitSign,
+                ..Default::default()
             },
         )
         .unwrap();
@@ -165,6 +180,7 @@ mod tests {
             prefs,
             PercentFormatterOptions {
                 display: Display::Approximate,
+                ..Default::default()
             },
         )
         .unwrap();
@@ -176,6 +192,7 @@ mod tests {
             prefs,
             PercentFormatterOptions {
                 display: Display::ExplicitSign,
+                ..Default::default()
             },
         )
         .unwrap();
@@ -203,6 +220,7 @@ mod tests {
             prefs,
             PercentFormatterOptions {
                 display: Display::Approximate,
+                ..Default::default()
             },
         )
         .unwrap();
@@ -214,10 +232,46 @@ mod tests {
             prefs,
             PercentFormatterOptions {
                 display: Display::ExplicitSign,
+                ..Default::default()
             },
         )
         .unwrap();
         let formatted_percent = explicit_fmt.format(&positive_value);
         assert_writeable_eq!(formatted_percent, "%\u{a0}+12\u{a0}345,67");
     }
+
+    #[test]
+    pub fn test_precision() {
+        let prefs: PercentFormatterPreferences = locale!("en-US").into();
+        let value = "-12345.67".parse().unwrap();
+
+        let mut precision = PrecisionOptions::default();
+        precision.maximum_significant_digits = Some(3);
+        let fmt = PercentFormatter::try_new(prefs, precision.into()).unwrap();
+        assert_writeable_eq!(fmt.format(&value), "-12,300%");
+
+        // Unset fraction digits default to the digits of the value
+        let mut precision = PrecisionOptions::default();
+        precision.rounding_mode = Some(fixed_decimal::SignedRoundingMode::Floor);
+        let fmt = PercentFormatter::try_new(prefs, precision.into()).unwrap();
+        assert_writeable_eq!(fmt.format(&value), "-12,345.67%");
+
+        let mut precision = PrecisionOptions::default();
+        precision.maximum_fraction_digits = Some(1);
+        let fmt = PercentFormatter::try_new(prefs, precision.into()).unwrap();
+        assert_writeable_eq!(fmt.format(&value), "-12,345.7%");
+
+        let mut precision = PrecisionOptions::default();
+        precision.minimum_fraction_digits = Some(3);
+        let fmt = PercentFormatter::try_new(prefs, precision.into()).unwrap();
+        assert_writeable_eq!(fmt.format(&value), "-12,345.670%");
+
+        let mut precision = PrecisionOptions::default();
+        precision.trailing_zero_display =
+            Some(icu_decimal::options::TrailingZeroDisplay::StripIfInteger);
+        let fmt = PercentFormatter::try_new(prefs, precision.into()).unwrap();
+        assert_writeable_eq!(fmt.format(&value), "-12,345.67%");
+        let integer = "50.00".parse().unwrap();
+        assert_writeable_eq!(fmt.format(&integer), "50%");
+    }
 }
@@ -4,6 +4,8 @@
 
 //! Options for [`PercentFormatter`](crate::dimension::percent::formatter::PercentFormatter).
 
+use icu_decimal::options::PrecisionOptions;
+
 /// A collection of configuration options that determine the formatting behavior of
 /// [`PercentFormatter`](crate::dimension::percent::formatter::PercentFormatter).
 #[derive(Copy, Debug, Eq, PartialEq, Clone, Default)]
@@ -11,11 +13,29 @@
 pub struct PercentFormatterOptions {
     /// The display of the percent format.
     pub display: Display,
+
+    /// How many digits to display and how to round.
+    ///
+    /// Unset fraction digits default to the fraction digits of the number, so by default
+    /// the number is displayed with exactly the digits it has.
+    pub precision: PrecisionOptions,
 }
 
 impl From<Display> for PercentFormatterOptions {
     fn from(display: Display) -> Self {
-        Self { display }
+        Self {
+            display,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PrecisionOptions> for PercentFormatterOptions {
+    fn from(precision: PrecisionOptions) -> Self {
+        Self {
+            precision,
+            ..Default::default()
+        }
     }
 }
 
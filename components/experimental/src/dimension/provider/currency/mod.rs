@@ -9,4 +9,5 @@ pub mod fractions;
 mod fractions_ule;
 pub mod no_currency;
 pub mod patterns;
+pub mod regions;
 pub mod symbols;
@@ -0,0 +1,94 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for the currencies used in each region.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::fallback::{LocaleFallbackConfig, LocaleFallbackPriority};
+use icu_provider::prelude::*;
+use tinystr::UnvalidatedTinyAsciiStr;
+use zerovec::ZeroVec;
+
+icu_provider::data_marker!(
+    /// `CurrencyRegionsV1` provides the current and historical currencies of a region.
+    CurrencyRegionsV1,
+    CurrencyRegions<'static>,
+    fallback_config = {
+        let mut config = LocaleFallbackConfig::default();
+        config.priority = LocaleFallbackPriority::Region;
+        config
+    },
+);
+
+/// The currencies that are or were used in a region.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Clone, PartialEq, Debug, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::dimension::provider::currency::regions))]
+#[yoke(prove_covariance_manually)]
+pub struct CurrencyRegions<'data> {
+    /// The currencies of the region, in the order of CLDR `<currencyData>`,
+    /// i.e. starting with the most recently introduced one.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub currencies: ZeroVec<'data, RegionCurrency>,
+}
+
+icu_provider::data_struct!(CurrencyRegions<'_>, #[cfg(feature = "datagen")]);
+
+/// A currency that is or was used in a region, as listed in CLDR `<currencyData>`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(RegionCurrencyULE)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::dimension::provider::currency::regions))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[zerovec::skip_derive(ZeroMapKV)]
+pub struct RegionCurrency {
+    /// The 3-letter upper case ISO 4217 code of the currency.
+    pub iso_code: UnvalidatedTinyAsciiStr<3>,
+    /// The first day on which the currency was used, if known.
+    pub from: Option<CurrencyDate>,
+    /// The first day on which the currency was no longer used, if it is no longer used.
+    pub to: Option<CurrencyDate>,
+    /// Whether the currency is legal tender.
+    ///
+    /// This is `false` for financial instruments and other non-tender currencies,
+    /// such as `USN` (US Dollar, next day).
+    pub tender: bool,
+}
+
+/// A day in the ISO calendar, at which a currency started or stopped being used.
+///
+/// The time zone of the transition is not recorded.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(CurrencyDateULE)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::dimension::provider::currency::regions))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CurrencyDate {
+    /// The ISO year
+    pub year: i32,
+    /// The ISO month, starting at 1
+    pub month: u8,
+    /// The day of the month, starting at 1
+    pub day: u8,
+}
@@ -58,4 +58,64 @@ define_units_data_markers!(
     "Display names for volume units covering units from other locales\' preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-meter).";
     UnitsNamesVolumeOutlierV1,
     "Display names for volume units not specified by any locale\'s preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-meter).";
+
+    // Consumption
+    UnitsNamesConsumptionCoreV1,
+    "Display names for fuel consumption units defined by locale-specific preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-liter-per-100-kilometer).";
+    UnitsNamesConsumptionExtendedV1,
+    "Display names for fuel consumption units covering units from other locales\' preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-liter-per-100-kilometer).";
+    UnitsNamesConsumptionOutlierV1,
+    "Display names for fuel consumption units not specified by any locale\'s preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-liter-per-100-kilometer).";
+
+    // Digital
+    UnitsNamesDigitalCoreV1,
+    "Display names for digital storage units defined by locale-specific preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-byte).";
+    UnitsNamesDigitalExtendedV1,
+    "Display names for digital storage units covering units from other locales\' preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-byte).";
+    UnitsNamesDigitalOutlierV1,
+    "Display names for digital storage units not specified by any locale\'s preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-byte).";
+
+    // Energy
+    UnitsNamesEnergyCoreV1,
+    "Display names for energy units defined by locale-specific preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-joule).";
+    UnitsNamesEnergyExtendedV1,
+    "Display names for energy units covering units from other locales\' preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-joule).";
+    UnitsNamesEnergyOutlierV1,
+    "Display names for energy units not specified by any locale\'s preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-joule).";
+
+    // Power
+    UnitsNamesPowerCoreV1,
+    "Display names for power units defined by locale-specific preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-watt).";
+    UnitsNamesPowerExtendedV1,
+    "Display names for power units covering units from other locales\' preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-watt).";
+    UnitsNamesPowerOutlierV1,
+    "Display names for power units not specified by any locale\'s preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-watt).";
+
+    // Pressure
+    UnitsNamesPressureCoreV1,
+    "Display names for pressure units defined by locale-specific preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-bar).";
+    UnitsNamesPressureExtendedV1,
+    "Display names for pressure units covering units from other locales\' preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-bar).";
+    UnitsNamesPressureOutlierV1,
+    "Display names for pressure units not specified by any locale\'s preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-bar).";
+
+    // Speed
+    UnitsNamesSpeedCoreV1,
+    "Display names for speed units defined by locale-specific preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-kilometer-per-hour).";
+    UnitsNamesSpeedExtendedV1,
+    "Display names for speed units covering units from other locales\' preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-kilometer-per-hour).";
+    UnitsNamesSpeedOutlierV1,
+    "Display names for speed units not specified by any locale\'s preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-kilometer-per-hour).";
+
+    // Temperature
+    UnitsNamesTemperatureCoreV1,
+    "Display names for temperature units defined by locale-specific preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-celsius).";
+    UnitsNamesTemperatureExtendedV1,
+    "Display names for temperature units covering units from other locales\' preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-celsius).";
+    UnitsNamesTemperatureOutlierV1,
+    "Display names for temperature units not specified by any locale\'s preferences. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-celsius).";
+
+    // Other
+    UnitsNamesOtherV1,
+    "Display names for units of all other categories, such as acceleration, force and frequency units. Access requires specifying width and unit in DataMarkerAttributes (e.g., short-hertz).";
 );
@@ -0,0 +1,59 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use zerovec::{ZeroMap, ZeroMap2d};
+
+use icu_provider::prelude::*;
+
+use crate::dimension::provider::units::pattern_key::PatternKey;
+
+icu_provider::data_marker!(
+    /// `UnitsCompoundPatternsV1`
+    UnitsCompoundPatternsV1,
+    UnitsCompoundPatterns<'static>
+);
+
+/// This type contains the data, complementing [`UnitsEssentials`](super::essentials::UnitsEssentials),
+/// that is needed to compose the display names of compound units, such as `kilometer-per-hour`.
+///
+/// Note:
+///     Auxiliary key represent the length: e.g. `long`, `short`, `narrow`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Clone, PartialEq, Debug, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::dimension::provider::units::compound_patterns))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsCompoundPatterns<'data> {
+    /// Maps a unit to the pattern used when it is the only unit of the denominator,
+    /// such as `{0} per hour` for `hour`.
+    // TODO: use `SinglePlaceholderPattern` instead of `str` for the patterns' string representations.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub per_unit_patterns: ZeroMap<'data, str, str>,
+
+    /// Maps a unit to its grammatical gender, such as `masculine` for `meter` in French.
+    ///
+    /// Only present if some of the power patterns depend on the gender.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub genders: ZeroMap<'data, str, str>,
+
+    /// Maps a grammatical gender and a power pattern key to the power pattern used with the units
+    /// of that gender, such as `{0} carrée` for the feminine units in French.
+    ///
+    /// The patterns that do not depend on the gender are in [`UnitsEssentials::prefixes`](super::essentials::UnitsEssentials::prefixes).
+    // TODO: use `SinglePlaceholderPattern` instead of `str` for the patterns' string representations.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub gendered_powers: ZeroMap2d<'data, str, PatternKey, str>,
+}
+
+icu_provider::data_struct!(UnitsCompoundPatterns<'_>, #[cfg(feature = "datagen")]);
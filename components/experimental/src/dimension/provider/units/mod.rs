@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 pub mod categorized_display_names;
+pub mod compound_patterns;
 pub mod display_names;
 pub mod essentials;
 pub mod pattern_key;
@@ -11,7 +11,7 @@ use core::marker::PhantomData;
 use fixed_decimal::Decimal;
 use icu_decimal::DecimalFormatter;
 use icu_decimal::DecimalFormatterPreferences;
-use icu_decimal::options::DecimalFormatterOptions;
+use icu_decimal::options::{DecimalFormatterOptions, PrecisionOptions};
 use icu_locale_core::DataLocale;
 use icu_locale_core::preferences::{define_preferences, prefs_convert};
 use icu_plurals::PluralRules;
@@ -60,6 +60,7 @@ pub struct CategorizedFormatter<C: MeasureUnitCategory> {
     display_name: DataPayload<ErasedMarker<UnitsDisplayNames<'static>>>,
     decimal_formatter: DecimalFormatter,
     plural_rules: PluralRules,
+    precision: PrecisionOptions,
 }
 
 impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
@@ -191,6 +192,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
             display_name,
             decimal_formatter,
             plural_rules,
+            precision: options.precision,
         })
     }
 
@@ -232,6 +234,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
             display_name,
             decimal_formatter,
             plural_rules,
+            precision: options.precision,
         })
     }
 
@@ -278,6 +281,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
             display_name,
             decimal_formatter,
             plural_rules,
+            precision: options.precision,
         })
     }
 
@@ -319,6 +323,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
             display_name,
             decimal_formatter,
             plural_rules,
+            precision: options.precision,
         })
     }
 
@@ -374,6 +379,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
             display_name,
             decimal_formatter,
             plural_rules,
+            precision: options.precision,
         })
     }
 
@@ -418,6 +424,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
             display_name,
             decimal_formatter,
             plural_rules,
+            precision: options.precision,
         })
     }
 
@@ -428,6 +435,7 @@ impl<C: MeasureUnitCategory> CategorizedFormatter<C> {
             display_name: self.display_name.get(),
             decimal_formatter: &self.decimal_formatter,
             plural_rules: &self.plural_rules,
+            precision: &self.precision,
         }
     }
 }
@@ -442,7 +450,7 @@ mod tests {
 
     use crate::dimension::units::categorized_formatter::CategorizedFormatter;
     use crate::dimension::units::options::{UnitsFormatterOptions, Width};
-    use crate::measure::category::{Area, Duration};
+    use crate::measure::category::{Area, Consumption, Digital, Duration, Speed, Temperature};
 
     #[test]
     fn test_area_categorized_core_formatter() {
@@ -675,4 +683,148 @@ mod tests {
             assert_writeable_eq!(formatted, expected);
         }
     }
+
+    #[test]
+    fn test_temperature_categorized_core_formatter() {
+        let test_cases = vec![
+            (
+                locale!("en-US"),
+                Temperature::fahrenheit(),
+                "72",
+                UnitsFormatterOptions::default(),
+                "72°F",
+            ),
+            (
+                locale!("en-US"),
+                Temperature::fahrenheit(),
+                "72",
+                UnitsFormatterOptions {
+                    width: Width::Long,
+                    ..Default::default()
+                },
+                "72 degrees Fahrenheit",
+            ),
+            (
+                locale!("fr-FR"),
+                Temperature::celsius(),
+                "21.5",
+                UnitsFormatterOptions::default(),
+                "21,5\u{202f}°C",
+            ),
+        ];
+
+        for (locale, categorized_unit, value_str, options, expected) in test_cases {
+            let formatter = CategorizedFormatter::<Temperature>::try_new_core(
+                locale.into(),
+                categorized_unit,
+                options,
+            )
+            .unwrap();
+            let signed_decimal = Decimal::from_str(value_str).unwrap();
+            let formatted = formatter.format_fixed_decimal(&signed_decimal);
+            assert_writeable_eq!(formatted, expected);
+        }
+    }
+
+    #[test]
+    fn test_speed_categorized_core_formatter() {
+        let test_cases = vec![
+            (
+                locale!("en-US"),
+                Speed::mile_per_hour(),
+                "65",
+                UnitsFormatterOptions::default(),
+                "65 mph",
+            ),
+            (
+                locale!("fr-FR"),
+                Speed::kilometer_per_hour(),
+                "130",
+                UnitsFormatterOptions {
+                    width: Width::Long,
+                    ..Default::default()
+                },
+                "130\u{a0}kilomètres par heure",
+            ),
+        ];
+
+        for (locale, categorized_unit, value_str, options, expected) in test_cases {
+            let formatter = CategorizedFormatter::<Speed>::try_new_core(
+                locale.into(),
+                categorized_unit,
+                options,
+            )
+            .unwrap();
+            let signed_decimal = Decimal::from_str(value_str).unwrap();
+            let formatted = formatter.format_fixed_decimal(&signed_decimal);
+            assert_writeable_eq!(formatted, expected);
+        }
+    }
+
+    #[test]
+    fn test_consumption_categorized_extended_formatter() {
+        let test_cases = vec![
+            (
+                locale!("en-US"),
+                Consumption::mile_per_gallon(),
+                "30",
+                UnitsFormatterOptions::default(),
+                "30 mpg",
+            ),
+            (
+                locale!("en-US"),
+                Consumption::liter_per_100_kilometer(),
+                "6.5",
+                UnitsFormatterOptions::default(),
+                "6.5 L/100km",
+            ),
+        ];
+
+        for (locale, categorized_unit, value_str, options, expected) in test_cases {
+            let formatter = CategorizedFormatter::<Consumption>::try_new_extended(
+                locale.into(),
+                categorized_unit,
+                options,
+            )
+            .unwrap();
+            let signed_decimal = Decimal::from_str(value_str).unwrap();
+            let formatted = formatter.format_fixed_decimal(&signed_decimal);
+            assert_writeable_eq!(formatted, expected);
+        }
+    }
+
+    #[test]
+    fn test_digital_categorized_outlier_formatter() {
+        let test_cases = vec![
+            (
+                locale!("en-US"),
+                Digital::gigabyte(),
+                "1.5",
+                UnitsFormatterOptions::default(),
+                "1.5 GB",
+            ),
+            (
+                locale!("fr-FR"),
+                Digital::megabit(),
+                "100",
+                UnitsFormatterOptions {
+                    width: Width::Long,
+                    ..Default::default()
+                },
+                "100\u{a0}mégabits",
+            ),
+        ];
+
+        for (locale, categorized_unit, value_str, options, expected) in test_cases {
+            let formatter = CategorizedFormatter::<Digital>::try_new_outlier(
+                locale.into(),
+                categorized_unit,
+                options,
+            )
+            .unwrap();
+            let signed_decimal = Decimal::from_str(value_str).unwrap();
+            let formatted = formatter.format_fixed_decimal(&signed_decimal);
+            assert_writeable_eq!(formatted, expected);
+        }
+    }
 }
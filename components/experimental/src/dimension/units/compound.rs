@@ -0,0 +1,226 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Composition of the display names of compound units, such as `kilometer-per-hour`,
+//! from the display names of their single units and the CLDR compound unit patterns.
+//!
+//! See <https://unicode.org/reports/tr35/tr35-general.html#compound-units>.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use icu_pattern::PatternItem;
+use icu_plurals::{PluralCategory, PluralOperands, PluralRules};
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::*;
+
+use crate::dimension::provider::units::compound_patterns::UnitsCompoundPatternsV1;
+use crate::dimension::provider::units::display_names::UnitsDisplayNames;
+use crate::dimension::provider::units::essentials::{CompoundCount, UnitsEssentialsV1};
+use crate::dimension::provider::units::pattern_key::{PatternKey, PowerValue};
+
+/// The display names of a single unit of a compound unit.
+#[derive(Debug)]
+pub(crate) struct SingleUnitNames {
+    /// The CLDR identifier of the unit whose display names are loaded, such as `meter`.
+    pub(crate) id: String,
+
+    /// The display names of the unit.
+    pub(crate) names: DataPayload<ErasedMarker<UnitsDisplayNames<'static>>>,
+
+    /// The SI prefix to apply to the display names, if they do not include it.
+    pub(crate) prefix: Option<PatternKey>,
+
+    /// The power to apply to the display names, if they do not include it.
+    pub(crate) power: Option<PowerValue>,
+}
+
+/// The display names of a compound unit that has no precomputed display names.
+#[derive(Debug)]
+pub(crate) struct CompoundUnitNames {
+    /// The single units with a positive power.
+    pub(crate) numerator: Vec<SingleUnitNames>,
+
+    /// The single units with a negative power.
+    pub(crate) denominator: Vec<SingleUnitNames>,
+
+    pub(crate) essentials: DataPayload<UnitsEssentialsV1>,
+    pub(crate) compound_patterns: DataPayload<UnitsCompoundPatternsV1>,
+}
+
+/// The number placeholder of a composed display name, with its adjacent whitespace.
+#[derive(Debug)]
+pub(crate) struct Placeholder {
+    pub(crate) whitespace: String,
+    pub(crate) at_start: bool,
+}
+
+impl CompoundUnitNames {
+    /// Composes the display name of the compound unit for the given plural operands.
+    ///
+    /// The number placeholder is taken out of the returned display name, as in
+    /// `kilometers per hour` for `{0} kilometers per hour`.
+    ///
+    /// The `constant_denominator`, if any, is the formatted constant denominator of the unit,
+    /// such as `100` for `liter-per-100-kilometer`, with its plural operands.
+    pub(crate) fn compose(
+        &self,
+        operands: PluralOperands,
+        rules: &PluralRules,
+        constant_denominator: Option<(&str, PluralOperands)>,
+    ) -> (String, Option<Placeholder>) {
+        let essentials = self.essentials.get();
+        let singular = PluralOperands::from(1);
+        let mut placeholder = None;
+
+        // Only the last unit of the numerator agrees with the number, such as in `newton-meters`.
+        let last = self.numerator.len().saturating_sub(1);
+        let numerator = self
+            .numerator
+            .iter()
+            .enumerate()
+            .map(|(index, unit)| {
+                let operands = if index == last { operands } else { singular };
+                self.core_name(unit, operands, rules, &mut placeholder, None)
+            })
+            .reduce(|first, second| times(&essentials.times, &first, &second));
+
+        // The denominator is singular, unless there is a constant denominator, such as in
+        // `liters per 100 kilometers`.
+        let denominator = self
+            .denominator
+            .iter()
+            .enumerate()
+            .map(|(index, unit)| match constant_denominator {
+                Some((constant, constant_operands)) if index == 0 => self.core_name(
+                    unit,
+                    constant_operands,
+                    rules,
+                    &mut placeholder,
+                    Some(constant),
+                ),
+                _ => self.core_name(unit, singular, rules, &mut placeholder, None),
+            })
+            .reduce(|first, second| times(&essentials.times, &first, &second))
+            .or_else(|| constant_denominator.map(|(constant, _)| String::from(constant)));
+
+        let per_unit_pattern = match (self.denominator.as_slice(), constant_denominator) {
+            ([unit], None) => self.compound_patterns.get().per_unit_patterns.get(&unit.id),
+            _ => None,
+        };
+
+        let name = match (numerator, denominator) {
+            (Some(numerator), None) => numerator,
+            (Some(numerator), Some(denominator)) => match per_unit_pattern {
+                Some(per_unit_pattern) => per_unit_pattern.replace("{0}", &numerator),
+                None => essentials
+                    .per
+                    .replace("{0}", &numerator)
+                    .replace("{1}", &denominator),
+            },
+            (None, Some(denominator)) => String::from(
+                essentials
+                    .per
+                    .replace("{0}", "")
+                    .replace("{1}", &denominator)
+                    .trim(),
+            ),
+            (None, None) => String::new(),
+        };
+
+        (name, placeholder)
+    }
+
+    /// Returns the display name of a single unit, with its SI prefix and power.
+    ///
+    /// The number placeholder is taken out of the display name. The first one that is taken out
+    /// is stored in `placeholder`. If `constant` is given, it replaces the placeholder instead.
+    fn core_name(
+        &self,
+        unit: &SingleUnitNames,
+        operands: PluralOperands,
+        rules: &PluralRules,
+        placeholder: &mut Option<Placeholder>,
+        constant: Option<&str>,
+    ) -> String {
+        let mut before = String::new();
+        let mut after = String::new();
+        let mut has_placeholder = false;
+        for item in unit.names.get().get(operands, rules).iter() {
+            match item {
+                PatternItem::Literal(literal) if has_placeholder => after.push_str(literal),
+                PatternItem::Literal(literal) => before.push_str(literal),
+                PatternItem::Placeholder(_) => has_placeholder = true,
+            }
+        }
+
+        let mut name = match constant {
+            _ if !has_placeholder => before,
+            Some(constant) => format!("{before}{constant}{after}"),
+            None => {
+                let (name, whitespace, at_start) = if before.trim().is_empty() {
+                    let name = after.trim_start();
+                    (name, &after[..after.len() - name.len()], true)
+                } else if after.trim().is_empty() {
+                    let name = before.trim_end();
+                    (name, &before[name.len()..], false)
+                } else {
+                    // The placeholder is in the middle of the name, which does not happen in CLDR.
+                    (before.as_str(), " ", true)
+                };
+                if placeholder.is_none() {
+                    *placeholder = Some(Placeholder {
+                        whitespace: String::from(whitespace),
+                        at_start,
+                    });
+                }
+                String::from(name)
+            }
+        };
+
+        let essentials = self.essentials.get();
+        if let Some(prefix) = unit
+            .prefix
+            .and_then(|prefix| essentials.prefixes.get(&prefix))
+        {
+            name = prefix.replace("{0}", &name);
+        }
+
+        if let Some(power) = unit.power {
+            let count = match rules.category_for(operands) {
+                PluralCategory::Zero => CompoundCount::Zero,
+                PluralCategory::One => CompoundCount::One,
+                PluralCategory::Two => CompoundCount::Two,
+                PluralCategory::Few => CompoundCount::Few,
+                PluralCategory::Many => CompoundCount::Many,
+                PluralCategory::Other => CompoundCount::Other,
+            };
+            let keys = [
+                PatternKey::Power { power, count },
+                PatternKey::Power {
+                    power,
+                    count: CompoundCount::Other,
+                },
+            ];
+            let compound_patterns = self.compound_patterns.get();
+            let gendered = compound_patterns.genders.get(&unit.id).and_then(|gender| {
+                keys.iter()
+                    .find_map(|key| compound_patterns.gendered_powers.get_2d(gender, key))
+            });
+            if let Some(pattern) =
+                gendered.or_else(|| keys.iter().find_map(|key| essentials.prefixes.get(key)))
+            {
+                name = pattern.replace("{0}", &name);
+            }
+        }
+
+        name
+    }
+}
+
+/// Applies the `times` pattern, such as `{0}-{1}`, to two display names.
+fn times(pattern: &str, first: &str, second: &str) -> String {
+    pattern.replace("{0}", first).replace("{1}", second)
+}
@@ -3,9 +3,15 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::dimension::provider::units::display_names::UnitsDisplayNames;
+use crate::dimension::units::compound::Placeholder;
+use crate::dimension::units::formatter::MeasureUnitNames;
 use fixed_decimal::Decimal;
 use icu_decimal::DecimalFormatter;
+use icu_decimal::options::PrecisionOptions;
+use icu_list::ListFormatter;
 use icu_plurals::PluralRules;
+use icu_provider::DataPayload;
+use icu_provider::marker::ErasedMarker;
 use writeable::{Writeable, impl_display_with_writeable};
 
 #[derive(Debug)]
@@ -17,6 +23,7 @@ pub struct FormattedUnit<'l> {
     pub(crate) display_name: &'l UnitsDisplayNames<'l>,
     pub(crate) decimal_formatter: &'l DecimalFormatter,
     pub(crate) plural_rules: &'l PluralRules,
+    pub(crate) precision: &'l PrecisionOptions,
 }
 
 impl Writeable for FormattedUnit<'_> {
@@ -24,11 +31,127 @@ impl Writeable for FormattedUnit<'_> {
     where
         W: writeable::PartsWrite + ?Sized,
     {
+        let mut value = self.value.clone();
+        self.precision.round(&mut value, 0, 3);
         self.display_name
-            .get(self.value.into(), self.plural_rules)
-            .interpolate((self.decimal_formatter.format(self.value),))
+            .get((&value).into(), self.plural_rules)
+            .interpolate((self.decimal_formatter.format(&value),))
             .write_to_parts(sink)
     }
 }
 
 impl_display_with_writeable!(FormattedUnit<'_>);
+
+/// The result of formatting values of a mixed unit with a
+/// [`MixedUnitsFormatter`](crate::dimension::units::mixed_formatter::MixedUnitsFormatter).
+#[derive(Debug)]
+pub struct FormattedMixedUnit<'l> {
+    pub(crate) values: &'l [Decimal],
+    pub(crate) display_names: &'l [DataPayload<ErasedMarker<UnitsDisplayNames<'static>>>],
+    pub(crate) decimal_formatter: &'l DecimalFormatter,
+    pub(crate) plural_rules: &'l PluralRules,
+    pub(crate) list: &'l ListFormatter,
+    pub(crate) precision: &'l PrecisionOptions,
+}
+
+impl Writeable for FormattedMixedUnit<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> Result<(), core::fmt::Error>
+    where
+        W: writeable::PartsWrite + ?Sized,
+    {
+        // Only the last value is rounded; the others are whole numbers of their unit.
+        let integer = PrecisionOptions::default();
+        let last = self.values.len().min(self.display_names.len());
+        let units = self.values.iter().zip(self.display_names).enumerate().map(
+            |(index, (value, display_name))| FormattedUnit {
+                value,
+                display_name: display_name.get(),
+                decimal_formatter: self.decimal_formatter,
+                plural_rules: self.plural_rules,
+                precision: if index + 1 == last {
+                    self.precision
+                } else {
+                    &integer
+                },
+            },
+        );
+        self.list.format(units).write_to_parts(sink)
+    }
+}
+
+impl_display_with_writeable!(FormattedMixedUnit<'_>);
+
+/// The result of formatting a value of a unit with a
+/// [`UnitsFormatter`](crate::dimension::units::formatter::UnitsFormatter).
+#[derive(Debug)]
+pub struct FormattedMeasureUnit<'l> {
+    pub(crate) value: &'l Decimal,
+    pub(crate) names: &'l MeasureUnitNames,
+    pub(crate) constant_denominator: Option<&'l Decimal>,
+    pub(crate) decimal_formatter: &'l DecimalFormatter,
+    pub(crate) plural_rules: &'l PluralRules,
+    pub(crate) precision: &'l PrecisionOptions,
+}
+
+impl Writeable for FormattedMeasureUnit<'_> {
+    fn write_to_parts<W>(&self, sink: &mut W) -> Result<(), core::fmt::Error>
+    where
+        W: writeable::PartsWrite + ?Sized,
+    {
+        let names = match self.names {
+            MeasureUnitNames::Precomputed(display_name) => {
+                return FormattedUnit {
+                    value: self.value,
+                    display_name: display_name.get(),
+                    decimal_formatter: self.decimal_formatter,
+                    plural_rules: self.plural_rules,
+                    precision: self.precision,
+                }
+                .write_to_parts(sink);
+            }
+            MeasureUnitNames::Composed(names) => names,
+        };
+
+        let mut value = self.value.clone();
+        self.precision.round(&mut value, 0, 3);
+        let constant_denominator = self.constant_denominator.map(|constant| {
+            (
+                self.decimal_formatter
+                    .format(constant)
+                    .write_to_string()
+                    .into_owned(),
+                constant.into(),
+            )
+        });
+        let (name, placeholder) = names.compose(
+            (&value).into(),
+            self.plural_rules,
+            constant_denominator
+                .as_ref()
+                .map(|(constant, operands)| (constant.as_ref(), *operands)),
+        );
+
+        let number = self.decimal_formatter.format(&value);
+        match placeholder {
+            Some(Placeholder {
+                whitespace,
+                at_start: true,
+            }) => {
+                number.write_to_parts(sink)?;
+                sink.write_str(&whitespace)?;
+                sink.write_str(&name)
+            }
+            Some(Placeholder {
+                whitespace,
+                at_start: false,
+            }) => {
+                sink.write_str(&name)?;
+                sink.write_str(&whitespace)?;
+                number.write_to_parts(sink)
+            }
+            None => sink.write_str(&name),
+        }
+    }
+}
+
+impl_display_with_writeable!(FormattedMeasureUnit<'_>);
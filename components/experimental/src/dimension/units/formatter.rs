@@ -0,0 +1,616 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use fixed_decimal::Decimal;
+use icu_decimal::options::{DecimalFormatterOptions, PrecisionOptions};
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_plurals::provider::PluralsCardinalV1;
+use icu_plurals::{PluralRules, PluralRulesPreferences};
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::*;
+
+use crate::dimension::provider::units::categorized_display_names::*;
+use crate::dimension::provider::units::compound_patterns::UnitsCompoundPatternsV1;
+use crate::dimension::provider::units::display_names::UnitsDisplayNames;
+use crate::dimension::provider::units::essentials::UnitsEssentialsV1;
+use crate::dimension::provider::units::pattern_key::{PatternKey, PowerValue};
+use crate::dimension::units::compound::{CompoundUnitNames, SingleUnitNames};
+use crate::dimension::units::format::FormattedMeasureUnit;
+use crate::dimension::units::names::load_unit_names;
+use crate::dimension::units::options::{UnitsFormatterOptions, Width};
+use crate::measure::measureunit::MeasureUnit;
+use crate::measure::parser::ids::unit_name;
+use crate::measure::parser::si_prefix::si_prefix_name;
+use crate::measure::provider::si_prefix::Base;
+use crate::measure::provider::single_unit::SingleUnit;
+
+define_preferences!(
+    /// The preferences for units formatting.
+    [Copy]
+    UnitsFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: crate::dimension::preferences::NumberingSystem
+    }
+);
+prefs_convert!(UnitsFormatterPreferences, DecimalFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(UnitsFormatterPreferences, PluralRulesPreferences);
+
+/// The display names of a [`MeasureUnit`].
+#[derive(Debug)]
+pub(crate) enum MeasureUnitNames {
+    /// The unit has precomputed display names, such as `kilometer-per-hour`.
+    Precomputed(DataPayload<ErasedMarker<UnitsDisplayNames<'static>>>),
+
+    /// The display names of the unit are composed from its single units,
+    /// such as `kilowatt-hour-per-100-kilometer`.
+    Composed(Box<CompoundUnitNames>),
+}
+
+/// A formatter for values of any [`MeasureUnit`], such as "12 km/h", "9.8 m/s²" or "5 kilobytes per second".
+///
+/// If the locale data has no display names for the unit, they are composed from the display names of its
+/// single units, following the CLDR compound unit patterns for `per`, `times`, powers and SI prefixes.
+/// The power patterns agree with the grammatical gender of their unit, and the names are in the nominative case.
+///
+/// Use [`CategorizedFormatter`](crate::dimension::units::categorized_formatter::CategorizedFormatter) instead if the
+/// unit is known at compile time, to load less data.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::units::formatter::UnitsFormatter;
+/// use icu::experimental::dimension::units::options::Width;
+/// use icu::experimental::measure::measureunit::MeasureUnit;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let unit = MeasureUnit::try_from_str("meter-per-square-second").unwrap();
+/// let formatter =
+///     UnitsFormatter::try_new(locale!("en").into(), &unit, Width::Short.into())
+///         .unwrap();
+/// let value = "9.8".parse().unwrap();
+/// assert_writeable_eq!(formatter.format(&value), "9.8 m/s²");
+///
+/// let unit = MeasureUnit::try_from_str("kilobyte-per-second").unwrap();
+/// let formatter =
+///     UnitsFormatter::try_new(locale!("en").into(), &unit, Width::Long.into())
+///         .unwrap();
+/// let value = 5.into();
+/// assert_writeable_eq!(formatter.format(&value), "5 kilobytes per second");
+/// ```
+#[derive(Debug)]
+pub struct UnitsFormatter {
+    names: MeasureUnitNames,
+    constant_denominator: Option<Decimal>,
+    decimal_formatter: DecimalFormatter,
+    plural_rules: PluralRules,
+    precision: PrecisionOptions,
+}
+
+impl UnitsFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (
+            prefs: UnitsFormatterPreferences,
+            unit: &MeasureUnit,
+            options: UnitsFormatterOptions
+        ) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`UnitsFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: UnitsFormatterPreferences,
+        unit: &MeasureUnit,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = UnitsNamesLengthCoreV1::make_locale(prefs.locale_preferences);
+        Ok(Self {
+            names: load_measure_unit_names(&crate::provider::Baked, &locale, options.width, unit)?,
+            constant_denominator: constant_denominator(unit),
+            decimal_formatter: DecimalFormatter::try_new(
+                (&prefs).into(),
+                DecimalFormatterOptions::default(),
+            )?,
+            plural_rules: PluralRules::try_new_cardinal((&prefs).into())?,
+            precision: options.precision,
+        })
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: UnitsFormatterPreferences,
+        unit: &MeasureUnit,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<PluralsCardinalV1>
+            + DataProvider<UnitsEssentialsV1>
+            + DataProvider<UnitsCompoundPatternsV1>
+            + DataProvider<UnitsNamesAreaCoreV1>
+            + DataProvider<UnitsNamesAreaExtendedV1>
+            + DataProvider<UnitsNamesAreaOutlierV1>
+            + DataProvider<UnitsNamesDurationCoreV1>
+            + DataProvider<UnitsNamesDurationExtendedV1>
+            + DataProvider<UnitsNamesDurationOutlierV1>
+            + DataProvider<UnitsNamesLengthCoreV1>
+            + DataProvider<UnitsNamesLengthExtendedV1>
+            + DataProvider<UnitsNamesLengthOutlierV1>
+            + DataProvider<UnitsNamesMassCoreV1>
+            + DataProvider<UnitsNamesMassExtendedV1>
+            + DataProvider<UnitsNamesMassOutlierV1>
+            + DataProvider<UnitsNamesConsumptionCoreV1>
+            + DataProvider<UnitsNamesConsumptionExtendedV1>
+            + DataProvider<UnitsNamesConsumptionOutlierV1>
+            + DataProvider<UnitsNamesDigitalCoreV1>
+            + DataProvider<UnitsNamesDigitalExtendedV1>
+            + DataProvider<UnitsNamesDigitalOutlierV1>
+            + DataProvider<UnitsNamesEnergyCoreV1>
+            + DataProvider<UnitsNamesEnergyExtendedV1>
+            + DataProvider<UnitsNamesEnergyOutlierV1>
+            + DataProvider<UnitsNamesPowerCoreV1>
+            + DataProvider<UnitsNamesPowerExtendedV1>
+            + DataProvider<UnitsNamesPowerOutlierV1>
+            + DataProvider<UnitsNamesPressureCoreV1>
+            + DataProvider<UnitsNamesPressureExtendedV1>
+            + DataProvider<UnitsNamesPressureOutlierV1>
+            + DataProvider<UnitsNamesSpeedCoreV1>
+            + DataProvider<UnitsNamesSpeedExtendedV1>
+            + DataProvider<UnitsNamesSpeedOutlierV1>
+            + DataProvider<UnitsNamesTemperatureCoreV1>
+            + DataProvider<UnitsNamesTemperatureExtendedV1>
+            + DataProvider<UnitsNamesTemperatureOutlierV1>
+            + DataProvider<UnitsNamesOtherV1>
+            + DataProvider<UnitsNamesVolumeCoreV1>
+            + DataProvider<UnitsNamesVolumeExtendedV1>
+            + DataProvider<UnitsNamesVolumeOutlierV1>,
+    {
+        let locale = UnitsNamesLengthCoreV1::make_locale(prefs.locale_preferences);
+        Ok(Self {
+            names: load_measure_unit_names(provider, &locale, options.width, unit)?,
+            constant_denominator: constant_denominator(unit),
+            decimal_formatter: DecimalFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                DecimalFormatterOptions::default(),
+            )?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?,
+            precision: options.precision,
+        })
+    }
+
+    /// Formats a [`Decimal`] value for the unit of this formatter.
+    pub fn format<'l>(&'l self, value: &'l Decimal) -> FormattedMeasureUnit<'l> {
+        FormattedMeasureUnit {
+            value,
+            names: &self.names,
+            constant_denominator: self.constant_denominator.as_ref(),
+            decimal_formatter: &self.decimal_formatter,
+            plural_rules: &self.plural_rules,
+            precision: &self.precision,
+        }
+    }
+}
+
+/// Returns the constant denominator of the unit, if any, such as `100` for `liter-per-100-kilometer`.
+fn constant_denominator(unit: &MeasureUnit) -> Option<Decimal> {
+    match unit.constant_denominator() {
+        0 => None,
+        constant => Some(constant.into()),
+    }
+}
+
+/// Returns the CLDR identifier of a single unit, with its SI prefix but without its power,
+/// such as `kilometer` for `square-kilometer`.
+fn prefixed_id(single_unit: SingleUnit) -> Option<String> {
+    let mut id = si_prefix_name(single_unit.si_prefix)?;
+    id.push_str(&unit_name(single_unit.unit_id)?);
+    Some(id)
+}
+
+/// Returns the CLDR identifier of the unit, such as `kilometer-per-hour`.
+fn measure_unit_id(unit: &MeasureUnit) -> Option<String> {
+    let mut id = String::new();
+    let mut constant_denominator = match unit.constant_denominator() {
+        0 => None,
+        // Powers of ten, such as in `part-per-1e9`, are written in scientific notation.
+        constant if constant >= 1000 && 10_u64.pow(constant.ilog10()) == constant => {
+            Some(format!("1e{}", constant.ilog10()))
+        }
+        constant => Some(format!("{constant}")),
+    };
+    let mut per = false;
+    for single_unit in unit.single_units() {
+        if single_unit.power < 0 && !per {
+            per = true;
+            id.push_str(if id.is_empty() { "per-" } else { "-per-" });
+            if let Some(constant) = constant_denominator.take() {
+                let _infallible = write!(id, "{constant}-");
+            }
+        } else if !id.is_empty() {
+            id.push('-');
+        }
+        match single_unit.power.unsigned_abs() {
+            1 => (),
+            2 => id.push_str("square-"),
+            3 => id.push_str("cubic-"),
+            power => {
+                let _infallible = write!(id, "pow{power}-");
+            }
+        }
+        id.push_str(&prefixed_id(*single_unit)?);
+    }
+    if let Some(constant) = constant_denominator {
+        let _infallible = write!(id, "-per-{constant}");
+    }
+    Some(id)
+}
+
+/// Loads the display names of a unit, or returns `None` if the locale data has none.
+fn try_load_unit_names<D>(
+    provider: &D,
+    locale: &DataLocale,
+    width: Width,
+    unit: &str,
+) -> Result<Option<DataPayload<ErasedMarker<UnitsDisplayNames<'static>>>>, DataError>
+where
+    D: ?Sized
+        + DataProvider<UnitsNamesAreaCoreV1>
+        + DataProvider<UnitsNamesAreaExtendedV1>
+        + DataProvider<UnitsNamesAreaOutlierV1>
+        + DataProvider<UnitsNamesDurationCoreV1>
+        + DataProvider<UnitsNamesDurationExtendedV1>
+        + DataProvider<UnitsNamesDurationOutlierV1>
+        + DataProvider<UnitsNamesLengthCoreV1>
+        + DataProvider<UnitsNamesLengthExtendedV1>
+        + DataProvider<UnitsNamesLengthOutlierV1>
+        + DataProvider<UnitsNamesMassCoreV1>
+        + DataProvider<UnitsNamesMassExtendedV1>
+        + DataProvider<UnitsNamesMassOutlierV1>
+        + DataProvider<UnitsNamesConsumptionCoreV1>
+        + DataProvider<UnitsNamesConsumptionExtendedV1>
+        + DataProvider<UnitsNamesConsumptionOutlierV1>
+        + DataProvider<UnitsNamesDigitalCoreV1>
+        + DataProvider<UnitsNamesDigitalExtendedV1>
+        + DataProvider<UnitsNamesDigitalOutlierV1>
+        + DataProvider<UnitsNamesEnergyCoreV1>
+        + DataProvider<UnitsNamesEnergyExtendedV1>
+        + DataProvider<UnitsNamesEnergyOutlierV1>
+        + DataProvider<UnitsNamesPowerCoreV1>
+        + DataProvider<UnitsNamesPowerExtendedV1>
+        + DataProvider<UnitsNamesPowerOutlierV1>
+        + DataProvider<UnitsNamesPressureCoreV1>
+        + DataProvider<UnitsNamesPressureExtendedV1>
+        + DataProvider<UnitsNamesPressureOutlierV1>
+        + DataProvider<UnitsNamesSpeedCoreV1>
+        + DataProvider<UnitsNamesSpeedExtendedV1>
+        + DataProvider<UnitsNamesSpeedOutlierV1>
+        + DataProvider<UnitsNamesTemperatureCoreV1>
+        + DataProvider<UnitsNamesTemperatureExtendedV1>
+        + DataProvider<UnitsNamesTemperatureOutlierV1>
+        + DataProvider<UnitsNamesOtherV1>
+        + DataProvider<UnitsNamesVolumeCoreV1>
+        + DataProvider<UnitsNamesVolumeExtendedV1>
+        + DataProvider<UnitsNamesVolumeOutlierV1>,
+{
+    match load_unit_names(provider, locale, width, unit) {
+        Ok(names) => Ok(Some(names)),
+        Err(error) if error.kind == DataErrorKind::IdentifierNotFound => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Loads the display names of a unit, composing them from its single units if the locale data
+/// has no display names for the whole unit.
+pub(crate) fn load_measure_unit_names<D>(
+    provider: &D,
+    locale: &DataLocale,
+    width: Width,
+    unit: &MeasureUnit,
+) -> Result<MeasureUnitNames, DataError>
+where
+    D: ?Sized
+        + DataProvider<UnitsEssentialsV1>
+        + DataProvider<UnitsCompoundPatternsV1>
+        + DataProvider<UnitsNamesAreaCoreV1>
+        + DataProvider<UnitsNamesAreaExtendedV1>
+        + DataProvider<UnitsNamesAreaOutlierV1>
+        + DataProvider<UnitsNamesDurationCoreV1>
+        + DataProvider<UnitsNamesDurationExtendedV1>
+        + DataProvider<UnitsNamesDurationOutlierV1>
+        + DataProvider<UnitsNamesLengthCoreV1>
+        + DataProvider<UnitsNamesLengthExtendedV1>
+        + DataProvider<UnitsNamesLengthOutlierV1>
+        + DataProvider<UnitsNamesMassCoreV1>
+        + DataProvider<UnitsNamesMassExtendedV1>
+        + DataProvider<UnitsNamesMassOutlierV1>
+        + DataProvider<UnitsNamesConsumptionCoreV1>
+        + DataProvider<UnitsNamesConsumptionExtendedV1>
+        + DataProvider<UnitsNamesConsumptionOutlierV1>
+        + DataProvider<UnitsNamesDigitalCoreV1>
+        + DataProvider<UnitsNamesDigitalExtendedV1>
+        + DataProvider<UnitsNamesDigitalOutlierV1>
+        + DataProvider<UnitsNamesEnergyCoreV1>
+        + DataProvider<UnitsNamesEnergyExtendedV1>
+        + DataProvider<UnitsNamesEnergyOutlierV1>
+        + DataProvider<UnitsNamesPowerCoreV1>
+        + DataProvider<UnitsNamesPowerExtendedV1>
+        + DataProvider<UnitsNamesPowerOutlierV1>
+        + DataProvider<UnitsNamesPressureCoreV1>
+        + DataProvider<UnitsNamesPressureExtendedV1>
+        + DataProvider<UnitsNamesPressureOutlierV1>
+        + DataProvider<UnitsNamesSpeedCoreV1>
+        + DataProvider<UnitsNamesSpeedExtendedV1>
+        + DataProvider<UnitsNamesSpeedOutlierV1>
+        + DataProvider<UnitsNamesTemperatureCoreV1>
+        + DataProvider<UnitsNamesTemperatureExtendedV1>
+        + DataProvider<UnitsNamesTemperatureOutlierV1>
+        + DataProvider<UnitsNamesOtherV1>
+        + DataProvider<UnitsNamesVolumeCoreV1>
+        + DataProvider<UnitsNamesVolumeExtendedV1>
+        + DataProvider<UnitsNamesVolumeOutlierV1>,
+{
+    let invalid_unit = || {
+        DataErrorKind::IdentifierNotFound
+            .into_error()
+            .with_debug_context("invalid unit")
+    };
+
+    if let Some(names) = try_load_unit_names(
+        provider,
+        locale,
+        width,
+        &measure_unit_id(unit).ok_or_else(invalid_unit)?,
+    )? {
+        return Ok(MeasureUnitNames::Precomputed(names));
+    }
+
+    let request = DataRequest {
+        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+            DataMarkerAttributes::from_str_or_panic(match width {
+                Width::Long => "long",
+                Width::Short => "short",
+                Width::Narrow => "narrow",
+            }),
+            locale,
+        ),
+        ..Default::default()
+    };
+    let essentials = DataProvider::<UnitsEssentialsV1>::load(provider, request)?.payload;
+    let compound_patterns =
+        DataProvider::<UnitsCompoundPatternsV1>::load(provider, request)?.payload;
+
+    let mut numerator = Vec::new();
+    let mut denominator = Vec::new();
+    for single_unit in unit.single_units() {
+        let power = match single_unit.power.unsigned_abs() {
+            1 => None,
+            2 => Some(PowerValue::Two),
+            3 => Some(PowerValue::Three),
+            _ => {
+                return Err(DataError::custom("Unsupported power").with_debug_context(single_unit));
+            }
+        };
+        let prefixed = prefixed_id(*single_unit).ok_or_else(invalid_unit)?;
+
+        // Prefer the precomputed display names of the unit with its power, such as `square-kilometer`,
+        // then with its SI prefix, such as `kilometer`, then of the unit alone.
+        let powered = power.map(|power| match power {
+            PowerValue::Two => format!("square-{prefixed}"),
+            PowerValue::Three => format!("cubic-{prefixed}"),
+        });
+        let names = match powered {
+            Some(powered) => try_load_unit_names(provider, locale, width, &powered)?.map(|names| {
+                SingleUnitNames {
+                    id: powered,
+                    names,
+                    prefix: None,
+                    power: None,
+                }
+            }),
+            None => None,
+        };
+        let names = match names {
+            Some(names) => names,
+            None => match try_load_unit_names(provider, locale, width, &prefixed)? {
+                Some(names) => SingleUnitNames {
+                    id: prefixed,
+                    names,
+                    prefix: None,
+                    power,
+                },
+                None => {
+                    let id = unit_name(single_unit.unit_id).ok_or_else(invalid_unit)?;
+                    let prefix = match single_unit.si_prefix.base {
+                        _ if single_unit.si_prefix.power == 0 => None,
+                        Base::Decimal => Some(PatternKey::Decimal(single_unit.si_prefix.power)),
+                        Base::Binary => Some(PatternKey::Binary(
+                            single_unit.si_prefix.power.unsigned_abs() / 10,
+                        )),
+                    };
+                    if prefix.is_some_and(|prefix| essentials.get().prefixes.get(&prefix).is_none())
+                    {
+                        return Err(DataErrorKind::IdentifierNotFound
+                            .with_req(UnitsEssentialsV1::INFO, request)
+                            .with_debug_context(&prefixed));
+                    }
+                    SingleUnitNames {
+                        names: load_unit_names(provider, locale, width, &id)?,
+                        id,
+                        prefix,
+                        power,
+                    }
+                }
+            },
+        };
+
+        if single_unit.power > 0 {
+            numerator.push(names);
+        } else {
+            denominator.push(names);
+        }
+    }
+
+    Ok(MeasureUnitNames::Composed(Box::new(CompoundUnitNames {
+        numerator,
+        denominator,
+        essentials,
+        compound_patterns,
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_measure_unit_id() {
+        for id in [
+            "meter",
+            "kilometer-per-hour",
+            "meter-per-square-second",
+            "liter-per-100-kilometer",
+            "part-per-1e9",
+            "per-second",
+            "kilowatt-hour",
+            "cubic-centimeter",
+            "gibibyte",
+        ] {
+            let unit = MeasureUnit::try_from_str(id).unwrap();
+            assert_eq!(measure_unit_id(&unit).as_deref(), Some(id));
+        }
+    }
+
+    #[test]
+    fn test_units_formatter() {
+        let cases = [
+            // Precomputed names.
+            ("kilometer-per-hour", Width::Short, "12", "12 km/h"),
+            (
+                "kilometer-per-hour",
+                Width::Long,
+                "1",
+                "1 kilometer per hour",
+            ),
+            ("square-meter", Width::Long, "2", "2 square meters"),
+            (
+                "square-kilometer-per-hour",
+                Width::Long,
+                "4",
+                "4 square kilometers per hour",
+            ),
+            // Composed names.
+            ("meter-per-square-second", Width::Short, "9.8", "9.8 m/s²"),
+            (
+                "meter-per-square-second",
+                Width::Long,
+                "1",
+                "1 meter per second squared",
+            ),
+            (
+                "kilobyte-per-second",
+                Width::Long,
+                "5",
+                "5 kilobytes per second",
+            ),
+            ("kilobyte-per-second", Width::Narrow, "5", "5kB/s"),
+            ("newton-meter", Width::Long, "3", "3 newton-meters"),
+            (
+                "kilowatt-hour-per-100-kilometer",
+                Width::Long,
+                "3",
+                "3 kilowatt-hours per 100 kilometers",
+            ),
+            (
+                "kilowatt-hour-per-100-kilometer",
+                Width::Short,
+                "3",
+                "3 kWh/100km",
+            ),
+            ("cubic-second", Width::Long, "4", "4 cubic seconds"),
+            ("per-second", Width::Long, "6", "6 per second"),
+            ("megajoule", Width::Short, "7", "7 MJ"),
+        ];
+
+        for (unit, width, value, expected) in cases {
+            let measure_unit = MeasureUnit::try_from_str(unit).unwrap();
+            let formatter =
+                UnitsFormatter::try_new(locale!("en").into(), &measure_unit, width.into()).unwrap();
+            let value = value.parse().unwrap();
+            assert_writeable_eq!(formatter.format(&value), expected, "{unit} {width:?}");
+        }
+    }
+
+    #[test]
+    fn test_units_formatter_locales() {
+        let cases = [
+            (
+                "fr",
+                "square-kilometer-per-hour",
+                Width::Long,
+                "3",
+                "3\u{a0}kilomètres carrés par heure",
+            ),
+            ("fr", "square-gram", Width::Long, "1", "1\u{a0}gramme carré"),
+            (
+                "de",
+                "kilowatt-hour-per-kilometer",
+                Width::Short,
+                "2.5",
+                "2,5 kW⋅Std./km",
+            ),
+            (
+                "ja",
+                "meter-per-square-second",
+                Width::Long,
+                "2",
+                "2 メートル毎秒毎秒",
+            ),
+        ];
+
+        for (locale, unit, width, value, expected) in cases {
+            let measure_unit = MeasureUnit::try_from_str(unit).unwrap();
+            let formatter = UnitsFormatter::try_new(
+                locale.parse::<icu_locale_core::Locale>().unwrap().into(),
+                &measure_unit,
+                width.into(),
+            )
+            .unwrap();
+            let value = value.parse().unwrap();
+            assert_writeable_eq!(
+                formatter.format(&value),
+                expected,
+                "{locale} {unit} {width:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,303 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::vec::Vec;
+
+use fixed_decimal::Decimal;
+use icu_decimal::options::{DecimalFormatterOptions, PrecisionOptions};
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_list::options::{ListFormatterOptions, ListLength};
+use icu_list::{ListFormatter, ListFormatterPreferences};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_plurals::provider::PluralsCardinalV1;
+use icu_plurals::{PluralRules, PluralRulesPreferences};
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::*;
+
+use crate::dimension::provider::units::categorized_display_names::*;
+use crate::dimension::provider::units::display_names::UnitsDisplayNames;
+use crate::dimension::units::format::FormattedMixedUnit;
+use crate::dimension::units::names::load_unit_names;
+use crate::dimension::units::options::{UnitsFormatterOptions, Width};
+use crate::measure::mixedmeasureunit::MixedMeasureUnit;
+
+define_preferences!(
+    /// The preferences for mixed units formatting.
+    [Copy]
+    MixedUnitsFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: crate::dimension::preferences::NumberingSystem
+    }
+);
+prefs_convert!(
+    MixedUnitsFormatterPreferences,
+    DecimalFormatterPreferences,
+    { numbering_system }
+);
+prefs_convert!(MixedUnitsFormatterPreferences, PluralRulesPreferences);
+prefs_convert!(MixedUnitsFormatterPreferences, ListFormatterPreferences);
+
+/// A formatter for values of mixed units, such as "5 ft, 3 in" or "1 hr, 23 min, 4 sec".
+///
+/// Each value is formatted with the unit names of the locale, and the values are joined
+/// with the locale's unit list pattern.
+///
+/// The values are usually obtained from a [`MixedUnitsConverter`](crate::units::mixed_converter::MixedUnitsConverter).
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::units::mixed_formatter::MixedUnitsFormatter;
+/// use icu::experimental::dimension::units::options::{UnitsFormatterOptions, Width};
+/// use icu::experimental::measure::measureunit::MeasureUnit;
+/// use icu::experimental::measure::mixedmeasureunit::MixedMeasureUnit;
+/// use icu::experimental::units::converter_factory::ConverterFactory;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let meter = MeasureUnit::try_from_str("meter").unwrap();
+/// let foot_and_inch = MixedMeasureUnit::try_from_str("foot-and-inch").unwrap();
+///
+/// let converter = ConverterFactory::new()
+///     .mixed_converter(&meter, &foot_and_inch)
+///     .unwrap();
+/// let formatter = MixedUnitsFormatter::try_new(
+///     locale!("en").into(),
+///     &foot_and_inch,
+///     UnitsFormatterOptions::from(Width::Long),
+/// )
+/// .unwrap();
+///
+/// let values = converter.convert(&"1.6".parse().unwrap(), 0);
+/// assert_writeable_eq!(formatter.format(&values), "5 feet, 3 inches");
+/// ```
+#[derive(Debug)]
+pub struct MixedUnitsFormatter {
+    display_names: Vec<DataPayload<ErasedMarker<UnitsDisplayNames<'static>>>>,
+    decimal_formatter: DecimalFormatter,
+    plural_rules: PluralRules,
+    list: ListFormatter,
+    precision: PrecisionOptions,
+}
+
+impl MixedUnitsFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (
+            prefs: MixedUnitsFormatterPreferences,
+            unit: &MixedMeasureUnit,
+            options: UnitsFormatterOptions
+        ) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`MixedUnitsFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: MixedUnitsFormatterPreferences,
+        unit: &MixedMeasureUnit,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = UnitsNamesLengthCoreV1::make_locale(prefs.locale_preferences);
+        let display_names = unit
+            .unit_ids()
+            .map(|id| load_unit_names(&crate::provider::Baked, &locale, options.width, id))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            display_names,
+            decimal_formatter: DecimalFormatter::try_new(
+                (&prefs).into(),
+                DecimalFormatterOptions::default(),
+            )?,
+            plural_rules: PluralRules::try_new_cardinal((&prefs).into())?,
+            list: ListFormatter::try_new_unit((&prefs).into(), list_options(options.width))?,
+            precision: options.precision,
+        })
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: MixedUnitsFormatterPreferences,
+        unit: &MixedMeasureUnit,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<PluralsCardinalV1>
+            + DataProvider<icu_list::provider::ListUnitV1>
+            + DataProvider<UnitsNamesAreaCoreV1>
+            + DataProvider<UnitsNamesAreaExtendedV1>
+            + DataProvider<UnitsNamesAreaOutlierV1>
+            + DataProvider<UnitsNamesDurationCoreV1>
+            + DataProvider<UnitsNamesDurationExtendedV1>
+            + DataProvider<UnitsNamesDurationOutlierV1>
+            + DataProvider<UnitsNamesLengthCoreV1>
+            + DataProvider<UnitsNamesLengthExtendedV1>
+            + DataProvider<UnitsNamesLengthOutlierV1>
+            + DataProvider<UnitsNamesMassCoreV1>
+            + DataProvider<UnitsNamesMassExtendedV1>
+            + DataProvider<UnitsNamesMassOutlierV1>
+            + DataProvider<UnitsNamesConsumptionCoreV1>
+            + DataProvider<UnitsNamesConsumptionExtendedV1>
+            + DataProvider<UnitsNamesConsumptionOutlierV1>
+            + DataProvider<UnitsNamesDigitalCoreV1>
+            + DataProvider<UnitsNamesDigitalExtendedV1>
+            + DataProvider<UnitsNamesDigitalOutlierV1>
+            + DataProvider<UnitsNamesEnergyCoreV1>
+            + DataProvider<UnitsNamesEnergyExtendedV1>
+            + DataProvider<UnitsNamesEnergyOutlierV1>
+            + DataProvider<UnitsNamesPowerCoreV1>
+            + DataProvider<UnitsNamesPowerExtendedV1>
+            + DataProvider<UnitsNamesPowerOutlierV1>
+            + DataProvider<UnitsNamesPressureCoreV1>
+            + DataProvider<UnitsNamesPressureExtendedV1>
+            + DataProvider<UnitsNamesPressureOutlierV1>
+            + DataProvider<UnitsNamesSpeedCoreV1>
+            + DataProvider<UnitsNamesSpeedExtendedV1>
+            + DataProvider<UnitsNamesSpeedOutlierV1>
+            + DataProvider<UnitsNamesTemperatureCoreV1>
+            + DataProvider<UnitsNamesTemperatureExtendedV1>
+            + DataProvider<UnitsNamesTemperatureOutlierV1>
+            + DataProvider<UnitsNamesOtherV1>
+            + DataProvider<UnitsNamesVolumeCoreV1>
+            + DataProvider<UnitsNamesVolumeExtendedV1>
+            + DataProvider<UnitsNamesVolumeOutlierV1>,
+    {
+        let locale = UnitsNamesLengthCoreV1::make_locale(prefs.locale_preferences);
+        let display_names = unit
+            .unit_ids()
+            .map(|id| load_unit_names(provider, &locale, options.width, id))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            display_names,
+            decimal_formatter: DecimalFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                DecimalFormatterOptions::default(),
+            )?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?,
+            list: ListFormatter::try_new_unit_unstable(
+                provider,
+                (&prefs).into(),
+                list_options(options.width),
+            )?,
+            precision: options.precision,
+        })
+    }
+
+    /// Formats the values of the units of the mixed unit, from the largest to the smallest.
+    ///
+    /// The precision of the options only applies to the last value. Values beyond the number
+    /// of units of the mixed unit are ignored.
+    pub fn format<'l>(&'l self, values: &'l [Decimal]) -> FormattedMixedUnit<'l> {
+        FormattedMixedUnit {
+            values,
+            display_names: &self.display_names,
+            decimal_formatter: &self.decimal_formatter,
+            plural_rules: &self.plural_rules,
+            list: &self.list,
+            precision: &self.precision,
+        }
+    }
+}
+
+fn list_options(width: Width) -> ListFormatterOptions {
+    let length = match width {
+        Width::Long => ListLength::Wide,
+        Width::Short => ListLength::Short,
+        Width::Narrow => ListLength::Narrow,
+    };
+    ListFormatterOptions::default().with_length(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measure::measureunit::MeasureUnit;
+    use crate::units::converter_factory::ConverterFactory;
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    #[test]
+    fn test_mixed_units_formatter() {
+        let factory = ConverterFactory::new();
+        let cases = [
+            (
+                "centimeter",
+                "foot-and-inch",
+                "160",
+                Width::Long,
+                "5 feet, 3 inches",
+            ),
+            (
+                "centimeter",
+                "foot-and-inch",
+                "160",
+                Width::Short,
+                "5 ft, 3 in",
+            ),
+            ("centimeter", "foot-and-inch", "160", Width::Narrow, "5′ 3″"),
+            (
+                "meter",
+                "foot-and-inch",
+                "1.8288",
+                Width::Long,
+                "6 feet, 0 inches",
+            ),
+            (
+                "second",
+                "hour-and-minute-and-second",
+                "4984",
+                Width::Short,
+                "1 hr, 23 min, 4 sec",
+            ),
+            (
+                "second",
+                "hour-and-minute-and-second",
+                "-4984",
+                Width::Narrow,
+                "-1h 23m 4s",
+            ),
+        ];
+
+        for (input, output, value, width, expected) in cases {
+            let input = MeasureUnit::try_from_str(input).unwrap();
+            let output = MixedMeasureUnit::try_from_str(output).unwrap();
+            let converter = factory.mixed_converter(&input, &output).unwrap();
+            let formatter =
+                MixedUnitsFormatter::try_new(locale!("en").into(), &output, width.into()).unwrap();
+
+            let values = converter.convert(&value.parse().unwrap(), 0);
+            assert_writeable_eq!(formatter.format(&values), expected);
+        }
+    }
+
+    #[test]
+    fn test_mixed_units_formatter_locales() {
+        let foot_and_inch = MixedMeasureUnit::try_from_str("foot-and-inch").unwrap();
+        let values: [Decimal; 2] = ["5".parse().unwrap(), "3.5".parse().unwrap()];
+
+        let formatter =
+            MixedUnitsFormatter::try_new(locale!("de").into(), &foot_and_inch, Width::Long.into())
+                .unwrap();
+        assert_writeable_eq!(formatter.format(&values), "5 Fuß, 3,5 Zoll");
+    }
+}
@@ -3,5 +3,10 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 pub mod categorized_formatter;
+pub(crate) mod compound;
 pub mod format;
+pub mod formatter;
+pub mod mixed_formatter;
+pub(crate) mod names;
 pub mod options;
+pub mod parser;
@@ -0,0 +1,155 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::*;
+
+use crate::dimension::provider::units::categorized_display_names::*;
+use crate::dimension::provider::units::display_names::UnitsDisplayNames;
+use crate::dimension::units::options::Width;
+
+/// Loads the display names of a unit, whose category is not known, from the first marker that has it.
+pub(crate) fn load_unit_names<D>(
+    provider: &D,
+    locale: &DataLocale,
+    width: Width,
+    unit: &str,
+) -> Result<DataPayload<ErasedMarker<UnitsDisplayNames<'static>>>, DataError>
+where
+    D: ?Sized
+        + DataProvider<UnitsNamesAreaCoreV1>
+        + DataProvider<UnitsNamesAreaExtendedV1>
+        + DataProvider<UnitsNamesAreaOutlierV1>
+        + DataProvider<UnitsNamesDurationCoreV1>
+        + DataProvider<UnitsNamesDurationExtendedV1>
+        + DataProvider<UnitsNamesDurationOutlierV1>
+        + DataProvider<UnitsNamesLengthCoreV1>
+        + DataProvider<UnitsNamesLengthExtendedV1>
+        + DataProvider<UnitsNamesLengthOutlierV1>
+        + DataProvider<UnitsNamesMassCoreV1>
+        + DataProvider<UnitsNamesMassExtendedV1>
+        + DataProvider<UnitsNamesMassOutlierV1>
+        + DataProvider<UnitsNamesConsumptionCoreV1>
+        + DataProvider<UnitsNamesConsumptionExtendedV1>
+        + DataProvider<UnitsNamesConsumptionOutlierV1>
+        + DataProvider<UnitsNamesDigitalCoreV1>
+        + DataProvider<UnitsNamesDigitalExtendedV1>
+        + DataProvider<UnitsNamesDigitalOutlierV1>
+        + DataProvider<UnitsNamesEnergyCoreV1>
+        + DataProvider<UnitsNamesEnergyExtendedV1>
+        + DataProvider<UnitsNamesEnergyOutlierV1>
+        + DataProvider<UnitsNamesPowerCoreV1>
+        + DataProvider<UnitsNamesPowerExtendedV1>
+        + DataProvider<UnitsNamesPowerOutlierV1>
+        + DataProvider<UnitsNamesPressureCoreV1>
+        + DataProvider<UnitsNamesPressureExtendedV1>
+        + DataProvider<UnitsNamesPressureOutlierV1>
+        + DataProvider<UnitsNamesSpeedCoreV1>
+        + DataProvider<UnitsNamesSpeedExtendedV1>
+        + DataProvider<UnitsNamesSpeedOutlierV1>
+        + DataProvider<UnitsNamesTemperatureCoreV1>
+        + DataProvider<UnitsNamesTemperatureExtendedV1>
+        + DataProvider<UnitsNamesTemperatureOutlierV1>
+        + DataProvider<UnitsNamesOtherV1>
+        + DataProvider<UnitsNamesVolumeCoreV1>
+        + DataProvider<UnitsNamesVolumeExtendedV1>
+        + DataProvider<UnitsNamesVolumeOutlierV1>,
+{
+    let mut attribute = String::from(match width {
+        Width::Long => "long-",
+        Width::Short => "short-",
+        Width::Narrow => "narrow-",
+    });
+    attribute.push_str(unit);
+    let attribute = DataMarkerAttributes::try_from_str(&attribute).map_err(|_| {
+        DataErrorKind::IdentifierNotFound
+            .into_error()
+            .with_debug_context("invalid unit identifier")
+    })?;
+    let request = DataRequest {
+        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attribute, locale),
+        metadata: {
+            let mut metadata = DataRequestMetadata::default();
+            metadata.silent = true;
+            metadata
+        },
+    };
+
+    // A marker may fall back to the root locale for a unit that is not in it for the requested
+    // locale, such as `kilometer-per-hour` in the core speed marker for `en`, whose root display
+    // names are short ones even for the long width. Such a response is only used if no other
+    // marker has display names of the requested locale. TODO(#8125)
+    let mut root = None;
+
+    macro_rules! load_first {
+        ($($marker:ident),+) => {
+            $(
+                if let Some(response) =
+                    DataProvider::<$marker>::load(provider, request).allow_identifier_not_found()?
+                {
+                    if !locale.is_unknown()
+                        && response
+                            .metadata
+                            .locale
+                            .as_ref()
+                            .is_some_and(DataLocale::is_unknown)
+                    {
+                        root.get_or_insert(response.payload.cast());
+                    } else {
+                        return Ok(response.payload.cast());
+                    }
+                }
+            )+
+        };
+    }
+    load_first!(
+        UnitsNamesLengthCoreV1,
+        UnitsNamesAreaCoreV1,
+        UnitsNamesVolumeCoreV1,
+        UnitsNamesMassCoreV1,
+        UnitsNamesDurationCoreV1,
+        UnitsNamesConsumptionCoreV1,
+        UnitsNamesDigitalCoreV1,
+        UnitsNamesEnergyCoreV1,
+        UnitsNamesPowerCoreV1,
+        UnitsNamesPressureCoreV1,
+        UnitsNamesSpeedCoreV1,
+        UnitsNamesTemperatureCoreV1,
+        UnitsNamesLengthExtendedV1,
+        UnitsNamesAreaExtendedV1,
+        UnitsNamesVolumeExtendedV1,
+        UnitsNamesMassExtendedV1,
+        UnitsNamesDurationExtendedV1,
+        UnitsNamesConsumptionExtendedV1,
+        UnitsNamesDigitalExtendedV1,
+        UnitsNamesEnergyExtendedV1,
+        UnitsNamesPowerExtendedV1,
+        UnitsNamesPressureExtendedV1,
+        UnitsNamesSpeedExtendedV1,
+        UnitsNamesTemperatureExtendedV1,
+        UnitsNamesLengthOutlierV1,
+        UnitsNamesAreaOutlierV1,
+        UnitsNamesVolumeOutlierV1,
+        UnitsNamesMassOutlierV1,
+        UnitsNamesDurationOutlierV1,
+        UnitsNamesConsumptionOutlierV1,
+        UnitsNamesDigitalOutlierV1,
+        UnitsNamesEnergyOutlierV1,
+        UnitsNamesPowerOutlierV1,
+        UnitsNamesPressureOutlierV1,
+        UnitsNamesSpeedOutlierV1,
+        UnitsNamesTemperatureOutlierV1,
+        UnitsNamesOtherV1
+    );
+
+    if let Some(root) = root {
+        return Ok(root);
+    }
+
+    Err(DataErrorKind::IdentifierNotFound
+        .with_req(UnitsNamesLengthCoreV1::INFO, request)
+        .with_debug_context(unit))
+}
@@ -2,20 +2,42 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Options for [`CategorizedFormatter`](crate::dimension::units::categorized_formatter::CategorizedFormatter).
+//! Options for [`CategorizedFormatter`](crate::dimension::units::categorized_formatter::CategorizedFormatter)
+//! and [`UnitsFormatter`](crate::dimension::units::formatter::UnitsFormatter).
+
+use icu_decimal::options::PrecisionOptions;
 
 /// A collection of configuration options that determine the formatting behavior of
-/// [`CategorizedFormatter`](crate::dimension::units::categorized_formatter::CategorizedFormatter).
+/// [`CategorizedFormatter`](crate::dimension::units::categorized_formatter::CategorizedFormatter)
+/// and [`UnitsFormatter`](crate::dimension::units::formatter::UnitsFormatter).
 #[derive(Copy, Debug, Eq, PartialEq, Clone, Default)]
 #[non_exhaustive]
 pub struct UnitsFormatterOptions {
     /// The width of the units format.
     pub width: Width,
+
+    /// How many digits to display and how to round.
+    ///
+    /// The plural form of the unit is selected from the rounded number.
+    /// By default, the number is displayed with exactly the digits it has.
+    pub precision: PrecisionOptions,
 }
 
 impl From<Width> for UnitsFormatterOptions {
     fn from(width: Width) -> Self {
-        Self { width }
+        Self {
+            width,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<PrecisionOptions> for UnitsFormatterOptions {
+    fn from(precision: PrecisionOptions) -> Self {
+        Self {
+            precision,
+            ..Default::default()
+        }
     }
 }
 
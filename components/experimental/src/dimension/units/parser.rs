@@ -0,0 +1,590 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of localized measurements, such as "5 km" or "3,5 Kilogramm".
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use fixed_decimal::{Decimal, Sign};
+use icu_decimal::options::DecimalFormatterOptions;
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_pattern::PatternItem;
+use icu_plurals::provider::PluralsCardinalV1;
+use icu_plurals::{PluralCategory, PluralOperands, PluralRules, PluralRulesPreferences};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+
+use crate::dimension::parse::{
+    load_decimal_data, parse_number, prefix_len, strip_minus_sign, suffix_len, trim,
+};
+use crate::dimension::provider::units::categorized_display_names::*;
+use crate::dimension::provider::units::compound_patterns::UnitsCompoundPatternsV1;
+use crate::dimension::provider::units::essentials::UnitsEssentialsV1;
+use crate::dimension::units::formatter::{MeasureUnitNames, load_measure_unit_names};
+use crate::dimension::units::options::Width;
+use crate::measure::measureunit::MeasureUnit;
+
+define_preferences!(
+    /// The preferences for units parsing.
+    [Copy]
+    UnitsParserPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: crate::dimension::preferences::NumberingSystem
+    }
+);
+prefs_convert!(UnitsParserPreferences, DecimalFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(UnitsParserPreferences, PluralRulesPreferences);
+
+/// An error returned by [`UnitsParser::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum UnitsParseError {
+    /// The input does not contain the symbol or name of any of the candidate units.
+    #[displaydoc("No unit symbol or name found")]
+    MissingUnit,
+    /// The input does not contain a valid number in the locale's format.
+    #[displaydoc("Invalid amount")]
+    InvalidAmount,
+}
+
+impl core::error::Error for UnitsParseError {}
+
+/// A measurement returned by [`UnitsParser::parse`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ParsedMeasure {
+    /// The parsed unit.
+    ///
+    /// If several units match the input, this is the first one in the order
+    /// given to the parser's constructor.
+    pub unit: MeasureUnit,
+    /// The parsed value.
+    pub value: Decimal,
+    /// All units that match the input, in the order given to the parser's constructor.
+    ///
+    /// This contains more than one unit if the input uses a symbol that is shared
+    /// by several units, such as "m" for both meters and minutes in the narrow width.
+    pub candidates: Vec<MeasureUnit>,
+}
+
+impl ParsedMeasure {
+    /// Returns whether the input matches more than one unit.
+    pub fn is_ambiguous(&self) -> bool {
+        self.candidates.len() > 1
+    }
+}
+
+#[derive(Debug)]
+struct UnitStrings {
+    unit: MeasureUnit,
+    /// The short and narrow symbols in all plural forms.
+    symbols: Vec<String>,
+    /// The long names in all plural forms.
+    names: Vec<String>,
+}
+
+impl UnitStrings {
+    /// Returns the length in bytes of the longest symbol or name of this unit that
+    /// `input` starts with (or ends with, if `at_end` is set).
+    ///
+    /// Symbols are matched exactly, as their case is significant in SI prefixes such as
+    /// "mm" and "Mm", while names are matched ignoring case.
+    fn match_len(&self, input: &str, at_end: bool) -> Option<usize> {
+        let match_one = |token: &str, ignore_case| {
+            if at_end {
+                suffix_len(input, token, ignore_case)
+            } else {
+                prefix_len(input, token, ignore_case)
+            }
+        };
+        let symbols = self
+            .symbols
+            .iter()
+            .filter_map(|symbol| match_one(symbol, false));
+        let names = self.names.iter().filter_map(|name| match_one(name, true));
+        symbols.chain(names).max()
+    }
+}
+
+/// A parser for localized measurements, the inverse of
+/// [`UnitsFormatter`](super::formatter::UnitsFormatter) and
+/// [`CategorizedFormatter`](super::categorized_formatter::CategorizedFormatter).
+///
+/// The parser recognizes values written with the long name (in any plural form), the short
+/// symbol or the narrow symbol of a fixed set of candidate units, on either side of a number
+/// in the locale's format. The number may use the locale's or ASCII digits, and can be
+/// negated with the locale's minus sign or an ASCII hyphen.
+///
+/// Parsing is lenient about whitespace and grouping separator positions. Symbols are
+/// matched exactly, while long names are matched ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::dimension::units::parser::UnitsParser;
+/// use icu::experimental::measure::measureunit::MeasureUnit;
+/// use icu::locale::locale;
+///
+/// let units = ["kilometer", "kilogram", "mile-per-hour"]
+///     .map(|id| MeasureUnit::try_from_str(id).unwrap());
+///
+/// let parser = UnitsParser::try_new(locale!("en").into(), &units).unwrap();
+///
+/// let measure = parser.parse("5 km").unwrap();
+/// assert_eq!(measure.unit, units[0]);
+/// assert_eq!(measure.value, 5.into());
+///
+/// let measure = parser.parse("10 mph").unwrap();
+/// assert_eq!(measure.unit, units[2]);
+/// assert_eq!(measure.value, 10.into());
+///
+/// let parser = UnitsParser::try_new(locale!("de").into(), &units).unwrap();
+///
+/// let measure = parser.parse("3,5 Kilogramm").unwrap();
+/// assert_eq!(measure.unit, units[1]);
+/// assert_eq!(measure.value, "3.5".parse().unwrap());
+/// assert!(!measure.is_ambiguous());
+/// ```
+#[derive(Debug)]
+pub struct UnitsParser {
+    units: Vec<UnitStrings>,
+    symbols: DataPayload<DecimalSymbolsV1>,
+    digits: DataPayload<DecimalDigitsV1>,
+}
+
+impl UnitsParser {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: UnitsParserPreferences, units: &[MeasureUnit]) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`UnitsParser`] from compiled locale data, recognizing the given units.
+    ///
+    /// The order of the units is used to resolve ambiguous inputs.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: UnitsParserPreferences,
+        units: &[MeasureUnit],
+    ) -> Result<Self, DataError> {
+        let (symbols, digits) = load_decimal_data(&icu_decimal::provider::Baked, (&prefs).into())?;
+        let decimal_formatter =
+            DecimalFormatter::try_new((&prefs).into(), DecimalFormatterOptions::default())?;
+        let plural_rules = PluralRules::try_new_cardinal((&prefs).into())?;
+        Self::try_new_with_formatting_data(
+            &crate::provider::Baked,
+            prefs,
+            units,
+            (symbols, digits),
+            &decimal_formatter,
+            &plural_rules,
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: UnitsParserPreferences,
+        units: &[MeasureUnit],
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<PluralsCardinalV1>
+            + DataProvider<UnitsEssentialsV1>
+            + DataProvider<UnitsCompoundPatternsV1>
+            + DataProvider<UnitsNamesAreaCoreV1>
+            + DataProvider<UnitsNamesAreaExtendedV1>
+            + DataProvider<UnitsNamesAreaOutlierV1>
+            + DataProvider<UnitsNamesDurationCoreV1>
+            + DataProvider<UnitsNamesDurationExtendedV1>
+            + DataProvider<UnitsNamesDurationOutlierV1>
+            + DataProvider<UnitsNamesLengthCoreV1>
+            + DataProvider<UnitsNamesLengthExtendedV1>
+            + DataProvider<UnitsNamesLengthOutlierV1>
+            + DataProvider<UnitsNamesMassCoreV1>
+            + DataProvider<UnitsNamesMassExtendedV1>
+            + DataProvider<UnitsNamesMassOutlierV1>
+            + DataProvider<UnitsNamesConsumptionCoreV1>
+            + DataProvider<UnitsNamesConsumptionExtendedV1>
+            + DataProvider<UnitsNamesConsumptionOutlierV1>
+            + DataProvider<UnitsNamesDigitalCoreV1>
+            + DataProvider<UnitsNamesDigitalExtendedV1>
+            + DataProvider<UnitsNamesDigitalOutlierV1>
+            + DataProvider<UnitsNamesEnergyCoreV1>
+            + DataProvider<UnitsNamesEnergyExtendedV1>
+            + DataProvider<UnitsNamesEnergyOutlierV1>
+            + DataProvider<UnitsNamesPowerCoreV1>
+            + DataProvider<UnitsNamesPowerExtendedV1>
+            + DataProvider<UnitsNamesPowerOutlierV1>
+            + DataProvider<UnitsNamesPressureCoreV1>
+            + DataProvider<UnitsNamesPressureExtendedV1>
+            + DataProvider<UnitsNamesPressureOutlierV1>
+            + DataProvider<UnitsNamesSpeedCoreV1>
+            + DataProvider<UnitsNamesSpeedExtendedV1>
+            + DataProvider<UnitsNamesSpeedOutlierV1>
+            + DataProvider<UnitsNamesTemperatureCoreV1>
+            + DataProvider<UnitsNamesTemperatureExtendedV1>
+            + DataProvider<UnitsNamesTemperatureOutlierV1>
+            + DataProvider<UnitsNamesOtherV1>
+            + DataProvider<UnitsNamesVolumeCoreV1>
+            + DataProvider<UnitsNamesVolumeExtendedV1>
+            + DataProvider<UnitsNamesVolumeOutlierV1>,
+    {
+        let decimal_data = load_decimal_data(provider, (&prefs).into())?;
+        let decimal_formatter = DecimalFormatter::try_new_unstable(
+            provider,
+            (&prefs).into(),
+            DecimalFormatterOptions::default(),
+        )?;
+        let plural_rules = PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?;
+        Self::try_new_with_formatting_data(
+            provider,
+            prefs,
+            units,
+            decimal_data,
+            &decimal_formatter,
+            &plural_rules,
+        )
+    }
+
+    fn try_new_with_formatting_data<D>(
+        provider: &D,
+        prefs: UnitsParserPreferences,
+        units: &[MeasureUnit],
+        (symbols, digits): (DataPayload<DecimalSymbolsV1>, DataPayload<DecimalDigitsV1>),
+        decimal_formatter: &DecimalFormatter,
+        plural_rules: &PluralRules,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<UnitsEssentialsV1>
+            + DataProvider<UnitsCompoundPatternsV1>
+            + DataProvider<UnitsNamesAreaCoreV1>
+            + DataProvider<UnitsNamesAreaExtendedV1>
+            + DataProvider<UnitsNamesAreaOutlierV1>
+            + DataProvider<UnitsNamesDurationCoreV1>
+            + DataProvider<UnitsNamesDurationExtendedV1>
+            + DataProvider<UnitsNamesDurationOutlierV1>
+            + DataProvider<UnitsNamesLengthCoreV1>
+            + DataProvider<UnitsNamesLengthExtendedV1>
+            + DataProvider<UnitsNamesLengthOutlierV1>
+            + DataProvider<UnitsNamesMassCoreV1>
+            + DataProvider<UnitsNamesMassExtendedV1>
+            + DataProvider<UnitsNamesMassOutlierV1>
+            + DataProvider<UnitsNamesConsumptionCoreV1>
+            + DataProvider<UnitsNamesConsumptionExtendedV1>
+            + DataProvider<UnitsNamesConsumptionOutlierV1>
+            + DataProvider<UnitsNamesDigitalCoreV1>
+            + DataProvider<UnitsNamesDigitalExtendedV1>
+            + DataProvider<UnitsNamesDigitalOutlierV1>
+            + DataProvider<UnitsNamesEnergyCoreV1>
+            + DataProvider<UnitsNamesEnergyExtendedV1>
+            + DataProvider<UnitsNamesEnergyOutlierV1>
+            + DataProvider<UnitsNamesPowerCoreV1>
+            + DataProvider<UnitsNamesPowerExtendedV1>
+            + DataProvider<UnitsNamesPowerOutlierV1>
+            + DataProvider<UnitsNamesPressureCoreV1>
+            + DataProvider<UnitsNamesPressureExtendedV1>
+            + DataProvider<UnitsNamesPressureOutlierV1>
+            + DataProvider<UnitsNamesSpeedCoreV1>
+            + DataProvider<UnitsNamesSpeedExtendedV1>
+            + DataProvider<UnitsNamesSpeedOutlierV1>
+            + DataProvider<UnitsNamesTemperatureCoreV1>
+            + DataProvider<UnitsNamesTemperatureExtendedV1>
+            + DataProvider<UnitsNamesTemperatureOutlierV1>
+            + DataProvider<UnitsNamesOtherV1>
+            + DataProvider<UnitsNamesVolumeCoreV1>
+            + DataProvider<UnitsNamesVolumeExtendedV1>
+            + DataProvider<UnitsNamesVolumeOutlierV1>,
+    {
+        let locale = UnitsNamesLengthCoreV1::make_locale(prefs.locale_preferences);
+        let samples = plural_samples(plural_rules);
+        let units = units
+            .iter()
+            .map(|unit| {
+                let constant_denominator = match unit.constant_denominator() {
+                    0 => None,
+                    constant => {
+                        let constant = Decimal::from(constant);
+                        Some((
+                            decimal_formatter
+                                .format(&constant)
+                                .write_to_string()
+                                .into_owned(),
+                            PluralOperands::from(&constant),
+                        ))
+                    }
+                };
+                let mut strings = UnitStrings {
+                    unit: unit.clone(),
+                    symbols: Vec::new(),
+                    names: Vec::new(),
+                };
+                for width in [Width::Long, Width::Short, Width::Narrow] {
+                    let names = load_measure_unit_names(provider, &locale, width, unit)?;
+                    let list = match width {
+                        Width::Long => &mut strings.names,
+                        Width::Short | Width::Narrow => &mut strings.symbols,
+                    };
+                    let mut push = |name: String| {
+                        if !name.is_empty() && !list.contains(&name) {
+                            list.push(name);
+                        }
+                    };
+                    match &names {
+                        MeasureUnitNames::Precomputed(names) => {
+                            for (_, pattern) in names.get().elements.iter() {
+                                push_pattern_name(pattern.iter(), &mut push);
+                            }
+                        }
+                        MeasureUnitNames::Composed(names) => {
+                            for &operands in &samples {
+                                let (name, _) = names.compose(
+                                    operands,
+                                    plural_rules,
+                                    constant_denominator
+                                        .as_ref()
+                                        .map(|(constant, operands)| (constant.as_str(), *operands)),
+                                );
+                                push(String::from(trim(&name)));
+                            }
+                        }
+                    }
+                }
+                Ok(strings)
+            })
+            .collect::<Result<Vec<_>, DataError>>()?;
+
+        Ok(Self {
+            units,
+            symbols,
+            digits,
+        })
+    }
+
+    /// Parses a localized measurement, such as "5 km", "10 mph" or "3,5 Kilogramm".
+    ///
+    /// See the [type-level documentation](Self) for the accepted inputs.
+    pub fn parse(&self, input: &str) -> Result<ParsedMeasure, UnitsParseError> {
+        let mut rest = trim(input);
+        let mut negative = false;
+
+        rest = self.strip_minus_sign(rest, &mut negative)?;
+
+        let (unit, candidates, rest) = self
+            .match_unit(rest, false)
+            .or_else(|| self.match_unit(rest, true))
+            .ok_or(UnitsParseError::MissingUnit)?;
+
+        let rest = self.strip_minus_sign(trim(rest), &mut negative)?;
+        let strings = &self.symbols.get().strings;
+        let mut value = parse_number(
+            rest,
+            strings.decimal_separator(),
+            strings.grouping_separator(),
+            self.digits.get(),
+        )
+        .ok_or(UnitsParseError::InvalidAmount)?;
+        if negative {
+            value.set_sign(Sign::Negative);
+        }
+
+        Ok(ParsedMeasure {
+            unit,
+            value,
+            candidates,
+        })
+    }
+
+    /// Finds the longest unit string at the start (or end) of the input, and returns
+    /// the first matching unit, all matching units, and the remaining input.
+    fn match_unit<'a>(
+        &self,
+        input: &'a str,
+        at_end: bool,
+    ) -> Option<(MeasureUnit, Vec<MeasureUnit>, &'a str)> {
+        let len = self
+            .units
+            .iter()
+            .filter_map(|u| u.match_len(input, at_end))
+            .max()?;
+        let mut candidates: Vec<MeasureUnit> = Vec::new();
+        for u in self.units.iter() {
+            if u.match_len(input, at_end) == Some(len) && !candidates.contains(&u.unit) {
+                candidates.push(u.unit.clone());
+            }
+        }
+        let rest = if at_end {
+            input.get(..input.len() - len)
+        } else {
+            input.get(len..)
+        }?;
+        Some((candidates.first()?.clone(), candidates, rest))
+    }
+
+    /// Strips a minus sign from either end of the input, failing if the value was already negated.
+    fn strip_minus_sign<'a>(
+        &self,
+        input: &'a str,
+        negative: &mut bool,
+    ) -> Result<&'a str, UnitsParseError> {
+        match strip_minus_sign(input, &self.symbols.get().strings) {
+            Some(_) if *negative => Err(UnitsParseError::InvalidAmount),
+            Some(s) => {
+                *negative = true;
+                Ok(s)
+            }
+            None => Ok(input),
+        }
+    }
+}
+
+/// Takes the number placeholder out of a display name pattern, such as `kilometers`
+/// for `{0} kilometers`, and passes the name to `push`.
+///
+/// Patterns with literal text on both sides of the placeholder are skipped, as the
+/// parser only recognizes units before or after the number.
+fn push_pattern_name<'a, K>(
+    items: impl Iterator<Item = PatternItem<'a, K>>,
+    push: &mut impl FnMut(String),
+) {
+    let mut before = String::new();
+    let mut after = String::new();
+    let mut has_placeholder = false;
+    for item in items {
+        match item {
+            PatternItem::Literal(literal) if has_placeholder => after.push_str(literal),
+            PatternItem::Literal(literal) => before.push_str(literal),
+            PatternItem::Placeholder(_) => has_placeholder = true,
+        }
+    }
+    match (trim(&before), trim(&after)) {
+        (name, "") | ("", name) => push(String::from(name)),
+        _ => (),
+    }
+}
+
+/// Returns a plural operand for each plural category of the rules.
+fn plural_samples(rules: &PluralRules) -> Vec<PluralOperands> {
+    let mut categories: Vec<PluralCategory> = Vec::new();
+    let mut samples = Vec::new();
+    for sample in [
+        "0", "1", "2", "3", "4", "5", "6", "7", "11", "21", "100", "1000000", "0.5", "1.5",
+    ] {
+        let Ok(sample) = Decimal::try_from_str(sample) else {
+            continue;
+        };
+        let operands = PluralOperands::from(&sample);
+        let category = rules.category_for(operands);
+        if !categories.contains(&category) {
+            categories.push(category);
+            samples.push(operands);
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_locale_core::{Locale, locale};
+
+    fn units(ids: &[&str]) -> Vec<MeasureUnit> {
+        ids.iter()
+            .map(|id| MeasureUnit::try_from_str(id).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_units_parser() {
+        let units = units(&[
+            "meter",
+            "kilometer",
+            "kilogram",
+            "mile-per-hour",
+            "kilometer-per-hour",
+            "celsius",
+        ]);
+        let parser = UnitsParser::try_new(locale!("en").into(), &units).unwrap();
+
+        let cases = [
+            ("5 km", 1, "5"),
+            ("1 kilometer", 1, "1"),
+            ("2.5 Kilometers", 1, "2.5"),
+            ("10 mph", 3, "10"),
+            ("10 miles per hour", 3, "10"),
+            ("1,200 m", 0, "1200"),
+            ("120km/h", 4, "120"),
+            ("-5°C", 5, "-5"),
+            ("-3 degrees Celsius", 5, "-3"),
+        ];
+        for (input, unit, value) in cases {
+            let measure = parser.parse(input).unwrap();
+            assert_eq!(measure.unit, units[unit], "{input}");
+            assert_eq!(measure.value, value.parse().unwrap(), "{input}");
+            assert!(!measure.is_ambiguous(), "{input}");
+        }
+
+        assert_eq!(parser.parse("5"), Err(UnitsParseError::MissingUnit));
+        assert_eq!(parser.parse("5 parsecs"), Err(UnitsParseError::MissingUnit));
+        assert_eq!(parser.parse("km"), Err(UnitsParseError::InvalidAmount));
+        assert_eq!(parser.parse("--5 km"), Err(UnitsParseError::InvalidAmount));
+        // Symbols are case-sensitive
+        assert_eq!(parser.parse("5 KM"), Err(UnitsParseError::MissingUnit));
+    }
+
+    #[test]
+    fn test_units_parser_locales() {
+        let units = units(&["kilogram", "kilometer", "liter-per-100-kilometer"]);
+        let cases = [
+            ("de", "3,5 Kilogramm", 0, "3.5"),
+            ("de", "1.000 km", 1, "1000"),
+            ("de", "7 L/100km", 2, "7"),
+            ("fr", "2,5\u{a0}kilomètres", 1, "2.5"),
+            ("ar", "3 كغم", 0, "3"),
+            ("ar-u-nu-arab", "٣٫٥ كغم", 0, "3.5"),
+        ];
+        for (locale, input, unit, value) in cases {
+            let parser =
+                UnitsParser::try_new(locale.parse::<Locale>().unwrap().into(), &units).unwrap();
+            let measure = parser.parse(input).unwrap();
+            assert_eq!(measure.unit, units[unit], "{locale} {input}");
+            assert_eq!(measure.value, value.parse().unwrap(), "{locale} {input}");
+        }
+    }
+
+    #[test]
+    fn test_units_parser_ambiguity() {
+        let units = units(&["minute", "meter"]);
+        let parser = UnitsParser::try_new(locale!("en").into(), &units).unwrap();
+
+        // The narrow symbol of both minutes and meters is "m"
+        let measure = parser.parse("5m").unwrap();
+        assert_eq!(measure.unit, units[0]);
+        assert_eq!(measure.candidates, units);
+        assert!(measure.is_ambiguous());
+
+        let measure = parser.parse("5 min").unwrap();
+        assert_eq!(measure.unit, units[0]);
+        assert!(!measure.is_ambiguous());
+    }
+}
@@ -7,14 +7,18 @@
 use crate::displaynames::options::*;
 use crate::displaynames::provider::*;
 use alloc::borrow::Cow;
-use alloc::string::String;
+use alloc::vec::Vec;
+use icu_locale_core::extensions::unicode::{Key, Value};
 use icu_locale_core::preferences::define_preferences;
+use icu_locale_core::preferences::extensions::unicode::keywords::CurrencyType;
 use icu_locale_core::{
     LanguageIdentifier, Locale, subtags::Language, subtags::Region, subtags::Script,
     subtags::Variant,
 };
 use icu_provider::prelude::*;
 use potential_utf::PotentialUtf8;
+use tinystr::TinyAsciiStr;
+use writeable::Writeable;
 
 define_preferences!(
     /// The preferences for display names.
@@ -38,7 +42,7 @@ define_preferences!(
 /// let display_name = RegionDisplayNames::try_new(locale, options)
 ///     .expect("Data should load successfully");
 ///
-/// assert_eq!(display_name.of(region!("AE")), Some("United Arab Emirates"));
+/// assert_eq!(display_name.of(region!("AE")).as_deref(), Some("United Arab Emirates"));
 /// ```
 #[derive(Default, Debug)]
 pub struct RegionDisplayNames {
@@ -79,14 +83,17 @@ impl RegionDisplayNames {
     }
 
     /// Returns the display name of a region.
-    pub fn of(&self, region: Region) -> Option<&str> {
+    ///
+    /// Regions without a display name are displayed as their code with [`Fallback::Code`],
+    /// and return `None` with [`Fallback::None`].
+    pub fn of(&self, region: Region) -> Option<Cow<'_, str>> {
         let data = self.region_data.get();
-        match self.options.style {
+        let name = match self.options.style {
             Some(Style::Short) => data.short_names.get(&region.to_tinystr().to_unvalidated()),
             _ => None,
         }
-        .or_else(|| data.names.get(&region.to_tinystr().to_unvalidated()))
-        // TODO: Respect options.fallback
+        .or_else(|| data.names.get(&region.to_tinystr().to_unvalidated()));
+        name_or_code(name, self.options.fallback, region.as_str())
     }
 }
 
@@ -105,7 +112,7 @@ impl RegionDisplayNames {
 /// let display_name = ScriptDisplayNames::try_new(locale, options)
 ///     .expect("Data should load successfully");
 ///
-/// assert_eq!(display_name.of(script!("Maya")), Some("Mayan hieroglyphs"));
+/// assert_eq!(display_name.of(script!("Maya")).as_deref(), Some("Mayan hieroglyphs"));
 /// ```
 #[derive(Default, Debug)]
 pub struct ScriptDisplayNames {
@@ -146,14 +153,17 @@ impl ScriptDisplayNames {
     }
 
     /// Returns the display name of a script.
-    pub fn of(&self, script: Script) -> Option<&str> {
+    ///
+    /// Scripts without a display name are displayed as their code with [`Fallback::Code`],
+    /// and return `None` with [`Fallback::None`].
+    pub fn of(&self, script: Script) -> Option<Cow<'_, str>> {
         let data = self.script_data.get();
-        match self.options.style {
+        let name = match self.options.style {
             Some(Style::Short) => data.short_names.get(&script.to_tinystr().to_unvalidated()),
             _ => None,
         }
-        .or_else(|| data.names.get(&script.to_tinystr().to_unvalidated()))
-        // TODO: Respect options.fallback
+        .or_else(|| data.names.get(&script.to_tinystr().to_unvalidated()));
+        name_or_code(name, self.options.fallback, script.as_str())
     }
 }
 
@@ -172,11 +182,10 @@ impl ScriptDisplayNames {
 /// let display_name = VariantDisplayNames::try_new(locale, options)
 ///     .expect("Data should load successfully");
 ///
-/// assert_eq!(display_name.of(variant!("POSIX")), Some("Computer"));
+/// assert_eq!(display_name.of(variant!("POSIX")).as_deref(), Some("Computer"));
 /// ```
 #[derive(Default, Debug)]
 pub struct VariantDisplayNames {
-    #[allow(dead_code)] //TODO: Add DisplayNamesOptions support for Variants.
     options: DisplayNamesOptions,
     variant_data: DataPayload<VariantDisplayNamesV1>,
 }
@@ -214,10 +223,14 @@ impl VariantDisplayNames {
     }
 
     /// Returns the display name of a variant.
-    pub fn of(&self, variant: Variant) -> Option<&str> {
+    ///
+    /// Variants without a display name are displayed as their code with [`Fallback::Code`],
+    /// and return `None` with [`Fallback::None`]. There are no variant display names for
+    /// the other [`Style`]s.
+    pub fn of(&self, variant: Variant) -> Option<Cow<'_, str>> {
         let data = self.variant_data.get();
-        data.names.get(&variant.to_tinystr().to_unvalidated())
-        // TODO: Respect options.fallback
+        let name = data.names.get(&variant.to_tinystr().to_unvalidated());
+        name_or_code(name, self.options.fallback, variant.as_str())
     }
 }
 
@@ -236,7 +249,7 @@ impl VariantDisplayNames {
 /// let display_name = LanguageDisplayNames::try_new(locale, options)
 ///     .expect("Data should load successfully");
 ///
-/// assert_eq!(display_name.of(language!("de")), Some("German"));
+/// assert_eq!(display_name.of(language!("de")).as_deref(), Some("German"));
 /// ```
 #[derive(Default, Debug)]
 pub struct LanguageDisplayNames {
@@ -277,9 +290,12 @@ impl LanguageDisplayNames {
     }
 
     /// Returns the display name of a language.
-    pub fn of(&self, language: Language) -> Option<&str> {
+    ///
+    /// Languages without a display name are displayed as their code with [`Fallback::Code`],
+    /// and return `None` with [`Fallback::None`].
+    pub fn of(&self, language: Language) -> Option<Cow<'_, str>> {
         let data = self.language_data.get();
-        match self.options.style {
+        let name = match self.options.style {
             Some(Style::Short) => data
                 .short_names
                 .get(&language.to_tinystr().to_unvalidated()),
@@ -287,20 +303,101 @@ impl LanguageDisplayNames {
             Some(Style::Menu) => data.menu_names.get(&language.to_tinystr().to_unvalidated()),
             _ => None,
         }
-        .or_else(|| data.names.get(&language.to_tinystr().to_unvalidated()))
-        // TODO: Respect options.fallback
+        .or_else(|| data.names.get(&language.to_tinystr().to_unvalidated()));
+        name_or_code(name, self.options.fallback, language.as_str())
+    }
+}
+
+/// Lookup of the locale-specific display names by currency code.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::displaynames::{
+///     DisplayNamesOptions, multi::CurrencyDisplayNames,
+/// };
+/// use icu::locale::{
+///     locale, preferences::extensions::unicode::keywords::CurrencyType,
+/// };
+///
+/// let locale = locale!("en-001").into();
+/// let options: DisplayNamesOptions = Default::default();
+/// let display_name = CurrencyDisplayNames::try_new(locale, options)
+///     .expect("Data should load successfully");
+///
+/// let eur = CurrencyType::try_from_str("eur").unwrap();
+/// assert_eq!(display_name.of(eur).as_deref(), Some("Euro"));
+/// ```
+#[derive(Default, Debug)]
+pub struct CurrencyDisplayNames {
+    options: DisplayNamesOptions,
+    currency_data: DataPayload<CurrencyDisplayNamesV1>,
+}
+
+impl CurrencyDisplayNames {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: DisplayNamesPreferences, options: DisplayNamesOptions) -> error: DataError,
+        /// Creates a new [`CurrencyDisplayNames`] from locale data and an options bag using compiled data.
+        functions: [
+            try_new,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D: DataProvider<CurrencyDisplayNamesV1> + ?Sized>(
+        provider: &D,
+        prefs: DisplayNamesPreferences,
+        options: DisplayNamesOptions,
+    ) -> Result<Self, DataError> {
+        let locale = CurrencyDisplayNamesV1::make_locale(prefs.locale_preferences);
+        let currency_data = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        Ok(Self {
+            options,
+            currency_data,
+        })
+    }
+
+    /// Returns the display name of a currency.
+    ///
+    /// Currencies without a display name are displayed as their uppercase ISO 4217 code with
+    /// [`Fallback::Code`], and return `None` with [`Fallback::None`]. There are no currency
+    /// display names for the other [`Style`]s.
+    pub fn of(&self, currency: CurrencyType) -> Option<Cow<'_, str>> {
+        let code = currency.iso_code();
+        let name = self.currency_data.get().names.get(&code.to_unvalidated());
+        name_or_code(name, self.options.fallback, code.as_str())
+    }
+}
+
+/// Returns the display name, or the code if there is none and the fallback allows it.
+fn name_or_code<'a>(name: Option<&'a str>, fallback: Fallback, code: &str) -> Option<Cow<'a, str>> {
+    match name {
+        Some(name) => Some(Cow::Borrowed(name)),
+        None => fallback.code(code).map(|code| Cow::Owned(code.into())),
     }
 }
 
 /// Format a locale as a display string.
 ///
+/// Variants and Unicode extension keywords are displayed as qualifiers, using the
+/// display names of their keys and types.
+///
 /// # Examples
 ///
 /// ```
 /// use icu::experimental::displaynames::{
 ///     DisplayNamesOptions, multi::LocaleDisplayNamesFormatter,
 /// };
-/// use icu::locale::locale;
+/// use icu::locale::{Locale, locale};
 ///
 /// let locale = locale!("en-001").into();
 /// let options: DisplayNamesOptions = Default::default();
@@ -312,18 +409,29 @@ impl LanguageDisplayNames {
 /// assert_eq!(display_name.of(&locale!("en-MX")), "English (Mexico)");
 /// assert_eq!(display_name.of(&locale!("xx-YY")), "xx (YY)");
 /// assert_eq!(display_name.of(&locale!("xx")), "xx");
+/// assert_eq!(
+///     display_name
+///         .of(&"en-US-u-ca-gregory-co-phonebk".parse::<Locale>().unwrap()),
+///     "American English (Gregorian Calendar, Phonebook Sort Order)"
+/// );
+/// assert_eq!(
+///     display_name
+///         .of(&"de-u-cu-eur-co-xxx".parse::<Locale>().unwrap()),
+///     "German (Sort Order: xxx, Currency: Euro)"
+/// );
 /// ```
 #[derive(Debug)]
 pub struct LocaleDisplayNamesFormatter {
     options: DisplayNamesOptions,
-    // patterns: DataPayload<LocaleDisplayNamesPatternsV1>,
+    patterns: DataPayload<LocaleDisplayNamesPatternsV1>,
     locale_data: DataPayload<LocaleDisplayNamesV1>,
 
     language_data: DataPayload<LanguageDisplayNamesV1>,
     script_data: DataPayload<ScriptDisplayNamesV1>,
     region_data: DataPayload<RegionDisplayNamesV1>,
     variant_data: DataPayload<VariantDisplayNamesV1>,
-    // key_data: DataPayload<KeyDisplayNamesV1>,
+    key_data: DataPayload<KeyDisplayNamesV1>,
+    currency_data: DataPayload<CurrencyDisplayNamesV1>,
     // measurement_data: DataPayload<MeasurementSystemsDisplayNamesV1>,
     // subdivisions_data: DataPayload<SubdivisionsDisplayNamesV1>,
     // transforms_data: DataPayload<TransformsDisplayNamesV1>,
@@ -349,10 +457,13 @@ impl LocaleDisplayNamesFormatter {
     ) -> Result<Self, DataError>
     where
         D: DataProvider<LocaleDisplayNamesV1>
+            + DataProvider<LocaleDisplayNamesPatternsV1>
             + DataProvider<LanguageDisplayNamesV1>
             + DataProvider<ScriptDisplayNamesV1>
             + DataProvider<RegionDisplayNamesV1>
             + DataProvider<VariantDisplayNamesV1>
+            + DataProvider<KeyDisplayNamesV1>
+            + DataProvider<CurrencyDisplayNamesV1>
             + ?Sized,
     {
         let locale = LocaleDisplayNamesV1::make_locale(prefs.locale_preferences);
@@ -363,19 +474,64 @@ impl LocaleDisplayNamesFormatter {
 
         Ok(Self {
             options,
+            patterns: provider.load(req)?.payload,
             language_data: provider.load(req)?.payload,
             locale_data: provider.load(req)?.payload,
             script_data: provider.load(req)?.payload,
             region_data: provider.load(req)?.payload,
             variant_data: provider.load(req)?.payload,
+            key_data: provider.load(req)?.payload,
+            currency_data: provider.load(req)?.payload,
         })
     }
 
     /// Returns the display name of a locale.
     /// This implementation is based on the algorithm described in
     /// <https://www.unicode.org/reports/tr35/tr35-general.html#locale_display_name_algorithm>
+    ///
+    /// Subtags and Unicode extension keys without a display name are displayed as their
+    /// codes, as with [`Fallback::Code`]. Use [`Self::try_of`] to honor [`Fallback::None`].
     // TODO: Make this return a writeable instead of using alloc
     pub fn of<'a, 'b: 'a, 'c: 'a>(&'b self, locale: &'c Locale) -> Cow<'a, str> {
+        // With `Fallback::Code`, every subtag and key has a name.
+        self.display_name(locale, Fallback::Code)
+            .unwrap_or_default()
+    }
+
+    /// Returns the display name of a locale, honoring the [`Fallback`] option.
+    ///
+    /// Returns `None` with [`Fallback::None`] if a subtag or a Unicode extension key has no
+    /// display name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::displaynames::{
+    ///     DisplayNamesOptions, Fallback, multi::LocaleDisplayNamesFormatter,
+    /// };
+    /// use icu::locale::locale;
+    ///
+    /// let mut options = DisplayNamesOptions::default();
+    /// options.fallback = Fallback::None;
+    /// let display_name =
+    ///     LocaleDisplayNamesFormatter::try_new(locale!("en-001").into(), options)
+    ///         .expect("Data should load successfully");
+    ///
+    /// assert_eq!(
+    ///     display_name.try_of(&locale!("en-MX")).as_deref(),
+    ///     Some("English (Mexico)")
+    /// );
+    /// assert_eq!(display_name.try_of(&locale!("xx-YY")), None);
+    /// ```
+    pub fn try_of<'a, 'b: 'a, 'c: 'a>(&'b self, locale: &'c Locale) -> Option<Cow<'a, str>> {
+        self.display_name(locale, self.options.fallback)
+    }
+
+    fn display_name<'a, 'b: 'a, 'c: 'a>(
+        &'b self,
+        locale: &'c Locale,
+        fallback: Fallback,
+    ) -> Option<Cow<'a, str>> {
         // Step - 1: Construct a locale display name string (LDN) for the longest matching subtag.
         let mut ldn = None;
 
@@ -383,7 +539,8 @@ impl LocaleDisplayNamesFormatter {
         // Using references as we might borrow from these later
         let mut script_qs = locale.id.script.as_ref();
         let mut region_qs = locale.id.region.as_ref();
-        let variants_qs = Cow::Borrowed(&*locale.id.variants);
+        let variants_qs = &*locale.id.variants;
+        let keywords_qs = &locale.extensions.unicode.keywords;
 
         // TODO: handle the other possible longest subtag cases
 
@@ -426,8 +583,9 @@ impl LocaleDisplayNamesFormatter {
             }
         }
 
-        let ldn = ldn
-            .or_else(|| {
+        let ldn = match ldn {
+            Some(ldn) => ldn,
+            None => {
                 let data = self.language_data.get();
                 let key = locale.id.language.to_tinystr().to_unvalidated();
                 match self.options.style {
@@ -437,73 +595,137 @@ impl LocaleDisplayNamesFormatter {
                     _ => None,
                 }
                 .or_else(|| data.names.get(&key))
-            })
-            .unwrap_or(locale.id.language.as_str());
+                .or_else(|| fallback.code(locale.id.language.as_str()))?
+            }
+        };
 
-        if script_qs.is_none() && region_qs.is_none() && variants_qs.is_empty() {
+        if script_qs.is_none()
+            && region_qs.is_none()
+            && variants_qs.is_empty()
+            && keywords_qs.is_empty()
+        {
             // The LDN fully represents the locale
-            return Cow::Borrowed(ldn);
+            return Some(Cow::Borrowed(ldn));
         }
 
         // Step - 2: Construct the list of qualifying substrings (LQS).
+        let mut lqs: Vec<Cow<'a, str>> = Vec::new();
 
-        let script_qs = script_qs.map(|script| {
+        if let Some(script) = script_qs {
             let data = self.script_data.get();
             let key = script.to_tinystr().to_unvalidated();
-            match self.options.style {
+            let name = match self.options.style {
                 Some(Style::Short) => data.short_names.get(&key),
                 _ => None,
             }
             .or_else(|| data.names.get(&key))
-            .unwrap_or(script.as_str())
-        });
+            .or_else(|| fallback.code(script.as_str()))?;
+            lqs.push(Cow::Borrowed(name));
+        }
 
-        let region_qs = region_qs.map(|region| {
+        if let Some(region) = region_qs {
             let data = self.region_data.get();
             let key = region.to_tinystr().to_unvalidated();
-            match self.options.style {
+            let name = match self.options.style {
                 Some(Style::Short) => data.short_names.get(&key),
                 _ => None,
             }
             .or_else(|| data.names.get(&key))
-            .unwrap_or(region.as_str())
-        });
+            .or_else(|| fallback.code(region.as_str()))?;
+            lqs.push(Cow::Borrowed(name));
+        }
 
-        let variants_qs = variants_qs.iter().map(|variant_key| {
-            self.variant_data
+        for variant in variants_qs {
+            let name = self
+                .variant_data
                 .get()
                 .names
-                .get(&variant_key.to_tinystr().to_unvalidated())
-                .unwrap_or(variant_key.as_str())
-        });
+                .get(&variant.to_tinystr().to_unvalidated())
+                .or_else(|| fallback.code(variant.as_str()))?;
+            lqs.push(Cow::Borrowed(name));
+        }
 
-        let lqs = script_qs.into_iter().chain(region_qs).chain(variants_qs);
+        for (key, value) in keywords_qs.iter() {
+            lqs.push(self.key_type_name(key, value, fallback)?);
+        }
 
         // Step - 3: Write LDN and LQS to output
         // TODO: Move to an `impl Writeable`
+        let patterns = self.patterns.get();
+        let mut lqs = lqs.into_iter();
+        let first = lqs.next()?;
+        let qualifiers = lqs.fold(first, |qualifiers, qs| {
+            patterns
+                .locale_separator
+                .interpolate((qualifiers, qs))
+                .write_to_string()
+                .into_owned()
+                .into()
+        });
 
-        // TODO: load from data
-        let (before, middle, after) = (" (", ", ", ")");
-
-        let mut output = String::with_capacity(
-            ldn.len() + before.len() + lqs.clone().map(|x| x.len() + middle.len()).sum::<usize>()
-                - middle.len()
-                + after.len(),
-        );
-        output.push_str(ldn);
-        output.push_str(before);
-
-        let mut first = true;
-        for lqs in lqs {
-            if !first {
-                output.push_str(middle);
-            } else {
-                first = false;
-            }
-            output.push_str(lqs);
+        Some(
+            patterns
+                .locale_pattern
+                .interpolate((ldn, qualifiers))
+                .write_to_string()
+                .into_owned()
+                .into(),
+        )
+    }
+
+    /// Returns the display name of a Unicode extension keyword.
+    ///
+    /// Keywords without a type display name use the key display name with the
+    /// `localeKeyTypePattern`, e.g. "Sort Order: xxx".
+    fn key_type_name<'a>(
+        &'a self,
+        key: &'a Key,
+        value: &Value,
+        fallback: Fallback,
+    ) -> Option<Cow<'a, str>> {
+        let data = self.key_data.get();
+        let unvalidated_key = key.to_tinystr().to_unvalidated();
+        // An empty value stands for "true".
+        let value = if value.is_empty() {
+            Cow::Borrowed("true")
+        } else {
+            value.write_to_string()
+        };
+
+        if key.as_str() != "cu"
+            && let Some(name) = data
+                .types
+                .get_2d(&unvalidated_key, PotentialUtf8::from_str(&value))
+        {
+            return Some(Cow::Borrowed(name));
         }
-        output.push_str(after);
-        Cow::Owned(output)
+
+        let key_name = data
+            .keys
+            .get(&unvalidated_key)
+            .or_else(|| fallback.code(key.as_str()))?;
+        let currency_name = if key.as_str() == "cu" {
+            TinyAsciiStr::<3>::try_from_str(&value)
+                .ok()
+                .and_then(|code| {
+                    self.currency_data
+                        .get()
+                        .names
+                        .get(&code.to_ascii_uppercase().to_unvalidated())
+                })
+        } else {
+            None
+        };
+        let pattern = &self.patterns.get().locale_key_type_pattern;
+        Some(
+            match currency_name {
+                Some(currency_name) => pattern.interpolate((key_name, currency_name)),
+                None => pattern.interpolate((key_name, &*value)),
+            }
+            .write_to_string()
+            .into_owned()
+            .into(),
+        )
     }
 }
 
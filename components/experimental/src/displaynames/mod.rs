@@ -55,6 +55,7 @@ pub mod multi {
     //!
     //! See [the parent module](mod@super) for a comparison of single and multi.
     use super::displaynames;
+    pub use displaynames::CurrencyDisplayNames;
     pub use displaynames::LanguageDisplayNames;
     pub use displaynames::LocaleDisplayNamesFormatter;
     pub use displaynames::RegionDisplayNames;
@@ -21,7 +21,7 @@
 ///     .expect("Data should load successfully");
 ///
 /// // Full name would be "Bosnia & Herzegovina"
-/// assert_eq!(display_name.of(region!("BA")), Some("Bosnia"));
+/// assert_eq!(display_name.of(region!("BA")).as_deref(), Some("Bosnia"));
 /// ```
 #[derive(Copy, Debug, Eq, PartialEq, Clone, Default)]
 #[non_exhaustive]
@@ -59,6 +59,16 @@ pub enum Fallback {
     None,
 }
 
+impl Fallback {
+    /// Returns the code to display when no display name is available, if any.
+    pub(crate) fn code(self, code: &str) -> Option<&str> {
+        match self {
+            Fallback::Code => Some(code),
+            Fallback::None => None,
+        }
+    }
+}
+
 /// An enum for the language display kind.
 #[allow(missing_docs)] // The variants are self explanatory.
 #[non_exhaustive]
@@ -9,10 +9,11 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use icu_pattern::DoublePlaceholderPattern;
 use icu_provider::prelude::*;
 use potential_utf::PotentialUtf8;
 use tinystr::UnvalidatedTinyAsciiStr;
-use zerovec::ZeroMap;
+use zerovec::{VarZeroCow, ZeroMap, ZeroMap2d};
 
 // We use raw TinyAsciiStrs for map keys, as we then don't have to
 // validate them as subtags on deserialization. Map lookup can be
@@ -23,6 +24,9 @@ type UnvalidatedLanguage = UnvalidatedTinyAsciiStr<3>;
 type UnvalidatedScript = UnvalidatedTinyAsciiStr<4>;
 type UnvalidatedLocale = PotentialUtf8;
 type UnvalidatedVariant = UnvalidatedTinyAsciiStr<8>;
+type UnvalidatedKey = UnvalidatedTinyAsciiStr<2>;
+type UnvalidatedKeyType = PotentialUtf8;
+type UnvalidatedCurrency = UnvalidatedTinyAsciiStr<3>;
 
 icu_provider::data_marker!(
     /// `LocaleDisplayNamesV1`
@@ -49,6 +53,21 @@ icu_provider::data_marker!(
     RegionDisplayNamesV1,
     RegionDisplayNames<'static>
 );
+icu_provider::data_marker!(
+    /// `KeyDisplayNamesV1`
+    KeyDisplayNamesV1,
+    KeyDisplayNames<'static>
+);
+icu_provider::data_marker!(
+    /// `CurrencyDisplayNamesV1`
+    CurrencyDisplayNamesV1,
+    CurrencyDisplayNames<'static>
+);
+icu_provider::data_marker!(
+    /// `LocaleDisplayNamesPatternsV1`
+    LocaleDisplayNamesPatternsV1,
+    LocaleDisplayNamesPatterns<'static>
+);
 
 #[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
@@ -143,3 +162,54 @@ pub struct VariantDisplayNames<'data> {
 }
 
 icu_provider::data_struct!(VariantDisplayNames<'_>, #[cfg(feature = "datagen")]);
+
+#[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::displaynames::provider))]
+#[yoke(prove_covariance_manually)]
+/// [`KeyDisplayNames`] provides the display names of Unicode extension keys and their types.
+pub struct KeyDisplayNames<'data> {
+    /// Mapping for a BCP-47 key (e.g. `ca`) to its display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub keys: ZeroMap<'data, UnvalidatedKey, str>,
+    /// Mapping for a BCP-47 key and type (e.g. `ca`, `gregory`) to the display name of the type.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub types: ZeroMap2d<'data, UnvalidatedKey, UnvalidatedKeyType, str>,
+}
+
+icu_provider::data_struct!(KeyDisplayNames<'_>, #[cfg(feature = "datagen")]);
+
+#[derive(Debug, PartialEq, Clone, Default, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::displaynames::provider))]
+#[yoke(prove_covariance_manually)]
+/// [`CurrencyDisplayNames`] provides mapping between ISO 4217 currency codes and display names.
+pub struct CurrencyDisplayNames<'data> {
+    /// Mapping for currency code to display name.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: ZeroMap<'data, UnvalidatedCurrency, str>,
+}
+
+icu_provider::data_struct!(CurrencyDisplayNames<'_>, #[cfg(feature = "datagen")]);
+
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::displaynames::provider))]
+#[yoke(prove_covariance_manually)]
+/// [`LocaleDisplayNamesPatterns`] provides the patterns used to assemble a locale display name.
+pub struct LocaleDisplayNamesPatterns<'data> {
+    /// The pattern combining the base name with the qualifiers (e.g., `"{0} ({1})"`).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locale_pattern: VarZeroCow<'data, DoublePlaceholderPattern>,
+    /// The pattern joining two qualifiers (e.g., `"{0}, {1}"`).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locale_separator: VarZeroCow<'data, DoublePlaceholderPattern>,
+    /// The pattern combining a key name with a type (e.g., `"{0}: {1}"`).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locale_key_type_pattern: VarZeroCow<'data, DoublePlaceholderPattern>,
+}
+
+icu_provider::data_struct!(LocaleDisplayNamesPatterns<'_>, #[cfg(feature = "datagen")]);
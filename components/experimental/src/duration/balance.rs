@@ -0,0 +1,793 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Balancing and rounding of durations
+
+use fixed_decimal::{SignedRoundingMode, UnsignedRoundingMode};
+use icu_calendar::options::{DateAddOptions, DateDifferenceOptions, DateDurationUnit};
+use icu_calendar::types::DateDuration;
+use icu_calendar::{AsCalendar, Date, Ref};
+
+use super::duration::{
+    NANOSECONDS_PER_DAY, NANOSECONDS_PER_HOUR, NANOSECONDS_PER_MICROSECOND,
+    NANOSECONDS_PER_MILLISECOND, NANOSECONDS_PER_MINUTE, NANOSECONDS_PER_SECOND,
+};
+use super::{Duration, DurationError, DurationSign};
+
+/// A unit of a [`Duration`], used for balancing and rounding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DurationUnit {
+    /// Years.
+    Years,
+    /// Months.
+    Months,
+    /// Weeks.
+    Weeks,
+    /// Days.
+    Days,
+    /// Hours.
+    Hours,
+    /// Minutes.
+    Minutes,
+    /// Seconds.
+    Seconds,
+    /// Milliseconds.
+    Milliseconds,
+    /// Microseconds.
+    Microseconds,
+    /// Nanoseconds.
+    Nanoseconds,
+}
+
+/// The units in the order of [`Duration::iter_units`].
+const UNITS: [DurationUnit; 10] = [
+    DurationUnit::Years,
+    DurationUnit::Months,
+    DurationUnit::Weeks,
+    DurationUnit::Days,
+    DurationUnit::Hours,
+    DurationUnit::Minutes,
+    DurationUnit::Seconds,
+    DurationUnit::Milliseconds,
+    DurationUnit::Microseconds,
+    DurationUnit::Nanoseconds,
+];
+
+impl DurationUnit {
+    /// Returns the length of the unit in nanoseconds, treating a day as 24 hours, or `None`
+    /// for years, months and weeks, whose length depends on a date.
+    fn nanoseconds(self) -> Option<u128> {
+        match self {
+            Self::Years | Self::Months | Self::Weeks => None,
+            Self::Days => Some(NANOSECONDS_PER_DAY),
+            Self::Hours => Some(NANOSECONDS_PER_HOUR),
+            Self::Minutes => Some(NANOSECONDS_PER_MINUTE),
+            Self::Seconds => Some(NANOSECONDS_PER_SECOND),
+            Self::Milliseconds => Some(NANOSECONDS_PER_MILLISECOND),
+            Self::Microseconds => Some(NANOSECONDS_PER_MICROSECOND),
+            Self::Nanoseconds => Some(1),
+        }
+    }
+
+    fn is_larger_than(self, other: Self) -> bool {
+        (self as u8) < (other as u8)
+    }
+
+    fn date_duration_unit(self) -> DateDurationUnit {
+        match self {
+            Self::Years => DateDurationUnit::Years,
+            Self::Months => DateDurationUnit::Months,
+            Self::Weeks => DateDurationUnit::Weeks,
+            _ => DateDurationUnit::Days,
+        }
+    }
+}
+
+/// Options for [`Duration::try_round`] and [`Duration::try_round_relative_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[non_exhaustive]
+pub struct DurationRoundingOptions {
+    /// The largest unit of the result, into which the smaller units are balanced.
+    ///
+    /// Defaults to the largest non-zero unit of the duration, or to the smallest unit if it is larger.
+    pub largest_unit: Option<DurationUnit>,
+    /// The smallest unit of the result, to which the duration is rounded.
+    ///
+    /// Defaults to [`DurationUnit::Nanoseconds`].
+    pub smallest_unit: Option<DurationUnit>,
+    /// How to round the duration to the smallest unit.
+    ///
+    /// Defaults to half expand, i.e. rounding half away from zero.
+    pub rounding_mode: Option<SignedRoundingMode>,
+}
+
+impl Duration {
+    /// Balances and rounds the duration so that its largest and smallest non-zero units are within
+    /// the units given in the options, treating a day as 24 hours.
+    ///
+    /// Years, months and weeks have no fixed length. If the duration has any of them or if the
+    /// options require them, [`Duration::try_round_relative_to`] must be used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::duration::{
+    ///     Duration, DurationRoundingOptions, DurationUnit,
+    /// };
+    ///
+    /// let duration = Duration::try_from_str("PT90M").unwrap();
+    ///
+    /// let mut options = DurationRoundingOptions::default();
+    /// options.largest_unit = Some(DurationUnit::Hours);
+    /// assert_eq!(
+    ///     duration.try_round(options),
+    ///     Ok(Duration {
+    ///         hours: 1,
+    ///         minutes: 30,
+    ///         ..Default::default()
+    ///     })
+    /// );
+    ///
+    /// options.smallest_unit = Some(DurationUnit::Hours);
+    /// assert_eq!(
+    ///     duration.try_round(options),
+    ///     Ok(Duration {
+    ///         hours: 2,
+    ///         ..Default::default()
+    ///     })
+    /// );
+    /// ```
+    pub fn try_round(&self, options: DurationRoundingOptions) -> Result<Self, DurationError> {
+        let (largest_unit, smallest_unit, rounding_mode) =
+            self.resolve_rounding_options(options)?;
+        let Some(increment) = smallest_unit.nanoseconds() else {
+            return Err(DurationError::MissingRelativeDate);
+        };
+        if largest_unit.nanoseconds().is_none()
+            || self.years != 0
+            || self.months != 0
+            || self.weeks != 0
+        {
+            return Err(DurationError::MissingRelativeDate);
+        }
+        self.validate()?;
+
+        let is_negative = self.is_negative();
+        let total = round_to_increment(
+            self.total_nanoseconds(),
+            increment,
+            rounding_mode,
+            is_negative,
+        );
+        Self::try_from_nanoseconds(is_negative, total, largest_unit)
+    }
+
+    /// Balances and rounds the duration so that its largest and smallest non-zero units are within
+    /// the units given in the options, using the lengths of the years, months and weeks starting
+    /// at the given date. A day is treated as 24 hours.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::experimental::duration::{
+    ///     Duration, DurationRoundingOptions, DurationUnit,
+    /// };
+    ///
+    /// let duration = Duration::try_from_str("P45D").unwrap();
+    ///
+    /// let mut options = DurationRoundingOptions::default();
+    /// options.largest_unit = Some(DurationUnit::Months);
+    ///
+    /// // February 2025 has 28 days.
+    /// let date = Date::try_new_iso(2025, 2, 1).unwrap();
+    /// assert_eq!(
+    ///     duration.try_round_relative_to(options, &date),
+    ///     Ok(Duration {
+    ///         months: 1,
+    ///         days: 17,
+    ///         ..Default::default()
+    ///     })
+    /// );
+    ///
+    /// // March 2025 has 31 days.
+    /// let date = Date::try_new_iso(2025, 3, 1).unwrap();
+    /// assert_eq!(
+    ///     duration.try_round_relative_to(options, &date),
+    ///     Ok(Duration {
+    ///         months: 1,
+    ///         days: 14,
+    ///         ..Default::default()
+    ///     })
+    /// );
+    ///
+    /// options.smallest_unit = Some(DurationUnit::Months);
+    /// assert_eq!(
+    ///     duration.try_round_relative_to(options, &date),
+    ///     Ok(Duration {
+    ///         months: 1,
+    ///         ..Default::default()
+    ///     })
+    /// );
+    /// ```
+    pub fn try_round_relative_to<A: AsCalendar>(
+        &self,
+        options: DurationRoundingOptions,
+        relative_to: &Date<A>,
+    ) -> Result<Self, DurationError> {
+        let (largest_unit, smallest_unit, rounding_mode) =
+            self.resolve_rounding_options(options)?;
+        self.validate()?;
+
+        let is_negative = self.is_negative();
+        let start = relative_to.as_borrowed();
+        let time = self.time_nanoseconds();
+        let end = add_to_date(
+            start,
+            DateDuration {
+                is_negative,
+                years: to_u32(self.years)?,
+                months: to_u32(self.months)?,
+                weeks: to_u32(self.weeks)?,
+                days: to_u32(u128::from(self.days) + time / NANOSECONDS_PER_DAY)?,
+            },
+        )?;
+        let time = time % NANOSECONDS_PER_DAY;
+        let total = u128::from(days_between(start, end)) * NANOSECONDS_PER_DAY + time;
+
+        match smallest_unit.nanoseconds() {
+            // The result has only days and time units.
+            Some(increment) if !largest_unit.is_larger_than(DurationUnit::Days) => {
+                let total = round_to_increment(total, increment, rounding_mode, is_negative);
+                Self::try_from_nanoseconds(is_negative, total, largest_unit)
+            }
+            // The time units are rounded, and the days are balanced into the calendar units.
+            Some(increment) => {
+                let total = round_to_increment(total, increment, rounding_mode, is_negative);
+                let end = add_to_date(
+                    start,
+                    DateDuration {
+                        is_negative,
+                        days: to_u32(total / NANOSECONDS_PER_DAY)?,
+                        ..Default::default()
+                    },
+                )?;
+                let date_duration = until_date(start, end, largest_unit)?;
+                Self::try_from_date_duration(date_duration, total % NANOSECONDS_PER_DAY)
+            }
+            // The duration is rounded to a calendar unit relative to the surrounding dates.
+            None => {
+                let date_duration = until_date(start, end, largest_unit)?;
+                let mut truncated = DateDuration {
+                    is_negative,
+                    ..Default::default()
+                };
+                let mut next = truncated;
+                match smallest_unit {
+                    DurationUnit::Years => {
+                        truncated.years = date_duration.years;
+                        next.years = date_duration.years.saturating_add(1);
+                    }
+                    DurationUnit::Months => {
+                        truncated.years = date_duration.years;
+                        truncated.months = date_duration.months;
+                        next.years = date_duration.years;
+                        next.months = date_duration.months.saturating_add(1);
+                    }
+                    _ => {
+                        let weeks = date_duration.weeks + date_duration.days / 7;
+                        truncated.years = date_duration.years;
+                        truncated.months = date_duration.months;
+                        truncated.weeks = weeks;
+                        next.years = date_duration.years;
+                        next.months = date_duration.months;
+                        next.weeks = weeks.saturating_add(1);
+                    }
+                }
+                let truncated_date = add_to_date(start, truncated)?;
+                let next_date = add_to_date(start, next)?;
+                let unit_length =
+                    u128::from(days_between(truncated_date, next_date)) * NANOSECONDS_PER_DAY;
+                let remainder =
+                    u128::from(days_between(truncated_date, end)) * NANOSECONDS_PER_DAY + time;
+                let (mut rounded, rounded_date) = if round_to_increment(
+                    remainder,
+                    unit_length,
+                    rounding_mode,
+                    is_negative,
+                ) == 0
+                {
+                    (truncated, truncated_date)
+                } else {
+                    (next, next_date)
+                };
+                // Rounding up may reach the next month or year.
+                let rounded_days = days_between(start, rounded_date);
+                for unit in [DurationUnit::Months, DurationUnit::Years] {
+                    if !unit.is_larger_than(smallest_unit) || unit.is_larger_than(largest_unit) {
+                        continue;
+                    }
+                    let mut candidate = DateDuration {
+                        is_negative,
+                        years: rounded.years,
+                        ..Default::default()
+                    };
+                    if unit == DurationUnit::Months {
+                        candidate.months = rounded.months.saturating_add(1);
+                    } else {
+                        candidate.years = rounded.years.saturating_add(1);
+                    }
+                    if days_between(start, add_to_date(start, candidate)?) > rounded_days {
+                        break;
+                    }
+                    rounded = candidate;
+                }
+                Self::try_from_date_duration(rounded, 0)
+            }
+        }
+    }
+
+    fn is_negative(&self) -> bool {
+        self.get_sign() == fixed_decimal::Sign::Negative
+    }
+
+    fn resolve_rounding_options(
+        &self,
+        options: DurationRoundingOptions,
+    ) -> Result<(DurationUnit, DurationUnit, SignedRoundingMode), DurationError> {
+        let smallest_unit = options.smallest_unit.unwrap_or(DurationUnit::Nanoseconds);
+        let largest_unit = options.largest_unit.unwrap_or_else(|| {
+            let default_largest_unit = self
+                .iter_units()
+                .iter()
+                .zip(UNITS)
+                .find(|(value, _)| **value != 0)
+                .map_or(DurationUnit::Nanoseconds, |(_, unit)| unit);
+            if default_largest_unit.is_larger_than(smallest_unit) {
+                default_largest_unit
+            } else {
+                smallest_unit
+            }
+        });
+        if smallest_unit.is_larger_than(largest_unit) {
+            return Err(DurationError::InvalidUnitRange);
+        }
+        let rounding_mode = options
+            .rounding_mode
+            .unwrap_or(SignedRoundingMode::Unsigned(
+                UnsignedRoundingMode::HalfExpand,
+            ));
+        Ok((largest_unit, smallest_unit, rounding_mode))
+    }
+
+    /// Creates a duration from a number of nanoseconds, balanced up to the largest unit, which
+    /// must be days or a time unit.
+    fn try_from_nanoseconds(
+        is_negative: bool,
+        nanoseconds: u128,
+        largest_unit: DurationUnit,
+    ) -> Result<Self, DurationError> {
+        let mut duration = Self::new();
+        let mut rest = nanoseconds;
+        for (unit, field) in [
+            (DurationUnit::Days, &mut duration.days),
+            (DurationUnit::Hours, &mut duration.hours),
+            (DurationUnit::Minutes, &mut duration.minutes),
+            (DurationUnit::Seconds, &mut duration.seconds),
+            (DurationUnit::Milliseconds, &mut duration.milliseconds),
+            (DurationUnit::Microseconds, &mut duration.microseconds),
+            (DurationUnit::Nanoseconds, &mut duration.nanoseconds),
+        ] {
+            if unit.is_larger_than(largest_unit) {
+                continue;
+            }
+            let Some(length) = unit.nanoseconds() else {
+                continue;
+            };
+            *field = u64::try_from(rest / length).map_err(|_| DurationError::OutOfRange)?;
+            rest %= length;
+        }
+        if is_negative && nanoseconds != 0 {
+            duration.sign = DurationSign::Negative;
+        }
+        duration.validate()?;
+        Ok(duration)
+    }
+
+    /// Creates a duration from a date duration and a number of nanoseconds less than a day.
+    fn try_from_date_duration(
+        date_duration: DateDuration,
+        nanoseconds: u128,
+    ) -> Result<Self, DurationError> {
+        let mut duration = Self::try_from_nanoseconds(
+            date_duration.is_negative,
+            nanoseconds,
+            DurationUnit::Hours,
+        )?;
+        duration.years = u64::from(date_duration.years);
+        duration.months = u64::from(date_duration.months);
+        duration.weeks = u64::from(date_duration.weeks);
+        duration.days = u64::from(date_duration.days);
+        if date_duration.is_negative && duration.iter_units().iter().any(|&unit| unit != 0) {
+            duration.sign = DurationSign::Negative;
+        }
+        duration.validate()?;
+        Ok(duration)
+    }
+}
+
+/// Rounds a number of nanoseconds to a multiple of the increment.
+fn round_to_increment(
+    value: u128,
+    increment: u128,
+    rounding_mode: SignedRoundingMode,
+    is_negative: bool,
+) -> u128 {
+    let quotient = value / increment;
+    let remainder = value % increment;
+    let rounding_mode = match rounding_mode {
+        SignedRoundingMode::Unsigned(rounding_mode) => rounding_mode,
+        SignedRoundingMode::Ceil if is_negative => UnsignedRoundingMode::Trunc,
+        SignedRoundingMode::Ceil => UnsignedRoundingMode::Expand,
+        SignedRoundingMode::Floor if is_negative => UnsignedRoundingMode::Expand,
+        SignedRoundingMode::Floor => UnsignedRoundingMode::Trunc,
+        SignedRoundingMode::HalfCeil if is_negative => UnsignedRoundingMode::HalfTrunc,
+        SignedRoundingMode::HalfCeil => UnsignedRoundingMode::HalfExpand,
+        SignedRoundingMode::HalfFloor if is_negative => UnsignedRoundingMode::HalfExpand,
+        SignedRoundingMode::HalfFloor => UnsignedRoundingMode::HalfTrunc,
+        _ => UnsignedRoundingMode::HalfExpand,
+    };
+    let round_up = match rounding_mode {
+        UnsignedRoundingMode::Expand => remainder != 0,
+        UnsignedRoundingMode::Trunc => false,
+        UnsignedRoundingMode::HalfTrunc => remainder * 2 > increment,
+        UnsignedRoundingMode::HalfEven => {
+            remainder * 2 > increment || (remainder * 2 == increment && quotient % 2 == 1)
+        }
+        _ => remainder * 2 >= increment,
+    };
+    (quotient + u128::from(round_up)) * increment
+}
+
+fn to_u32(value: impl TryInto<u32>) -> Result<u32, DurationError> {
+    value.try_into().map_err(|_| DurationError::OutOfRange)
+}
+
+fn add_to_date<'a, A: AsCalendar>(
+    date: Date<Ref<'a, A>>,
+    duration: DateDuration,
+) -> Result<Date<Ref<'a, A>>, DurationError> {
+    date.try_added_with_options(duration, DateAddOptions::default())
+        .map_err(|_| DurationError::OutOfRange)
+}
+
+fn until_date<A: AsCalendar>(
+    start: Date<Ref<'_, A>>,
+    end: Date<Ref<'_, A>>,
+    largest_unit: DurationUnit,
+) -> Result<DateDuration, DurationError> {
+    let mut options = DateDifferenceOptions::default();
+    options.largest_unit = Some(largest_unit.date_duration_unit());
+    start
+        .try_until_with_options(&end, options)
+        .map_err(|_| DurationError::OutOfRange)
+}
+
+fn days_between<A: AsCalendar>(start: Date<Ref<'_, A>>, end: Date<Ref<'_, A>>) -> u64 {
+    (end.to_rata_die() - start.to_rata_die()).unsigned_abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_calendar::Iso;
+
+    fn round(duration: &str, largest_unit: DurationUnit, smallest_unit: DurationUnit) -> String {
+        let options = DurationRoundingOptions {
+            largest_unit: Some(largest_unit),
+            smallest_unit: Some(smallest_unit),
+            ..Default::default()
+        };
+        format!(
+            "{:?}",
+            Duration::try_from_str(duration)
+                .unwrap()
+                .try_round(options)
+                .map(|d| d.iter_units())
+        )
+    }
+
+    fn round_relative_to(
+        duration: &str,
+        date: Date<Iso>,
+        largest_unit: DurationUnit,
+        smallest_unit: DurationUnit,
+    ) -> String {
+        let options = DurationRoundingOptions {
+            largest_unit: Some(largest_unit),
+            smallest_unit: Some(smallest_unit),
+            ..Default::default()
+        };
+        let duration = Duration::try_from_str(duration).unwrap();
+        format!(
+            "{:?}",
+            duration
+                .try_round_relative_to(options, &date)
+                .map(|d| (d.sign, d.iter_units()))
+        )
+    }
+
+    #[test]
+    fn test_round() {
+        use DurationUnit::*;
+        let cases = [
+            (
+                "PT90M",
+                Hours,
+                Nanoseconds,
+                "Ok([0, 0, 0, 0, 1, 30, 0, 0, 0, 0])",
+            ),
+            ("PT90M", Hours, Hours, "Ok([0, 0, 0, 0, 2, 0, 0, 0, 0, 0])"),
+            ("PT89M", Hours, Hours, "Ok([0, 0, 0, 0, 1, 0, 0, 0, 0, 0])"),
+            ("PT36H", Days, Hours, "Ok([0, 0, 0, 1, 12, 0, 0, 0, 0, 0])"),
+            (
+                "P1DT12H",
+                Hours,
+                Hours,
+                "Ok([0, 0, 0, 0, 36, 0, 0, 0, 0, 0])",
+            ),
+            ("P1DT12H", Days, Days, "Ok([0, 0, 0, 2, 0, 0, 0, 0, 0, 0])"),
+            (
+                "PT1.0015S",
+                Seconds,
+                Milliseconds,
+                "Ok([0, 0, 0, 0, 0, 0, 1, 2, 0, 0])",
+            ),
+            (
+                "PT0.123456789S",
+                Milliseconds,
+                Microseconds,
+                "Ok([0, 0, 0, 0, 0, 0, 0, 123, 457, 0])",
+            ),
+            (
+                "PT100000S",
+                Hours,
+                Minutes,
+                "Ok([0, 0, 0, 0, 27, 47, 0, 0, 0, 0])",
+            ),
+            ("PT1H", Minutes, Hours, "Err(InvalidUnitRange)"),
+            ("P1M", Days, Days, "Err(MissingRelativeDate)"),
+            ("PT1H", Months, Hours, "Err(MissingRelativeDate)"),
+            ("PT1H", Hours, Weeks, "Err(InvalidUnitRange)"),
+        ];
+        for (duration, largest_unit, smallest_unit, expected) in cases {
+            assert_eq!(
+                round(duration, largest_unit, smallest_unit),
+                expected,
+                "{duration} {largest_unit:?} {smallest_unit:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_defaults() {
+        let duration = Duration::try_from_str("PT1H90M").unwrap();
+        assert_eq!(
+            duration.try_round(Default::default()),
+            Ok(Duration {
+                hours: 2,
+                minutes: 30,
+                ..Default::default()
+            })
+        );
+
+        let duration = Duration::try_from_str("PT20H").unwrap();
+        let options = DurationRoundingOptions {
+            smallest_unit: Some(DurationUnit::Days),
+            ..Default::default()
+        };
+        assert_eq!(
+            duration.try_round(options),
+            Ok(Duration {
+                days: 1,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_round_modes() {
+        let cases = [
+            (SignedRoundingMode::Ceil, "PT1H30M", 2),
+            (SignedRoundingMode::Ceil, "-PT1H30M", 1),
+            (SignedRoundingMode::Floor, "PT1H30M", 1),
+            (SignedRoundingMode::Floor, "-PT1H30M", 2),
+            (SignedRoundingMode::HalfCeil, "-PT1H30M", 1),
+            (SignedRoundingMode::HalfFloor, "-PT1H30M", 2),
+            (SignedRoundingMode::HalfFloor, "PT1H30M", 1),
+            (
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::Expand),
+                "PT1H1M",
+                2,
+            ),
+            (
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::Trunc),
+                "PT1H59M",
+                1,
+            ),
+            (
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand),
+                "-PT1H30M",
+                2,
+            ),
+            (
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfTrunc),
+                "PT1H30M",
+                1,
+            ),
+            (
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven),
+                "PT1H30M",
+                2,
+            ),
+            (
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven),
+                "PT2H30M",
+                2,
+            ),
+        ];
+        for (rounding_mode, duration, hours) in cases {
+            let options = DurationRoundingOptions {
+                smallest_unit: Some(DurationUnit::Hours),
+                rounding_mode: Some(rounding_mode),
+                ..Default::default()
+            };
+            let duration = Duration::try_from_str(duration).unwrap();
+            let rounded = duration.try_round(options).unwrap();
+            assert_eq!(rounded.hours, hours, "{duration:?} {rounding_mode:?}");
+            assert_eq!(
+                rounded.sign, duration.sign,
+                "{duration:?} {rounding_mode:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_relative_to() {
+        use DurationUnit::*;
+        let jan_31 = Date::try_new_iso(2024, 1, 31).unwrap();
+        let feb_1 = Date::try_new_iso(2025, 2, 1).unwrap();
+        let mar_1 = Date::try_new_iso(2025, 3, 1).unwrap();
+        let cases = [
+            (
+                "P45D",
+                feb_1,
+                Months,
+                Days,
+                "Ok((Positive, [0, 1, 0, 17, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P45D",
+                mar_1,
+                Months,
+                Days,
+                "Ok((Positive, [0, 1, 0, 14, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "-P45D",
+                mar_1,
+                Months,
+                Days,
+                "Ok((Negative, [0, 1, 0, 17, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P45D",
+                feb_1,
+                Months,
+                Months,
+                "Ok((Positive, [0, 2, 0, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P45D",
+                mar_1,
+                Months,
+                Months,
+                "Ok((Positive, [0, 1, 0, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P45D",
+                mar_1,
+                Weeks,
+                Days,
+                "Ok((Positive, [0, 0, 6, 3, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P45D",
+                mar_1,
+                Months,
+                Weeks,
+                "Ok((Positive, [0, 1, 2, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P4W2D",
+                feb_1,
+                Months,
+                Weeks,
+                "Ok((Positive, [0, 1, 0, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P1M",
+                jan_31,
+                Days,
+                Days,
+                "Ok((Positive, [0, 0, 0, 29, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P1M",
+                feb_1,
+                Hours,
+                Hours,
+                "Ok((Positive, [0, 0, 0, 0, 672, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P1Y",
+                jan_31,
+                Days,
+                Days,
+                "Ok((Positive, [0, 0, 0, 366, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P11M20D",
+                mar_1,
+                Years,
+                Months,
+                "Ok((Positive, [1, 0, 0, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P11M10D",
+                mar_1,
+                Years,
+                Months,
+                "Ok((Positive, [0, 11, 0, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P1Y6M",
+                mar_1,
+                Years,
+                Years,
+                "Ok((Positive, [2, 0, 0, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P27DT23H",
+                feb_1,
+                Months,
+                Days,
+                "Ok((Positive, [0, 1, 0, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "PT50H",
+                feb_1,
+                Days,
+                Nanoseconds,
+                "Ok((Positive, [0, 0, 0, 2, 2, 0, 0, 0, 0, 0]))",
+            ),
+            (
+                "P1M",
+                feb_1,
+                Weeks,
+                Hours,
+                "Ok((Positive, [0, 0, 4, 0, 0, 0, 0, 0, 0, 0]))",
+            ),
+            ("P1D", feb_1, Hours, Days, "Err(InvalidUnitRange)"),
+        ];
+        for (duration, date, largest_unit, smallest_unit, expected) in cases {
+            assert_eq!(
+                round_relative_to(duration, date, largest_unit, smallest_unit),
+                expected,
+                "{duration} {date:?} {largest_unit:?} {smallest_unit:?}"
+            );
+        }
+    }
+}
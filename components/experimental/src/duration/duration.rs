@@ -4,6 +4,25 @@
 
 //! Duration formatting
 
+use core::str::FromStr;
+
+use ixdtf::ParseError;
+use ixdtf::encoding::Utf8;
+use ixdtf::parsers::IsoDurationParser;
+use ixdtf::records::{DurationParseRecord, Sign, TimeDurationRecord};
+
+pub(crate) const NANOSECONDS_PER_MICROSECOND: u128 = 1_000;
+pub(crate) const NANOSECONDS_PER_MILLISECOND: u128 = 1_000_000;
+pub(crate) const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+pub(crate) const NANOSECONDS_PER_MINUTE: u128 = 60 * NANOSECONDS_PER_SECOND;
+pub(crate) const NANOSECONDS_PER_HOUR: u128 = 60 * NANOSECONDS_PER_MINUTE;
+pub(crate) const NANOSECONDS_PER_DAY: u128 = 24 * NANOSECONDS_PER_HOUR;
+
+/// Years, months and weeks must be less than 2^32.
+const MAX_CALENDAR_UNIT_VALUE: u64 = 1 << 32;
+/// The days and time units must add up to less than 2^53 seconds.
+const MAX_NANOSECONDS: u128 = (1 << 53) * NANOSECONDS_PER_SECOND;
+
 /// Represents a duration of time (intuitively, how long something took / will take).
 /// Can be constructed ergonomically using the [`Default`] trait like so:
 ///
@@ -73,11 +92,274 @@ impl Duration {
         fixed_decimal::Sign::None
     }
 
-    // TODO: Currently, we do not validate durations.
-    // // Section 1.1.5
-    // pub(crate) fn is_valid_duration(&self) -> bool {
-    //     todo!();
-    // }
+    /// Returns the number of nanoseconds in the days and time units of the duration,
+    /// treating a day as 24 hours.
+    pub(crate) fn total_nanoseconds(&self) -> u128 {
+        u128::from(self.days) * NANOSECONDS_PER_DAY + self.time_nanoseconds()
+    }
+
+    /// Returns the number of nanoseconds in the time units of the duration.
+    pub(crate) fn time_nanoseconds(&self) -> u128 {
+        u128::from(self.hours) * NANOSECONDS_PER_HOUR
+            + u128::from(self.minutes) * NANOSECONDS_PER_MINUTE
+            + u128::from(self.seconds) * NANOSECONDS_PER_SECOND
+            + u128::from(self.milliseconds) * NANOSECONDS_PER_MILLISECOND
+            + u128::from(self.microseconds) * NANOSECONDS_PER_MICROSECOND
+            + u128::from(self.nanoseconds)
+    }
+
+    // Section 1.1.5 IsValidDuration
+    /// Checks that the duration is within the limits of ECMA-402, i.e. that the years, months
+    /// and weeks are each less than 2<sup>32</sup>, and that the days and time units add up to
+    /// less than 2<sup>53</sup> seconds.
+    ///
+    /// A [`Duration`] cannot have fields of mixed signs, see [`Duration::try_from_units`] for
+    /// constructing one from signed values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::duration::{Duration, DurationError};
+    ///
+    /// let duration = Duration {
+    ///     days: 104_249_991_374,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(duration.validate(), Ok(()));
+    ///
+    /// let duration = Duration {
+    ///     days: 104_249_991_375,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(duration.validate(), Err(DurationError::OutOfRange));
+    ///
+    /// let duration = Duration {
+    ///     years: 1 << 32,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(duration.validate(), Err(DurationError::OutOfRange));
+    /// ```
+    pub fn validate(&self) -> Result<(), DurationError> {
+        if self.years >= MAX_CALENDAR_UNIT_VALUE
+            || self.months >= MAX_CALENDAR_UNIT_VALUE
+            || self.weeks >= MAX_CALENDAR_UNIT_VALUE
+            || self.total_nanoseconds() >= MAX_NANOSECONDS
+        {
+            return Err(DurationError::OutOfRange);
+        }
+        Ok(())
+    }
+
+    /// Creates a [`Duration`] from signed values of its units, in descending order from years
+    /// to nanoseconds.
+    ///
+    /// Returns an error if the values have mixed signs or if the duration is not
+    /// [valid](Duration::validate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::duration::{Duration, DurationError, DurationSign};
+    ///
+    /// let duration = Duration::try_from_units([0, 0, 0, -1, -2, 0, 0, 0, 0, 0])
+    ///     .expect("signs are not mixed");
+    /// assert_eq!(
+    ///     duration,
+    ///     Duration {
+    ///         sign: DurationSign::Negative,
+    ///         days: 1,
+    ///         hours: 2,
+    ///         ..Default::default()
+    ///     }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Duration::try_from_units([0, 0, 0, -1, 2, 0, 0, 0, 0, 0]),
+    ///     Err(DurationError::MixedSigns)
+    /// );
+    /// ```
+    pub fn try_from_units(units: [i64; 10]) -> Result<Self, DurationError> {
+        let sign = if units.iter().any(|unit| unit.is_negative()) {
+            if units.iter().any(|unit| unit.is_positive()) {
+                return Err(DurationError::MixedSigns);
+            }
+            DurationSign::Negative
+        } else {
+            DurationSign::Positive
+        };
+        let [
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        ] = units.map(i64::unsigned_abs);
+        let duration = Self {
+            sign,
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+        };
+        duration.validate()?;
+        Ok(duration)
+    }
+
+    /// Parses an ISO 8601 duration string, such as `"P1Y2M10DT2H30M"` or `"-PT1.5S"`, into a
+    /// [`Duration`].
+    ///
+    /// A fraction of an hour or a minute is balanced into the smaller units. Returns an error
+    /// if the string is not a valid duration string or if the duration is not
+    /// [valid](Duration::validate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::duration::{Duration, DurationSign};
+    ///
+    /// let duration = Duration::try_from_str("P1Y2M10DT2H30M").unwrap();
+    /// assert_eq!(
+    ///     duration,
+    ///     Duration {
+    ///         years: 1,
+    ///         months: 2,
+    ///         days: 10,
+    ///         hours: 2,
+    ///         minutes: 30,
+    ///         ..Default::default()
+    ///     }
+    /// );
+    ///
+    /// let duration = Duration::try_from_str("-PT1.5H").unwrap();
+    /// assert_eq!(
+    ///     duration,
+    ///     Duration {
+    ///         sign: DurationSign::Negative,
+    ///         hours: 1,
+    ///         minutes: 30,
+    ///         ..Default::default()
+    ///     }
+    /// );
+    ///
+    /// assert!(Duration::try_from_str("P1H").is_err());
+    /// ```
+    pub fn try_from_str(s: &str) -> Result<Self, DurationError> {
+        Self::try_from_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::try_from_str`].
+    pub fn try_from_utf8(code_units: &[u8]) -> Result<Self, DurationError> {
+        let record = IsoDurationParser::<Utf8>::from_utf8(code_units).parse()?;
+        Self::try_from_ixdtf_record(&record)
+    }
+
+    /// Creates a [`Duration`] from a [`DurationParseRecord`] of the `ixdtf` crate.
+    ///
+    /// See [`Self::try_from_str`].
+    pub fn try_from_ixdtf_record(record: &DurationParseRecord) -> Result<Self, DurationError> {
+        let mut duration = Self::new();
+        if let Some(date) = record.date {
+            duration.years = u64::from(date.years);
+            duration.months = u64::from(date.months);
+            duration.weeks = u64::from(date.weeks);
+            duration.days = date.days;
+        }
+        if let Some(time) = record.time {
+            let (fraction, fraction_unit) = match time {
+                TimeDurationRecord::Hours { hours, fraction } => {
+                    duration.hours = hours;
+                    (fraction, NANOSECONDS_PER_HOUR)
+                }
+                TimeDurationRecord::Minutes {
+                    hours,
+                    minutes,
+                    fraction,
+                } => {
+                    duration.hours = hours;
+                    duration.minutes = minutes;
+                    (fraction, NANOSECONDS_PER_MINUTE)
+                }
+                TimeDurationRecord::Seconds {
+                    hours,
+                    minutes,
+                    seconds,
+                    fraction,
+                } => {
+                    duration.hours = hours;
+                    duration.minutes = minutes;
+                    duration.seconds = seconds;
+                    (fraction, NANOSECONDS_PER_SECOND)
+                }
+            };
+            if let Some(fraction) = fraction {
+                // ECMA-262 allows at most nine fractional digits.
+                let fraction = fraction
+                    .to_nanoseconds()
+                    .ok_or(DurationError::Syntax(ParseError::FractionPart))?;
+                // The fraction is less than one of the largest time unit present, so the
+                // smaller units were all zero before.
+                let mut rest = u128::from(fraction) * (fraction_unit / NANOSECONDS_PER_SECOND);
+                let mut take = |unit: u128| {
+                    let value = rest / unit;
+                    rest %= unit;
+                    value as u64
+                };
+                duration.minutes += take(NANOSECONDS_PER_MINUTE);
+                duration.seconds += take(NANOSECONDS_PER_SECOND);
+                duration.milliseconds = take(NANOSECONDS_PER_MILLISECOND);
+                duration.microseconds = take(NANOSECONDS_PER_MICROSECOND);
+                duration.nanoseconds = take(1);
+            }
+        }
+        if record.sign == Sign::Negative && duration.iter_units().iter().any(|&unit| unit != 0) {
+            duration.sign = DurationSign::Negative;
+        }
+        duration.validate()?;
+        Ok(duration)
+    }
+}
+
+impl FromStr for Duration {
+    type Err = DurationError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s)
+    }
+}
+
+/// An error returned when constructing or rounding a [`Duration`].
+#[derive(Debug, Clone, Copy, PartialEq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum DurationError {
+    /// Syntax error.
+    #[displaydoc("Syntax error in the ISO 8601 duration string: {0}")]
+    Syntax(ParseError),
+    /// The units of the duration have mixed signs.
+    MixedSigns,
+    /// The duration is outside of the range allowed by ECMA-402.
+    OutOfRange,
+    /// The largest unit is smaller than the smallest unit.
+    InvalidUnitRange,
+    /// Years, months and weeks require a relative date to be balanced or rounded.
+    MissingRelativeDate,
+}
+
+impl core::error::Error for DurationError {}
+
+impl From<ParseError> for DurationError {
+    fn from(value: ParseError) -> Self {
+        Self::Syntax(value)
+    }
 }
 
 /// Describes whether a [`Duration`] is positive or negative.
@@ -98,3 +380,108 @@ impl Duration {
         Self::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_str() {
+        let cases = [
+            ("PT90M", Ok([0, 0, 0, 0, 0, 90, 0, 0, 0, 0])),
+            ("P1Y2M10DT2H30M", Ok([1, 2, 0, 10, 2, 30, 0, 0, 0, 0])),
+            ("P3W", Ok([0, 0, 3, 0, 0, 0, 0, 0, 0, 0])),
+            ("PT0.5H", Ok([0, 0, 0, 0, 0, 30, 0, 0, 0, 0])),
+            ("PT1.2345H", Ok([0, 0, 0, 0, 1, 14, 4, 200, 0, 0])),
+            ("PT1.5M", Ok([0, 0, 0, 0, 0, 1, 30, 0, 0, 0])),
+            ("PT1,000000001S", Ok([0, 0, 0, 0, 0, 0, 1, 0, 0, 1])),
+            ("PT0.123456789S", Ok([0, 0, 0, 0, 0, 0, 0, 123, 456, 789])),
+            (
+                "PT0.0000000001S",
+                Err(DurationError::Syntax(ParseError::FractionPart)),
+            ),
+            (
+                "P4294967296Y",
+                Err(DurationError::Syntax(
+                    ParseError::DurationValueExceededRange,
+                )),
+            ),
+            ("P4294967295Y", Ok([4294967295, 0, 0, 0, 0, 0, 0, 0, 0, 0])),
+            ("PT9007199254740992S", Err(DurationError::OutOfRange)),
+            (
+                "PT9007199254740991S",
+                Ok([0, 0, 0, 0, 0, 0, 9007199254740991, 0, 0, 0]),
+            ),
+            (
+                "PT9007199254740991.999999999S",
+                Ok([0, 0, 0, 0, 0, 0, 9007199254740991, 999, 999, 999]),
+            ),
+            (
+                "P104249991374DT7H36M31.999999999S",
+                Ok([0, 0, 0, 104249991374, 7, 36, 31, 999, 999, 999]),
+            ),
+            ("P104249991374DT7H36M32S", Err(DurationError::OutOfRange)),
+        ];
+        for (s, expected) in cases {
+            assert_eq!(
+                Duration::try_from_str(s).map(|d| d.iter_units()),
+                expected,
+                "{s}"
+            );
+        }
+
+        for s in ["P", "PT", "P1H", "PT1D", "P1DT", "1D", "P1.5D", "PT1.5H30M"] {
+            assert!(
+                matches!(Duration::try_from_str(s), Err(DurationError::Syntax(_))),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_try_from_str_sign() {
+        let duration: Duration = "-P1DT1.5S".parse().unwrap();
+        assert_eq!(
+            duration,
+            Duration {
+                sign: DurationSign::Negative,
+                days: 1,
+                seconds: 1,
+                milliseconds: 500,
+                ..Default::default()
+            }
+        );
+        assert_eq!(duration.get_sign(), fixed_decimal::Sign::Negative);
+
+        let duration: Duration = "+PT1S".parse().unwrap();
+        assert_eq!(duration.sign, DurationSign::Positive);
+
+        // A zero duration is positive.
+        let duration: Duration = "-PT0S".parse().unwrap();
+        assert_eq!(duration, Duration::new());
+    }
+
+    #[test]
+    fn test_try_from_units() {
+        assert_eq!(
+            Duration::try_from_units([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).map(|d| d.iter_units()),
+            Ok([1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
+        );
+        assert_eq!(
+            Duration::try_from_units([0, 0, 0, 0, 0, 0, 0, 0, 0, -10]).map(|d| d.sign),
+            Ok(DurationSign::Negative)
+        );
+        assert_eq!(
+            Duration::try_from_units([1, 0, 0, 0, 0, 0, 0, 0, 0, -10]),
+            Err(DurationError::MixedSigns)
+        );
+        assert_eq!(
+            Duration::try_from_units([0, 0, -4294967296, 0, 0, 0, 0, 0, 0, 0]),
+            Err(DurationError::OutOfRange)
+        );
+        assert_eq!(
+            Duration::try_from_units([0, 0, 0, 0, 0, 0, i64::MIN, 0, 0, 0]),
+            Err(DurationError::OutOfRange)
+        );
+    }
+}
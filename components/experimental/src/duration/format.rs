@@ -71,11 +71,11 @@ pub struct FormattedDuration<'l> {
 
 /// Exists to allow creating lists of heterogeneous [`Writeable`]s to pass to [`ListFormatter`].
 /// The (Unit, Decimal) pair is used to crerate [`FormattedUnit`]s.
-type HeterogenousToFormatter = Either<DigitalDuration, (Unit, Decimal)>;
+pub(super) type HeterogenousToFormatter = Either<DigitalDuration, (Unit, Decimal)>;
 
 /// Describes a formatted duration.
 #[derive(Default)]
-struct DigitalDuration {
+pub(super) struct DigitalDuration {
     hours: Option<Decimal>,
     add_hour_minute_separator: bool,
     minutes: Option<Decimal>,
@@ -94,6 +94,17 @@ impl DigitalDuration {
             seconds: self.seconds.as_ref().map(|s| fmt.fdf.format(s)),
         }
     }
+
+    /// Returns the largest unit of the digital duration, with its value.
+    pub(super) fn leading_unit(&self) -> Option<(Unit, &Decimal)> {
+        [
+            (Unit::Hour, &self.hours),
+            (Unit::Minute, &self.minutes),
+            (Unit::Second, &self.seconds),
+        ]
+        .into_iter()
+        .find_map(|(unit, value)| Some((unit, value.as_ref()?)))
+    }
 }
 
 struct FormattedDigitalDuration<'l> {
@@ -450,10 +461,9 @@ impl FormattedDuration<'_> {
         }
     }
 
-    fn partition_duration_format_pattern<V: PartsWrite + ?Sized>(
+    pub(super) fn partition_duration_format_pattern(
         &self,
-        sink: &mut V,
-    ) -> fmt::Result {
+    ) -> SmallVec<[HeterogenousToFormatter; 10]> {
         // We can have a maximum of 10 writeables (one FormattedUnit for each unit).
         // In the digital case, one or more unit is represented by the FormattedDigitalDuration,
         // which is a single writeable.
@@ -563,12 +573,12 @@ impl FormattedDuration<'_> {
         }
 
         // 5. Return ListFormatParts(durationFormat, result).
-        self.list_format_parts(parts_list, sink)
+        parts_list
     }
 
     /// 1.1.13 `ListFormatParts` ( durationFormat, partitionedPartsList )
     /// Given a partitioned part list of formatted duration parts, it creates and returns a List with all the corresponding parts according to the effective locale and the formatting options of durationFormat.
-    fn list_format_parts<V: PartsWrite + ?Sized, const CAP: usize>(
+    pub(super) fn list_format_parts<V: PartsWrite + ?Sized, const CAP: usize>(
         &self,
         parts_list: SmallVec<[HeterogenousToFormatter; CAP]>,
         sink: &mut V,
@@ -584,7 +594,23 @@ impl FormattedDuration<'_> {
         // 6. Let lf be ! Construct(%ListFormat%, « durationFormat.[[Locale]], lfOpts »).
 
         // Note: the above steps are performed while initializing DurationFormatter.
-        let formatted_list = self.fmt.list.format(parts_list.iter().map(|w| match w {
+        let formatted_list = self
+            .fmt
+            .list
+            .format(parts_list.iter().map(|w| self.format_part(w)));
+
+        formatted_list.write_to_parts(sink)?;
+
+        // 14. Return flattenedPartsList.
+        Ok(())
+    }
+
+    /// Formats one element of a partitioned part list.
+    pub(super) fn format_part<'a>(
+        &'a self,
+        part: &'a HeterogenousToFormatter,
+    ) -> impl Writeable + 'a {
+        match part {
             Either::Left(digital) => Either::Left(digital.format(self.fmt)),
             Either::Right((unit, formatted_fixed_decimal)) => {
                 let nf = &self.fmt.unit[*unit];
@@ -598,18 +624,14 @@ impl FormattedDuration<'_> {
                 };
                 Either::Right(formatted_unit)
             }
-        }));
-
-        formatted_list.write_to_parts(sink)?;
-
-        // 14. Return flattenedPartsList.
-        Ok(())
+        }
     }
 }
 
 impl Writeable for FormattedDuration<'_> {
     fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
-        self.partition_duration_format_pattern(sink)
+        let parts_list = self.partition_duration_format_pattern();
+        self.list_format_parts(parts_list, sink)
     }
 }
 
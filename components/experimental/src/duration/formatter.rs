@@ -27,6 +27,7 @@ use icu_list::{ListFormatter, ListFormatterPreferences, options::ListLength};
 use icu_locale_core::preferences::{
     define_preferences, extensions::unicode::keywords::NumberingSystem, prefs_convert,
 };
+use icu_plurals::PluralRulesPreferences;
 use icu_provider::prelude::*;
 
 define_preferences!(
@@ -50,6 +51,7 @@ prefs_convert!(DurationFormatterPreferences, DecimalFormatterPreferences, {
     numbering_system
 });
 prefs_convert!(DurationFormatterPreferences, ListFormatterPreferences);
+prefs_convert!(DurationFormatterPreferences, PluralRulesPreferences);
 
 /// A formatter for [`Duration`](crate::duration::Duration)s.
 ///
@@ -126,7 +128,7 @@ impl DurationUnitFormatter {
     ) -> Result<Self, DataError> {
         let get_unit_formatter = |unit: Unit, style| {
             let w = DurationUnitFormatter::field_style_to_unit_width(style, options.base);
-            let options = UnitsFormatterOptions { width: w };
+            let options = UnitsFormatterOptions::from(w);
 
             CategorizedFormatter::<crate::measure::category::Duration>::try_new_outlier(
                 (&prefs).into(),
@@ -175,7 +177,7 @@ impl DurationUnitFormatter {
     ) -> Result<Self, DataError> {
         let get_unit_formatter = |unit: Unit, style| {
             let w = DurationUnitFormatter::field_style_to_unit_width(style, options.base);
-            let options = UnitsFormatterOptions { width: w };
+            let options = UnitsFormatterOptions::from(w);
 
             CategorizedFormatter::<crate::measure::category::Duration>::try_new_outlier_unstable(
                 provider,
@@ -6,16 +6,20 @@
 
 //! Duration formatting
 
+mod balance;
 mod duration;
 mod format;
 mod formatter;
 pub mod provider;
+mod relative;
 
 pub mod options;
 mod validated_options;
 
-pub use duration::{Duration, DurationSign};
+pub use balance::{DurationRoundingOptions, DurationUnit};
+pub use duration::{Duration, DurationError, DurationSign};
 pub use format::FormattedDuration;
 pub use formatter::DurationFormatter;
 pub use formatter::DurationFormatterPreferences;
+pub use relative::{FormattedRelativeDuration, RelativeDurationFormatter};
 pub use validated_options::{DurationFormatterOptionsError, ValidatedDurationFormatterOptions};
@@ -0,0 +1,526 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+use either::Either;
+use fixed_decimal::{Decimal, Sign};
+use icu_pattern::{PatternItem, SinglePlaceholderPattern};
+use icu_plurals::PluralRules;
+use icu_plurals::provider::PluralsCardinalV1;
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::*;
+use writeable::{PartsWrite, Writeable};
+
+use super::format::{FormattedDuration, HeterogenousToFormatter, parts};
+use super::formatter::DurationFormatterPreferences;
+use super::options::BaseStyle;
+use super::validated_options::Unit;
+use super::{Duration, DurationFormatter, DurationSign, ValidatedDurationFormatterOptions};
+use crate::dimension::provider::units::categorized_display_names::{
+    UnitsNamesDurationCoreV1, UnitsNamesDurationExtendedV1, UnitsNamesDurationOutlierV1,
+};
+use crate::relativetime::FormattedRelativeTime;
+use crate::relativetime::options::Numeric;
+use crate::relativetime::provider::*;
+use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
+
+/// The relative time patterns of the units that have them.
+#[derive(Debug)]
+struct RelativePatterns {
+    year: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    month: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    week: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    day: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    hour: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    minute: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    second: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+}
+
+impl RelativePatterns {
+    /// Returns the patterns of a unit. Units smaller than a second, which have no patterns,
+    /// use those of seconds.
+    fn get(&self, unit: Unit) -> &RelativeTimePatternData<'_> {
+        match unit {
+            Unit::Year => self.year.get(),
+            Unit::Month => self.month.get(),
+            Unit::Week => self.week.get(),
+            Unit::Day => self.day.get(),
+            Unit::Hour => self.hour.get(),
+            Unit::Minute => self.minute.get(),
+            Unit::Second | Unit::Millisecond | Unit::Microsecond | Unit::Nanosecond => {
+                self.second.get()
+            }
+        }
+    }
+
+    fn try_new_unstable<D>(
+        provider: &D,
+        prefs: DurationFormatterPreferences,
+        base: BaseStyle,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<LongSecondRelativeV1>
+            + DataProvider<LongMinuteRelativeV1>
+            + DataProvider<LongHourRelativeV1>
+            + DataProvider<LongDayRelativeV1>
+            + DataProvider<LongWeekRelativeV1>
+            + DataProvider<LongMonthRelativeV1>
+            + DataProvider<LongYearRelativeV1>
+            + DataProvider<ShortSecondRelativeV1>
+            + DataProvider<ShortMinuteRelativeV1>
+            + DataProvider<ShortHourRelativeV1>
+            + DataProvider<ShortDayRelativeV1>
+            + DataProvider<ShortWeekRelativeV1>
+            + DataProvider<ShortMonthRelativeV1>
+            + DataProvider<ShortYearRelativeV1>
+            + DataProvider<NarrowSecondRelativeV1>
+            + DataProvider<NarrowMinuteRelativeV1>
+            + DataProvider<NarrowHourRelativeV1>
+            + DataProvider<NarrowDayRelativeV1>
+            + DataProvider<NarrowWeekRelativeV1>
+            + DataProvider<NarrowMonthRelativeV1>
+            + DataProvider<NarrowYearRelativeV1>,
+    {
+        let locale = LongSecondRelativeV1::make_locale(prefs.locale_preferences);
+        let request = DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&locale),
+            ..Default::default()
+        };
+
+        macro_rules! load {
+            ($second: ty, $minute: ty, $hour: ty, $day: ty, $week: ty, $month: ty, $year: ty) => {
+                RelativePatterns {
+                    year: DataProvider::<$year>::load(provider, request)?
+                        .payload
+                        .cast(),
+                    month: DataProvider::<$month>::load(provider, request)?
+                        .payload
+                        .cast(),
+                    week: DataProvider::<$week>::load(provider, request)?
+                        .payload
+                        .cast(),
+                    day: DataProvider::<$day>::load(provider, request)?
+                        .payload
+                        .cast(),
+                    hour: DataProvider::<$hour>::load(provider, request)?
+                        .payload
+                        .cast(),
+                    minute: DataProvider::<$minute>::load(provider, request)?
+                        .payload
+                        .cast(),
+                    second: DataProvider::<$second>::load(provider, request)?
+                        .payload
+                        .cast(),
+                }
+            };
+        }
+
+        // The width of the patterns follows the style of the list, as in `ListFormatterOptions::from(BaseStyle)`.
+        Ok(match base {
+            BaseStyle::Long => load!(
+                LongSecondRelativeV1,
+                LongMinuteRelativeV1,
+                LongHourRelativeV1,
+                LongDayRelativeV1,
+                LongWeekRelativeV1,
+                LongMonthRelativeV1,
+                LongYearRelativeV1
+            ),
+            BaseStyle::Short | BaseStyle::Digital => load!(
+                ShortSecondRelativeV1,
+                ShortMinuteRelativeV1,
+                ShortHourRelativeV1,
+                ShortDayRelativeV1,
+                ShortWeekRelativeV1,
+                ShortMonthRelativeV1,
+                ShortYearRelativeV1
+            ),
+            BaseStyle::Narrow => load!(
+                NarrowSecondRelativeV1,
+                NarrowMinuteRelativeV1,
+                NarrowHourRelativeV1,
+                NarrowDayRelativeV1,
+                NarrowWeekRelativeV1,
+                NarrowMonthRelativeV1,
+                NarrowYearRelativeV1
+            ),
+        })
+    }
+}
+
+/// A formatter for [`Duration`]s relative to now, such as "in 2 hours, 30 minutes"
+/// or "3 days, 4 hours ago".
+///
+/// The units are formatted and joined in the same way as by [`DurationFormatter`], with the
+/// same options. Positive durations are in the future and negative durations in the past.
+///
+/// The future or past framing comes from the CLDR relative time pattern of one of the units,
+/// such as "in {0} hours". It is applied to the first unit if the pattern has text before
+/// the number, as in "in 2 hours, 30 minutes", and to the last unit otherwise, as in
+/// "3 days, 4 hours ago", so that the framing ends up at the edge of the phrase and the
+/// framed unit takes the grammatical case that the pattern requires, as in German
+/// "vor 3 Tagen, 4 Stunden".
+///
+/// Units smaller than a second and units in the digital style have no relative time patterns
+/// of their own. If one of them is framed, the text that the patterns of the enclosing unit add
+/// for the future or the past, such as "in" or "ago", is placed around the whole phrase instead.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::duration::options::{BaseStyle, DurationFormatterOptions};
+/// use icu::experimental::duration::{
+///     Duration, DurationSign, RelativeDurationFormatter,
+///     ValidatedDurationFormatterOptions,
+/// };
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = DurationFormatterOptions::default();
+/// options.base = BaseStyle::Long;
+/// let options = ValidatedDurationFormatterOptions::validate(options).unwrap();
+/// let formatter =
+///     RelativeDurationFormatter::try_new(locale!("en").into(), options).unwrap();
+///
+/// let duration = Duration {
+///     hours: 2,
+///     minutes: 30,
+///     ..Default::default()
+/// };
+/// assert_writeable_eq!(
+///     formatter.format(&duration),
+///     "in 2 hours, 30 minutes"
+/// );
+///
+/// let duration = Duration {
+///     sign: DurationSign::Negative,
+///     days: 3,
+///     hours: 4,
+///     ..Default::default()
+/// };
+/// assert_writeable_eq!(formatter.format(&duration), "3 days, 4 hours ago");
+/// ```
+#[derive(Debug)]
+pub struct RelativeDurationFormatter {
+    duration: DurationFormatter,
+    patterns: RelativePatterns,
+    plural_rules: PluralRules,
+}
+
+impl RelativeDurationFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: DurationFormatterPreferences, options: ValidatedDurationFormatterOptions) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`RelativeDurationFormatter`] from compiled locale data and an options bag.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: DurationFormatterPreferences,
+        options: ValidatedDurationFormatterOptions,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            duration: DurationFormatter::try_new(prefs, options)?,
+            patterns: RelativePatterns::try_new_unstable(
+                &crate::provider::Baked,
+                prefs,
+                options.base,
+            )?,
+            plural_rules: PluralRules::try_new_cardinal((&prefs).into())?,
+        })
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: DurationFormatterPreferences,
+        options: ValidatedDurationFormatterOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: ?Sized
+            + DataProvider<super::provider::DigitalDurationDataV1>
+            + DataProvider<UnitsNamesDurationCoreV1>
+            + DataProvider<UnitsNamesDurationExtendedV1>
+            + DataProvider<UnitsNamesDurationOutlierV1>
+            + DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<PluralsCardinalV1>
+            + DataProvider<icu_list::provider::ListUnitV1>
+            + DataProvider<LongSecondRelativeV1>
+            + DataProvider<LongMinuteRelativeV1>
+            + DataProvider<LongHourRelativeV1>
+            + DataProvider<LongDayRelativeV1>
+            + DataProvider<LongWeekRelativeV1>
+            + DataProvider<LongMonthRelativeV1>
+            + DataProvider<LongYearRelativeV1>
+            + DataProvider<ShortSecondRelativeV1>
+            + DataProvider<ShortMinuteRelativeV1>
+            + DataProvider<ShortHourRelativeV1>
+            + DataProvider<ShortDayRelativeV1>
+            + DataProvider<ShortWeekRelativeV1>
+            + DataProvider<ShortMonthRelativeV1>
+            + DataProvider<ShortYearRelativeV1>
+            + DataProvider<NarrowSecondRelativeV1>
+            + DataProvider<NarrowMinuteRelativeV1>
+            + DataProvider<NarrowHourRelativeV1>
+            + DataProvider<NarrowDayRelativeV1>
+            + DataProvider<NarrowWeekRelativeV1>
+            + DataProvider<NarrowMonthRelativeV1>
+            + DataProvider<NarrowYearRelativeV1>,
+    {
+        Ok(Self {
+            duration: DurationFormatter::try_new_unstable(provider, prefs, options)?,
+            patterns: RelativePatterns::try_new_unstable(provider, prefs, options.base)?,
+            plural_rules: PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?,
+        })
+    }
+
+    /// Formats a [`Duration`] relative to now into a [`FormattedRelativeDuration`].
+    pub fn format<'l>(&'l self, duration: &'l Duration) -> FormattedRelativeDuration<'l> {
+        FormattedRelativeDuration {
+            fmt: self,
+            duration,
+        }
+    }
+}
+
+/// The [`Writeable`] implementation that is returned by [`RelativeDurationFormatter::format`].
+/// See the [`writeable`] crate for how to consume this.
+#[derive(Debug)]
+pub struct FormattedRelativeDuration<'l> {
+    fmt: &'l RelativeDurationFormatter,
+    duration: &'l Duration,
+}
+
+impl FormattedRelativeDuration<'_> {
+    /// Returns the unit whose relative time patterns frame a part of the list, with its value.
+    fn frame_unit(part: &HeterogenousToFormatter) -> Option<(Unit, &Decimal)> {
+        match part {
+            Either::Left(digital) => digital.leading_unit(),
+            Either::Right((unit, value)) => Some((*unit, value)),
+        }
+    }
+
+    /// Returns the future or past pattern of a unit for a value.
+    fn pattern(&self, unit: Unit, value: &Decimal, past: bool) -> &SinglePlaceholderPattern {
+        let patterns = self.fmt.patterns.get(unit);
+        if past {
+            &patterns.past
+        } else {
+            &patterns.future
+        }
+        .get(value.into(), &self.fmt.plural_rules)
+    }
+
+    /// Returns the text that the future or past pattern of a unit adds before and after
+    /// the unit, such as `("in ", "")` for "in {0} hours", by removing the text that the
+    /// future and past patterns have in common next to the number.
+    fn affixes(&self, unit: Unit, value: &Decimal, past: bool) -> (String, String) {
+        let (future_before, future_after) = split(self.pattern(unit, value, false));
+        let (past_before, past_after) = split(self.pattern(unit, value, true));
+        let (before, after) = if past {
+            (&past_before, &past_after)
+        } else {
+            (&future_before, &future_after)
+        };
+
+        // Whitespace between the framing and the unit stays with the framing.
+        let common_before = common_suffix(&future_before, &past_before).trim_start();
+        let common_after = common_prefix(&future_after, &past_after).trim_end();
+        let (prefix, _) = before.split_at(before.len() - common_before.len());
+        let (_, suffix) = after.split_at(common_after.len());
+        (String::from(prefix), String::from(suffix))
+    }
+}
+
+impl Writeable for FormattedRelativeDuration<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let past = self.duration.get_sign() == Sign::Negative;
+        let absolute = Duration {
+            sign: DurationSign::Positive,
+            ..self.duration.clone()
+        };
+        let formatted = FormattedDuration {
+            fmt: &self.fmt.duration,
+            duration: &absolute,
+        };
+        let parts_list = formatted.partition_duration_format_pattern();
+
+        let Some((unit, value)) = parts_list.first().and_then(Self::frame_unit) else {
+            return formatted.list_format_parts(parts_list, sink);
+        };
+
+        // The framing is at the start of the phrase if the pattern has text before the number.
+        let framing_at_start = matches!(
+            self.pattern(unit, value, past).iter().next(),
+            Some(PatternItem::Literal(literal)) if !literal.trim().is_empty()
+        );
+        let index = if framing_at_start {
+            0
+        } else {
+            parts_list.len() - 1
+        };
+
+        match parts_list.get(index) {
+            Some(Either::Right((unit, value)))
+                if !matches!(
+                    unit,
+                    Unit::Millisecond | Unit::Microsecond | Unit::Nanosecond
+                ) =>
+            {
+                let relative = FormattedRelativeTime {
+                    patterns: self.fmt.patterns.get(*unit),
+                    plural_rules: &self.fmt.plural_rules,
+                    decimal_formatter: &self.fmt.duration.fdf,
+                    numeric: Numeric::Always,
+                    value: value.clone(),
+                    is_negative: past,
+                };
+                self.fmt
+                    .duration
+                    .list
+                    .format(parts_list.iter().enumerate().map(|(i, part)| {
+                        if i == index {
+                            Either::Left(&relative)
+                        } else {
+                            Either::Right(formatted.format_part(part))
+                        }
+                    }))
+                    .write_to_parts(sink)
+            }
+            part => {
+                let (prefix, suffix) = match part.and_then(Self::frame_unit) {
+                    Some((unit, value)) => self.affixes(unit, value, past),
+                    None => Default::default(),
+                };
+                sink.with_part(parts::LITERAL, |w| w.write_str(&prefix))?;
+                formatted.list_format_parts(parts_list, sink)?;
+                sink.with_part(parts::LITERAL, |w| w.write_str(&suffix))
+            }
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedRelativeDuration<'_>);
+
+/// Returns the text of a pattern before and after its placeholder.
+fn split(pattern: &SinglePlaceholderPattern) -> (String, String) {
+    let mut before = String::new();
+    let mut after = String::new();
+    let mut has_placeholder = false;
+    for item in pattern.iter() {
+        match item {
+            PatternItem::Literal(literal) if has_placeholder => after.push_str(literal),
+            PatternItem::Literal(literal) => before.push_str(literal),
+            PatternItem::Placeholder(_) => has_placeholder = true,
+        }
+    }
+    (before, after)
+}
+
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    a.split_at(len).0
+}
+
+fn common_suffix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len: usize = a
+        .chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    a.split_at(a.len() - len).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::duration::options::DurationFormatterOptions;
+    use icu_locale_core::{Locale, locale};
+
+    fn formatter(locale: Locale, base: BaseStyle) -> RelativeDurationFormatter {
+        let options = DurationFormatterOptions {
+            base,
+            ..Default::default()
+        };
+        let options = ValidatedDurationFormatterOptions::validate(options).unwrap();
+        RelativeDurationFormatter::try_new(locale.into(), options).unwrap()
+    }
+
+    fn format(formatter: &RelativeDurationFormatter, units: [i64; 10]) -> String {
+        let duration = Duration::try_from_units(units).unwrap();
+        formatter.format(&duration).write_to_string().into_owned()
+    }
+
+    #[test]
+    fn test_relative_duration() {
+        let long = formatter(locale!("en"), BaseStyle::Long);
+        let cases = [
+            ([0, 0, 0, 0, 2, 30, 0, 0, 0, 0], "in 2 hours, 30 minutes"),
+            ([0, 0, 0, -3, -4, 0, 0, 0, 0, 0], "3 days, 4 hours ago"),
+            ([1, 2, 0, 0, 0, 0, 0, 0, 0, 0], "in 1 year, 2 months"),
+            ([0, 0, 0, 0, 0, 0, -1, 0, 0, 0], "1 second ago"),
+            ([0, 0, 0, 0, 0, 0, 0, 500, 0, 0], "in 500 milliseconds"),
+            ([0, 0, 0, 0, 0, 0, 0, -500, 0, 0], "500 milliseconds ago"),
+        ];
+        for (units, expected) in cases {
+            assert_eq!(format(&long, units), expected, "{units:?}");
+        }
+
+        let short = formatter(locale!("en"), BaseStyle::Short);
+        assert_eq!(
+            format(&short, [0, 0, 0, 0, 2, 30, 0, 0, 0, 0]),
+            "in 2 hr., 30 min"
+        );
+
+        let digital = formatter(locale!("en"), BaseStyle::Digital);
+        assert_eq!(format(&digital, [0, 0, 0, 0, 1, 30, 0, 0, 0, 0]), "in 1:30");
+        assert_eq!(
+            format(&digital, [0, 0, 0, 0, -1, -30, 0, 0, 0, 0]),
+            "1:30 ago"
+        );
+    }
+
+    #[test]
+    fn test_relative_duration_locales() {
+        let cases = [
+            (
+                "de",
+                [0, 0, 0, -3, -4, 0, 0, 0, 0, 0],
+                "vor 3 Tagen, 4 Stunden",
+            ),
+            (
+                "de",
+                [0, 0, 0, 3, 4, 0, 0, 0, 0, 0],
+                "in 3 Tagen, 4 Stunden",
+            ),
+            ("ja", [0, 0, 0, 0, 2, 30, 0, 0, 0, 0], "2 時間 30 分後"),
+            ("ja", [0, 0, 0, 0, -2, -30, 0, 0, 0, 0], "2 時間 30 分前"),
+            ("ja", [0, 0, 0, 0, 0, 0, 0, -5, 0, 0], "5 ミリ秒前"),
+        ];
+        for (locale, units, expected) in cases {
+            let formatter = formatter(locale.parse().unwrap(), BaseStyle::Long);
+            assert_eq!(format(&formatter, units), expected, "{locale} {units:?}");
+        }
+    }
+}
@@ -27,6 +27,8 @@
 
 extern crate alloc;
 
+#[cfg(feature = "alphabetic_index")]
+pub mod alphabetic_index;
 pub mod dimension;
 pub mod displaynames;
 pub mod duration;
@@ -63,6 +65,7 @@ pub mod provider {
         impl_currency_patterns_data_v1!(Baked);
         impl_currency_extended_data_v1!(Baked);
         impl_currency_fractions_v1!(Baked);
+        impl_currency_regions_v1!(Baked);
         impl_currency_patterns_no_currency_v1!(Baked);
         impl_units_names_area_core_v1!(Baked);
         impl_units_names_area_extended_v1!(Baked);
@@ -79,6 +82,29 @@ pub mod provider {
         impl_units_names_volume_core_v1!(Baked);
         impl_units_names_volume_extended_v1!(Baked);
         impl_units_names_volume_outlier_v1!(Baked);
+        impl_units_names_consumption_core_v1!(Baked);
+        impl_units_names_consumption_extended_v1!(Baked);
+        impl_units_names_consumption_outlier_v1!(Baked);
+        impl_units_names_digital_core_v1!(Baked);
+        impl_units_names_digital_extended_v1!(Baked);
+        impl_units_names_digital_outlier_v1!(Baked);
+        impl_units_names_energy_core_v1!(Baked);
+        impl_units_names_energy_extended_v1!(Baked);
+        impl_units_names_energy_outlier_v1!(Baked);
+        impl_units_names_power_core_v1!(Baked);
+        impl_units_names_power_extended_v1!(Baked);
+        impl_units_names_power_outlier_v1!(Baked);
+        impl_units_names_pressure_core_v1!(Baked);
+        impl_units_names_pressure_extended_v1!(Baked);
+        impl_units_names_pressure_outlier_v1!(Baked);
+        impl_units_names_speed_core_v1!(Baked);
+        impl_units_names_speed_extended_v1!(Baked);
+        impl_units_names_speed_outlier_v1!(Baked);
+        impl_units_names_temperature_core_v1!(Baked);
+        impl_units_names_temperature_extended_v1!(Baked);
+        impl_units_names_temperature_outlier_v1!(Baked);
+        impl_units_names_other_v1!(Baked);
+        impl_units_compound_patterns_v1!(Baked);
         impl_units_essentials_v1!(Baked);
         impl_language_display_names_v1!(Baked);
         impl_digital_duration_data_v1!(Baked);
@@ -86,6 +112,9 @@ pub mod provider {
         impl_region_display_names_v1!(Baked);
         impl_script_display_names_v1!(Baked);
         impl_variant_display_names_v1!(Baked);
+        impl_key_display_names_v1!(Baked);
+        impl_currency_display_names_v1!(Baked);
+        impl_locale_display_names_patterns_v1!(Baked);
         impl_percent_essentials_v1!(Baked);
         impl_person_names_format_v1!(Baked);
         impl_long_day_relative_v1!(Baked);
@@ -114,6 +143,8 @@ pub mod provider {
         impl_short_year_relative_v1!(Baked);
         impl_transliterator_rules_v1!(Baked);
         impl_units_info_v1!(Baked);
+        impl_units_preferences_v1!(Baked);
+        impl_units_quantities_v1!(Baked);
         impl_unit_ids_v1!(Baked);
     };
 
@@ -129,6 +160,7 @@ pub mod provider {
         super::dimension::provider::currency::patterns::CurrencyPatternsDataV1::INFO,
         super::dimension::provider::currency::extended::CurrencyExtendedDataV1::INFO,
         super::dimension::provider::currency::fractions::CurrencyFractionsV1::INFO,
+        super::dimension::provider::currency::regions::CurrencyRegionsV1::INFO,
         super::dimension::provider::currency::no_currency::CurrencyPatternsNoCurrencyV1::INFO,
         super::dimension::provider::percent::PercentEssentialsV1::INFO,
         super::dimension::provider::units::essentials::UnitsEssentialsV1::INFO,
@@ -147,12 +179,38 @@ pub mod provider {
         super::dimension::provider::units::categorized_display_names::UnitsNamesVolumeCoreV1::INFO,
         super::dimension::provider::units::categorized_display_names::UnitsNamesVolumeExtendedV1::INFO,
         super::dimension::provider::units::categorized_display_names::UnitsNamesVolumeOutlierV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesConsumptionCoreV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesConsumptionExtendedV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesConsumptionOutlierV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesDigitalCoreV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesDigitalExtendedV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesDigitalOutlierV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesEnergyCoreV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesEnergyExtendedV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesEnergyOutlierV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesPowerCoreV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesPowerExtendedV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesPowerOutlierV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesPressureCoreV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesPressureExtendedV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesPressureOutlierV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesSpeedCoreV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesSpeedExtendedV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesSpeedOutlierV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesTemperatureCoreV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesTemperatureExtendedV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesTemperatureOutlierV1::INFO,
+        super::dimension::provider::units::categorized_display_names::UnitsNamesOtherV1::INFO,
+        super::dimension::provider::units::compound_patterns::UnitsCompoundPatternsV1::INFO,
         super::displaynames::provider::LanguageDisplayNamesV1::INFO,
         super::duration::provider::DigitalDurationDataV1::INFO,
         super::displaynames::provider::LocaleDisplayNamesV1::INFO,
         super::displaynames::provider::RegionDisplayNamesV1::INFO,
         super::displaynames::provider::ScriptDisplayNamesV1::INFO,
         super::displaynames::provider::VariantDisplayNamesV1::INFO,
+        super::displaynames::provider::KeyDisplayNamesV1::INFO,
+        super::displaynames::provider::CurrencyDisplayNamesV1::INFO,
+        super::displaynames::provider::LocaleDisplayNamesPatternsV1::INFO,
         super::measure::provider::UnitIdsV1::INFO,
         super::personnames::provider::PersonNamesFormatV1::INFO,
         super::relativetime::provider::LongDayRelativeV1::INFO,
@@ -181,5 +239,7 @@ pub mod provider {
         super::relativetime::provider::ShortYearRelativeV1::INFO,
         super::transliterate::provider::TransliteratorRulesV1::INFO,
         super::units::provider::UnitsInfoV1::INFO,
+        super::units::provider::UnitsPreferencesV1::INFO,
+        super::units::provider::UnitsQuantitiesV1::INFO,
     ];
 }
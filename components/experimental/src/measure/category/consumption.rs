@@ -0,0 +1,157 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "compiled_data")]
+use crate::measure::{
+    category::{CategorizedMeasureUnit, Consumption},
+    measureunit::MeasureUnit,
+    provider::{
+        si_prefix::{Base, SiPrefix},
+        single_unit::SingleUnit,
+    },
+    single_unit_vec::SingleUnitVec,
+};
+
+impl Consumption {
+    /// Returns a [`MeasureUnit`] representing fuel consumption in liters per kilometer.
+    pub fn liter_per_kilometer() -> CategorizedMeasureUnit<Consumption> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("liter-per-kilometer"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_LITER,
+                    },
+                    SingleUnit {
+                        power: -1,
+                        si_prefix: SiPrefix {
+                            power: 3,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_METER,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing fuel consumption in liters per 100 kilometers.
+    pub fn liter_per_100_kilometer() -> CategorizedMeasureUnit<Consumption> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("liter-per-100-kilometer"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_LITER,
+                    },
+                    SingleUnit {
+                        power: -1,
+                        si_prefix: SiPrefix {
+                            power: 3,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_METER,
+                    },
+                ]),
+                constant_denominator: 100,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing fuel consumption in miles per US gallon.
+    pub fn mile_per_gallon() -> CategorizedMeasureUnit<Consumption> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("mile-per-gallon"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_MILE,
+                    },
+                    SingleUnit {
+                        power: -1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_GALLON,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing fuel consumption in miles per imperial gallon.
+    pub fn mile_per_gallon_imperial() -> CategorizedMeasureUnit<Consumption> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("mile-per-gallon-imperial"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_MILE,
+                    },
+                    SingleUnit {
+                        power: -1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_GALLON_IMPERIAL,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measure::measureunit::MeasureUnit;
+
+    #[test]
+    fn test_consumption_category() {
+        for (unit, id) in [
+            (Consumption::liter_per_kilometer(), "liter-per-kilometer"),
+            (
+                Consumption::liter_per_100_kilometer(),
+                "liter-per-100-kilometer",
+            ),
+            (Consumption::mile_per_gallon(), "mile-per-gallon"),
+            (
+                Consumption::mile_per_gallon_imperial(),
+                "mile-per-gallon-imperial",
+            ),
+        ] {
+            let parsed = MeasureUnit::try_from_str(id).unwrap();
+            assert_eq!(unit.unit, parsed, "{id}");
+        }
+    }
+}
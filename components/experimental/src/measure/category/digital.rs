@@ -0,0 +1,211 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "compiled_data")]
+use crate::measure::{
+    category::{CategorizedMeasureUnit, Digital},
+    measureunit::MeasureUnit,
+    provider::{
+        si_prefix::{Base, SiPrefix},
+        single_unit::SingleUnit,
+    },
+    single_unit_vec::SingleUnitVec,
+};
+
+impl Digital {
+    /// Returns a [`MeasureUnit`] representing digital storage in bits.
+    pub fn bit() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("bit"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BIT,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing digital storage in bytes.
+    pub fn byte() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("byte"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BYTE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing digital storage in kilobits.
+    pub fn kilobit() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("kilobit"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 3,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BIT,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing digital storage in kilobytes.
+    pub fn kilobyte() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("kilobyte"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 3,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BYTE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing digital storage in megabits.
+    pub fn megabit() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("megabit"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 6,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BIT,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing digital storage in megabytes.
+    pub fn megabyte() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("megabyte"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 6,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BYTE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing digital storage in gigabits.
+    pub fn gigabit() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("gigabit"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 9,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BIT,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing digital storage in gigabytes.
+    pub fn gigabyte() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("gigabyte"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 9,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BYTE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing digital storage in terabytes.
+    pub fn terabyte() -> CategorizedMeasureUnit<Digital> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("terabyte"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 12,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BYTE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measure::measureunit::MeasureUnit;
+
+    #[test]
+    fn test_digital_category() {
+        for (unit, id) in [
+            (Digital::bit(), "bit"),
+            (Digital::byte(), "byte"),
+            (Digital::kilobit(), "kilobit"),
+            (Digital::kilobyte(), "kilobyte"),
+            (Digital::megabit(), "megabit"),
+            (Digital::megabyte(), "megabyte"),
+            (Digital::gigabit(), "gigabit"),
+            (Digital::gigabyte(), "gigabyte"),
+            (Digital::terabyte(), "terabyte"),
+        ] {
+            let parsed = MeasureUnit::try_from_str(id).unwrap();
+            assert_eq!(unit.unit, parsed, "{id}");
+        }
+    }
+}
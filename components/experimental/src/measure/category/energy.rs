@@ -0,0 +1,161 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "compiled_data")]
+use crate::measure::{
+    category::{CategorizedMeasureUnit, Energy},
+    measureunit::MeasureUnit,
+    provider::{
+        si_prefix::{Base, SiPrefix},
+        single_unit::SingleUnit,
+    },
+    single_unit_vec::SingleUnitVec,
+};
+
+impl Energy {
+    /// Returns a [`MeasureUnit`] representing energy in joules.
+    pub fn joule() -> CategorizedMeasureUnit<Energy> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("joule"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_JOULE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing energy in kilojoules.
+    pub fn kilojoule() -> CategorizedMeasureUnit<Energy> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("kilojoule"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 3,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_JOULE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing energy in calories.
+    pub fn calorie() -> CategorizedMeasureUnit<Energy> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("calorie"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_CALORIE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing energy in kilocalories.
+    pub fn kilocalorie() -> CategorizedMeasureUnit<Energy> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("kilocalorie"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 3,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_CALORIE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing energy in food calories.
+    pub fn foodcalorie() -> CategorizedMeasureUnit<Energy> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("foodcalorie"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_FOODCALORIE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing energy in kilowatt-hours.
+    pub fn kilowatt_hour() -> CategorizedMeasureUnit<Energy> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("kilowatt-hour"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 3,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_WATT,
+                    },
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_HOUR,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measure::measureunit::MeasureUnit;
+
+    #[test]
+    fn test_energy_category() {
+        for (unit, id) in [
+            (Energy::joule(), "joule"),
+            (Energy::kilojoule(), "kilojoule"),
+            (Energy::calorie(), "calorie"),
+            (Energy::kilocalorie(), "kilocalorie"),
+            (Energy::foodcalorie(), "foodcalorie"),
+            (Energy::kilowatt_hour(), "kilowatt-hour"),
+        ] {
+            let parsed = MeasureUnit::try_from_str(id).unwrap();
+            assert_eq!(unit.unit, parsed, "{id}");
+        }
+    }
+}
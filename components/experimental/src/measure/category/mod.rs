@@ -7,23 +7,21 @@ use core::marker::PhantomData;
 use icu_provider::{DataMarker, DynamicDataMarker};
 
 use crate::{
-    dimension::provider::units::{
-        categorized_display_names::{
-            UnitsNamesAreaCoreV1, UnitsNamesAreaExtendedV1, UnitsNamesAreaOutlierV1,
-            UnitsNamesDurationCoreV1, UnitsNamesDurationExtendedV1, UnitsNamesDurationOutlierV1,
-            UnitsNamesLengthCoreV1, UnitsNamesLengthExtendedV1, UnitsNamesLengthOutlierV1,
-            UnitsNamesMassCoreV1, UnitsNamesMassExtendedV1, UnitsNamesMassOutlierV1,
-            UnitsNamesVolumeCoreV1, UnitsNamesVolumeExtendedV1, UnitsNamesVolumeOutlierV1,
-        },
-        display_names::UnitsDisplayNames,
-    },
+    dimension::provider::units::{categorized_display_names::*, display_names::UnitsDisplayNames},
     measure::measureunit::MeasureUnit,
 };
 
 pub mod area;
+pub mod consumption;
+pub mod digital;
 pub mod duration;
+pub mod energy;
 pub mod length;
 pub mod mass;
+pub mod power;
+pub mod pressure;
+pub mod speed;
+pub mod temperature;
 pub mod volume;
 
 pub trait MeasureUnitCategory {
@@ -77,6 +75,45 @@ pub struct Mass;
 #[non_exhaustive]
 pub struct Volume;
 
+/// A [`MeasureUnit`] that is related to the fuel consumption category, such as liters per 100 kilometers or miles per gallon.
+///
+/// Note that converting between these two kinds of units is a reciprocal conversion.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Consumption;
+
+/// A [`MeasureUnit`] that is related to the digital storage category.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Digital;
+
+/// A [`MeasureUnit`] that is related to the energy category.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Energy;
+
+/// A [`MeasureUnit`] that is related to the power category.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Power;
+
+/// A [`MeasureUnit`] that is related to the pressure category.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Pressure;
+
+/// A [`MeasureUnit`] that is related to the speed category.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Speed;
+
+/// A [`MeasureUnit`] that is related to the temperature category.
+///
+/// Note that converting between temperature units requires an offset, such as from `celsius` to `fahrenheit`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Temperature;
+
 impl MeasureUnitCategory for Area {
     type DataMarkerCore = UnitsNamesAreaCoreV1;
     type DataMarkerExtended = UnitsNamesAreaExtendedV1;
@@ -102,3 +139,38 @@ impl MeasureUnitCategory for Volume {
     type DataMarkerExtended = UnitsNamesVolumeExtendedV1;
     type DataMarkerOutlier = UnitsNamesVolumeOutlierV1;
 }
+impl MeasureUnitCategory for Consumption {
+    type DataMarkerCore = UnitsNamesConsumptionCoreV1;
+    type DataMarkerExtended = UnitsNamesConsumptionExtendedV1;
+    type DataMarkerOutlier = UnitsNamesConsumptionOutlierV1;
+}
+impl MeasureUnitCategory for Digital {
+    type DataMarkerCore = UnitsNamesDigitalCoreV1;
+    type DataMarkerExtended = UnitsNamesDigitalExtendedV1;
+    type DataMarkerOutlier = UnitsNamesDigitalOutlierV1;
+}
+impl MeasureUnitCategory for Energy {
+    type DataMarkerCore = UnitsNamesEnergyCoreV1;
+    type DataMarkerExtended = UnitsNamesEnergyExtendedV1;
+    type DataMarkerOutlier = UnitsNamesEnergyOutlierV1;
+}
+impl MeasureUnitCategory for Power {
+    type DataMarkerCore = UnitsNamesPowerCoreV1;
+    type DataMarkerExtended = UnitsNamesPowerExtendedV1;
+    type DataMarkerOutlier = UnitsNamesPowerOutlierV1;
+}
+impl MeasureUnitCategory for Pressure {
+    type DataMarkerCore = UnitsNamesPressureCoreV1;
+    type DataMarkerExtended = UnitsNamesPressureExtendedV1;
+    type DataMarkerOutlier = UnitsNamesPressureOutlierV1;
+}
+impl MeasureUnitCategory for Speed {
+    type DataMarkerCore = UnitsNamesSpeedCoreV1;
+    type DataMarkerExtended = UnitsNamesSpeedExtendedV1;
+    type DataMarkerOutlier = UnitsNamesSpeedOutlierV1;
+}
+impl MeasureUnitCategory for Temperature {
+    type DataMarkerCore = UnitsNamesTemperatureCoreV1;
+    type DataMarkerExtended = UnitsNamesTemperatureExtendedV1;
+    type DataMarkerOutlier = UnitsNamesTemperatureOutlierV1;
+}
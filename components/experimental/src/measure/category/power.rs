@@ -0,0 +1,111 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "compiled_data")]
+use crate::measure::{
+    category::{CategorizedMeasureUnit, Power},
+    measureunit::MeasureUnit,
+    provider::{
+        si_prefix::{Base, SiPrefix},
+        single_unit::SingleUnit,
+    },
+    single_unit_vec::SingleUnitVec,
+};
+
+impl Power {
+    /// Returns a [`MeasureUnit`] representing power in watts.
+    pub fn watt() -> CategorizedMeasureUnit<Power> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("watt"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_WATT,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing power in kilowatts.
+    pub fn kilowatt() -> CategorizedMeasureUnit<Power> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("kilowatt"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 3,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_WATT,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing power in megawatts.
+    pub fn megawatt() -> CategorizedMeasureUnit<Power> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("megawatt"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 6,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_WATT,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing power in horsepower.
+    pub fn horsepower() -> CategorizedMeasureUnit<Power> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("horsepower"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_HORSEPOWER,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measure::measureunit::MeasureUnit;
+
+    #[test]
+    fn test_power_category() {
+        for (unit, id) in [
+            (Power::watt(), "watt"),
+            (Power::kilowatt(), "kilowatt"),
+            (Power::megawatt(), "megawatt"),
+            (Power::horsepower(), "horsepower"),
+        ] {
+            let parsed = MeasureUnit::try_from_str(id).unwrap();
+            assert_eq!(unit.unit, parsed, "{id}");
+        }
+    }
+}
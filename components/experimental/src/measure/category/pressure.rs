@@ -0,0 +1,244 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "compiled_data")]
+use crate::measure::{
+    category::{CategorizedMeasureUnit, Pressure},
+    measureunit::MeasureUnit,
+    provider::{
+        si_prefix::{Base, SiPrefix},
+        single_unit::SingleUnit,
+    },
+    single_unit_vec::SingleUnitVec,
+};
+
+impl Pressure {
+    /// Returns a [`MeasureUnit`] representing pressure in pascals.
+    pub fn pascal() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("pascal"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_PASCAL,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing pressure in hectopascals.
+    pub fn hectopascal() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("hectopascal"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 2,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_PASCAL,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing pressure in kilopascals.
+    pub fn kilopascal() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("kilopascal"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 3,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_PASCAL,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing pressure in bars.
+    pub fn bar() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("bar"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BAR,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing pressure in millibars.
+    pub fn millibar() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("millibar"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: -3,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_BAR,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing pressure in atmospheres.
+    pub fn atmosphere() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("atmosphere"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_ATMOSPHERE,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing pressure in millimeters of mercury.
+    pub fn millimeter_ofhg() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("millimeter-ofhg"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: -3,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_METER,
+                    },
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_OFHG,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing pressure in inches of mercury.
+    pub fn inch_ofhg() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("inch-ofhg"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_INCH,
+                    },
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_OFHG,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing pressure in pounds-force per square inch.
+    pub fn pound_force_per_square_inch() -> CategorizedMeasureUnit<Pressure> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("pound-force-per-square-inch"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_POUND_FORCE,
+                    },
+                    SingleUnit {
+                        power: -2,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_INCH,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measure::measureunit::MeasureUnit;
+
+    #[test]
+    fn test_pressure_category() {
+        for (unit, id) in [
+            (Pressure::pascal(), "pascal"),
+            (Pressure::hectopascal(), "hectopascal"),
+            (Pressure::kilopascal(), "kilopascal"),
+            (Pressure::bar(), "bar"),
+            (Pressure::millibar(), "millibar"),
+            (Pressure::atmosphere(), "atmosphere"),
+            (Pressure::millimeter_ofhg(), "millimeter-ofhg"),
+            (Pressure::inch_ofhg(), "inch-ofhg"),
+            (
+                Pressure::pound_force_per_square_inch(),
+                "pound-force-per-square-inch",
+            ),
+        ] {
+            let parsed = MeasureUnit::try_from_str(id).unwrap();
+            assert_eq!(unit.unit, parsed, "{id}");
+        }
+    }
+}
@@ -0,0 +1,141 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![cfg(feature = "compiled_data")]
+use crate::measure::{
+    category::{CategorizedMeasureUnit, Speed},
+    measureunit::MeasureUnit,
+    provider::{
+        si_prefix::{Base, SiPrefix},
+        single_unit::SingleUnit,
+    },
+    single_unit_vec::SingleUnitVec,
+};
+
+impl Speed {
+    /// Returns a [`MeasureUnit`] representing speed in kilometers per hour.
+    pub fn kilometer_per_hour() -> CategorizedMeasureUnit<Speed> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("kilometer-per-hour"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 3,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_METER,
+                    },
+                    SingleUnit {
+                        power: -1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_HOUR,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing speed in meters per second.
+    pub fn meter_per_second() -> CategorizedMeasureUnit<Speed> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("meter-per-second"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_METER,
+                    },
+                    SingleUnit {
+                        power: -1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_SECOND,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing speed in miles per hour.
+    pub fn mile_per_hour() -> CategorizedMeasureUnit<Speed> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("mile-per-hour"),
+                single_units: SingleUnitVec::Two([
+                    SingleUnit {
+                        power: 1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_MILE,
+                    },
+                    SingleUnit {
+                        power: -1,
+                        si_prefix: SiPrefix {
+                            power: 0,
+                            base: Base::Decimal,
+                        },
+                        unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_HOUR,
+                    },
+                ]),
+                constant_denominator: 0,
+            },
+        }
+    }
+
+    /// Returns a [`MeasureUnit`] representing speed in knots.
+    pub fn knot() -> CategorizedMeasureUnit<Speed> {
+        CategorizedMeasureUnit {
+            _category: core::marker::PhantomData,
+            unit: MeasureUnit {
+                id: Some("knot"),
+                single_units: SingleUnitVec::One(SingleUnit {
+                    power: 1,
+                    si_prefix: SiPrefix {
+                        power: 0,
+                        base: Base::Decimal,
+                    },
+                    unit_id: *crate::provider::Baked::UNIT_IDS_V1_UND_KNOT,
+                }),
+                constant_denominator: 0,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measure::measureunit::MeasureUnit;
+
+    #[test]
+    fn test_speed_category() {
+        for (unit, id) in [
+            (Speed::kilometer_per_hour(), "kilometer-per-hour"),
+            (Speed::meter_per_second(), "meter-per-second"),
+            (Speed::mile_per_hour(), "mile-per-hour"),
+            (Speed::knot(), "knot"),
+        ] {
+            let parsed = MeasureUnit::try_from_str(id).unwrap();
+            assert_eq!(unit.unit, parsed, "{id}");
+        }
+    }
+}
@@ -0,0 +1,41 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::measureunit::MeasureUnit;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// The [`MixedMeasureUnit`] struct represents a processed CLDR mixed unit.
+///
+/// A value in a mixed unit is expressed in several units, from the largest to the smallest,
+/// such as "5 ft 3 in". Examples include:
+///  1. `foot-and-inch`
+///  2. `hour-and-minute-and-second`
+///  3. `meter-and-centimeter`
+///  4. `foot` (Note: a single unit is a special case of a mixed unit containing only one unit.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MixedMeasureUnit {
+    /// The CLDR ID of the mixed unit.
+    pub(crate) id: String,
+
+    /// Contains the processed units, from the largest to the smallest.
+    pub(crate) units: Vec<MeasureUnit>,
+}
+
+impl MixedMeasureUnit {
+    /// Returns the units of this mixed unit, from the largest to the smallest.
+    pub fn units(&self) -> &[MeasureUnit] {
+        &self.units
+    }
+
+    /// Returns the CLDR ID of this mixed unit, such as `foot-and-inch`.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the CLDR IDs of the units of this mixed unit, such as `foot` and `inch`.
+    pub fn unit_ids(&self) -> impl Iterator<Item = &str> {
+        self.id.split("-and-")
+    }
+}
@@ -6,6 +6,7 @@
 
 pub mod category;
 pub mod measureunit;
+pub mod mixedmeasureunit;
 pub mod parser;
 pub mod provider;
 pub mod single_unit_vec;
@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::string::String;
 use zerotrie::ZeroTrieSimpleAscii;
 
 use crate::measure::provider::single_unit::UnitID;
@@ -11,6 +12,13 @@ pub fn unit_id(id: &str) -> Option<UnitID> {
     CLDR_IDS_TRIE.get(id).map(|value| value as UnitID)
 }
 
+/// Maps unique identifiers back to their simple CLDR unit names.
+pub(crate) fn unit_name(unit_id: UnitID) -> Option<String> {
+    CLDR_IDS_TRIE
+        .iter()
+        .find_map(|(name, value)| (value == unit_id as usize).then_some(name))
+}
+
 #[test]
 fn all_ids_u16() {
     for (_, value) in CLDR_IDS_TRIE.iter() {
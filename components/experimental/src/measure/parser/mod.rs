@@ -7,6 +7,8 @@ pub(crate) mod power;
 pub(crate) mod si_prefix;
 
 use crate::measure::measureunit::MeasureUnit;
+use crate::measure::mixedmeasureunit::MixedMeasureUnit;
+use alloc::string::String;
 use displaydoc::Display;
 use ids::CLDR_IDS_TRIE;
 use power::get_power;
@@ -200,9 +202,47 @@ impl MeasureUnit {
     }
 }
 
+impl MixedMeasureUnit {
+    /// Parses a CLDR mixed unit identifier and returns a [`MixedMeasureUnit`].
+    /// Examples include: `foot-and-inch`, `hour-and-minute-and-second`, `meter-and-centimeter`, `foot`, etc.
+    ///
+    /// Each unit of a mixed unit is a single unit, possibly with a power and an SI prefix,
+    /// such as `square-meter`, and not a compound unit, such as `meter-per-second`.
+    /// Returns:
+    ///    - `Ok(MixedMeasureUnit)` if the identifier is valid.
+    ///    - `Err(InvalidUnitError)` if the identifier is invalid.
+    #[inline]
+    pub fn try_from_str(s: &str) -> Result<MixedMeasureUnit, InvalidUnitError> {
+        Self::try_from_utf8(s.as_bytes())
+    }
+
+    /// See [`Self::try_from_str`]
+    pub fn try_from_utf8(code_units: &[u8]) -> Result<MixedMeasureUnit, InvalidUnitError> {
+        let id = str::from_utf8(code_units).map_err(|_| InvalidUnitError)?;
+        let units = id
+            .split("-and-")
+            .map(|unit| {
+                let unit = MeasureUnit::try_from_str(unit)?;
+                match unit.single_units() {
+                    [single_unit] if single_unit.power > 0 && unit.constant_denominator == 0 => {
+                        Ok(unit)
+                    }
+                    _ => Err(InvalidUnitError),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(MixedMeasureUnit {
+            id: String::from(id),
+            units,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::measure::measureunit::MeasureUnit;
+    use crate::measure::mixedmeasureunit::MixedMeasureUnit;
 
     #[test]
     fn test_parser_cases() {
@@ -286,4 +326,37 @@ mod tests {
             assert!(measure_unit.is_err());
         }
     }
+
+    #[test]
+    fn test_mixed_units() {
+        let test_cases = vec![
+            ("foot-and-inch", 2),
+            ("hour-and-minute-and-second", 3),
+            ("meter-and-centimeter", 2),
+            ("square-meter-and-square-centimeter", 2),
+            ("foot", 1),
+        ];
+
+        for (input, expected_len) in test_cases {
+            let mixed_unit = MixedMeasureUnit::try_from_str(input).unwrap();
+            assert_eq!(mixed_unit.units().len(), expected_len);
+            assert_eq!(mixed_unit.unit_ids().count(), expected_len);
+            assert_eq!(mixed_unit.id(), input);
+        }
+
+        let invalid_cases = vec![
+            "-and-mile",
+            "mile-and-",
+            "mile-and-and-foot",
+            "per-hour-and-hertz",
+            "hertz-and-per-hour",
+            "kilonewton-meter-and-newton-meter",
+            "meter-per-second-and-foot",
+            "per-1000-and-per-1000",
+        ];
+
+        for input in invalid_cases {
+            assert!(MixedMeasureUnit::try_from_str(input).is_err(), "{input}");
+        }
+    }
 }
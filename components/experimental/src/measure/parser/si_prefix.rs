@@ -2,6 +2,7 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use alloc::string::String;
 use zerotrie::ZeroTrieSimpleAscii;
 
 use crate::measure::provider::si_prefix::{Base, SiPrefix};
@@ -136,3 +137,21 @@ pub fn get_si_prefix(part: &[u8]) -> (SiPrefix, &[u8]) {
         part,
     )
 }
+
+/// Returns the name of the given SI prefix, such as `kilo` for the decimal prefix of power `3`.
+///
+/// NOTE:
+///    if the power of the prefix is `0`, the function will return an empty string.
+pub(crate) fn si_prefix_name(si_prefix: SiPrefix) -> Option<String> {
+    if si_prefix.power == 0 {
+        return Some(String::new());
+    }
+    match si_prefix.base {
+        Base::Decimal => DECIMAL_PREFIXES_TRIE.iter().find_map(|(name, value)| {
+            (value as i16 - SI_PREFIXES_OFFSET as i16 == si_prefix.power as i16).then_some(name)
+        }),
+        Base::Binary => BINARY_TRIE
+            .iter()
+            .find_map(|(name, value)| (value == si_prefix.power as usize).then_some(name)),
+    }
+}
@@ -13,6 +13,7 @@ use super::api::{
     FormattingOrder, NameField, NameFieldKind, PersonName, PersonNamesFormatterError,
     PersonNamesFormatterOptions, PreferredOrder,
 };
+use super::parser::{self, ParsedPersonName};
 use super::provider::{
     PersonNamesFormat, PersonNamesFormatV1, PersonNamesFormattingAttributes,
     PersonNamesFormattingAttributesMask, PersonNamesFormattingData,
@@ -152,6 +153,56 @@ impl PersonNamesFormatter {
             .join(space_replacement))
     }
 
+    /// Parses a full name such as `"van der Berg, Anna Maria"` or `"山田 太郎"` into its fields.
+    ///
+    /// The script of the input and `name_locale` determine the name order, using the same
+    /// CLDR data as [`Self::format_to_string`]. When `name_locale` is `None`, the language is
+    /// guessed from the script of the input.
+    ///
+    /// Titles, generations, credentials and surname prefixes are only recognized for some
+    /// languages, and only when the language of the name is known.
+    ///
+    /// Returns [`PersonNamesFormatterError::InvalidPersonName`] if no given name or surname
+    /// could be found.
+    pub fn parse_person_name<P>(
+        &self,
+        provider: &P,
+        input: &str,
+        name_locale: Option<&Locale>,
+    ) -> Result<ParsedPersonName, PersonNamesFormatterError>
+    where
+        P: ?Sized + DataProvider<PersonNamesFormatV1>,
+    {
+        let person_name_locale = parser::input_locale(
+            input,
+            name_locale,
+            self.swe.as_borrowed(),
+            self.scripts.as_borrowed(),
+        );
+        let effective_locale = specifications::effective_locale(
+            &self.default_options.target_locale,
+            &person_name_locale,
+        );
+        let data: DataResponse<PersonNamesFormatV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&DataLocale::from(effective_locale)),
+                ..Default::default()
+            })
+            .map_err(PersonNamesFormatterError::Data)?;
+        let formatting_definition: &PersonNamesFormat = data.payload.get();
+        // A guessed locale such as "zh-Hani" would fall back to "und-Hani", skipping "zh".
+        let order_locale = name_locale
+            .cloned()
+            .unwrap_or_else(|| person_name_locale.id.language.into());
+        let order = specifications::name_order_derive(
+            &order_locale,
+            &formatting_definition.surname_first_locales,
+            &formatting_definition.given_first_locales,
+            self.fallbacker.as_borrowed(),
+        );
+        parser::parse_person_name(input, person_name_locale, order)
+    }
+
     fn final_person_names_formatter_options<N>(
         &self,
         locale: &Locale,
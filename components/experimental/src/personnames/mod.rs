@@ -10,9 +10,11 @@
 )] // todo
 
 pub use formatter::PersonNamesFormatter;
+pub use parser::{ParseConfidence, ParsedPersonName};
 
 pub mod api;
 pub mod formatter;
+mod parser;
 pub mod provided_struct;
 pub mod provider;
 
@@ -0,0 +1,685 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_locale_core::Locale;
+use icu_locale_core::subtags::{Language, language, script};
+use icu_properties::props::Script;
+use icu_properties::{PropertyNamesShortBorrowed, script::ScriptWithExtensionsBorrowed};
+use litemap::LiteMap;
+
+use crate::personnames::api::{
+    FieldModifierSet, FieldPart, FormattingOrder, NameField, NameFieldKind,
+    PersonNamesFormatterError, PreferredOrder,
+};
+use crate::personnames::provided_struct::DefaultPersonName;
+
+/// How much the parser trusts the split it produced.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub enum ParseConfidence {
+    /// The split is a guess, e.g. a single token or an unspaced CJK name.
+    Low,
+    /// The split relies on heuristics, e.g. middle names or multiple surnames.
+    Medium,
+    /// The structure of the input is unambiguous, e.g. `"Surname, Given"`.
+    High,
+}
+
+/// Result of [`PersonNamesFormatter::parse_person_name`](super::PersonNamesFormatter::parse_person_name).
+#[derive(Debug)]
+pub struct ParsedPersonName {
+    /// The structured person name. Its name locale is the locale used for parsing.
+    pub person_name: DefaultPersonName,
+    /// The order in which the fields appeared in the input.
+    ///
+    /// [`FormattingOrder::Sorting`] is returned for the `"Surname, Given"` form.
+    pub order: FormattingOrder,
+    /// How reliable the split into fields is.
+    pub confidence: ParseConfidence,
+}
+
+/// Affixes of names in some languages, compared case-insensitively and ignoring a trailing
+/// period.
+///
+/// CLDR has no data for these, so they are only listed for a few languages. Names in other
+/// languages are split without recognizing any affixes.
+struct NameAffixes {
+    languages: &'static [Language],
+    /// Particles that start a surname.
+    ///
+    /// <https://www.unicode.org/reports/tr35/tr35-personNames.html#handle-core-and-prefix>
+    surname_prefixes: &'static [&'static str],
+    titles: &'static [&'static str],
+    generations: &'static [&'static str],
+    credentials: &'static [&'static str],
+}
+
+const NO_AFFIXES: NameAffixes = NameAffixes {
+    languages: &[],
+    surname_prefixes: &[],
+    titles: &[],
+    generations: &[],
+    credentials: &[],
+};
+
+const AFFIXES: &[NameAffixes] = &[
+    NameAffixes {
+        languages: &[language!("en")],
+        surname_prefixes: &[],
+        titles: &["dame", "dr", "miss", "mr", "mrs", "ms", "mx", "prof", "sir"],
+        generations: &["i", "ii", "iii", "iv", "jr", "jnr", "sr", "snr"],
+        credentials: &["dds", "esq", "mba", "md", "phd"],
+    },
+    NameAffixes {
+        languages: &[language!("nl")],
+        surname_prefixes: &["de", "den", "der", "het", "op", "te", "ten", "ter", "van"],
+        titles: &["dhr", "dr", "ir", "mevr", "mr", "prof"],
+        generations: &[],
+        credentials: &[],
+    },
+    NameAffixes {
+        languages: &[language!("de")],
+        surname_prefixes: &["dem", "der", "von", "vom", "zu", "zum", "zur"],
+        titles: &["dr", "frau", "herr", "prof"],
+        generations: &[],
+        credentials: &[],
+    },
+    NameAffixes {
+        languages: &[language!("fr")],
+        surname_prefixes: &["de", "des", "du", "la", "le"],
+        titles: &["dr", "m", "mlle", "mme", "pr"],
+        generations: &[],
+        credentials: &[],
+    },
+    NameAffixes {
+        languages: &[language!("ca"), language!("es"), language!("gl")],
+        surname_prefixes: &["de", "del", "la", "las", "los"],
+        titles: &["don", "dr", "dra", "sr", "sra", "srta"],
+        generations: &[],
+        credentials: &[],
+    },
+    NameAffixes {
+        languages: &[language!("it")],
+        surname_prefixes: &["da", "de", "degli", "dei", "del", "della", "di", "lo"],
+        titles: &["dott", "dr", "prof", "sig"],
+        generations: &[],
+        credentials: &[],
+    },
+    NameAffixes {
+        languages: &[language!("pt")],
+        surname_prefixes: &["da", "das", "de", "do", "dos"],
+        titles: &["dr", "dra", "sr", "sra"],
+        generations: &["filho", "jr", "neto"],
+        credentials: &[],
+    },
+    NameAffixes {
+        languages: &[
+            language!("da"),
+            language!("nb"),
+            language!("nn"),
+            language!("no"),
+            language!("sv"),
+        ],
+        surname_prefixes: &["af", "av", "von"],
+        titles: &[],
+        generations: &[],
+        credentials: &[],
+    },
+];
+
+impl NameAffixes {
+    fn for_language(language: Language) -> &'static Self {
+        AFFIXES
+            .iter()
+            .find(|affixes| affixes.languages.contains(&language))
+            .unwrap_or(&NO_AFFIXES)
+    }
+}
+
+/// Languages in which a given-first name usually ends with two surnames.
+const TWO_SURNAME_LANGUAGES: &[Language] = &[language!("ca"), language!("es"), language!("gl")];
+
+/// Languages whose names are commonly written without a space between surname and given name.
+const UNSPACED_SURNAME_FIRST_LANGUAGES: &[Language] =
+    &[language!("ko"), language!("yue"), language!("zh")];
+
+fn matches_any(token: &str, list: &[&str]) -> bool {
+    let token = token.trim_end_matches('.');
+    list.iter().any(|item| item.eq_ignore_ascii_case(token))
+}
+
+/// Returns the locale to use for parsing `input`.
+///
+/// The script always comes from the input. If no locale is provided, the language is guessed
+/// from the script for scripts that are mostly used by a single surname-first language.
+pub(crate) fn input_locale(
+    input: &str,
+    name_locale: Option<&Locale>,
+    swe: ScriptWithExtensionsBorrowed,
+    scripts: PropertyNamesShortBorrowed<Script>,
+) -> Locale {
+    let mut found_script = Script::Unknown;
+    let mut has_kana = false;
+    for c in input.chars() {
+        let char_script = swe.get_script_val(c);
+        match char_script {
+            Script::Common | Script::Unknown | Script::Inherited => continue,
+            Script::Hiragana | Script::Katakana => has_kana = true,
+            _ => (),
+        }
+        if found_script == Script::Unknown {
+            found_script = char_script;
+        }
+    }
+    let locid_script = if has_kana {
+        script!("Jpan")
+    } else {
+        scripts
+            .get_locale_script(found_script)
+            .unwrap_or(script!("Zzzz"))
+    };
+    let mut locale = name_locale.cloned().unwrap_or(Locale::UNKNOWN);
+    if locale.id.language == Language::UNKNOWN {
+        locale.id.language = match locid_script {
+            s if s == script!("Jpan") => language!("ja"),
+            s if s == script!("Hang") => language!("ko"),
+            s if s == script!("Hani") => language!("zh"),
+            _ => Language::UNKNOWN,
+        };
+    }
+    locale.id.script = Some(locid_script);
+    locale
+}
+
+/// Splits `input` into name fields, `derived_order` being the order derived for `name_locale`.
+///
+/// The derived order is kept as the preferred order of the name, so that formatting it does
+/// not depend on deriving the order again from the script-specific name locale.
+pub(crate) fn parse_person_name(
+    input: &str,
+    name_locale: Locale,
+    derived_order: FormattingOrder,
+) -> Result<ParsedPersonName, PersonNamesFormatterError> {
+    let affixes = NameAffixes::for_language(name_locale.id.language);
+    let mut fields = LiteMap::new();
+    let input = input.trim();
+    // A comma either introduces the given name ("Surname, Given") or only suffixes ("Given Surname, Jr.").
+    let is_sorting = input.split([',', '，']).nth(1).is_some_and(|part| {
+        let part = part.trim();
+        !part.is_empty()
+            && !matches_any(part, affixes.generations)
+            && !matches_any(part, affixes.credentials)
+    });
+    let (order, confidence) = if is_sorting {
+        (
+            FormattingOrder::Sorting,
+            parse_sorting(input, affixes, &mut fields),
+        )
+    } else {
+        let mut tokens: Vec<&str> = input.split_whitespace().collect();
+        let affix_confidence = extract_affixes(&mut tokens, affixes, &mut fields);
+        let confidence = match derived_order {
+            FormattingOrder::SurnameFirst => {
+                parse_surname_first(&tokens, &name_locale, &mut fields)
+            }
+            _ => parse_given_first(&tokens, &name_locale, affixes, &mut fields),
+        };
+        (derived_order, confidence.min(affix_confidence))
+    };
+    let preferred_order = match derived_order {
+        FormattingOrder::SurnameFirst => PreferredOrder::SurnameFirst,
+        _ => PreferredOrder::GivenFirst,
+    };
+    Ok(ParsedPersonName {
+        person_name: DefaultPersonName::new(fields, Some(name_locale), Some(preferred_order))?,
+        order,
+        confidence,
+    })
+}
+
+fn insert(
+    fields: &mut LiteMap<NameField, String>,
+    kind: NameFieldKind,
+    part: FieldPart,
+    tokens: &[&str],
+) {
+    if tokens.is_empty() {
+        return;
+    }
+    fields.insert(
+        NameField {
+            kind,
+            modifier: FieldModifierSet::part(part),
+        },
+        tokens.join(" "),
+    );
+}
+
+/// Handles `"Surname, Given[, Generation or Credentials]*"`.
+fn parse_sorting(
+    input: &str,
+    affixes: &NameAffixes,
+    fields: &mut LiteMap<NameField, String>,
+) -> ParseConfidence {
+    let mut parts = input.split([',', '，']).map(str::trim);
+    let surname: Vec<&str> = parts
+        .next()
+        .map(|p| p.split_whitespace().collect())
+        .unwrap_or_default();
+    let given: Vec<&str> = parts
+        .next()
+        .map(|p| p.split_whitespace().collect())
+        .unwrap_or_default();
+    let mut confidence = ParseConfidence::High;
+    let mut generations = Vec::new();
+    let mut credentials = Vec::new();
+    for part in parts.filter(|p| !p.is_empty()) {
+        if matches_any(part, affixes.generations) {
+            generations.push(part);
+        } else {
+            if !matches_any(part, affixes.credentials) {
+                confidence = ParseConfidence::Medium;
+            }
+            credentials.push(part);
+        }
+    }
+    insert(
+        fields,
+        NameFieldKind::Generation,
+        FieldPart::Auto,
+        &generations,
+    );
+    insert(
+        fields,
+        NameFieldKind::Credentials,
+        FieldPart::Auto,
+        &credentials,
+    );
+    let titles = given
+        .iter()
+        .take(given.len().saturating_sub(1))
+        .take_while(|t| matches_any(t, affixes.titles))
+        .count();
+    let (titles, given) = given.split_at(titles);
+    insert(fields, NameFieldKind::Title, FieldPart::Auto, titles);
+    insert_given(given, fields);
+    insert_surname(NameFieldKind::Surname, &surname, affixes, fields);
+    confidence
+}
+
+/// Moves leading titles and trailing generations and credentials out of `tokens`.
+fn extract_affixes(
+    tokens: &mut Vec<&str>,
+    affixes: &NameAffixes,
+    fields: &mut LiteMap<NameField, String>,
+) -> ParseConfidence {
+    let titles = tokens
+        .iter()
+        .take(tokens.len().saturating_sub(1))
+        .take_while(|t| matches_any(t, affixes.titles))
+        .count();
+    insert(
+        fields,
+        NameFieldKind::Title,
+        FieldPart::Auto,
+        tokens.get(..titles).unwrap_or_default(),
+    );
+    tokens.drain(..titles);
+
+    let mut confidence = ParseConfidence::High;
+    let mut credentials = Vec::new();
+    while tokens.len() > 1 {
+        let Some(last) = tokens.last().map(|t| t.trim_end_matches(',')) else {
+            break;
+        };
+        if matches_any(last, affixes.credentials) {
+            credentials.insert(0, last);
+        } else if matches_any(last, affixes.generations)
+            && !fields
+                .keys()
+                .any(|f: &NameField| f.kind == NameFieldKind::Generation)
+        {
+            // A lone "I" or "V" could also be an initial.
+            if last.len() == 1 {
+                confidence = ParseConfidence::Medium;
+            }
+            insert(fields, NameFieldKind::Generation, FieldPart::Auto, &[last]);
+        } else {
+            break;
+        }
+        tokens.pop();
+    }
+    insert(
+        fields,
+        NameFieldKind::Credentials,
+        FieldPart::Auto,
+        &credentials,
+    );
+    // Strip the comma that may separate the name from its suffixes.
+    if let Some(last) = tokens.last_mut() {
+        *last = last.trim_end_matches(',');
+    }
+    confidence
+}
+
+fn parse_surname_first(
+    tokens: &[&str],
+    name_locale: &Locale,
+    fields: &mut LiteMap<NameField, String>,
+) -> ParseConfidence {
+    match tokens {
+        [] => ParseConfidence::Low,
+        [single] => {
+            let mut chars = single.char_indices();
+            match (chars.nth(1), single.chars().count()) {
+                (Some((index, _)), 2..=4)
+                    if UNSPACED_SURNAME_FIRST_LANGUAGES.contains(&name_locale.id.language) =>
+                {
+                    // Most surnames in these languages are written with a single character.
+                    let (surname, given) = single.split_at(index);
+                    insert(fields, NameFieldKind::Surname, FieldPart::Auto, &[surname]);
+                    insert(fields, NameFieldKind::Given, FieldPart::Auto, &[given]);
+                }
+                _ => insert(fields, NameFieldKind::Given, FieldPart::Auto, tokens),
+            }
+            ParseConfidence::Low
+        }
+        [surname, given] => {
+            insert(fields, NameFieldKind::Surname, FieldPart::Auto, &[surname]);
+            insert(fields, NameFieldKind::Given, FieldPart::Auto, &[given]);
+            ParseConfidence::High
+        }
+        [surname, given @ ..] => {
+            insert(fields, NameFieldKind::Surname, FieldPart::Auto, &[surname]);
+            insert_given(given, fields);
+            ParseConfidence::Medium
+        }
+    }
+}
+
+fn parse_given_first(
+    tokens: &[&str],
+    name_locale: &Locale,
+    affixes: &NameAffixes,
+    fields: &mut LiteMap<NameField, String>,
+) -> ParseConfidence {
+    let Some(last_index) = tokens.len().checked_sub(1) else {
+        return ParseConfidence::Low;
+    };
+    if last_index == 0 {
+        insert(fields, NameFieldKind::Given, FieldPart::Auto, tokens);
+        return ParseConfidence::Low;
+    }
+    let prefix_start = tokens
+        .iter()
+        .enumerate()
+        .take(last_index)
+        .skip(1)
+        .find(|(_, t)| matches_any(t, affixes.surname_prefixes))
+        .map(|(i, _)| i);
+    let two_surnames = prefix_start.is_none()
+        && last_index >= 2
+        && TWO_SURNAME_LANGUAGES.contains(&name_locale.id.language);
+    let surname_start = match prefix_start {
+        Some(i) => i,
+        None if two_surnames => last_index - 1,
+        None => last_index,
+    };
+    let (given, surname) = tokens.split_at(surname_start);
+    insert_given(given, fields);
+    if two_surnames && let Some((surname, surname2)) = surname.split_first() {
+        insert(fields, NameFieldKind::Surname, FieldPart::Auto, &[surname]);
+        insert(fields, NameFieldKind::Surname2, FieldPart::Auto, surname2);
+    } else {
+        insert_surname(NameFieldKind::Surname, surname, affixes, fields);
+    }
+    if given.len() > 1 || two_surnames {
+        ParseConfidence::Medium
+    } else {
+        ParseConfidence::High
+    }
+}
+
+fn insert_given(tokens: &[&str], fields: &mut LiteMap<NameField, String>) {
+    if let Some((given, given2)) = tokens.split_first() {
+        insert(fields, NameFieldKind::Given, FieldPart::Auto, &[given]);
+        insert(fields, NameFieldKind::Given2, FieldPart::Auto, given2);
+    }
+}
+
+/// Inserts `tokens` as a surname, split into prefix and core if it starts with particles.
+fn insert_surname(
+    kind: NameFieldKind,
+    tokens: &[&str],
+    affixes: &NameAffixes,
+    fields: &mut LiteMap<NameField, String>,
+) {
+    let prefix_len = tokens
+        .iter()
+        .take(tokens.len().saturating_sub(1))
+        .take_while(|t| matches_any(t, affixes.surname_prefixes))
+        .count();
+    if prefix_len == 0 {
+        insert(fields, kind, FieldPart::Auto, tokens);
+    } else {
+        let (prefix, core) = tokens.split_at(prefix_len);
+        insert(fields, kind, FieldPart::Prefix, prefix);
+        insert(fields, kind, FieldPart::Core, core);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use icu_locale_core::locale;
+
+    use super::{ParseConfidence, parse_person_name};
+    use crate::personnames::api::{
+        FieldModifierSet, FieldPart, FormattingOrder, NameField, NameFieldKind, PersonName,
+    };
+
+    fn fields(name: &dyn PersonName) -> Vec<(NameFieldKind, FieldPart, String)> {
+        let parts = [FieldPart::Auto, FieldPart::Prefix, FieldPart::Core];
+        name.available_name_fields()
+            .into_iter()
+            .map(|field| {
+                let part = parts
+                    .into_iter()
+                    .find(|&p| field.modifier == FieldModifierSet::part(p))
+                    .unwrap();
+                (field.kind, part, String::from(name.get(field)))
+            })
+            .collect()
+    }
+
+    fn field(
+        kind: NameFieldKind,
+        part: FieldPart,
+        value: &str,
+    ) -> (NameFieldKind, FieldPart, String) {
+        (kind, part, String::from(value))
+    }
+
+    #[test]
+    fn test_sorting_form_with_prefix() {
+        let parsed = parse_person_name(
+            "van der Berg, Anna Maria",
+            locale!("nl-Latn"),
+            FormattingOrder::GivenFirst,
+        )
+        .unwrap();
+        assert_eq!(parsed.order, FormattingOrder::Sorting);
+        assert_eq!(parsed.confidence, ParseConfidence::High);
+        assert_eq!(
+            fields(&parsed.person_name),
+            [
+                field(NameFieldKind::Given, FieldPart::Auto, "Anna"),
+                field(NameFieldKind::Given2, FieldPart::Auto, "Maria"),
+                field(NameFieldKind::Surname, FieldPart::Prefix, "van der"),
+                field(NameFieldKind::Surname, FieldPart::Core, "Berg"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_given_first_affixes() {
+        let parsed = parse_person_name(
+            "Dr. Martin Luther King, Jr., PhD",
+            locale!("en-Latn"),
+            FormattingOrder::GivenFirst,
+        )
+        .unwrap();
+        assert_eq!(parsed.order, FormattingOrder::GivenFirst);
+        assert_eq!(parsed.confidence, ParseConfidence::Medium);
+        assert_eq!(
+            fields(&parsed.person_name),
+            [
+                field(NameFieldKind::Title, FieldPart::Auto, "Dr."),
+                field(NameFieldKind::Given, FieldPart::Auto, "Martin"),
+                field(NameFieldKind::Given2, FieldPart::Auto, "Luther"),
+                field(NameFieldKind::Surname, FieldPart::Auto, "King"),
+                field(NameFieldKind::Generation, FieldPart::Auto, "Jr."),
+                field(NameFieldKind::Credentials, FieldPart::Auto, "PhD"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_two_surnames() {
+        let parsed = parse_person_name(
+            "José María García López",
+            locale!("es-Latn"),
+            FormattingOrder::GivenFirst,
+        )
+        .unwrap();
+        assert_eq!(parsed.confidence, ParseConfidence::Medium);
+        assert_eq!(
+            fields(&parsed.person_name),
+            [
+                field(NameFieldKind::Given, FieldPart::Auto, "José"),
+                field(NameFieldKind::Given2, FieldPart::Auto, "María"),
+                field(NameFieldKind::Surname, FieldPart::Auto, "García"),
+                field(NameFieldKind::Surname2, FieldPart::Auto, "López"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_surname_first() {
+        let parsed = parse_person_name(
+            "山田 太郎",
+            locale!("ja-Jpan"),
+            FormattingOrder::SurnameFirst,
+        )
+        .unwrap();
+        assert_eq!(parsed.confidence, ParseConfidence::High);
+        assert_eq!(
+            fields(&parsed.person_name),
+            [
+                field(NameFieldKind::Given, FieldPart::Auto, "太郎"),
+                field(NameFieldKind::Surname, FieldPart::Auto, "山田"),
+            ]
+        );
+
+        let parsed =
+            parse_person_name("王小明", locale!("zh-Hani"), FormattingOrder::SurnameFirst).unwrap();
+        assert_eq!(parsed.confidence, ParseConfidence::Low);
+        assert_eq!(
+            fields(&parsed.person_name),
+            [
+                field(NameFieldKind::Given, FieldPart::Auto, "小明"),
+                field(NameFieldKind::Surname, FieldPart::Auto, "王"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_affixes_depend_on_language() {
+        let parsed = parse_person_name(
+            "Sr. Juan Pérez",
+            locale!("es-Latn"),
+            FormattingOrder::GivenFirst,
+        )
+        .unwrap();
+        assert_eq!(
+            fields(&parsed.person_name),
+            [
+                field(NameFieldKind::Title, FieldPart::Auto, "Sr."),
+                field(NameFieldKind::Given, FieldPart::Auto, "Juan"),
+                field(NameFieldKind::Surname, FieldPart::Auto, "Pérez"),
+            ]
+        );
+
+        let parsed = parse_person_name(
+            "John Smith Sr.",
+            locale!("en-Latn"),
+            FormattingOrder::GivenFirst,
+        )
+        .unwrap();
+        assert_eq!(
+            fields(&parsed.person_name),
+            [
+                field(NameFieldKind::Given, FieldPart::Auto, "John"),
+                field(NameFieldKind::Surname, FieldPart::Auto, "Smith"),
+                field(NameFieldKind::Generation, FieldPart::Auto, "Sr."),
+            ]
+        );
+
+        // No affixes are known without a language.
+        let parsed = parse_person_name(
+            "Dr. Anna van Berg",
+            locale!("und-Latn"),
+            FormattingOrder::GivenFirst,
+        )
+        .unwrap();
+        assert_eq!(
+            fields(&parsed.person_name),
+            [
+                field(NameFieldKind::Given, FieldPart::Auto, "Dr."),
+                field(NameFieldKind::Given2, FieldPart::Auto, "Anna van"),
+                field(NameFieldKind::Surname, FieldPart::Auto, "Berg"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mononym_and_empty() {
+        let parsed =
+            parse_person_name("Zendaya", locale!("en-Latn"), FormattingOrder::GivenFirst).unwrap();
+        assert_eq!(parsed.confidence, ParseConfidence::Low);
+        assert_eq!(
+            fields(&parsed.person_name),
+            [field(NameFieldKind::Given, FieldPart::Auto, "Zendaya")]
+        );
+        assert!(parse_person_name("  ", locale!("en-Latn"), FormattingOrder::GivenFirst).is_err());
+    }
+
+    #[test]
+    fn test_prefix_is_not_last_token() {
+        let parsed = parse_person_name(
+            "Vincent van",
+            locale!("nl-Latn"),
+            FormattingOrder::GivenFirst,
+        )
+        .unwrap();
+        assert_eq!(
+            fields(&parsed.person_name)
+                .into_iter()
+                .find(|(kind, _, _)| *kind == NameFieldKind::Surname),
+            Some(field(NameFieldKind::Surname, FieldPart::Auto, "van"))
+        );
+        let parsed = parse_person_name(
+            "Vincent van Gogh",
+            locale!("nl-Latn"),
+            FormattingOrder::GivenFirst,
+        )
+        .unwrap();
+        assert!(parsed.person_name.has_name_field(NameField {
+            kind: NameFieldKind::Surname,
+            modifier: FieldModifierSet::part(FieldPart::Prefix),
+        }));
+    }
+}
@@ -25,24 +25,21 @@ pub fn name_order_derive(
 
     loop {
         let chain_locale = fallback_iterator.get();
-        let chain_locale_str = chain_locale.write_to_string();
 
-        // switch lookup with UND
+        // switch lookup with UND, only after the locale itself had no match
         let mut chain_locale_und = *chain_locale;
         chain_locale_und.language = Language::UNKNOWN;
-        let chain_locale_und_str = chain_locale_und.write_to_string();
 
-        if given_first
-            .iter()
-            .any(|i| i == chain_locale_str || i == chain_locale_und_str)
-        {
-            return FormattingOrder::GivenFirst;
-        }
-        if surname_first
-            .iter()
-            .any(|i| i == chain_locale_str || i == chain_locale_und_str)
-        {
-            return FormattingOrder::SurnameFirst;
+        for candidate in [
+            chain_locale.write_to_string(),
+            chain_locale_und.write_to_string(),
+        ] {
+            if given_first.iter().any(|i| i == candidate) {
+                return FormattingOrder::GivenFirst;
+            }
+            if surname_first.iter().any(|i| i == candidate) {
+                return FormattingOrder::SurnameFirst;
+            }
         }
         fallback_iterator.step();
     }
@@ -77,10 +74,7 @@ mod tests {
             "failed for de_Latn_ch"
         );
 
-        // As weird as it may look, it returns GivenFirst because given_first has precedence
-        // over surname_first in evaluation.
-        // As evaluation goes, the locale being checked are {"ja", "und"},
-        // since "und" is a catch all set in given first, it is a perfect match.
+        // "ja" is matched before its "und" variant, which is a catch all in given first.
         assert_eq!(
             name_order_derive(
                 &locale!("ja-Jpan-jp"),
@@ -88,7 +82,7 @@ mod tests {
                 &given_first,
                 fallbacker
             ),
-            FormattingOrder::GivenFirst,
+            FormattingOrder::SurnameFirst,
             "failed for ja_Jpan_jp"
         );
     }
@@ -124,7 +118,7 @@ mod tests {
             "failed for zh_Hans_CN"
         );
 
-        // This is not matching because of zh, but because of und-CN
+        // This is matching because of zh, before its "und" variant
         assert_eq!(
             name_order_derive(
                 &locale!("zh-Hans"),
@@ -132,7 +126,7 @@ mod tests {
                 &given_first,
                 fallbacker
             ),
-            FormattingOrder::GivenFirst,
+            FormattingOrder::SurnameFirst,
             "failed for zh_Hans"
         );
     }
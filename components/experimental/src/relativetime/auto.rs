@@ -0,0 +1,612 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::Decimal;
+use icu_calendar::options::{DateDifferenceOptions, DateDurationUnit};
+use icu_calendar::types::DateDuration;
+use icu_calendar::{AsCalendar, Date, Ref};
+use icu_decimal::{
+    DecimalFormatter, options::DecimalFormatterOptions, provider::DecimalDigitsV1,
+    provider::DecimalSymbolsV1,
+};
+use icu_plurals::{PluralRules, provider::PluralsCardinalV1};
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::*;
+use icu_time::zone::UtcOffset;
+use icu_time::{DateTime, Time, ZonedDateTime};
+
+use crate::relativetime::format::FormattedRelativeTime;
+use crate::relativetime::options::AutoRelativeTimeFormatterOptions;
+use crate::relativetime::provider::*;
+use crate::relativetime::relativetime::RelativeTimeFormatterPreferences;
+
+const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+const NANOSECONDS_PER_MINUTE: u128 = 60 * NANOSECONDS_PER_SECOND;
+const NANOSECONDS_PER_HOUR: u128 = 60 * NANOSECONDS_PER_MINUTE;
+const NANOSECONDS_PER_DAY: i128 = 24 * NANOSECONDS_PER_HOUR as i128;
+
+/// A formatter to render the relative time between two points in time, choosing the unit
+/// automatically.
+///
+/// The unit is the largest one out of seconds, minutes, hours, days, weeks, months and years
+/// whose value is below the [thresholds](crate::relativetime::options::RelativeTimeThresholds)
+/// of the smaller units. Days, months and years are counted across calendar boundaries rather
+/// than as elapsed time, so that a time late on the previous day is "yesterday" even if it was
+/// fewer than 24 hours ago.
+///
+/// # Example
+///
+/// ```
+/// use icu::calendar::Date;
+/// use icu::experimental::relativetime::options::Numeric;
+/// use icu::experimental::relativetime::{
+///     AutoRelativeTimeFormatter, AutoRelativeTimeFormatterOptions,
+/// };
+/// use icu::locale::locale;
+/// use icu::time::{DateTime, Time};
+/// use writeable::assert_writeable_eq;
+///
+/// let mut options = AutoRelativeTimeFormatterOptions::default();
+/// options.numeric = Numeric::Auto;
+///
+/// let formatter =
+///     AutoRelativeTimeFormatter::try_new_long(locale!("en").into(), options)
+///         .expect("locale should be present");
+///
+/// let now = DateTime {
+///     date: Date::try_new_iso(2025, 3, 10).unwrap(),
+///     time: Time::try_new(12, 0, 0, 0).unwrap(),
+/// };
+/// let datetime = |y, m, d, h, min| DateTime {
+///     date: Date::try_new_iso(y, m, d).unwrap(),
+///     time: Time::try_new(h, min, 0, 0).unwrap(),
+/// };
+///
+/// assert_writeable_eq!(
+///     formatter.format(&now, &datetime(2025, 3, 10, 9, 0)),
+///     "3 hours ago"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format(&now, &datetime(2025, 3, 9, 8, 0)),
+///     "yesterday"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format(&now, &datetime(2025, 3, 24, 12, 0)),
+///     "in 2 weeks"
+/// );
+/// assert_writeable_eq!(
+///     formatter.format(&now, &datetime(2025, 2, 1, 12, 0)),
+///     "last month"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct AutoRelativeTimeFormatter {
+    plural_rules: PluralRules,
+    decimal_formatter: DecimalFormatter,
+    options: AutoRelativeTimeFormatterOptions,
+    second: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    minute: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    hour: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    day: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    week: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    month: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+    year: DataPayload<ErasedMarker<RelativeTimePatternData<'static>>>,
+}
+
+macro_rules! constructor {
+    ($unstable: ident, $baked: ident, $buffer: ident, $width: literal, $second: ty, $minute: ty, $hour: ty, $day: ty, $week: ty, $month: ty, $year: ty) => {
+
+        #[doc = concat!("Create a new [`AutoRelativeTimeFormatter`] for the ", $width, " width from compiled data.")]
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        #[cfg(feature = "compiled_data")]
+        pub fn $baked(
+            prefs: RelativeTimeFormatterPreferences,
+            options: AutoRelativeTimeFormatterOptions,
+        ) -> Result<Self, DataError> {
+            let locale = <$second>::make_locale(prefs.locale_preferences);
+            let plural_rules = PluralRules::try_new_cardinal((&prefs).into())?;
+            // Initialize DecimalFormatter with default options
+            let decimal_formatter = DecimalFormatter::try_new(
+                (&prefs).into(),
+                DecimalFormatterOptions::default(),
+            )?;
+            let request = DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            };
+            Ok(AutoRelativeTimeFormatter {
+                plural_rules,
+                decimal_formatter,
+                options,
+                second: DataProvider::<$second>::load(&crate::provider::Baked, request)?.payload.cast(),
+                minute: DataProvider::<$minute>::load(&crate::provider::Baked, request)?.payload.cast(),
+                hour: DataProvider::<$hour>::load(&crate::provider::Baked, request)?.payload.cast(),
+                day: DataProvider::<$day>::load(&crate::provider::Baked, request)?.payload.cast(),
+                week: DataProvider::<$week>::load(&crate::provider::Baked, request)?.payload.cast(),
+                month: DataProvider::<$month>::load(&crate::provider::Baked, request)?.payload.cast(),
+                year: DataProvider::<$year>::load(&crate::provider::Baked, request)?.payload.cast(),
+            })
+        }
+
+        icu_provider::gen_buffer_data_constructors!(
+            (prefs: RelativeTimeFormatterPreferences, options: AutoRelativeTimeFormatterOptions) -> error: DataError,
+            functions: [
+                $baked: skip,
+                $buffer,
+                $unstable,
+                Self,
+            ]
+        );
+
+        #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::$baked)]
+        pub fn $unstable<D>(
+            provider: &D,
+            prefs: RelativeTimeFormatterPreferences,
+            options: AutoRelativeTimeFormatterOptions,
+        ) -> Result<Self, DataError>
+        where
+            D: DataProvider<PluralsCardinalV1>
+                + DataProvider<$second>
+                + DataProvider<$minute>
+                + DataProvider<$hour>
+                + DataProvider<$day>
+                + DataProvider<$week>
+                + DataProvider<$month>
+                + DataProvider<$year>
+                + DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1>
+                + ?Sized,
+        {
+            let locale = <$second>::make_locale(prefs.locale_preferences);
+            let plural_rules = PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?;
+            // Initialize DecimalFormatter with default options
+            let decimal_formatter = DecimalFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                DecimalFormatterOptions::default(),
+            )?;
+            let request = DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            };
+            Ok(AutoRelativeTimeFormatter {
+                plural_rules,
+                decimal_formatter,
+                options,
+                second: DataProvider::<$second>::load(provider, request)?.payload.cast(),
+                minute: DataProvider::<$minute>::load(provider, request)?.payload.cast(),
+                hour: DataProvider::<$hour>::load(provider, request)?.payload.cast(),
+                day: DataProvider::<$day>::load(provider, request)?.payload.cast(),
+                week: DataProvider::<$week>::load(provider, request)?.payload.cast(),
+                month: DataProvider::<$month>::load(provider, request)?.payload.cast(),
+                year: DataProvider::<$year>::load(provider, request)?.payload.cast(),
+            })
+        }
+    };
+}
+
+impl AutoRelativeTimeFormatter {
+    constructor!(
+        try_new_long_unstable,
+        try_new_long,
+        try_new_long_with_buffer_provider,
+        "long",
+        LongSecondRelativeV1,
+        LongMinuteRelativeV1,
+        LongHourRelativeV1,
+        LongDayRelativeV1,
+        LongWeekRelativeV1,
+        LongMonthRelativeV1,
+        LongYearRelativeV1
+    );
+    constructor!(
+        try_new_short_unstable,
+        try_new_short,
+        try_new_short_with_buffer_provider,
+        "short",
+        ShortSecondRelativeV1,
+        ShortMinuteRelativeV1,
+        ShortHourRelativeV1,
+        ShortDayRelativeV1,
+        ShortWeekRelativeV1,
+        ShortMonthRelativeV1,
+        ShortYearRelativeV1
+    );
+    constructor!(
+        try_new_narrow_unstable,
+        try_new_narrow,
+        try_new_narrow_with_buffer_provider,
+        "narrow",
+        NarrowSecondRelativeV1,
+        NarrowMinuteRelativeV1,
+        NarrowHourRelativeV1,
+        NarrowDayRelativeV1,
+        NarrowWeekRelativeV1,
+        NarrowMonthRelativeV1,
+        NarrowYearRelativeV1
+    );
+
+    /// Format the time of `target` relative to `reference`, such as "in 3 days" if `target` is
+    /// three days after `reference`.
+    ///
+    /// Both date times are local to the same time zone. The calendar of `reference` is used for
+    /// the boundaries of months and years.
+    pub fn format<A: AsCalendar>(
+        &self,
+        reference: &DateTime<A>,
+        target: &DateTime<A>,
+    ) -> FormattedRelativeTime<'_> {
+        let calendar = Ref(reference.date.calendar_wrapper());
+        self.format_inner(
+            reference.date.as_borrowed(),
+            nanoseconds_since_midnight(reference.time),
+            target.date.to_calendar(calendar),
+            nanoseconds_since_midnight(target.time),
+        )
+    }
+
+    /// Format the time of `target` relative to `reference`, such as "in 3 days" if `target` is
+    /// three days after `reference`.
+    ///
+    /// The boundaries of days, months and years are those in the time zone and calendar of
+    /// `reference`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::experimental::relativetime::{
+    ///     AutoRelativeTimeFormatter, AutoRelativeTimeFormatterOptions,
+    /// };
+    /// use icu::locale::locale;
+    /// use icu::time::ZonedDateTime;
+    /// use icu::time::zone::UtcOffset;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = AutoRelativeTimeFormatter::try_new_short(
+    ///     locale!("en").into(),
+    ///     AutoRelativeTimeFormatterOptions::default(),
+    /// )
+    /// .expect("locale should be present");
+    ///
+    /// let now = ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+    ///     1_741_608_000_000, // 2025-03-10T12:00:00Z
+    ///     UtcOffset::zero(),
+    /// );
+    /// let then = ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+    ///     1_741_599_900_000, // 2025-03-10T09:45:00Z
+    ///     "+0200".parse().unwrap(),
+    /// );
+    ///
+    /// assert_writeable_eq!(formatter.format_zoned(&now, &then), "2 hr. ago");
+    /// ```
+    pub fn format_zoned<A: AsCalendar>(
+        &self,
+        reference: &ZonedDateTime<A, UtcOffset>,
+        target: &ZonedDateTime<A, UtcOffset>,
+    ) -> FormattedRelativeTime<'_> {
+        // Move the target into the time zone of the reference.
+        let offset_difference =
+            i128::from(reference.zone.to_seconds()) - i128::from(target.zone.to_seconds());
+        let target_nanoseconds = i128::from(nanoseconds_since_midnight(target.time))
+            + offset_difference * NANOSECONDS_PER_SECOND as i128;
+        let target_date = Date::from_rata_die(
+            target.date.to_rata_die() + target_nanoseconds.div_euclid(NANOSECONDS_PER_DAY) as i64,
+            Ref(reference.date.calendar_wrapper()),
+        );
+        self.format_inner(
+            reference.date.as_borrowed(),
+            nanoseconds_since_midnight(reference.time),
+            target_date,
+            target_nanoseconds.rem_euclid(NANOSECONDS_PER_DAY) as u64,
+        )
+    }
+
+    fn format_inner<A: AsCalendar>(
+        &self,
+        reference_date: Date<Ref<'_, A>>,
+        reference_nanoseconds: u64,
+        target_date: Date<Ref<'_, A>>,
+        target_nanoseconds: u64,
+    ) -> FormattedRelativeTime<'_> {
+        let thresholds = &self.options.thresholds;
+        let days = target_date.to_rata_die() - reference_date.to_rata_die();
+        let elapsed = i128::from(days) * NANOSECONDS_PER_DAY + i128::from(target_nanoseconds)
+            - i128::from(reference_nanoseconds);
+        let is_negative = elapsed < 0;
+        let elapsed = elapsed.unsigned_abs();
+        let days = days.unsigned_abs();
+
+        let (patterns, value) = 'unit: {
+            let seconds = rounded_div(elapsed, NANOSECONDS_PER_SECOND);
+            if seconds < u128::from(thresholds.seconds) {
+                break 'unit (&self.second, seconds as u64);
+            }
+            let minutes = rounded_div(elapsed, NANOSECONDS_PER_MINUTE);
+            if minutes < u128::from(thresholds.minutes) {
+                break 'unit (&self.minute, minutes as u64);
+            }
+            let hours = rounded_div(elapsed, NANOSECONDS_PER_HOUR);
+            if hours < u128::from(thresholds.hours) || days == 0 {
+                break 'unit (&self.hour, hours as u64);
+            }
+            if days < u64::from(thresholds.days) {
+                break 'unit (&self.day, days);
+            }
+            let months = months_between(reference_date, target_date);
+            let weeks = (days + 3) / 7;
+            if thresholds.weeks != 0 && (weeks < u64::from(thresholds.weeks) || months == 0) {
+                break 'unit (&self.week, weeks);
+            }
+            if months == 0 {
+                break 'unit (&self.day, days);
+            }
+            let years = target_date
+                .year()
+                .extended_year()
+                .abs_diff(reference_date.year().extended_year());
+            if months < thresholds.months || years == 0 {
+                break 'unit (&self.month, u64::from(months));
+            }
+            (&self.year, u64::from(years))
+        };
+
+        FormattedRelativeTime {
+            patterns: patterns.get(),
+            plural_rules: &self.plural_rules,
+            decimal_formatter: &self.decimal_formatter,
+            numeric: self.options.numeric,
+            value: Decimal::from(value),
+            is_negative: is_negative && value != 0,
+        }
+    }
+}
+
+fn nanoseconds_since_midnight(time: Time) -> u64 {
+    ((u64::from(time.hour.number()) * 60 + u64::from(time.minute.number())) * 60
+        + u64::from(time.second.number()))
+        * NANOSECONDS_PER_SECOND as u64
+        + u64::from(time.subsecond.number())
+}
+
+/// Divides, rounding half up.
+fn rounded_div(dividend: u128, divisor: u128) -> u128 {
+    (dividend + divisor / 2) / divisor
+}
+
+/// Returns the number of month boundaries between two dates.
+fn months_between<A: AsCalendar>(start: Date<Ref<'_, A>>, end: Date<Ref<'_, A>>) -> u32 {
+    let mut options = DateDifferenceOptions::default();
+    options.largest_unit = Some(DateDurationUnit::Months);
+    start_of_month(start)
+        .try_until_with_options(&start_of_month(end), options)
+        .map(|duration| duration.months)
+        .unwrap_or_default()
+}
+
+fn start_of_month<A: AsCalendar>(date: Date<Ref<'_, A>>) -> Date<Ref<'_, A>> {
+    date.try_added_with_options(
+        DateDuration {
+            is_negative: true,
+            days: u32::from(date.day_of_month().0) - 1,
+            ..Default::default()
+        },
+        Default::default(),
+    )
+    .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relativetime::options::{Numeric, RelativeTimeThresholds};
+    use icu_calendar::cal::Hebrew;
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    fn datetime(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+    ) -> DateTime<icu_calendar::Iso> {
+        DateTime {
+            date: Date::try_new_iso(year, month, day).unwrap(),
+            time: Time::try_new(hour, minute, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_unit_selection() {
+        let numeric =
+            AutoRelativeTimeFormatter::try_new_long(locale!("en").into(), Default::default())
+                .unwrap();
+        let options = AutoRelativeTimeFormatterOptions {
+            numeric: Numeric::Auto,
+            ..Default::default()
+        };
+        let auto = AutoRelativeTimeFormatter::try_new_long(locale!("en").into(), options).unwrap();
+
+        let now = datetime(2025, 3, 10, 12, 0);
+        let cases = [
+            (now, "in 0 seconds", "now"),
+            (
+                DateTime {
+                    time: Time::try_new(11, 59, 30, 0).unwrap(),
+                    ..now
+                },
+                "30 seconds ago",
+                "30 seconds ago",
+            ),
+            (
+                DateTime {
+                    time: Time::try_new(12, 0, 50, 0).unwrap(),
+                    ..now
+                },
+                "in 1 minute",
+                "in 1 minute",
+            ),
+            (
+                datetime(2025, 3, 10, 11, 16),
+                "44 minutes ago",
+                "44 minutes ago",
+            ),
+            (datetime(2025, 3, 10, 11, 15), "1 hour ago", "1 hour ago"),
+            (datetime(2025, 3, 10, 9, 0), "3 hours ago", "3 hours ago"),
+            (datetime(2025, 3, 10, 23, 59), "in 12 hours", "in 12 hours"),
+            (datetime(2025, 3, 11, 1, 0), "in 13 hours", "in 13 hours"),
+            (datetime(2025, 3, 9, 15, 0), "21 hours ago", "21 hours ago"),
+            (datetime(2025, 3, 9, 13, 0), "1 day ago", "yesterday"),
+            (datetime(2025, 3, 11, 12, 0), "in 1 day", "tomorrow"),
+            (datetime(2025, 3, 12, 0, 0), "in 2 days", "in 2 days"),
+            (datetime(2025, 3, 4, 0, 0), "6 days ago", "6 days ago"),
+            (datetime(2025, 3, 3, 0, 0), "1 week ago", "last week"),
+            (datetime(2025, 3, 24, 12, 0), "in 2 weeks", "in 2 weeks"),
+            (datetime(2025, 3, 31, 12, 0), "in 3 weeks", "in 3 weeks"),
+            (datetime(2025, 2, 17, 12, 0), "3 weeks ago", "3 weeks ago"),
+            (datetime(2025, 2, 1, 12, 0), "1 month ago", "last month"),
+            (datetime(2025, 4, 30, 12, 0), "in 1 month", "next month"),
+            (
+                datetime(2024, 12, 20, 12, 0),
+                "3 months ago",
+                "3 months ago",
+            ),
+            (datetime(2025, 12, 31, 12, 0), "in 9 months", "in 9 months"),
+            (datetime(2024, 4, 1, 12, 0), "1 year ago", "last year"),
+            (
+                datetime(2024, 12, 31, 12, 0),
+                "3 months ago",
+                "3 months ago",
+            ),
+            (datetime(2027, 1, 1, 0, 0), "in 2 years", "in 2 years"),
+        ];
+        for (target, expected_numeric, expected_auto) in cases {
+            assert_writeable_eq!(
+                numeric.format(&now, &target),
+                expected_numeric,
+                "{target:?}"
+            );
+            assert_writeable_eq!(auto.format(&now, &target), expected_auto, "{target:?}");
+        }
+    }
+
+    #[test]
+    fn test_same_month() {
+        let formatter =
+            AutoRelativeTimeFormatter::try_new_long(locale!("en").into(), Default::default())
+                .unwrap();
+        let now = datetime(2025, 3, 1, 12, 0);
+        assert_writeable_eq!(
+            formatter.format(&now, &datetime(2025, 3, 31, 12, 0)),
+            "in 4 weeks"
+        );
+        assert_writeable_eq!(
+            formatter.format(&now, &datetime(2025, 4, 1, 12, 0)),
+            "in 1 month"
+        );
+
+        // Without weeks, days are used within the same month.
+        let mut options = AutoRelativeTimeFormatterOptions::default();
+        options.thresholds.weeks = 0;
+        let formatter =
+            AutoRelativeTimeFormatter::try_new_long(locale!("en").into(), options).unwrap();
+        assert_writeable_eq!(
+            formatter.format(&now, &datetime(2025, 3, 31, 12, 0)),
+            "in 30 days"
+        );
+        assert_writeable_eq!(
+            formatter.format(&now, &datetime(2025, 3, 12, 12, 0)),
+            "in 11 days"
+        );
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let options = AutoRelativeTimeFormatterOptions {
+            thresholds: RelativeTimeThresholds {
+                seconds: 60,
+                minutes: 60,
+                hours: 24,
+                days: 30,
+                weeks: 0,
+                months: 12,
+            },
+            ..Default::default()
+        };
+        let formatter =
+            AutoRelativeTimeFormatter::try_new_short(locale!("en").into(), options).unwrap();
+        let now = datetime(2025, 3, 10, 12, 0);
+        let cases = [
+            (datetime(2025, 3, 10, 11, 59), "1 min. ago"),
+            (datetime(2025, 3, 10, 11, 15), "45 min. ago"),
+            (datetime(2025, 3, 9, 13, 0), "23 hr. ago"),
+            (datetime(2025, 3, 9, 12, 0), "1 day ago"),
+            (datetime(2025, 2, 10, 12, 0), "28 days ago"),
+            (datetime(2025, 2, 8, 12, 0), "1 mo. ago"),
+            (datetime(2024, 4, 1, 12, 0), "11 mo. ago"),
+            (datetime(2024, 3, 31, 12, 0), "1 yr. ago"),
+        ];
+        for (target, expected) in cases {
+            assert_writeable_eq!(formatter.format(&now, &target), expected, "{target:?}");
+        }
+    }
+
+    #[test]
+    fn test_zoned() {
+        let formatter = AutoRelativeTimeFormatter::try_new_long(
+            locale!("en").into(),
+            AutoRelativeTimeFormatterOptions {
+                numeric: Numeric::Auto,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let zoned = |datetime: DateTime<icu_calendar::Iso>, offset: &str| ZonedDateTime {
+            date: datetime.date,
+            time: datetime.time,
+            zone: offset.parse::<UtcOffset>().unwrap(),
+        };
+
+        let now = zoned(datetime(2025, 3, 10, 1, 0), "+02:00");
+        // 2025-03-09T23:30+02:00, the previous day in the time zone of the reference.
+        let then = zoned(datetime(2025, 3, 9, 21, 30), "+00:00");
+        assert_writeable_eq!(formatter.format_zoned(&now, &then), "2 hours ago");
+        let then = zoned(datetime(2025, 3, 9, 1, 0), "+00:00");
+        assert_writeable_eq!(formatter.format_zoned(&now, &then), "yesterday");
+        // 2025-04-01T00:30+02:00, the next month in the time zone of the reference.
+        let now = zoned(datetime(2025, 3, 1, 12, 0), "+02:00");
+        let then = zoned(datetime(2025, 3, 31, 22, 30), "+00:00");
+        assert_writeable_eq!(formatter.format_zoned(&now, &then), "next month");
+    }
+
+    #[test]
+    fn test_calendar() {
+        let formatter = AutoRelativeTimeFormatter::try_new_long(
+            locale!("en").into(),
+            AutoRelativeTimeFormatterOptions {
+                numeric: Numeric::Auto,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let hebrew = |year, month, day| DateTime {
+            date: Date::try_new_iso(year, month, day)
+                .unwrap()
+                .to_calendar(Hebrew),
+            time: Time::start_of_day(),
+        };
+
+        // The Hebrew month Adar II 5784 starts on 2024-03-11.
+        let now = hebrew(2024, 3, 11);
+        assert_writeable_eq!(formatter.format(&now, &hebrew(2024, 3, 10)), "yesterday");
+        assert_writeable_eq!(formatter.format(&now, &hebrew(2024, 2, 10)), "last month");
+        // 5785 starts on 2024-10-03, after the 13 months of the leap year 5784.
+        assert_writeable_eq!(formatter.format(&now, &hebrew(2024, 10, 3)), "in 7 months");
+        // 5784 starts on 2023-09-16.
+        assert_writeable_eq!(formatter.format(&now, &hebrew(2023, 9, 16)), "6 months ago");
+        assert_writeable_eq!(formatter.format(&now, &hebrew(2023, 9, 15)), "7 months ago");
+    }
+}
@@ -4,13 +4,15 @@
 
 //! Relative time formatting
 
+mod auto;
 mod format;
 pub mod options;
 pub mod provider;
 mod relativetime;
 
+pub use auto::AutoRelativeTimeFormatter;
 pub use format::FormattedRelativeTime;
-pub use options::RelativeTimeFormatterOptions;
+pub use options::{AutoRelativeTimeFormatterOptions, RelativeTimeFormatterOptions};
 pub use relativetime::RelativeTimeFormatter;
 pub use relativetime::RelativeTimeFormatterPreferences;
 pub use relativetime::preferences;
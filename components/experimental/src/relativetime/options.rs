@@ -24,3 +24,57 @@ pub enum Numeric {
     /// Automatically select special formatting if available else fallback to numeric formatting.
     Auto,
 }
+
+/// A bag of options for defining how to format time using
+/// [`AutoRelativeTimeFormatter`](crate::relativetime::AutoRelativeTimeFormatter).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AutoRelativeTimeFormatterOptions {
+    /// Whether to always use numeric formatting for time.
+    pub numeric: Numeric,
+    /// The limits at which a larger unit is chosen.
+    pub thresholds: RelativeTimeThresholds,
+}
+
+/// The limits at which [`AutoRelativeTimeFormatter`](crate::relativetime::AutoRelativeTimeFormatter)
+/// switches to a larger unit.
+///
+/// Each threshold is the smallest value of its unit that is formatted in the next larger unit
+/// instead. For example, with the default `minutes` threshold of 45, a difference of 44 minutes
+/// is formatted as "44 minutes ago" and a difference of 45 minutes as "1 hour ago".
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct RelativeTimeThresholds {
+    /// The number of seconds from which minutes are used. Defaults to 45.
+    pub seconds: u32,
+    /// The number of minutes from which hours are used. Defaults to 45.
+    pub minutes: u32,
+    /// The number of hours from which days are used. Defaults to 22.
+    ///
+    /// Hours are always used if both times are on the same day.
+    pub hours: u32,
+    /// The number of days from which weeks are used. Defaults to 7.
+    pub days: u32,
+    /// The number of weeks from which months are used. Defaults to 4.
+    ///
+    /// Weeks are always used if both times are in the same month. Set this to 0 to never use
+    /// weeks, in which case days are used within the same month.
+    pub weeks: u32,
+    /// The number of months from which years are used. Defaults to 11.
+    ///
+    /// Months are always used if both times are in the same year.
+    pub months: u32,
+}
+
+impl Default for RelativeTimeThresholds {
+    fn default() -> Self {
+        Self {
+            seconds: 45,
+            minutes: 45,
+            hours: 22,
+            days: 7,
+            weeks: 4,
+            months: 11,
+        }
+    }
+}
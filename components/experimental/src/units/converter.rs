@@ -2,6 +2,10 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use num_bigint::BigInt;
+use num_rational::Ratio;
+use num_traits::Zero;
+
 use crate::units::convertible::Convertible;
 
 /// A converter for converting between two single or compound units.
@@ -32,6 +36,17 @@ where
     }
 }
 
+impl UnitsConverter<Ratio<BigInt>> {
+    /// Converts the given value from the input unit to the output unit, returning `None` if the
+    /// result is infinite, which is the case for zero in reciprocal conversions.
+    pub(crate) fn checked_convert(&self, value: Ratio<BigInt>) -> Option<Ratio<BigInt>> {
+        match &self.0 {
+            UnitsConverterInner::Reciprocal { .. } if value.is_zero() => None,
+            _ => Some(self.convert(value)),
+        }
+    }
+}
+
 /// Enum containing all the of converters: Proportional, Reciprocal, and Offset converters as follows:
 ///    1 - Proportional: Converts between two units that are proportionally related (e.g. `meter` to `foot`).
 ///    2 - Reciprocal: Converts between two units that are reciprocal (e.g. `mile-per-gallon` to `liter-per-100-kilometer`).
@@ -3,7 +3,9 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::measure::measureunit::MeasureUnit;
+use crate::measure::mixedmeasureunit::MixedMeasureUnit;
 use crate::measure::provider::single_unit::SingleUnit;
+use crate::units::mixed_converter::MixedUnitsConverter;
 use crate::units::ratio::IcuRatio;
 use crate::units::{InvalidConversionError, provider};
 use crate::units::{
@@ -11,9 +13,12 @@ use crate::units::{
     provider::Sign,
 };
 
+use alloc::vec::Vec;
 use icu_provider::DataError;
 use icu_provider::prelude::*;
 use litemap::LiteMap;
+use num_bigint::BigInt;
+use num_rational::Ratio;
 use num_traits::Pow;
 use num_traits::{One, Zero};
 use zerovec::ZeroSlice;
@@ -92,12 +97,13 @@ impl ConverterFactory {
     ///    which simplifies to: Offset = (Offset1 - Offset2) * (1/ConversionRate2).
     ///
     /// NOTE:
-    ///   - An offset can be calculated if both the input and output units are simple.
-    ///   - A unit is considered simple if it is made up of a single unit, with a power of 1 and an SI prefix power of 0.
+    ///   - An offset is only applied if both the input and output units are single units with a power of 1.
     ///     - For example:
-    ///         - `meter` and `foot` are simple units.
-    ///         - `square-meter` and `square-foot` are simple units.
-    ///         - `meter-per-second` and `foot-per-second` are not simple units.
+    ///         - `celsius`, `fahrenheit` and `millikelvin` are such units.
+    ///         - `square-meter` and `celsius-per-second` are not, as a temperature change per second
+    ///           does not depend on the zero of the temperature scale.
+    ///   - The SI prefixes are part of the conversion rates, so that converting `kilokelvin` to `celsius`
+    ///     has an offset of `-273.15`.
     fn compute_offset(
         &self,
         input_unit: &MeasureUnit,
@@ -111,11 +117,7 @@ impl ConverterFactory {
             return Some(IcuRatio::zero());
         };
 
-        if !(input_unit.power == 1
-            && output_unit.power == 1
-            && input_unit.si_prefix.power == 0
-            && output_unit.si_prefix.power == 0)
-        {
+        if input_unit.power != 1 || output_unit.power != 1 {
             return Some(IcuRatio::zero());
         }
 
@@ -146,7 +148,7 @@ impl ConverterFactory {
             return Some(IcuRatio::zero());
         }
 
-        let output_conversion_rate_recip = output_conversion_info.factor_as_ratio().recip();
+        let output_conversion_rate_recip = self.compute_conversion_term(output_unit, 1)?.recip();
         Some((input_offset - output_offset) * output_conversion_rate_recip)
     }
 
@@ -155,7 +157,7 @@ impl ConverterFactory {
     /// NOTE:
     ///   If the units are neither proportional nor reciprocal, the function will return `None`,
     ///   indicating that the units are incompatible.
-    fn is_reciprocal(
+    pub(crate) fn is_reciprocal(
         &self,
         unit1: &MeasureUnit,
         unit2: &MeasureUnit,
@@ -279,8 +281,8 @@ impl ConverterFactory {
     ///    4 - `celsius` to `fahrenheit` --> Needs an offset
     ///
     /// NOTE:
-    ///    This converter does not support conversions between mixed units,
-    ///    such as, from "meter" to "foot-and-inch".
+    ///    This converter does not support conversions to mixed units,
+    ///    such as, from "meter" to "foot-and-inch". Use [`Self::mixed_converter`] instead.
     pub fn converter<T: Convertible>(
         &self,
         input_unit: &MeasureUnit,
@@ -350,6 +352,45 @@ impl ConverterFactory {
             })
         })
     }
+
+    /// Creates a converter for converting from a single or compound unit to a mixed unit.
+    /// For example:
+    ///    1 - `meter` to `foot-and-inch`
+    ///    2 - `second` to `hour-and-minute-and-second`
+    ///
+    /// NOTE:
+    ///    The units of the mixed unit must be ordered from the largest to the smallest,
+    ///    and each unit must be a whole multiple of the following one.
+    pub fn mixed_converter(
+        &self,
+        input_unit: &MeasureUnit,
+        output_unit: &MixedMeasureUnit,
+    ) -> Result<MixedUnitsConverter, InvalidConversionError> {
+        let [first, rest @ ..] = output_unit.units() else {
+            return Err(InvalidConversionError);
+        };
+
+        let mut factors = Vec::with_capacity(rest.len());
+        let mut previous = first;
+        for unit in rest {
+            if self.is_reciprocal(previous, unit)? {
+                return Err(InvalidConversionError);
+            }
+            let factor = self
+                .converter::<Ratio<BigInt>>(previous, unit)?
+                .convert(Ratio::from_integer(BigInt::from(1)));
+            if !factor.is_integer() || factor <= Ratio::from_integer(BigInt::from(1)) {
+                return Err(InvalidConversionError);
+            }
+            factors.push(factor.to_integer());
+            previous = unit;
+        }
+
+        Ok(MixedUnitsConverter {
+            first: self.converter(input_unit, first)?,
+            factors,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -398,4 +439,83 @@ mod tests {
             ((result - 32.0) / 32.0).abs()
         );
     }
+
+    #[test]
+    fn test_converter_factory_temperature() {
+        use core::str::FromStr;
+        use num_bigint::BigInt;
+        use num_rational::Ratio;
+
+        use crate::units::ratio::IcuRatio;
+
+        let factory = ConverterFactory::new();
+        let cases = [
+            ("celsius", "fahrenheit", "100", "212"),
+            ("fahrenheit", "celsius", "-40", "-40"),
+            ("celsius", "kelvin", "0", "273.15"),
+            ("kelvin", "celsius", "0", "-273.15"),
+            ("fahrenheit", "kelvin", "32", "273.15"),
+            ("kelvin", "fahrenheit", "0", "-459.67"),
+            ("rankine", "fahrenheit", "0", "-459.67"),
+            ("celsius", "rankine", "0", "491.67"),
+            ("millikelvin", "celsius", "273150", "0"),
+            ("kilokelvin", "celsius", "1", "726.85"),
+            ("celsius", "millikelvin", "1", "274150"),
+            // Temperature changes have no offset.
+            ("celsius-per-second", "kelvin-per-second", "1", "1"),
+            ("celsius-per-second", "fahrenheit-per-second", "5", "9"),
+        ];
+
+        for (input, output, value, expected) in cases {
+            let input_unit = MeasureUnit::try_from_str(input).unwrap();
+            let output_unit = MeasureUnit::try_from_str(output).unwrap();
+            let converter = factory
+                .converter::<Ratio<BigInt>>(&input_unit, &output_unit)
+                .unwrap();
+            let result = converter.convert(IcuRatio::from_str(value).unwrap().get_ratio());
+            assert_eq!(
+                result,
+                IcuRatio::from_str(expected).unwrap().get_ratio(),
+                "{value} {input} -> {output}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_converter_factory_consumption() {
+        let factory = ConverterFactory::new();
+        let cases = [
+            (
+                "liter-per-100-kilometer",
+                "mile-per-gallon",
+                10.0,
+                23.521_458,
+            ),
+            (
+                "mile-per-gallon",
+                "liter-per-100-kilometer",
+                23.521_458,
+                10.0,
+            ),
+            (
+                "liter-per-100-kilometer",
+                "mile-per-gallon-imperial",
+                10.0,
+                28.248_094,
+            ),
+            ("liter-per-kilometer", "liter-per-100-kilometer", 0.1, 10.0),
+            ("kilometer-per-liter", "liter-per-100-kilometer", 20.0, 5.0),
+        ];
+
+        for (input, output, value, expected) in cases {
+            let input_unit = MeasureUnit::try_from_str(input).unwrap();
+            let output_unit = MeasureUnit::try_from_str(output).unwrap();
+            let converter = factory.converter::<f64>(&input_unit, &output_unit).unwrap();
+            let result = converter.convert(value);
+            assert!(
+                ((result - expected) / expected).abs() < 0.00001,
+                "{value} {input} -> {output}: {result}"
+            );
+        }
+    }
 }
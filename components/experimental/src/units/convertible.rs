@@ -57,6 +57,35 @@ impl Convertible for &'_ Ratio<BigInt> {
     }
 }
 
+impl Convertible for Ratio<BigInt> {
+    type Factor = Ratio<BigInt>;
+    type Addend = Ratio<BigInt>;
+    type Result = Ratio<BigInt>;
+
+    // Exact
+    fn mul(self, factor: &Self::Factor) -> Self::Result {
+        self * factor
+    }
+
+    // Exact
+    fn mul_add(self, factor: &Self::Factor, addend: &Self::Addend) -> Self::Result {
+        self * factor + addend
+    }
+
+    // Exact
+    fn reciprocal_mul(self, factor: &Self::Factor) -> Self::Result {
+        (self * factor).recip()
+    }
+
+    fn factor_from_ratio_bigint(factor: Ratio<BigInt>) -> Self::Factor {
+        factor
+    }
+
+    fn addend_from_ratio_bigint(addend: Ratio<BigInt>) -> Self::Addend {
+        addend
+    }
+}
+
 impl Convertible for f64 {
     type Factor = f64;
     type Addend = f64;
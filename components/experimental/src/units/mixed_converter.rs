@@ -0,0 +1,218 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use fixed_decimal::{Decimal, Sign};
+use num_bigint::BigInt;
+use num_rational::Ratio;
+use num_traits::{Pow, Signed, Zero};
+
+use crate::units::converter::UnitsConverter;
+
+/// A converter from a single or compound unit to a mixed unit, such as from `meter`
+/// to `foot-and-inch`.
+///
+/// It is created by [`ConverterFactory::mixed_converter`](crate::units::converter_factory::ConverterFactory::mixed_converter).
+#[derive(Debug, Clone)]
+pub struct MixedUnitsConverter {
+    /// Converts the input value to the first unit of the mixed unit.
+    pub(crate) first: UnitsConverter<Ratio<BigInt>>,
+
+    /// For each unit after the first one, how many of it make up one of the preceding unit.
+    /// For example, `12` for `inch` in `foot-and-inch`.
+    pub(crate) factors: Vec<BigInt>,
+}
+
+impl MixedUnitsConverter {
+    /// Converts the given value to the units of the mixed unit, from the largest to the smallest.
+    ///
+    /// All values but the last one are integers. The last value is rounded half away from zero
+    /// to the given `position`, as in [`Decimal::round`]: `0` rounds to an integer and `-1` to
+    /// tenths. If rounding makes the last value reach a whole preceding unit, it is carried over,
+    /// so that 5.999 feet become "6 ft 0 in" and not "5 ft 12 in".
+    ///
+    /// The sign of a negative value is carried by the first non-zero value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::measure::measureunit::MeasureUnit;
+    /// use icu::experimental::measure::mixedmeasureunit::MixedMeasureUnit;
+    /// use icu::experimental::units::converter_factory::ConverterFactory;
+    ///
+    /// let factory = ConverterFactory::new();
+    /// let centimeter = MeasureUnit::try_from_str("centimeter").unwrap();
+    /// let foot_and_inch = MixedMeasureUnit::try_from_str("foot-and-inch").unwrap();
+    ///
+    /// let converter = factory.mixed_converter(&centimeter, &foot_and_inch).unwrap();
+    ///
+    /// let values = converter.convert(&"160".parse().unwrap(), 0);
+    /// assert_eq!(values, ["5".parse().unwrap(), "3".parse().unwrap()]);
+    ///
+    /// let values = converter.convert(&"182.8".parse().unwrap(), 0);
+    /// assert_eq!(values, ["6".parse().unwrap(), "0".parse().unwrap()]);
+    /// ```
+    pub fn convert(&self, value: &Decimal, position: i16) -> Vec<Decimal> {
+        self.split(self.first.convert(to_ratio(value)), position)
+    }
+
+    /// Splits a value of the first unit into the units of the mixed unit, like [`Self::convert`].
+    pub(crate) fn split(&self, mut current: Ratio<BigInt>, position: i16) -> Vec<Decimal> {
+        let negative = current.is_negative();
+        current = current.abs();
+
+        let mut integers = Vec::with_capacity(self.factors.len());
+        for factor in &self.factors {
+            let whole = current.trunc();
+            current = (current - &whole) * factor;
+            integers.push(whole.to_integer());
+        }
+
+        // The last value, in multiples of 10^position.
+        let scale = Ratio::from_integer(BigInt::from(10)).pow(-i32::from(position));
+        let mut last = (current * &scale).round().to_integer();
+
+        let mut carry = false;
+        if let Some(factor) = self.factors.last() {
+            let factor = Ratio::from_integer(factor.clone());
+            if Ratio::from_integer(last.clone()) >= &factor * &scale {
+                last = ((Ratio::from_integer(last) / &scale - factor) * &scale)
+                    .round()
+                    .to_integer();
+                carry = true;
+            }
+        }
+
+        // The factor preceding each integer value, if any.
+        let preceding_factors = self.factors.iter().rev().skip(1).map(Some).chain([None]);
+        for (integer, factor) in integers.iter_mut().rev().zip(preceding_factors) {
+            if !carry {
+                break;
+            }
+            *integer += 1;
+            carry = factor.is_some_and(|factor| *integer >= *factor);
+            if let Some(factor) = factor.filter(|_| carry) {
+                *integer -= factor;
+            }
+        }
+
+        let mut values = integers
+            .iter()
+            .map(|integer| to_decimal(integer, 0))
+            .chain([to_decimal(&last, position)])
+            .collect::<Vec<_>>();
+
+        if negative && let Some(value) = values.iter_mut().find(|value| !value.absolute.is_zero()) {
+            value.sign = Sign::Negative;
+        }
+
+        values
+    }
+}
+
+/// Converts a [`Decimal`] to an exact [`Ratio`].
+pub(crate) fn to_ratio(value: &Decimal) -> Ratio<BigInt> {
+    let range = value.absolute.magnitude_range();
+    let lowest_magnitude = *range.start();
+    let numerator = range.rev().fold(BigInt::zero(), |numerator, magnitude| {
+        numerator * 10 + value.absolute.digit_at(magnitude)
+    });
+    let ratio = Ratio::from_integer(numerator)
+        * Ratio::from_integer(BigInt::from(10)).pow(i32::from(lowest_magnitude));
+    match value.sign {
+        Sign::Negative => -ratio,
+        _ => ratio,
+    }
+}
+
+/// Converts an integer number of multiples of 10^position to a [`Decimal`].
+fn to_decimal(value: &BigInt, position: i16) -> Decimal {
+    // The decimal representation of a `BigInt` is always a valid `Decimal`.
+    let mut decimal = Decimal::try_from_str(&value.to_string()).unwrap_or_default();
+    decimal.absolute.multiply_pow10(position);
+    decimal.absolute.pad_end(position);
+    decimal
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+    use alloc::vec::Vec;
+
+    use crate::measure::measureunit::MeasureUnit;
+    use crate::measure::mixedmeasureunit::MixedMeasureUnit;
+    use crate::units::converter_factory::ConverterFactory;
+
+    #[test]
+    fn test_mixed_converter() {
+        let factory = ConverterFactory::new();
+        let cases = [
+            ("centimeter", "foot-and-inch", "160", 0, &["5", "3"][..]),
+            ("centimeter", "foot-and-inch", "170", -1, &["5", "6.9"][..]),
+            ("foot", "foot-and-inch", "5.999", 0, &["6", "0"][..]),
+            ("foot", "foot-and-inch", "5.999", -2, &["5", "11.99"][..]),
+            ("foot", "foot-and-inch", "-5.5", 0, &["-5", "6"][..]),
+            ("inch", "foot-and-inch", "-6", 0, &["0", "-6"][..]),
+            ("meter", "mile-and-yard", "0", 0, &["0", "0"][..]),
+            (
+                "second",
+                "hour-and-minute-and-second",
+                "4984",
+                0,
+                &["1", "23", "4"][..],
+            ),
+            (
+                "second",
+                "hour-and-minute-and-second",
+                "7199.6",
+                0,
+                &["2", "0", "0"][..],
+            ),
+            (
+                "minute",
+                "hour-and-minute-and-second",
+                "90.25",
+                -1,
+                &["1", "30", "15.0"][..],
+            ),
+            ("pound", "stone-and-pound", "200", 0, &["14", "4"][..]),
+        ];
+
+        for (input, output, value, position, expected) in cases {
+            let input = MeasureUnit::try_from_str(input).unwrap();
+            let output = MixedMeasureUnit::try_from_str(output).unwrap();
+            let converter = factory.mixed_converter(&input, &output).unwrap();
+
+            let values = converter.convert(&value.parse().unwrap(), position);
+            let values = values
+                .iter()
+                .map(|value| writeable::Writeable::write_to_string(value).into_owned())
+                .collect::<Vec<String>>();
+            assert_eq!(values, expected, "{value} {input:?} -> {output:?}");
+        }
+    }
+
+    #[test]
+    fn test_invalid_mixed_converter() {
+        let factory = ConverterFactory::new();
+        let cases = [
+            // Not ordered from the largest to the smallest.
+            ("meter", "inch-and-foot"),
+            // Not convertible to each other.
+            ("meter", "foot-and-second"),
+            // Not convertible from the input unit.
+            ("second", "foot-and-inch"),
+            // Not a whole multiple.
+            ("meter", "meter-and-foot"),
+        ];
+
+        for (input, output) in cases {
+            let input = MeasureUnit::try_from_str(input).unwrap();
+            let output = MixedMeasureUnit::try_from_str(output).unwrap();
+            assert!(factory.mixed_converter(&input, &output).is_err());
+        }
+    }
+}
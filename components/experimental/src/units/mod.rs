@@ -9,8 +9,10 @@ use displaydoc::Display;
 pub mod converter;
 pub mod converter_factory;
 pub mod convertible;
+pub mod mixed_converter;
 pub mod provider;
 pub mod ratio;
+pub mod router;
 
 /// There is no conversion between the two units or the conversion data is missing.
 /// In the end, the conversion is not possible.
@@ -9,10 +9,11 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+use icu_provider::fallback::{LocaleFallbackConfig, LocaleFallbackPriority};
 use icu_provider::prelude::*;
 use num_bigint::BigInt;
 use zerovec::VarZeroSlice;
-use zerovec::{VarZeroVec, ZeroVec, maps::ZeroVecLike, ule::AsULE};
+use zerovec::{VarZeroVec, ZeroMap, ZeroVec, maps::ZeroVecLike, ule::AsULE};
 
 use crate::measure::provider::single_unit::{SingleUnit, UnitID};
 
@@ -103,12 +104,11 @@ pub struct ConversionInfo<'data> {
     /// Represents the sign of the conversion factor.
     pub factor_sign: Sign,
 
-    // TODO(#4311).
-    /// Represents the numerator of the offset.
+    /// Represents the numerator of the offset, which is added after multiplying by the conversion factor.
+    /// For example, the offset of `celsius` is `273.15`, as its base unit is `kelvin`.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub offset_num: ZeroVec<'data, u8>,
 
-    // TODO(#4311).
     /// Represents the denominator of the offset.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub offset_den: ZeroVec<'data, u8>,
@@ -165,3 +165,77 @@ impl ConversionInfoULE {
         )
     }
 }
+
+icu_provider::data_marker!(
+    /// `UnitsQuantitiesV1` maps the quantities that have unit preferences to their base units.
+    UnitsQuantitiesV1,
+    UnitsQuantities<'static>,
+    is_singleton = true
+);
+
+/// The base units of the quantities, such as `length` or `consumption`, for which
+/// CLDR has unit preferences.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Clone, PartialEq, Debug, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::units::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct UnitsQuantities<'data> {
+    /// Maps each quantity to its base unit, for instance `length` to `meter`
+    /// and `consumption` to `cubic-meter-per-meter`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub base_units: ZeroMap<'data, str, str>,
+}
+
+icu_provider::data_struct!(UnitsQuantities<'_>, #[cfg(feature = "datagen")]);
+
+icu_provider::data_marker!(
+    /// `UnitsPreferencesV1` provides the preferred units of a region for a quantity and usage.
+    ///
+    /// The marker attributes are the quantity and the usage separated by a slash,
+    /// such as `length/road`.
+    UnitsPreferencesV1,
+    UnitsPreferences<'static>,
+    fallback_config = {
+        let mut config = LocaleFallbackConfig::default();
+        config.priority = LocaleFallbackPriority::Region;
+        config
+    },
+);
+
+/// The preferred units of a region for a quantity and usage, as listed in CLDR's
+/// `unitPreferenceData`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Clone, PartialEq, Debug, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::units::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct UnitsPreferences<'data> {
+    /// The CLDR identifiers of the preferred units, from the largest to the smallest,
+    /// such as `kilometer` and `meter`.
+    ///
+    /// Mixed units are joined with `-and-`, such as `foot-and-inch`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub units: VarZeroVec<'data, str>,
+
+    /// For each unit in `units`, the minimum value, expressed in that unit, from which
+    /// the unit is used.
+    ///
+    /// The threshold of the last unit is not used, as the last unit is used for all
+    /// smaller values.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub thresholds: ZeroVec<'data, f64>,
+}
+
+icu_provider::data_struct!(UnitsPreferences<'_>, #[cfg(feature = "datagen")]);
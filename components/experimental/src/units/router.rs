@@ -0,0 +1,428 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Selection of the preferred output unit for a usage and region.
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use displaydoc::Display;
+use fixed_decimal::Decimal;
+use icu_locale_core::preferences::define_preferences;
+use icu_locale_core::preferences::extensions::unicode::keywords::{
+    MeasurementSystem, MeasurementUnitOverride,
+};
+use icu_locale_core::subtags::region;
+use icu_provider::prelude::*;
+use num_traits::{Signed, ToPrimitive};
+
+use crate::measure::measureunit::MeasureUnit;
+use crate::measure::mixedmeasureunit::MixedMeasureUnit;
+use crate::units::converter_factory::ConverterFactory;
+use crate::units::mixed_converter::{MixedUnitsConverter, to_ratio};
+use crate::units::provider::{UnitsInfoV1, UnitsPreferencesV1, UnitsQuantities, UnitsQuantitiesV1};
+
+define_preferences!(
+    /// The preferences for selecting the output unit of a [`UnitsRouter`].
+    ///
+    /// The region is taken from the `-u-rg` keyword if present, or from the region subtag
+    /// of the locale otherwise. Locales without a region, such as `en`, use the region
+    /// of their likely subtags.
+    [Copy]
+    UnitsRouterPreferences,
+    {
+        /// The user's preferred measurement system, which takes precedence over the region.
+        ///
+        /// Corresponds to the `-u-ms` in Unicode Locale Identifier.
+        measurement_system: MeasurementSystem,
+        /// The user's preferred temperature unit.
+        ///
+        /// Corresponds to the `-u-mu` in Unicode Locale Identifier.
+        measurement_unit_override: MeasurementUnitOverride
+    }
+);
+
+/// An error returned when creating a [`UnitsRouter`].
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum UnitsRouterError {
+    /// An error originating inside of the data provider.
+    #[displaydoc("{0}")]
+    Data(DataError),
+    /// The input unit does not belong to a quantity with unit preferences,
+    /// such as `length` or `temperature`.
+    #[displaydoc("The unit has no unit preferences")]
+    UnsupportedUnit,
+}
+
+impl core::error::Error for UnitsRouterError {}
+
+impl From<DataError> for UnitsRouterError {
+    fn from(e: DataError) -> Self {
+        Self::Data(e)
+    }
+}
+
+/// A value converted to the preferred unit by [`UnitsRouter::route`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RoutedValue<'a> {
+    /// The CLDR identifier of the preferred unit, such as `mile` or `foot-and-inch`.
+    pub unit: &'a str,
+    /// The value expressed in the preferred unit.
+    ///
+    /// For mixed units, such as `foot-and-inch`, this contains one value per unit, from the
+    /// largest to the smallest, as returned by [`MixedUnitsConverter::convert`]. Otherwise,
+    /// it contains a single value.
+    pub values: Vec<Decimal>,
+}
+
+#[derive(Debug)]
+struct Route {
+    unit: String,
+    threshold: f64,
+    /// Single units are converted by a converter without any further units.
+    converter: MixedUnitsConverter,
+}
+
+/// Converts values of a unit to the unit preferred for a usage in a region, as specified
+/// by CLDR's unit preferences.
+///
+/// The preferences depend on the quantity of the input unit, such as `length`, the usage,
+/// such as `road` or `person-height`, and the region. Several units can be preferred for
+/// different magnitudes, for example miles for long road distances and feet for short ones.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::measure::measureunit::MeasureUnit;
+/// use icu::experimental::units::router::UnitsRouter;
+/// use icu::locale::locale;
+///
+/// let meter = MeasureUnit::try_from_str("meter").unwrap();
+///
+/// let router = UnitsRouter::try_new(locale!("en-US").into(), &meter, "road").unwrap();
+/// let routed = router.route(&"5000".parse().unwrap(), -2).unwrap();
+/// assert_eq!(routed.unit, "mile");
+/// assert_eq!(routed.values, ["3.11".parse().unwrap()]);
+///
+/// let routed = router.route(&"50".parse().unwrap(), 0).unwrap();
+/// assert_eq!(routed.unit, "foot");
+/// assert_eq!(routed.values, ["164".parse().unwrap()]);
+///
+/// // Mixed units are split into all of their units
+/// let router =
+///     UnitsRouter::try_new(locale!("en-US").into(), &meter, "person-height").unwrap();
+/// let routed = router.route(&"1.8".parse().unwrap(), 0).unwrap();
+/// assert_eq!(routed.unit, "foot-and-inch");
+/// assert_eq!(routed.values, ["5".parse().unwrap(), "11".parse().unwrap()]);
+///
+/// // The `-u-ms` keyword takes precedence over the region
+/// let router =
+///     UnitsRouter::try_new(locale!("en-US-u-ms-metric").into(), &meter, "road").unwrap();
+/// assert_eq!(router.route(&"5000".parse().unwrap(), 0).unwrap().unit, "kilometer");
+///
+/// // Unknown usages fall back to the default usage
+/// let router =
+///     UnitsRouter::try_new(locale!("de").into(), &meter, "unknown-usage").unwrap();
+/// assert_eq!(router.route(&"5000".parse().unwrap(), 0).unwrap().unit, "kilometer");
+/// ```
+#[derive(Debug)]
+pub struct UnitsRouter {
+    /// The preferred units with a threshold, from the largest to the smallest.
+    routes: Vec<Route>,
+    /// The unit used for values below all thresholds.
+    fallback: Route,
+}
+
+impl UnitsRouter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: UnitsRouterPreferences, input_unit: &MeasureUnit, usage: &str) -> error: UnitsRouterError,
+        /// Creates a new [`UnitsRouter`] for values of `input_unit` from compiled data.
+        ///
+        /// The `usage` is a CLDR unit preference usage, such as `road` or `person-height`.
+        /// Usages without preferences fall back to shorter usages (`person-height` to `person`),
+        /// and finally to `default`.
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: UnitsRouterPreferences,
+        input_unit: &MeasureUnit,
+        usage: &str,
+    ) -> Result<Self, UnitsRouterError>
+    where
+        D: DataProvider<UnitsInfoV1>
+            + DataProvider<UnitsQuantitiesV1>
+            + DataProvider<UnitsPreferencesV1>
+            + ?Sized,
+    {
+        let factory = ConverterFactory::try_new_unstable(provider)?;
+        let quantities = DataProvider::<UnitsQuantitiesV1>::load(provider, Default::default())?;
+        let quantity = find_quantity(&factory, quantities.payload.get(), input_unit)
+            .ok_or(UnitsRouterError::UnsupportedUnit)?;
+
+        let make_route = |unit: &str, threshold: f64| -> Result<Route, UnitsRouterError> {
+            let converter = if unit.contains("-and-") {
+                MixedMeasureUnit::try_from_str(unit)
+                    .ok()
+                    .and_then(|output_unit| factory.mixed_converter(input_unit, &output_unit).ok())
+            } else {
+                MeasureUnit::try_from_str(unit)
+                    .ok()
+                    .and_then(|output_unit| factory.converter(input_unit, &output_unit).ok())
+                    .map(|first| MixedUnitsConverter {
+                        first,
+                        factors: Vec::new(),
+                    })
+            }
+            .ok_or_else(|| {
+                DataError::custom("Invalid unit preference").with_display_context(unit)
+            })?;
+            Ok(Route {
+                unit: String::from(unit),
+                threshold,
+                converter,
+            })
+        };
+
+        if quantity == "temperature" {
+            let unit = match prefs.measurement_unit_override {
+                Some(MeasurementUnitOverride::Celsius) => Some("celsius"),
+                Some(MeasurementUnitOverride::Fahrenheit) => Some("fahrenheit"),
+                Some(MeasurementUnitOverride::Kelvin) => Some("kelvin"),
+                _ => None,
+            };
+            if let Some(unit) = unit {
+                return Ok(Self {
+                    routes: Vec::new(),
+                    fallback: make_route(unit, 0.0)?,
+                });
+            }
+        }
+
+        let region = match prefs.measurement_system {
+            Some(MeasurementSystem::Metric) => Some(None),
+            Some(MeasurementSystem::USSystem) => Some(Some(region!("US"))),
+            Some(MeasurementSystem::UKSystem) => Some(Some(region!("GB"))),
+            _ => None,
+        };
+        let locale = match region {
+            Some(region) => {
+                let mut locale = DataLocale::default();
+                locale.region = region;
+                locale
+            }
+            None => UnitsPreferencesV1::make_locale(prefs.locale_preferences),
+        };
+
+        let mut usage = usage;
+        let payload = loop {
+            let attributes = format!("{quantity}/{usage}");
+            let response = match DataMarkerAttributes::try_from_str(&attributes) {
+                Ok(attributes) => DataProvider::<UnitsPreferencesV1>::load(
+                    provider,
+                    DataRequest {
+                        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                            attributes, &locale,
+                        ),
+                        metadata: {
+                            let mut metadata = DataRequestMetadata::default();
+                            metadata.silent = true;
+                            metadata
+                        },
+                    },
+                ),
+                Err(_) => Err(DataErrorKind::IdentifierNotFound.into_error()),
+            };
+            match response {
+                Ok(response) => break response.payload,
+                Err(DataError {
+                    kind: DataErrorKind::IdentifierNotFound,
+                    ..
+                }) if usage != "default" => {
+                    usage = usage
+                        .rsplit_once('-')
+                        .map_or("default", |(prefix, _)| prefix);
+                }
+                Err(e) => return Err(e.into()),
+            }
+        };
+
+        let preferences = payload.get();
+        let mut routes = preferences
+            .units
+            .iter()
+            .zip(preferences.thresholds.iter())
+            .map(|(unit, threshold)| make_route(unit, threshold))
+            .collect::<Result<Vec<_>, _>>()?;
+        let fallback = routes.pop().ok_or_else(|| {
+            DataError::custom("No unit preferences").with_display_context(quantity)
+        })?;
+
+        Ok(Self { routes, fallback })
+    }
+
+    /// Converts the value to the first preferred unit whose threshold it reaches,
+    /// or to the last preferred unit otherwise.
+    ///
+    /// The last value is rounded to the given `position`, as in
+    /// [`MixedUnitsConverter::convert`].
+    ///
+    /// Returns `None` if the value has no finite equivalent in the preferred unit, such as
+    /// 0 miles per gallon in liters per 100 kilometers.
+    pub fn route(&self, value: &Decimal, position: i16) -> Option<RoutedValue<'_>> {
+        let value = to_ratio(value);
+        let mut routes = self.routes.iter();
+        let (route, first) = loop {
+            let Some(route) = routes.next() else {
+                let first = self.fallback.converter.first.checked_convert(value)?;
+                break (&self.fallback, first);
+            };
+            let first = route.converter.first.checked_convert(value.clone())?;
+            // Tolerate the rounding of the threshold when comparing with it.
+            if first
+                .abs()
+                .to_f64()
+                .is_some_and(|first| first * (1.0 + f64::EPSILON) >= route.threshold)
+            {
+                break (route, first);
+            }
+        };
+        Some(RoutedValue {
+            unit: &route.unit,
+            values: route.converter.split(first, position),
+        })
+    }
+}
+
+/// Returns the quantity of the unit, such as `length` for `foot`.
+///
+/// Several quantities can have proportional base units, such as `area` (`square-meter`) and
+/// `consumption` (`cubic-meter-per-meter`). In that case, the quantity whose base unit has a
+/// denominator if and only if the unit has one is preferred, so that `liter-per-100-kilometer`
+/// is a consumption and `hectare` an area.
+///
+/// Units that are the reciprocal of a base unit are only accepted for `consumption`,
+/// for example `mile-per-gallon`.
+fn find_quantity<'a>(
+    factory: &ConverterFactory,
+    quantities: &'a UnitsQuantities,
+    unit: &MeasureUnit,
+) -> Option<&'a str> {
+    let has_denominator = |unit: &MeasureUnit| unit.single_units().iter().any(|u| u.power < 0);
+    let mut proportional = None;
+    let mut reciprocal = None;
+    for (quantity, base_unit) in quantities.base_units.iter() {
+        let Ok(base_unit) = MeasureUnit::try_from_str(base_unit) else {
+            continue;
+        };
+        match factory.is_reciprocal(unit, &base_unit) {
+            Ok(false) if has_denominator(unit) == has_denominator(&base_unit) => {
+                return Some(quantity);
+            }
+            Ok(false) => proportional = proportional.or(Some(quantity)),
+            Ok(true) if quantity == "consumption" => reciprocal = Some(quantity),
+            _ => (),
+        }
+    }
+    proportional.or(reciprocal)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+    use icu_locale_core::locale;
+    use writeable::Writeable;
+
+    use super::UnitsRouter;
+    use crate::measure::measureunit::MeasureUnit;
+
+    #[test]
+    fn test_router() {
+        let route = |locale: icu_locale_core::Locale, unit: &str, usage: &str, value: &str| {
+            let unit = MeasureUnit::try_from_str(unit).unwrap();
+            let router = UnitsRouter::try_new(locale.into(), &unit, usage).unwrap();
+            let routed = router.route(&value.parse().unwrap(), -2).unwrap();
+            let values = routed
+                .values
+                .iter()
+                .map(|value| value.write_to_string().into_owned())
+                .collect::<Vec<String>>();
+            (routed.unit.to_string(), values)
+        };
+
+        let (unit, values) = route(locale!("en-GB"), "kilometer", "road", "10");
+        assert_eq!(unit, "mile");
+        assert_eq!(values, ["6.21"]);
+
+        let (unit, values) = route(locale!("en-GB"), "meter", "road", "50");
+        assert_eq!(unit, "yard");
+        assert_eq!(values, ["54.68"]);
+
+        let (unit, values) = route(locale!("fr"), "meter", "road", "5");
+        assert_eq!(unit, "meter");
+        assert_eq!(values, ["5.00"]);
+
+        // Thresholds are inclusive.
+        let (unit, _) = route(locale!("fr"), "meter", "road", "900");
+        assert_eq!(unit, "kilometer");
+
+        // Mixed units are split into all of their units.
+        let (unit, values) = route(locale!("en-US"), "centimeter", "person-height", "170");
+        assert_eq!(unit, "foot-and-inch");
+        assert_eq!(values, ["5", "6.93"]);
+
+        let (unit, _) = route(locale!("en-US"), "centimeter", "person-height", "80");
+        assert_eq!(unit, "inch");
+
+        // `person-height` has no entry for Germany, but falls back to the world preference.
+        let (unit, _) = route(locale!("de-DE"), "inch", "person-height", "70");
+        assert_eq!(unit, "centimeter");
+
+        // `-u-rg` overrides the region.
+        let (unit, _) = route(locale!("en-US-u-rg-frzzzz"), "mile", "road", "10");
+        assert_eq!(unit, "kilometer");
+
+        // Reciprocal units are supported for consumption.
+        let (unit, values) = route(
+            locale!("de"),
+            "mile-per-gallon",
+            "vehicle-fuel",
+            "23.5214583",
+        );
+        assert_eq!(unit, "liter-per-100-kilometer");
+        assert_eq!(values, ["10.00"]);
+
+        let (unit, _) = route(
+            locale!("en-US"),
+            "liter-per-100-kilometer",
+            "vehicle-fuel",
+            "10",
+        );
+        assert_eq!(unit, "mile-per-gallon");
+
+        // Zero has no reciprocal.
+        let mile_per_gallon = MeasureUnit::try_from_str("mile-per-gallon").unwrap();
+        let router =
+            UnitsRouter::try_new(locale!("de").into(), &mile_per_gallon, "vehicle-fuel").unwrap();
+        assert_eq!(router.route(&"0".parse().unwrap(), 0), None);
+
+        let (unit, values) = route(locale!("en-US-u-mu-celsius"), "kelvin", "weather", "300");
+        assert_eq!(unit, "celsius");
+        assert_eq!(values, ["26.85"]);
+
+        let (unit, _) = route(locale!("en-GB-u-ms-ussystem"), "kelvin", "default", "300");
+        assert_eq!(unit, "fahrenheit");
+
+        let hertz = MeasureUnit::try_from_str("hertz").unwrap();
+        assert_eq!(
+            UnitsRouter::try_new(locale!("en").into(), &hertz, "default").unwrap_err(),
+            super::UnitsRouterError::UnsupportedUnit
+        );
+    }
+}
@@ -0,0 +1,159 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_experimental::alphabetic_index::{
+    AlphabeticIndex, AlphabeticIndexOptions, BucketLabelType,
+};
+use icu_locale::exemplar_chars::ExemplarCharacters;
+use icu_locale_core::{Locale, locale};
+
+fn index(locale: Locale) -> AlphabeticIndex {
+    AlphabeticIndex::try_new(locale.into(), Default::default()).unwrap()
+}
+
+fn labels(index: &AlphabeticIndex) -> Vec<&str> {
+    index.buckets().iter().map(|b| b.label()).collect()
+}
+
+fn label<'a>(index: &'a AlphabeticIndex, name: &str) -> &'a str {
+    index.buckets()[index.bucket_index(name)].label()
+}
+
+#[test]
+fn test_latin() {
+    let index = index(locale!("en"));
+    let labels = labels(&index);
+    assert_eq!(labels.first(), Some(&"…"));
+    assert_eq!(labels[1..27].concat(), "ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+    assert_eq!(labels.last(), Some(&"…"));
+    assert_eq!(
+        index
+            .buckets()
+            .iter()
+            .map(|b| b.label_type())
+            .filter(|t| *t != BucketLabelType::Normal)
+            .collect::<Vec<_>>(),
+        [BucketLabelType::Underflow, BucketLabelType::Overflow]
+    );
+
+    assert_eq!(label(&index, "apple"), "A");
+    assert_eq!(label(&index, "Ärger"), "A");
+    assert_eq!(label(&index, "Zoë"), "Z");
+    assert_eq!(label(&index, "Żubr"), "Z");
+    assert_eq!(index.bucket_index("3 Musketeers"), 0);
+    assert_eq!(index.bucket_index(""), 0);
+    assert_eq!(index.bucket_index("Жуков"), 27);
+    assert_eq!(index.bucket_index("東京"), 27);
+
+    // Swedish sorts Ä after Z.
+    let index = self::index(locale!("sv"));
+    assert_eq!(label(&index, "Ärla"), "Ä");
+    assert_eq!(label(&index, "Wåhlin"), "W");
+    assert_eq!(labels_after(&index, "Z"), ["Å", "Ä", "Ö", "…"]);
+}
+
+fn labels_after<'a>(index: &'a AlphabeticIndex, label: &str) -> Vec<&'a str> {
+    let labels = labels(index);
+    let position = labels.iter().position(|l| *l == label).unwrap();
+    labels[position + 1..].to_vec()
+}
+
+#[test]
+fn test_contractions() {
+    // Czech has a label for the contraction "ch" between "h" and "i".
+    let index = index(locale!("cs"));
+    assert_eq!(labels_after(&index, "H")[..2], ["CH", "I"]);
+    assert_eq!(label(&index, "chata"), "CH");
+    assert_eq!(label(&index, "cena"), "C");
+    assert_eq!(label(&index, "hora"), "H");
+}
+
+#[test]
+fn test_japanese() {
+    let index = index(locale!("ja"));
+    assert_eq!(
+        labels(&index),
+        [
+            "…", "あ", "か", "さ", "た", "な", "は", "ま", "や", "ら", "わ", "…"
+        ]
+    );
+    assert_eq!(label(&index, "さくら"), "さ");
+    assert_eq!(label(&index, "カメラ"), "か");
+    assert_eq!(label(&index, "ガラス"), "か");
+    assert_eq!(
+        index.buckets()[index.bucket_index("漢字")].label_type(),
+        BucketLabelType::Overflow
+    );
+    assert_eq!(
+        index.buckets()[index.bucket_index("Tokyo")].label_type(),
+        BucketLabelType::Underflow
+    );
+}
+
+#[test]
+fn test_chinese() {
+    // Pinyin: Latin and Han strings share the buckets.
+    let index = index(locale!("zh"));
+    let labels = labels(&index);
+    assert_eq!(labels.first(), Some(&"…"));
+    assert_eq!(labels[1], "A");
+    assert_eq!(labels.last(), Some(&"…"));
+    assert_eq!(label(&index, "阿"), "A");
+    assert_eq!(label(&index, "apple"), "A");
+    assert_eq!(label(&index, "北京"), "B");
+    assert_eq!(label(&index, "Beijing"), "B");
+    assert_eq!(label(&index, "上海"), "S");
+    assert_eq!(label(&index, "中国"), "Z");
+    assert_eq!(labels.iter().filter(|l| **l == "A").count(), 1);
+
+    // Stroke counts
+    let index = self::index(locale!("zh-u-co-stroke"));
+    assert_eq!(labels_after(&index, "…")[..3], ["1劃", "2劃", "3劃"]);
+    assert_eq!(label(&index, "一"), "1劃");
+    assert_eq!(label(&index, "人"), "2劃");
+    assert_eq!(label(&index, "口"), "3劃");
+
+    // Zhuyin
+    let index = self::index(locale!("zh-u-co-zhuyin"));
+    assert_eq!(labels_after(&index, "…")[..3], ["ㄅ", "ㄆ", "ㄇ"]);
+    assert_eq!(label(&index, "八"), "ㄅ");
+}
+
+#[test]
+fn test_inflow() {
+    let mut index = index(locale!("en"));
+    index.add_labels(&ExemplarCharacters::try_new_index(&locale!("ru").into()).unwrap());
+    assert_eq!(labels_after(&index, "Z")[..3], ["…", "А", "Б"]);
+    assert_eq!(labels_after(&index, "Я"), ["…"]);
+
+    let inflow = index.bucket_index("Αθήνα");
+    assert_eq!(
+        index.buckets()[inflow].label_type(),
+        BucketLabelType::Inflow
+    );
+    assert_eq!(label(&index, "Zürich"), "Z");
+    assert_eq!(label(&index, "Москва"), "М");
+    assert_eq!(
+        index.buckets()[index.bucket_index("תל אביב")].label_type(),
+        BucketLabelType::Overflow
+    );
+
+    index.set_underflow_label("#");
+    index.set_inflow_label("~");
+    index.set_overflow_label("*");
+    assert_eq!(label(&index, "42"), "#");
+    assert_eq!(label(&index, "Αθήνα"), "~");
+    assert_eq!(label(&index, "תל אביב"), "*");
+}
+
+#[test]
+fn test_max_label_count() {
+    let mut options = AlphabeticIndexOptions::default();
+    options.max_label_count = Some(5);
+    let index = AlphabeticIndex::try_new(locale!("en").into(), options).unwrap();
+    let labels = labels(&index);
+    assert_eq!(labels.len(), 5 + 2);
+    assert_eq!(labels[1], "A");
+    assert_eq!(label(&index, "Zoë"), *labels.iter().rev().nth(1).unwrap());
+}
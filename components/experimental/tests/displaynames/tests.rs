@@ -2,12 +2,22 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use icu_experimental::displaynames::{DisplayNamesOptions, multi::LocaleDisplayNamesFormatter};
+use icu_experimental::displaynames::{
+    DisplayNamesOptions, Fallback,
+    multi::{
+        CurrencyDisplayNames, LanguageDisplayNames, LocaleDisplayNamesFormatter,
+        RegionDisplayNames, ScriptDisplayNames, VariantDisplayNames,
+    },
+};
 use icu_locale::names::{
     DisplayNamesPreferences, LanguageIdentifierDisplayName, LanguageIdentifierDisplayNameBorrowed,
     LanguageIdentifierDisplayNameOptions, LanguageIdentifierNameFallbackError, RegionDisplayName,
 };
-use icu_locale_core::{Locale, langid, locale, subtags::region};
+use icu_locale_core::preferences::extensions::unicode::keywords::CurrencyType;
+use icu_locale_core::{
+    Locale, langid, locale,
+    subtags::{language, region, script, variant},
+};
 use std::borrow::Cow;
 use writeable::{
     Part, Writeable, assert_try_writeable_eq, assert_try_writeable_parts_eq, assert_writeable_eq,
@@ -716,3 +726,89 @@ fn test_region_display_name_overrides() {
     assert!(RegionDisplayName::try_new_tiny(prefs_fa, region!("SA")).is_err());
     assert!(RegionDisplayName::try_new_short_tiny(prefs_fa, region!("SA")).is_err());
 }
+
+fn no_fallback() -> DisplayNamesOptions {
+    let mut options = DisplayNamesOptions::default();
+    options.fallback = Fallback::None;
+    options
+}
+
+#[test]
+fn test_region_display_names_fallback() {
+    let code = RegionDisplayNames::try_new(locale!("en").into(), Default::default()).unwrap();
+    let none = RegionDisplayNames::try_new(locale!("en").into(), no_fallback()).unwrap();
+
+    assert_eq!(code.of(region!("GB")).as_deref(), Some("United Kingdom"));
+    assert_eq!(none.of(region!("GB")).as_deref(), Some("United Kingdom"));
+    assert_eq!(code.of(region!("XY")).as_deref(), Some("XY"));
+    assert_eq!(none.of(region!("XY")), None);
+}
+
+#[test]
+fn test_script_display_names_fallback() {
+    let code = ScriptDisplayNames::try_new(locale!("en").into(), Default::default()).unwrap();
+    let none = ScriptDisplayNames::try_new(locale!("en").into(), no_fallback()).unwrap();
+
+    assert_eq!(code.of(script!("Latn")).as_deref(), Some("Latin"));
+    assert_eq!(none.of(script!("Latn")).as_deref(), Some("Latin"));
+    assert_eq!(code.of(script!("Xyzw")).as_deref(), Some("Xyzw"));
+    assert_eq!(none.of(script!("Xyzw")), None);
+}
+
+#[test]
+fn test_variant_display_names_fallback() {
+    let code = VariantDisplayNames::try_new(locale!("en").into(), Default::default()).unwrap();
+    let none = VariantDisplayNames::try_new(locale!("en").into(), no_fallback()).unwrap();
+
+    assert_eq!(
+        code.of(variant!("fonipa")).as_deref(),
+        Some("IPA Phonetics")
+    );
+    assert_eq!(
+        none.of(variant!("fonipa")).as_deref(),
+        Some("IPA Phonetics")
+    );
+    assert_eq!(code.of(variant!("xyzzy")).as_deref(), Some("xyzzy"));
+    assert_eq!(none.of(variant!("xyzzy")), None);
+}
+
+#[test]
+fn test_language_display_names_fallback() {
+    let code = LanguageDisplayNames::try_new(locale!("en").into(), Default::default()).unwrap();
+    let none = LanguageDisplayNames::try_new(locale!("en").into(), no_fallback()).unwrap();
+
+    assert_eq!(code.of(language!("fr")).as_deref(), Some("French"));
+    assert_eq!(none.of(language!("fr")).as_deref(), Some("French"));
+    assert_eq!(code.of(language!("xx")).as_deref(), Some("xx"));
+    assert_eq!(none.of(language!("xx")), None);
+}
+
+#[test]
+fn test_currency_display_names_fallback() {
+    let code = CurrencyDisplayNames::try_new(locale!("en").into(), Default::default()).unwrap();
+    let none = CurrencyDisplayNames::try_new(locale!("en").into(), no_fallback()).unwrap();
+
+    let jpy = CurrencyType::try_from_str("jpy").unwrap();
+    assert_eq!(code.of(jpy).as_deref(), Some("Japanese Yen"));
+    assert_eq!(none.of(jpy).as_deref(), Some("Japanese Yen"));
+    let xyz = CurrencyType::try_from_str("xyz").unwrap();
+    assert_eq!(code.of(xyz).as_deref(), Some("XYZ"));
+    assert_eq!(none.of(xyz), None);
+}
+
+#[test]
+fn test_locale_display_names_fallback() {
+    let code =
+        LocaleDisplayNamesFormatter::try_new(locale!("en").into(), Default::default()).unwrap();
+    let none = LocaleDisplayNamesFormatter::try_new(locale!("en").into(), no_fallback()).unwrap();
+
+    assert_eq!(code.of(&locale!("fr-CA")), "Canadian French");
+    assert_eq!(none.of(&locale!("fr-CA")), "Canadian French");
+    assert_eq!(
+        code.try_of(&locale!("fr-XY")).as_deref(),
+        Some("French (XY)")
+    );
+    assert_eq!(none.try_of(&locale!("fr-XY")), None);
+    // `of` always falls back to the code.
+    assert_eq!(none.of(&locale!("fr-XY")), "French (XY)");
+}
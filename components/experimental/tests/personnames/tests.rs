@@ -5,9 +5,9 @@
 extern crate alloc;
 
 use PersonNamesFormatterError::ParseError;
-use icu_experimental::personnames::PersonNamesFormatter;
 use icu_experimental::personnames::api::*;
 use icu_experimental::personnames::provided_struct::DefaultPersonName;
+use icu_experimental::personnames::{ParseConfidence, PersonNamesFormatter};
 use icu_locale_core::locale;
 use litemap::LiteMap;
 
@@ -473,3 +473,109 @@ fn test_space_replacement_spec_formatting_locale_und_latn_jp()
     assert_eq!(&value, "Hayao Miyazaki");
     Ok(())
 }
+
+#[test]
+fn test_parse_person_name_derives_order_from_script() -> Result<(), PersonNamesFormatterError> {
+    let formatter = PersonNamesFormatter::try_new_unstable(
+        &TestingProvider,
+        PersonNamesFormatterOptions::new(
+            locale!("en"),
+            FormattingOrder::GivenFirst,
+            FormattingLength::Medium,
+            FormattingUsage::Referring,
+            FormattingFormality::Formal,
+        ),
+    )?;
+
+    let parsed = formatter.parse_person_name(&TestingProvider, "山田 太郎", None)?;
+    assert_eq!(parsed.order, FormattingOrder::SurnameFirst);
+    assert_eq!(parsed.confidence, ParseConfidence::High);
+    assert_eq!(parsed.person_name.name_locale(), Some(&locale!("zh-Hani")));
+    assert_eq!(
+        parsed.person_name.get(NameField {
+            kind: NameFieldKind::Surname,
+            modifier: FieldModifierSet::default(),
+        }),
+        "山田"
+    );
+
+    let parsed = formatter.parse_person_name(
+        &TestingProvider,
+        "Anna Maria van der Berg",
+        Some(&locale!("nl")),
+    )?;
+    assert_eq!(parsed.order, FormattingOrder::GivenFirst);
+    assert_eq!(parsed.confidence, ParseConfidence::Medium);
+    assert_eq!(
+        parsed.person_name.get(NameField {
+            kind: NameFieldKind::Surname,
+            modifier: FieldModifierSet::part(FieldPart::Core),
+        }),
+        "Berg"
+    );
+
+    let parsed = formatter.parse_person_name(
+        &TestingProvider,
+        "José María García López",
+        Some(&locale!("es")),
+    )?;
+    assert_eq!(
+        parsed.person_name.get(NameField {
+            kind: NameFieldKind::Surname2,
+            modifier: FieldModifierSet::default(),
+        }),
+        "López"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_parse_person_name_order_agrees_with_formatter() -> Result<(), PersonNamesFormatterError> {
+    let formatter = PersonNamesFormatter::try_new_unstable(
+        &TestingProvider,
+        PersonNamesFormatterOptions::new(
+            locale!("en"),
+            FormattingOrder::GivenFirst,
+            FormattingLength::Medium,
+            FormattingUsage::Referring,
+            FormattingFormality::Formal,
+        ),
+    )?;
+    let surname = NameField {
+        kind: NameFieldKind::Surname,
+        modifier: FieldModifierSet::default(),
+    };
+    let given = NameField {
+        kind: NameFieldKind::Given,
+        modifier: FieldModifierSet::default(),
+    };
+
+    for name_locale in [
+        locale!("ja"),
+        locale!("ko"),
+        locale!("zh"),
+        locale!("hu"),
+        locale!("vi"),
+        locale!("en"),
+        locale!("de"),
+        locale!("und-JP"),
+    ] {
+        let parsed =
+            formatter.parse_person_name(&TestingProvider, "Alpha Beta", Some(&name_locale))?;
+
+        // Without a preferred order, the formatter derives the order from the name locale.
+        let mut person_data: LiteMap<NameField, String> = LiteMap::new();
+        person_data.insert(surname, parsed.person_name.get(surname).into());
+        person_data.insert(given, parsed.person_name.get(given).into());
+        let person_name = DefaultPersonName::new(person_data, Some(name_locale.clone()), None)?;
+
+        // Both orders keep the words of the input in place.
+        let formatted = formatter.format_to_string(&TestingProvider, &person_name)?;
+        assert!(
+            formatted.starts_with("Alpha Beta"),
+            "{name_locale} parsed as {:?}, formatted as {formatted:?}",
+            parsed.order
+        );
+    }
+    Ok(())
+}
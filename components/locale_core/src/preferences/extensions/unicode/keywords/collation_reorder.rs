@@ -0,0 +1,210 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::extensions::unicode::{Key, Value};
+use crate::preferences::PreferenceKey;
+use crate::preferences::extensions::unicode::errors::PreferencesParseError;
+use crate::subtags::{Script, Subtag, subtag};
+use core::ops::Deref;
+
+/// A script or a special group of characters whose position in the collation
+/// order can be set with [`CollationReorderCodes`].
+///
+/// The valid values are listed in [LDML](https://www.unicode.org/reports/tr35/tr35-collation.html#Script_Reordering).
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CollationReorderCode {
+    /// Whitespace characters (`space`)
+    Space,
+    /// Punctuation characters (`punct`)
+    Punctuation,
+    /// Symbols other than currency symbols (`symbol`)
+    Symbol,
+    /// Currency symbols (`currency`)
+    Currency,
+    /// Digits (`digit`)
+    Digit,
+    /// All the scripts that are not listed (`others` or `Zzzz`)
+    Others,
+    /// The characters of a script
+    Script(Script),
+}
+
+impl CollationReorderCode {
+    fn try_from_subtag(subtag: Subtag) -> Result<Self, PreferencesParseError> {
+        Ok(match subtag.as_str() {
+            "space" => Self::Space,
+            "punct" => Self::Punctuation,
+            "symbol" => Self::Symbol,
+            "currency" => Self::Currency,
+            "digit" => Self::Digit,
+            "others" | "zzzz" => Self::Others,
+            s => Script::try_from_str(s)
+                .map(Self::Script)
+                .map_err(|_| PreferencesParseError::InvalidKeywordValue)?,
+        })
+    }
+
+    fn to_subtag(self) -> Subtag {
+        match self {
+            Self::Space => subtag!("space"),
+            Self::Punctuation => subtag!("punct"),
+            Self::Symbol => subtag!("symbol"),
+            Self::Currency => subtag!("currency"),
+            Self::Digit => subtag!("digit"),
+            Self::Others => subtag!("others"),
+            // Keyword values are lowercase
+            Self::Script(script) => {
+                Subtag::from_tinystr_unvalidated(script.to_tinystr().to_ascii_lowercase().resize())
+            }
+        }
+    }
+}
+
+/// Collation parameter key for reordering scripts and special groups of characters.
+///
+/// The listed groups sort before all the others in the given order. The groups
+/// after [`CollationReorderCode::Others`] sort after all the others instead.
+/// Whitespace, punctuation, symbols, currency symbols, and digits that are not
+/// listed keep their positions at the start.
+///
+/// The list is never empty and does not contain duplicates. `[Others]` alone
+/// selects the root order without any reordering.
+///
+/// The details see [LDML](https://www.unicode.org/reports/tr35/tr35-collation.html#Script_Reordering).
+///
+/// ✨ *Enabled with the `alloc` Cargo feature.*
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::extensions::unicode::Value;
+/// use icu::locale::preferences::extensions::unicode::keywords::{
+///     CollationReorderCode, CollationReorderCodes,
+/// };
+/// use icu::locale::subtags::script;
+///
+/// let codes = CollationReorderCodes::try_from_slice(&[
+///     CollationReorderCode::Script(script!("Grek")),
+///     CollationReorderCode::Others,
+///     CollationReorderCode::Digit,
+/// ])
+/// .unwrap();
+///
+/// let value: Value = "grek-others-digit".parse().unwrap();
+/// assert_eq!(CollationReorderCodes::try_from(&value).unwrap(), codes);
+/// assert_eq!(Value::from(codes), value);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CollationReorderCodes {
+    // Unused slots hold `Others` so that the derived traits can look at all of them.
+    codes: [CollationReorderCode; Self::MAX_LEN],
+    len: u8,
+}
+
+impl CollationReorderCodes {
+    /// The maximum number of reorder codes in a list.
+    pub const MAX_LEN: usize = 16;
+
+    /// Creates a list of reorder codes.
+    ///
+    /// Returns an error if `codes` is empty, longer than [`Self::MAX_LEN`], or
+    /// contains a code more than once.
+    pub fn try_from_slice(codes: &[CollationReorderCode]) -> Result<Self, PreferencesParseError> {
+        let mut result = Self {
+            codes: [CollationReorderCode::Others; Self::MAX_LEN],
+            len: 0,
+        };
+        for &code in codes {
+            if result.contains(&code) {
+                return Err(PreferencesParseError::InvalidKeywordValue);
+            }
+            let slot = result
+                .codes
+                .get_mut(usize::from(result.len))
+                .ok_or(PreferencesParseError::InvalidKeywordValue)?;
+            *slot = code;
+            result.len += 1;
+        }
+        if result.is_empty() {
+            return Err(PreferencesParseError::InvalidKeywordValue);
+        }
+        Ok(result)
+    }
+
+    /// Returns the reorder codes as a slice.
+    pub fn as_slice(&self) -> &[CollationReorderCode] {
+        self.codes
+            .get(..usize::from(self.len))
+            .unwrap_or(&self.codes)
+    }
+
+    const UNICODE_EXTENSION_KEY: Key = crate::extensions::unicode::key!("kr");
+}
+
+impl Deref for CollationReorderCodes {
+    type Target = [CollationReorderCode];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl TryFrom<Value> for CollationReorderCodes {
+    type Error = PreferencesParseError;
+
+    fn try_from(input: Value) -> Result<Self, Self::Error> {
+        Self::try_from(&input)
+    }
+}
+
+impl TryFrom<&Value> for CollationReorderCodes {
+    type Error = PreferencesParseError;
+
+    fn try_from(input: &Value) -> Result<Self, Self::Error> {
+        let mut codes = [CollationReorderCode::Others; Self::MAX_LEN];
+        let subtags = input.as_subtags_slice();
+        if subtags.len() > Self::MAX_LEN {
+            return Err(PreferencesParseError::InvalidKeywordValue);
+        }
+        for (code, subtag) in codes.iter_mut().zip(subtags) {
+            *code = CollationReorderCode::try_from_subtag(*subtag)?;
+        }
+        Self::try_from_slice(codes.get(..subtags.len()).unwrap_or_default())
+    }
+}
+
+impl From<CollationReorderCodes> for Value {
+    fn from(input: CollationReorderCodes) -> Value {
+        (&input).into()
+    }
+}
+
+impl From<&CollationReorderCodes> for Value {
+    fn from(input: &CollationReorderCodes) -> Value {
+        input
+            .iter()
+            .copied()
+            .map(CollationReorderCode::to_subtag)
+            .collect()
+    }
+}
+
+impl PreferenceKey for CollationReorderCodes {
+    fn unicode_extension_key() -> Option<Key> {
+        Some(Self::UNICODE_EXTENSION_KEY)
+    }
+
+    fn try_from_key_value(key: &Key, value: &Value) -> Result<Option<Self>, PreferencesParseError> {
+        if Self::UNICODE_EXTENSION_KEY == *key {
+            Self::try_from(value).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn unicode_extension_value(&self) -> Option<Value> {
+        Some(self.into())
+    }
+}
@@ -10,6 +10,10 @@ mod calendar;
 pub use calendar::*;
 mod collation;
 pub use collation::*;
+#[cfg(feature = "alloc")]
+mod collation_reorder;
+#[cfg(feature = "alloc")]
+pub use collation_reorder::*;
 mod currency;
 pub use currency::*;
 mod currency_format;
@@ -0,0 +1,372 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The `NFKC_Casefold` and `NFKC_Simple_Casefold` mappings.
+
+use crate::ComposingNormalizer;
+use crate::ComposingNormalizerBorrowed;
+use crate::DecomposingNormalizer;
+#[cfg(feature = "compiled_data")]
+use crate::DecomposingNormalizerBorrowed;
+use crate::IgnorableBehavior;
+use crate::IsNormalizedSinkStr;
+#[cfg(feature = "utf8_iter")]
+use crate::IsNormalizedSinkUtf8;
+#[cfg(feature = "utf16_iter")]
+use crate::IsNormalizedSinkUtf16;
+use crate::normalizer_methods;
+#[cfg(feature = "compiled_data")]
+use crate::provider::DecompositionData;
+use crate::provider::{
+    NormalizerNfcV1, NormalizerNfdTablesV1, NormalizerNfkcCasefoldDataV1,
+    NormalizerNfkcCasefoldTablesV1, NormalizerNfkcSimpleCasefoldDataV1,
+};
+use alloc::borrow::Cow;
+use alloc::string::String;
+use icu_provider::prelude::*;
+#[cfg(feature = "utf8_iter")]
+use utf8_iter::Utf8CharsEx;
+#[cfg(feature = "utf16_iter")]
+use utf16_iter::Utf16CharsEx;
+
+// Implementation note: Like `Uts46Mapper`, this wraps a `ComposingNormalizer`
+// in order to deny access to the parts of its API that don't work when the
+// data contains markers for ignorables.
+
+/// Borrowed version of a normalizer that applies the `NFKC_Casefold` or the
+/// `NFKC_Simple_Casefold` mapping.
+///
+/// These mappings combine NFKC, case folding, and the removal of default ignorable
+/// code points, as defined in [UAX #44](https://www.unicode.org/reports/tr44/#NFKC_Casefold).
+/// They are meant for caseless identifier matching, e.g. per [UAX #31](https://www.unicode.org/reports/tr31/),
+/// and [`NfkcCasefoldNormalizerBorrowed::new_nfkc_simple_casefold`] only uses simple case
+/// folding so that each character maps to at most one character before normalization.
+///
+/// The output is in NFC. Normalizing text that is already normalized does not change it.
+///
+/// Warning: In these mappings, U+0345 COMBINING GREEK YPOGEGRAMMENI exhibits a behavior
+/// that no character in Unicode exhibits in NFD, NFKD, NFC, or NFKC: Case folding turns
+/// U+0345 from a reordered character into a non-reordered character before reordering happens.
+/// Therefore, the output of this normalization may differ for different inputs that are
+/// canonically equivalent with each other if they differ by how U+0345 is ordered relative
+/// to other reorderable characters.
+///
+/// # Example
+///
+/// ```
+/// use icu::normalizer::NfkcCasefoldNormalizerBorrowed;
+///
+/// let nfkc_cf = NfkcCasefoldNormalizerBorrowed::new_nfkc_casefold();
+/// assert_eq!(nfkc_cf.normalize("Stra\u{00DF}e"), "strasse");
+/// assert_eq!(nfkc_cf.normalize("ﬁ\u{00AD}le"), "file");
+/// assert!(nfkc_cf.is_normalized("stra\u{00DF}e") == false);
+///
+/// let nfkc_scf = NfkcCasefoldNormalizerBorrowed::new_nfkc_simple_casefold();
+/// assert_eq!(nfkc_scf.normalize("Stra\u{00DF}e"), "stra\u{00DF}e");
+/// assert_eq!(nfkc_scf.normalize("\u{212B}"), "\u{00E5}");
+/// ```
+#[derive(Debug)]
+pub struct NfkcCasefoldNormalizerBorrowed<'a> {
+    normalizer: ComposingNormalizerBorrowed<'a>,
+}
+
+#[cfg(feature = "compiled_data")]
+const _: () = assert!(
+    crate::provider::Baked::SINGLETON_NORMALIZER_NFD_TABLES_V1
+        .scalars16
+        .const_len()
+        + crate::provider::Baked::SINGLETON_NORMALIZER_NFD_TABLES_V1
+            .scalars24
+            .const_len()
+        + crate::provider::Baked::SINGLETON_NORMALIZER_NFKC_CASEFOLD_TABLES_V1
+            .scalars16
+            .const_len()
+        + crate::provider::Baked::SINGLETON_NORMALIZER_NFKC_CASEFOLD_TABLES_V1
+            .scalars24
+            .const_len()
+        <= 0xFFF,
+    "future extension"
+);
+
+/// The decomposing part of a normalizer for the compiled data `decompositions`.
+#[cfg(feature = "compiled_data")]
+const fn new_decomposed(
+    decompositions: &'static DecompositionData<'static>,
+) -> DecomposingNormalizerBorrowed<'static> {
+    let cap = decompositions.passthrough_cap;
+    DecomposingNormalizerBorrowed {
+        decompositions,
+        tables: crate::provider::Baked::SINGLETON_NORMALIZER_NFD_TABLES_V1,
+        supplementary_tables: Some(
+            crate::provider::Baked::SINGLETON_NORMALIZER_NFKC_CASEFOLD_TABLES_V1,
+        ),
+        decomposition_passthrough_bound: if cap < 0xC0 { cap as u8 } else { 0xC0 },
+        composition_passthrough_bound: if cap < 0x0300 { cap } else { 0x0300 },
+    }
+}
+
+impl NfkcCasefoldNormalizerBorrowed<'static> {
+    /// Cheaply converts a [`NfkcCasefoldNormalizerBorrowed<'static>`] into a [`NfkcCasefoldNormalizer`].
+    ///
+    /// Note: Due to branching and indirection, using [`NfkcCasefoldNormalizer`] might inhibit some
+    /// compile-time optimizations that are possible with [`NfkcCasefoldNormalizerBorrowed`].
+    pub const fn static_to_owned(self) -> NfkcCasefoldNormalizer {
+        NfkcCasefoldNormalizer {
+            normalizer: self.normalizer.static_to_owned(),
+        }
+    }
+
+    /// `NFKC_Casefold` constructor using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_nfkc_casefold() -> Self {
+        const _: () = assert!(
+            crate::provider::Baked::SINGLETON_NORMALIZER_NFKC_CASEFOLD_DATA_V1.passthrough_cap
+                <= 0x0300,
+            "invalid"
+        );
+        NfkcCasefoldNormalizerBorrowed {
+            normalizer: ComposingNormalizerBorrowed {
+                decomposing_normalizer: new_decomposed(
+                    crate::provider::Baked::SINGLETON_NORMALIZER_NFKC_CASEFOLD_DATA_V1,
+                ),
+                canonical_compositions: crate::provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+                only_contiguous: false,
+            },
+        }
+    }
+
+    /// `NFKC_Simple_Casefold` constructor using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_nfkc_simple_casefold() -> Self {
+        const _: () = assert!(
+            crate::provider::Baked::SINGLETON_NORMALIZER_NFKC_SIMPLE_CASEFOLD_DATA_V1
+                .passthrough_cap
+                <= 0x0300,
+            "invalid"
+        );
+        NfkcCasefoldNormalizerBorrowed {
+            normalizer: ComposingNormalizerBorrowed {
+                decomposing_normalizer: new_decomposed(
+                    crate::provider::Baked::SINGLETON_NORMALIZER_NFKC_SIMPLE_CASEFOLD_DATA_V1,
+                ),
+                canonical_compositions: crate::provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+                only_contiguous: false,
+            },
+        }
+    }
+}
+
+impl<'data> NfkcCasefoldNormalizerBorrowed<'data> {
+    /// Wraps a delegate iterator into an iterator adapter that yields
+    /// the mapping of the characters.
+    pub fn normalize_iter<I: Iterator<Item = char>>(
+        &self,
+        iter: I,
+    ) -> impl Iterator<Item = char> + use<'data, I> {
+        self.normalizer
+            .normalize_iter_private(iter, IgnorableBehavior::Ignored)
+    }
+
+    normalizer_methods!();
+
+    /// Normalize a string slice into a `Write` sink.
+    pub fn normalize_to<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &str,
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        for c in self.normalize_iter(text.chars()) {
+            sink.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    /// Normalize a slice of potentially-invalid UTF-8 into a `Write` sink.
+    ///
+    /// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER
+    /// according to the WHATWG Encoding Standard.
+    ///
+    /// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+    #[cfg(feature = "utf8_iter")]
+    pub fn normalize_utf8_to<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        for c in self.normalize_iter(text.chars()) {
+            sink.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    /// Normalize a slice of potentially-invalid UTF-16 into a `Write16` sink.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER
+    /// before normalizing.
+    ///
+    /// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+    #[cfg(feature = "utf16_iter")]
+    pub fn normalize_utf16_to<W: write16::Write16 + ?Sized>(
+        &self,
+        text: &[u16],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        sink.size_hint(text.len())?;
+        for c in self.normalize_iter(text.chars()) {
+            sink.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// A normalizer that applies the `NFKC_Casefold` or the `NFKC_Simple_Casefold` mapping.
+///
+/// See [`NfkcCasefoldNormalizerBorrowed`].
+#[derive(Debug)]
+pub struct NfkcCasefoldNormalizer {
+    normalizer: ComposingNormalizer,
+}
+
+impl NfkcCasefoldNormalizer {
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> NfkcCasefoldNormalizerBorrowed<'_> {
+        NfkcCasefoldNormalizerBorrowed {
+            normalizer: self.normalizer.as_borrowed(),
+        }
+    }
+
+    /// `NFKC_Casefold` constructor using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_nfkc_casefold() -> NfkcCasefoldNormalizerBorrowed<'static> {
+        NfkcCasefoldNormalizerBorrowed::new_nfkc_casefold()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new_nfkc_casefold: skip,
+            try_new_nfkc_casefold_with_buffer_provider,
+            try_new_nfkc_casefold_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_nfkc_casefold)]
+    pub fn try_new_nfkc_casefold_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerNfkcCasefoldDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkcCasefoldTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + ?Sized,
+    {
+        let decompositions: DataPayload<NormalizerNfkcCasefoldDataV1> =
+            provider.load(Default::default())?.payload;
+        Self::try_new_unstable(
+            provider,
+            decompositions.cast(),
+            NormalizerNfkcCasefoldDataV1::INFO,
+        )
+    }
+
+    /// `NFKC_Simple_Casefold` constructor using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_nfkc_simple_casefold() -> NfkcCasefoldNormalizerBorrowed<'static> {
+        NfkcCasefoldNormalizerBorrowed::new_nfkc_simple_casefold()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new_nfkc_simple_casefold: skip,
+            try_new_nfkc_simple_casefold_with_buffer_provider,
+            try_new_nfkc_simple_casefold_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_nfkc_simple_casefold)]
+    pub fn try_new_nfkc_simple_casefold_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerNfkcSimpleCasefoldDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkcCasefoldTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + ?Sized,
+    {
+        let decompositions: DataPayload<NormalizerNfkcSimpleCasefoldDataV1> =
+            provider.load(Default::default())?.payload;
+        Self::try_new_unstable(
+            provider,
+            decompositions.cast(),
+            NormalizerNfkcSimpleCasefoldDataV1::INFO,
+        )
+    }
+
+    fn try_new_unstable<D>(
+        provider: &D,
+        decompositions: DataPayload<crate::provider::NormalizerNfdDataV1>,
+        marker: DataMarkerInfo,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkcCasefoldTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + ?Sized,
+    {
+        let tables: DataPayload<NormalizerNfdTablesV1> = provider.load(Default::default())?.payload;
+        let supplementary_tables: DataPayload<NormalizerNfkcCasefoldTablesV1> =
+            provider.load(Default::default())?.payload;
+
+        if tables.get().scalars16.len()
+            + tables.get().scalars24.len()
+            + supplementary_tables.get().scalars16.len()
+            + supplementary_tables.get().scalars24.len()
+            > 0xFFF
+        {
+            // See `DecomposingNormalizer::try_new_nfkd_unstable`.
+            return Err(
+                DataError::custom("future extension").with_marker(NormalizerNfdTablesV1::INFO)
+            );
+        }
+
+        let cap = decompositions.get().passthrough_cap;
+        if cap > 0x0300 {
+            return Err(DataError::custom("invalid").with_marker(marker));
+        }
+
+        let canonical_compositions: DataPayload<NormalizerNfcV1> =
+            provider.load(Default::default())?.payload;
+
+        Ok(NfkcCasefoldNormalizer {
+            normalizer: ComposingNormalizer {
+                decomposing_normalizer: DecomposingNormalizer {
+                    decompositions,
+                    tables,
+                    supplementary_tables: Some(supplementary_tables.cast()),
+                    decomposition_passthrough_bound: cap.min(0xC0) as u8,
+                    composition_passthrough_bound: cap.min(0x0300),
+                },
+                canonical_compositions,
+                only_contiguous: false,
+            },
+        })
+    }
+}
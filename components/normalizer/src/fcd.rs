@@ -0,0 +1,302 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Checking and producing text in the FCD form.
+
+use crate::CanonicalCombiningClass;
+use crate::DecomposingNormalizer;
+use crate::DecomposingNormalizerBorrowed;
+use crate::IsNormalizedSinkStr;
+#[cfg(feature = "utf8_iter")]
+use crate::IsNormalizedSinkUtf8;
+#[cfg(feature = "utf16_iter")]
+use crate::IsNormalizedSinkUtf16;
+use crate::NormalizerNfdDataV1;
+use crate::NormalizerNfdTablesV1;
+use crate::ccc_from_trie_value;
+use crate::normalizer_methods;
+use crate::starter_and_decomposes_to_self_impl;
+use crate::trie_value_has_ccc;
+use crate::trie_value_indicates_special_non_starter_decomposition;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use icu_provider::prelude::*;
+use smallvec::SmallVec;
+#[cfg(feature = "utf8_iter")]
+use utf8_iter::Utf8CharsEx;
+#[cfg(feature = "utf16_iter")]
+use utf16_iter::Utf16CharsEx;
+
+/// Borrowed version of a normalizer for checking and producing the FCD form.
+///
+/// Text is in the FCD ("Fast C or D") form described in [UTN #5](https://www.unicode.org/notes/tn5/#FCD)
+/// if decomposing each character on its own results in text that is in the canonical
+/// order, i.e. if the canonical decomposition of the text does not require reordering.
+/// Text in NFD and text in NFC is almost always in the FCD form. For example, the FCD
+/// form allows processes like collation to skip normalization.
+///
+/// FCD is not a unique form: Normalizing text to FCD only decomposes the parts that are
+/// out of order and leaves the rest as is.
+///
+/// # Example
+///
+/// ```
+/// let fcd = icu::normalizer::FcdNormalizerBorrowed::new();
+///
+/// assert!(fcd.is_normalized("\u{00E0}\u{0301}"));
+/// // The dot below needs to go before the acute accent.
+/// assert!(!fcd.is_normalized("a\u{0301}\u{0323}"));
+/// assert_eq!(fcd.normalize("a\u{0301}\u{0323}"), "a\u{0323}\u{0301}");
+/// // The decomposition of à ends with a grave accent, which also needs to go
+/// // after the dot below.
+/// assert_eq!(fcd.normalize("x \u{00E0}\u{0323}"), "x a\u{0323}\u{0300}");
+/// ```
+#[derive(Debug)]
+pub struct FcdNormalizerBorrowed<'a> {
+    decomposing_normalizer: DecomposingNormalizerBorrowed<'a>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for FcdNormalizerBorrowed<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FcdNormalizerBorrowed<'static> {
+    /// Cheaply converts a [`FcdNormalizerBorrowed<'static>`] into a [`FcdNormalizer`].
+    ///
+    /// Note: Due to branching and indirection, using [`FcdNormalizer`] might inhibit some
+    /// compile-time optimizations that are possible with [`FcdNormalizerBorrowed`].
+    pub const fn static_to_owned(self) -> FcdNormalizer {
+        FcdNormalizer {
+            decomposing_normalizer: self.decomposing_normalizer.static_to_owned(),
+        }
+    }
+
+    /// Constructor using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        FcdNormalizerBorrowed {
+            decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfd(),
+        }
+    }
+}
+
+impl<'data> FcdNormalizerBorrowed<'data> {
+    /// The canonical combining classes of the first and the last character
+    /// of the canonical decomposition of `c`.
+    fn lead_and_trail_ccc(&self, c: char) -> (CanonicalCombiningClass, CanonicalCombiningClass) {
+        let nfd = &self.decomposing_normalizer;
+        if u32::from(c) < u32::from(nfd.decomposition_passthrough_bound) {
+            return (
+                CanonicalCombiningClass::NotReordered,
+                CanonicalCombiningClass::NotReordered,
+            );
+        }
+        let trie_value = nfd.decompositions.trie.get(c);
+        if starter_and_decomposes_to_self_impl(trie_value) {
+            (
+                CanonicalCombiningClass::NotReordered,
+                CanonicalCombiningClass::NotReordered,
+            )
+        } else if trie_value_has_ccc(trie_value)
+            && !trie_value_indicates_special_non_starter_decomposition(trie_value)
+        {
+            // A non-starter that decomposes to itself
+            let ccc = ccc_from_trie_value(trie_value);
+            (ccc, ccc)
+        } else {
+            let ccc = |c: char| ccc_from_trie_value(nfd.decompositions.trie.get(c));
+            let mut decomposition = nfd.normalize_iter(core::iter::once(c));
+            let first = decomposition.next().unwrap_or(c);
+            let last = decomposition.last().unwrap_or(first);
+            (ccc(first), ccc(last))
+        }
+    }
+
+    /// Wraps a delegate iterator into an iterator adapter that yields the
+    /// FCD form of the characters.
+    pub fn normalize_iter<'delegate, I: Iterator<Item = char> + 'delegate>(
+        &'delegate self,
+        iter: I,
+    ) -> impl Iterator<Item = char> + 'delegate {
+        FcdNormalization {
+            normalizer: self,
+            delegate: iter,
+            pending: None,
+            buffer: SmallVec::new(),
+            buffer_pos: 0,
+        }
+    }
+
+    normalizer_methods!();
+
+    /// Normalize a string slice into a `Write` sink.
+    pub fn normalize_to<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &str,
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        for c in self.normalize_iter(text.chars()) {
+            sink.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    /// Normalize a slice of potentially-invalid UTF-8 into a `Write` sink.
+    ///
+    /// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER
+    /// according to the WHATWG Encoding Standard.
+    ///
+    /// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+    #[cfg(feature = "utf8_iter")]
+    pub fn normalize_utf8_to<W: core::fmt::Write + ?Sized>(
+        &self,
+        text: &[u8],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        for c in self.normalize_iter(text.chars()) {
+            sink.write_char(c)?;
+        }
+        Ok(())
+    }
+
+    /// Normalize a slice of potentially-invalid UTF-16 into a `Write16` sink.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER
+    /// before normalizing.
+    ///
+    /// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+    #[cfg(feature = "utf16_iter")]
+    pub fn normalize_utf16_to<W: write16::Write16 + ?Sized>(
+        &self,
+        text: &[u16],
+        sink: &mut W,
+    ) -> core::fmt::Result {
+        sink.size_hint(text.len())?;
+        for c in self.normalize_iter(text.chars()) {
+            sink.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+/// The iterator behind [`FcdNormalizerBorrowed::normalize_iter`].
+///
+/// The text is processed in segments that start with a character whose
+/// decomposition starts with a starter. A segment that is not in the
+/// canonical order when decomposed is replaced with its NFD form.
+struct FcdNormalization<'a, I>
+where
+    I: Iterator<Item = char>,
+{
+    normalizer: &'a FcdNormalizerBorrowed<'a>,
+    delegate: I,
+    /// The start of the next segment and the canonical combining class
+    /// of the end of its decomposition.
+    pending: Option<(char, CanonicalCombiningClass)>,
+    buffer: SmallVec<[char; 17]>,
+    buffer_pos: usize,
+}
+
+impl<I> Iterator for FcdNormalization<'_, I>
+where
+    I: Iterator<Item = char>,
+{
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if let Some(&c) = self.buffer.get(self.buffer_pos) {
+            self.buffer_pos += 1;
+            return Some(c);
+        }
+        self.buffer.clear();
+        self.buffer_pos = 0;
+        let (first, mut previous_trail) = self.pending.take().or_else(|| {
+            let c = self.delegate.next()?;
+            Some((c, self.normalizer.lead_and_trail_ccc(c).1))
+        })?;
+        let mut in_order = true;
+        for c in self.delegate.by_ref() {
+            let (lead, trail) = self.normalizer.lead_and_trail_ccc(c);
+            if lead == CanonicalCombiningClass::NotReordered {
+                self.pending = Some((c, trail));
+                break;
+            }
+            if lead < previous_trail {
+                in_order = false;
+            }
+            if self.buffer.is_empty() {
+                self.buffer.push(first);
+            }
+            self.buffer.push(c);
+            previous_trail = trail;
+        }
+        if self.buffer.is_empty() {
+            return Some(first);
+        }
+        if !in_order {
+            let decomposed = self
+                .normalizer
+                .decomposing_normalizer
+                .normalize_iter(self.buffer.drain(..))
+                .collect();
+            self.buffer = decomposed;
+        }
+        self.next()
+    }
+}
+
+/// A normalizer for checking and producing the FCD form.
+///
+/// See [`FcdNormalizerBorrowed`].
+#[derive(Debug)]
+pub struct FcdNormalizer {
+    decomposing_normalizer: DecomposingNormalizer,
+}
+
+impl FcdNormalizer {
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> FcdNormalizerBorrowed<'_> {
+        FcdNormalizerBorrowed {
+            decomposing_normalizer: self.decomposing_normalizer.as_borrowed(),
+        }
+    }
+
+    /// Constructor using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[expect(clippy::new_ret_no_self)]
+    pub const fn new() -> FcdNormalizerBorrowed<'static> {
+        FcdNormalizerBorrowed::new()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerNfdDataV1> + DataProvider<NormalizerNfdTablesV1> + ?Sized,
+    {
+        Ok(FcdNormalizer {
+            decomposing_normalizer: DecomposingNormalizer::try_new_nfd_unstable(provider)?,
+        })
+    }
+}
@@ -27,11 +27,18 @@
 //!
 //! Three kinds of contiguous inputs are supported: known-well-formed UTF-8 (`&str`), potentially-not-well-formed UTF-8,
 //! and potentially-not-well-formed UTF-16. Additionally, an iterator over `char` can be wrapped in a normalizing iterator.
+//! Potentially-not-well-formed UTF-8 and UTF-16 that arrives in chunks can be pushed into a streaming normalizer, such as
+//! the one returned by [`ComposingNormalizerBorrowed::streaming_utf8`], which writes the normalized text into a sink.
 //!
-//! The `uts46` module provides the combination of mapping and normalization operations for [UTS #46: Unicode IDNA
-//! Compatibility Processing](https://www.unicode.org/reports/tr46/). This functionality is not meant to be used by
-//! applications directly. Instead, it is meant as a building block for a full implementation of UTS #46, such as the
-//! [`idna`](https://docs.rs/idna/latest/idna/) crate.
+//! The [`FcdNormalizerBorrowed`] type checks for and produces text in the FCD form described in [UTN #5: Canonical
+//! Equivalence in Applications](https://www.unicode.org/notes/tn5/), and [`ComposingNormalizerBorrowed::new_fcc`]
+//! provides the FCC form, a variant of NFC that only composes contiguous characters. The [`NfkcCasefoldNormalizerBorrowed`]
+//! type provides the `NFKC_Casefold` and `NFKC_Simple_Casefold` mappings for caseless identifier matching.
+//!
+//! The `uts46` module implements [UTS #46: Unicode IDNA Compatibility Processing](https://www.unicode.org/reports/tr46/).
+//! With the `icu_properties` Cargo feature, it provides the complete `ToASCII` and `ToUnicode` operations for domain names.
+//! It also provides the combination of mapping and normalization operations on its own, as a building block for other
+//! implementations of UTS #46, such as the [`idna`](https://docs.rs/idna/latest/idna/) crate.
 //!
 //! The `properties` module provides the non-recursive canonical decomposition operation on a per `char` basis and
 //! the canonical compositon operation given two `char`s. It also provides access to the Canonical Combining Class
@@ -83,17 +90,27 @@ type Trie<'trie> = CodePointTrie<'trie, u32>;
 #[cfg(icu4x_unstable_fast_trie_only)]
 type Trie<'trie> = FastCodePointTrie<'trie, u32>;
 
+mod casefold;
+mod fcd;
 #[cfg(feature = "harfbuzz_traits")]
 mod harfbuzz;
 pub mod properties;
 pub mod provider;
+#[cfg(any(feature = "utf8_iter", feature = "utf16_iter"))]
+mod streaming;
 pub mod uts46;
 
+pub use crate::casefold::{NfkcCasefoldNormalizer, NfkcCasefoldNormalizerBorrowed};
+pub use crate::fcd::{FcdNormalizer, FcdNormalizerBorrowed};
 use crate::provider::CanonicalCompositions;
 use crate::provider::DecompositionData;
 use crate::provider::NormalizerNfdDataV1;
 use crate::provider::NormalizerNfkdDataV1;
 use crate::provider::NormalizerUts46DataV1;
+#[cfg(feature = "utf8_iter")]
+pub use crate::streaming::StreamingNormalizerUtf8;
+#[cfg(feature = "utf16_iter")]
+pub use crate::streaming::StreamingNormalizerUtf16;
 use alloc::borrow::Cow;
 use alloc::string::String;
 use icu_collections::char16trie::Char16Trie;
@@ -259,6 +276,12 @@ static FDFA_NFKD: [u16; 17] = [
 /// See trie-value-format.md
 const FDFA_MARKER: u16 = 1;
 
+/// The composition passthrough bound for FCC: Unlike in NFC, the
+/// characters from U+00C0 on that decompose do not pass through
+/// unchanged, because the recomposition of their decompositions
+/// can be blocked by what follows.
+const FCC_COMPOSITION_PASSTHROUGH_BOUND: u16 = 0xC0;
+
 // These constants originate from page 143 of Unicode 14.0
 /// Syllable base
 const HANGUL_S_BASE: u32 = 0xAC00;
@@ -1033,6 +1056,8 @@ where
     /// 2. Decomposition starts with a non-starter
     /// 3. Is not a backward-combining starter
     composition_passthrough_bound: u32,
+    /// Whether only contiguous characters compose, as in FCC.
+    only_contiguous: bool,
 }
 
 impl<'data, I> Composition<'data, I>
@@ -1043,12 +1068,14 @@ where
         decomposition: Decomposition<'data, I>,
         canonical_compositions: Char16Trie<'data>,
         composition_passthrough_bound: u16,
+        only_contiguous: bool,
     ) -> Self {
         Self {
             decomposition,
             canonical_compositions,
             unprocessed_starter: None,
             composition_passthrough_bound: u32::from(composition_passthrough_bound),
+            only_contiguous,
         }
     }
 
@@ -1108,8 +1135,15 @@ where
                 }
                 debug_assert_eq!(self.decomposition.buffer_pos, 0);
                 undecomposed_starter = self.decomposition.pending.take()?;
+                // In FCC, a character that roundtrips via NFC may still need to be
+                // decomposed, because an upcoming non-starter could block the
+                // recomposition.
                 if u32::from(undecomposed_starter.character) < self.composition_passthrough_bound
-                    || undecomposed_starter.potential_passthrough()
+                    || (if self.only_contiguous {
+                        undecomposed_starter.starter_and_decomposes_to_self()
+                    } else {
+                        undecomposed_starter.potential_passthrough()
+                    })
                 {
                     // TODO(#2385): In the NFC case (moot for NFKC and UTS46), if the upcoming
                     // character is not below `decomposition_passthrough_bound` but is
@@ -1185,9 +1219,12 @@ where
                         .drain(0..self.decomposition.buffer_pos);
                 }
                 self.decomposition.buffer_pos = 0;
-                if most_recent_skipped_ccc == CanonicalCombiningClass::NotReordered {
-                    // We failed to compose a starter. Discontiguous match not allowed.
-                    // We leave the starter in `buffer` for `next()` to find.
+                if most_recent_skipped_ccc == CanonicalCombiningClass::NotReordered
+                    || self.only_contiguous
+                {
+                    // We failed to compose a starter or, in FCC, any character.
+                    // Discontiguous match not allowed.
+                    // We leave the character in `buffer` for `next()` to find.
                     return Some(starter);
                 }
                 let mut i = 1; // We have skipped one non-starter.
@@ -1278,6 +1315,14 @@ macro_rules! composing_normalize_to {
             $sink: &mut W,
         ) -> core::fmt::Result {
             $prolog
+            if self.only_contiguous {
+                // The fast paths and the discontiguous composition below are
+                // specific to NFC and NFKC.
+                for c in self.normalize_iter($text.chars()) {
+                    $sink.write_char(c)?;
+                }
+                return Ok(());
+            }
             let mut $composition = self.normalize_iter($text.chars());
             debug_assert_eq!($composition.decomposition.ignorable_behavior, IgnorableBehavior::Unsupported);
             for cc in $composition.decomposition.buffer.drain(..) {
@@ -1645,6 +1690,8 @@ macro_rules! normalizer_methods {
     };
 }
 
+pub(crate) use normalizer_methods;
+
 /// Borrowed version of a normalizer for performing decomposing normalization.
 #[derive(Debug)]
 pub struct DecomposingNormalizerBorrowed<'a> {
@@ -1833,6 +1880,24 @@ impl<'data> DecomposingNormalizerBorrowed<'data> {
         )
     }
 
+    /// Returns a normalizer that takes potentially-invalid UTF-8 in chunks
+    /// and writes the normalized text into `sink`.
+    ///
+    /// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+    #[cfg(feature = "utf8_iter")]
+    pub fn streaming_utf8<W: core::fmt::Write>(&self, sink: W) -> StreamingNormalizerUtf8<'_, W> {
+        StreamingNormalizerUtf8::new(streaming::Normalizer::Decomposing(self), sink)
+    }
+
+    /// Returns a normalizer that takes potentially-invalid UTF-16 in chunks
+    /// and writes the normalized text into `sink`.
+    ///
+    /// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+    #[cfg(feature = "utf16_iter")]
+    pub fn streaming_utf16<W: write16::Write16>(&self, sink: W) -> StreamingNormalizerUtf16<'_, W> {
+        StreamingNormalizerUtf16::new(streaming::Normalizer::Decomposing(self), sink)
+    }
+
     normalizer_methods!();
 
     decomposing_normalize_to!(
@@ -2407,6 +2472,7 @@ impl DecomposingNormalizer {
 pub struct ComposingNormalizerBorrowed<'a> {
     decomposing_normalizer: DecomposingNormalizerBorrowed<'a>,
     canonical_compositions: &'a CanonicalCompositions<'a>,
+    only_contiguous: bool,
 }
 
 impl ComposingNormalizerBorrowed<'static> {
@@ -2418,6 +2484,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizer {
             decomposing_normalizer: self.decomposing_normalizer.static_to_owned(),
             canonical_compositions: DataPayload::from_static_ref(self.canonical_compositions),
+            only_contiguous: self.only_contiguous,
         }
     }
 
@@ -2431,6 +2498,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfd(),
             canonical_compositions: provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+            only_contiguous: false,
         }
     }
 
@@ -2444,6 +2512,41 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_nfkd(),
             canonical_compositions: provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+            only_contiguous: false,
+        }
+    }
+
+    /// FCC constructor using compiled data.
+    ///
+    /// FCC is like NFC, except that a character only composes with the preceding
+    /// starter if nothing remains between them. The result is "Fast C Contiguous"
+    /// as described in [UTN #5](https://www.unicode.org/notes/tn5/#FCC), which is
+    /// not a Unicode Normalization Form, but always passes the FCD check of
+    /// [`FcdNormalizerBorrowed`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let fcc = icu::normalizer::ComposingNormalizerBorrowed::new_fcc();
+    /// let nfc = icu::normalizer::ComposingNormalizerBorrowed::new_nfc();
+    ///
+    /// assert_eq!(fcc.normalize("a\u{0301}"), "á");
+    /// // The acute accent is not adjacent to the "a" after canonical reordering.
+    /// assert_eq!(fcc.normalize("a\u{0301}\u{0334}"), "a\u{0334}\u{0301}");
+    /// assert_eq!(nfc.normalize("a\u{0301}\u{0334}"), "á\u{0334}");
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_fcc() -> Self {
+        let mut decomposing_normalizer = DecomposingNormalizerBorrowed::new_nfd();
+        decomposing_normalizer.composition_passthrough_bound = FCC_COMPOSITION_PASSTHROUGH_BOUND;
+        ComposingNormalizerBorrowed {
+            decomposing_normalizer,
+            canonical_compositions: provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+            only_contiguous: true,
         }
     }
 
@@ -2461,6 +2564,7 @@ impl ComposingNormalizerBorrowed<'static> {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: DecomposingNormalizerBorrowed::new_uts46_decomposed(),
             canonical_compositions: provider::Baked::SINGLETON_NORMALIZER_NFC_V1,
+            only_contiguous: false,
         }
     }
 }
@@ -2488,9 +2592,28 @@ impl<'data> ComposingNormalizerBorrowed<'data> {
             ),
             self.canonical_compositions.canonical_compositions.clone(),
             self.decomposing_normalizer.composition_passthrough_bound,
+            self.only_contiguous,
         )
     }
 
+    /// Returns a normalizer that takes potentially-invalid UTF-8 in chunks
+    /// and writes the normalized text into `sink`.
+    ///
+    /// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+    #[cfg(feature = "utf8_iter")]
+    pub fn streaming_utf8<W: core::fmt::Write>(&self, sink: W) -> StreamingNormalizerUtf8<'_, W> {
+        StreamingNormalizerUtf8::new(streaming::Normalizer::Composing(self), sink)
+    }
+
+    /// Returns a normalizer that takes potentially-invalid UTF-16 in chunks
+    /// and writes the normalized text into `sink`.
+    ///
+    /// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+    #[cfg(feature = "utf16_iter")]
+    pub fn streaming_utf16<W: write16::Write16>(&self, sink: W) -> StreamingNormalizerUtf16<'_, W> {
+        StreamingNormalizerUtf16::new(streaming::Normalizer::Composing(self), sink)
+    }
+
     normalizer_methods!();
 
     composing_normalize_to!(
@@ -2846,6 +2969,7 @@ impl<'data> ComposingNormalizerBorrowed<'data> {
 pub struct ComposingNormalizer {
     decomposing_normalizer: DecomposingNormalizer,
     canonical_compositions: DataPayload<NormalizerNfcV1>,
+    only_contiguous: bool,
 }
 
 impl ComposingNormalizer {
@@ -2854,6 +2978,7 @@ impl ComposingNormalizer {
         ComposingNormalizerBorrowed {
             decomposing_normalizer: self.decomposing_normalizer.as_borrowed(),
             canonical_compositions: self.canonical_compositions.get(),
+            only_contiguous: self.only_contiguous,
         }
     }
 
@@ -2893,6 +3018,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 
@@ -2933,6 +3059,52 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
+        })
+    }
+
+    /// FCC constructor using compiled data.
+    ///
+    /// See [`ComposingNormalizerBorrowed::new_fcc`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new_fcc() -> ComposingNormalizerBorrowed<'static> {
+        ComposingNormalizerBorrowed::new_fcc()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new_fcc: skip,
+            try_new_fcc_with_buffer_provider,
+            try_new_fcc_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_fcc)]
+    pub fn try_new_fcc_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + ?Sized,
+    {
+        let mut decomposing_normalizer = DecomposingNormalizer::try_new_nfd_unstable(provider)?;
+        decomposing_normalizer.composition_passthrough_bound = decomposing_normalizer
+            .composition_passthrough_bound
+            .min(FCC_COMPOSITION_PASSTHROUGH_BOUND);
+
+        let canonical_compositions: DataPayload<NormalizerNfcV1> =
+            provider.load(Default::default())?.payload;
+
+        Ok(ComposingNormalizer {
+            decomposing_normalizer,
+            canonical_compositions,
+            only_contiguous: true,
         })
     }
 
@@ -2955,6 +3127,7 @@ impl ComposingNormalizer {
         Ok(ComposingNormalizer {
             decomposing_normalizer,
             canonical_compositions,
+            only_contiguous: false,
         })
     }
 }
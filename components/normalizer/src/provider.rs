@@ -46,6 +46,9 @@ const _: () = {
     impl_normalizer_nfd_tables_v1!(Baked);
     impl_normalizer_nfkd_data_v1!(Baked);
     impl_normalizer_nfkd_tables_v1!(Baked);
+    impl_normalizer_nfkc_casefold_data_v1!(Baked);
+    impl_normalizer_nfkc_casefold_tables_v1!(Baked);
+    impl_normalizer_nfkc_simple_casefold_data_v1!(Baked);
     impl_normalizer_uts46_data_v1!(Baked);
 };
 
@@ -84,6 +87,28 @@ icu_provider::data_marker!(
     DecompositionData<'static>,
     is_singleton = true
 );
+icu_provider::data_marker!(
+    /// Marker for data for the decomposed form of the `NFKC_Casefold` mapping.
+    NormalizerNfkcCasefoldDataV1,
+    "normalizer/nfkc/casefold/data/v1",
+    DecompositionData<'static>,
+    is_singleton = true
+);
+icu_provider::data_marker!(
+    /// Marker for data for the decomposed form of the `NFKC_Simple_Casefold` mapping.
+    NormalizerNfkcSimpleCasefoldDataV1,
+    "normalizer/nfkc/simple/casefold/data/v1",
+    DecompositionData<'static>,
+    is_singleton = true
+);
+icu_provider::data_marker!(
+    /// Marker for additional data for the `NFKC_Casefold` and `NFKC_Simple_Casefold`
+    /// mappings.
+    NormalizerNfkcCasefoldTablesV1,
+    "normalizer/nfkc/casefold/tables/v1",
+    DecompositionTables<'static>,
+    is_singleton = true
+);
 icu_provider::data_marker!(
     /// Marker for data for composition.
     NormalizerNfcV1,
@@ -109,6 +134,9 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     NormalizerNfkdTablesV1::INFO,
     NormalizerNfdSupplementV1::INFO,
     NormalizerUts46DataV1::INFO,
+    NormalizerNfkcCasefoldDataV1::INFO,
+    NormalizerNfkcSimpleCasefoldDataV1::INFO,
+    NormalizerNfkcCasefoldTablesV1::INFO,
 ];
 
 /// Decomposition data
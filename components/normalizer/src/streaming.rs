@@ -0,0 +1,247 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Push-based normalization of text that arrives in chunks.
+
+use crate::BACKWARD_COMBINING_MARKER;
+use crate::ComposingNormalizerBorrowed;
+use crate::DecomposingNormalizerBorrowed;
+use crate::trie_value_has_ccc;
+use alloc::vec::Vec;
+
+/// The normalizer that a streaming normalizer delegates to.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Normalizer<'a> {
+    Decomposing(&'a DecomposingNormalizerBorrowed<'a>),
+    Composing(&'a ComposingNormalizerBorrowed<'a>),
+}
+
+impl Normalizer<'_> {
+    /// Whether the normalization of the text before `c` is independent
+    /// of `c` and the text after it.
+    fn has_boundary_before(self, c: char) -> bool {
+        match self {
+            Normalizer::Decomposing(normalizer) => {
+                u32::from(c) < u32::from(normalizer.decomposition_passthrough_bound)
+                    || !trie_value_has_ccc(normalizer.decompositions.trie.get(c))
+            }
+            Normalizer::Composing(normalizer) => {
+                let decomposing_normalizer = &normalizer.decomposing_normalizer;
+                if u32::from(c) < u32::from(decomposing_normalizer.composition_passthrough_bound) {
+                    return true;
+                }
+                let trie_value = decomposing_normalizer.decompositions.trie.get(c);
+                !trie_value_has_ccc(trie_value) && (trie_value & BACKWARD_COMBINING_MARKER) == 0
+            }
+        }
+    }
+
+    /// Normalizes the text before the last boundary in `pending` and `chunk`
+    /// using `normalize` and leaves the rest in `pending`.
+    fn push<T: Copy>(
+        self,
+        pending: &mut Vec<T>,
+        chunk: &[T],
+        char_at: fn(&[T], usize) -> Option<char>,
+        mut normalize: impl FnMut(&[T]) -> core::fmt::Result,
+    ) -> core::fmt::Result {
+        let is_boundary = |i: usize| char_at(chunk, i).is_some_and(|c| self.has_boundary_before(c));
+        let Some(last) = (0..chunk.len()).rev().find(|&i| is_boundary(i)) else {
+            pending.extend_from_slice(chunk);
+            return Ok(());
+        };
+        let (head, tail) = chunk.split_at(last);
+        if pending.is_empty() {
+            normalize(head)?;
+        } else {
+            // Only the part up to the first boundary needs to be copied
+            // in order to be normalized together with `pending`.
+            let first = (0..last).find(|&i| is_boundary(i)).unwrap_or(last);
+            let (head, middle) = head.split_at(first);
+            pending.extend_from_slice(head);
+            normalize(pending)?;
+            pending.clear();
+            normalize(middle)?;
+        }
+        pending.extend_from_slice(tail);
+        Ok(())
+    }
+}
+
+/// The character that starts at index `i` if it's well-formed.
+#[cfg(feature = "utf8_iter")]
+fn char_at_utf8(text: &[u8], i: usize) -> Option<char> {
+    let len = match *text.get(i)? {
+        0..=0x7F => 1,
+        0xC2..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF4 => 4,
+        _ => return None,
+    };
+    core::str::from_utf8(text.get(i..i + len)?)
+        .ok()?
+        .chars()
+        .next()
+}
+
+/// The character that starts at index `i` if it's well-formed.
+#[cfg(feature = "utf16_iter")]
+fn char_at_utf16(text: &[u16], i: usize) -> Option<char> {
+    char::decode_utf16(text.get(i..)?.iter().copied())
+        .next()?
+        .ok()
+}
+
+/// A normalizer that takes potentially-invalid UTF-8 in chunks and writes the
+/// normalized text into a `Write` sink.
+///
+/// Chunks may end in the middle of a character or of a combining character sequence.
+/// Only the input after the last point where normalization can restart is retained
+/// between chunks, which is normally no more than a handful of characters. The text
+/// is the same as if it had been normalized in one piece once [`finish`](Self::finish)
+/// has been called.
+///
+/// Ill-formed byte sequences are mapped to the REPLACEMENT CHARACTER
+/// according to the WHATWG Encoding Standard.
+///
+/// If the sink returns an error, the state of the normalizer is unspecified.
+///
+/// ✨ *Enabled with the `utf8_iter` Cargo feature.*
+///
+/// # Example
+///
+/// ```
+/// use icu::normalizer::ComposingNormalizerBorrowed;
+///
+/// let nfc = ComposingNormalizerBorrowed::new_nfc();
+/// let mut streaming = nfc.streaming_utf8(String::new());
+/// // The chunk boundary splits U+0301 COMBINING ACUTE ACCENT.
+/// streaming.push(b"Ame\xCC").unwrap();
+/// streaming.push(b"\x81lie").unwrap();
+/// assert_eq!(streaming.finish().unwrap(), "Amélie");
+/// ```
+#[cfg(feature = "utf8_iter")]
+#[derive(Debug)]
+pub struct StreamingNormalizerUtf8<'a, W: core::fmt::Write> {
+    normalizer: Normalizer<'a>,
+    pending: Vec<u8>,
+    sink: W,
+}
+
+#[cfg(feature = "utf8_iter")]
+impl<'a, W: core::fmt::Write> StreamingNormalizerUtf8<'a, W> {
+    pub(crate) fn new(normalizer: Normalizer<'a>, sink: W) -> Self {
+        StreamingNormalizerUtf8 {
+            normalizer,
+            pending: Vec::new(),
+            sink,
+        }
+    }
+
+    /// Normalizes the next chunk of the text.
+    ///
+    /// Output is written into the sink as soon as it can no longer be
+    /// affected by later chunks.
+    pub fn push(&mut self, chunk: &[u8]) -> core::fmt::Result {
+        let normalizer = self.normalizer;
+        let sink = &mut self.sink;
+        normalizer.push(
+            &mut self.pending,
+            chunk,
+            char_at_utf8,
+            |text| match normalizer {
+                Normalizer::Decomposing(n) => n.normalize_utf8_to(text, sink),
+                Normalizer::Composing(n) => n.normalize_utf8_to(text, sink),
+            },
+        )
+    }
+
+    /// Normalizes the rest of the text and returns the sink.
+    ///
+    /// A byte sequence that is incomplete at the end of the last chunk
+    /// is mapped to the REPLACEMENT CHARACTER.
+    pub fn finish(mut self) -> Result<W, core::fmt::Error> {
+        match self.normalizer {
+            Normalizer::Decomposing(n) => n.normalize_utf8_to(&self.pending, &mut self.sink)?,
+            Normalizer::Composing(n) => n.normalize_utf8_to(&self.pending, &mut self.sink)?,
+        }
+        Ok(self.sink)
+    }
+}
+
+/// A normalizer that takes potentially-invalid UTF-16 in chunks and writes the
+/// normalized text into a `Write16` sink.
+///
+/// Chunks may end in the middle of a surrogate pair or of a combining character
+/// sequence. Only the input after the last point where normalization can restart is
+/// retained between chunks, which is normally no more than a handful of characters.
+/// The text is the same as if it had been normalized in one piece once
+/// [`finish`](Self::finish) has been called.
+///
+/// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER
+/// before normalizing.
+///
+/// If the sink returns an error, the state of the normalizer is unspecified.
+///
+/// ✨ *Enabled with the `utf16_iter` Cargo feature.*
+///
+/// # Example
+///
+/// ```
+/// use icu::normalizer::DecomposingNormalizerBorrowed;
+///
+/// let nfd = DecomposingNormalizerBorrowed::new_nfd();
+/// let mut streaming = nfd.streaming_utf16(Vec::<u16>::new());
+/// streaming.push(&[0x0061, 0x0301]).unwrap();
+/// streaming.push(&[0x0323]).unwrap();
+/// assert_eq!(streaming.finish().unwrap(), [0x0061, 0x0323, 0x0301]);
+/// ```
+#[cfg(feature = "utf16_iter")]
+#[derive(Debug)]
+pub struct StreamingNormalizerUtf16<'a, W: write16::Write16> {
+    normalizer: Normalizer<'a>,
+    pending: Vec<u16>,
+    sink: W,
+}
+
+#[cfg(feature = "utf16_iter")]
+impl<'a, W: write16::Write16> StreamingNormalizerUtf16<'a, W> {
+    pub(crate) fn new(normalizer: Normalizer<'a>, sink: W) -> Self {
+        StreamingNormalizerUtf16 {
+            normalizer,
+            pending: Vec::new(),
+            sink,
+        }
+    }
+
+    /// Normalizes the next chunk of the text.
+    ///
+    /// Output is written into the sink as soon as it can no longer be
+    /// affected by later chunks.
+    pub fn push(&mut self, chunk: &[u16]) -> core::fmt::Result {
+        let normalizer = self.normalizer;
+        let sink = &mut self.sink;
+        normalizer.push(
+            &mut self.pending,
+            chunk,
+            char_at_utf16,
+            |text| match normalizer {
+                Normalizer::Decomposing(n) => n.normalize_utf16_to(text, sink),
+                Normalizer::Composing(n) => n.normalize_utf16_to(text, sink),
+            },
+        )
+    }
+
+    /// Normalizes the rest of the text and returns the sink.
+    ///
+    /// A high surrogate at the end of the last chunk is mapped
+    /// to the REPLACEMENT CHARACTER.
+    pub fn finish(mut self) -> Result<W, core::fmt::Error> {
+        match self.normalizer {
+            Normalizer::Decomposing(n) => n.normalize_utf16_to(&self.pending, &mut self.sink)?,
+            Normalizer::Composing(n) => n.normalize_utf16_to(&self.pending, &mut self.sink)?,
+        }
+        Ok(self.sink)
+    }
+}
@@ -2,11 +2,15 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-//! Bundles the part of UTS 46 that makes sense to implement as a
-//! normalization.
+//! Implements [UTS #46: Unicode IDNA Compatibility Processing](https://www.unicode.org/reports/tr46/).
 //!
-//! This is meant to be used as a building block of an UTS 46
-//! implementation, such as the `idna` crate.
+//! [`Uts46ProcessorBorrowed`] performs the complete `ToASCII` and `ToUnicode`
+//! operations, including Punycode and the validity criteria. It needs the
+//! `icu_properties` Cargo feature.
+//!
+//! [`Uts46MapperBorrowed`] bundles only the part of UTS 46 that makes sense to
+//! implement as a normalization. It is meant to be used as a building block of
+//! another UTS 46 implementation, such as the `idna` crate.
 
 use crate::ComposingNormalizer;
 use crate::ComposingNormalizerBorrowed;
@@ -17,6 +21,16 @@ use crate::NormalizerUts46DataV1;
 use icu_provider::DataError;
 use icu_provider::DataProvider;
 
+#[cfg(feature = "icu_properties")]
+mod processor;
+#[cfg(feature = "icu_properties")]
+mod punycode;
+
+#[cfg(feature = "icu_properties")]
+pub use processor::{
+    Uts46Error, Uts46ErrorKind, Uts46Options, Uts46Processor, Uts46ProcessorBorrowed,
+};
+
 // Implementation note: Despite merely wrapping a `ComposingNormalizer`,
 // having a `Uts46Mapper` serves two purposes:
 //
@@ -0,0 +1,667 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The `ToASCII` and `ToUnicode` operations of UTS 46.
+
+use super::Uts46Mapper;
+use super::Uts46MapperBorrowed;
+use super::punycode;
+use crate::NormalizerNfcV1;
+use crate::NormalizerNfdTablesV1;
+use crate::NormalizerNfkdTablesV1;
+use crate::NormalizerUts46DataV1;
+use alloc::string::String;
+use alloc::vec::Vec;
+use displaydoc::Display;
+use icu_properties::CodePointMapData;
+use icu_properties::CodePointMapDataBorrowed;
+use icu_properties::props::BidiClass;
+use icu_properties::props::GeneralCategory;
+use icu_properties::props::GeneralCategoryGroup;
+use icu_properties::props::JoiningType;
+use icu_properties::provider::PropertyEnumBidiClassV1;
+use icu_properties::provider::PropertyEnumGeneralCategoryV1;
+use icu_properties::provider::PropertyEnumJoiningTypeV1;
+use icu_provider::prelude::*;
+
+const ZWNJ: char = '\u{200C}';
+const ZWJ: char = '\u{200D}';
+
+/// The maximum length of a label in ASCII form.
+const MAX_LABEL_LENGTH: usize = 63;
+/// The maximum length of a domain name in ASCII form, excluding the root label.
+const MAX_DOMAIN_LENGTH: usize = 253;
+
+/// The processing flags of UTS 46.
+///
+/// The [`Default`] options are the strict ones that UTS 46 uses for registration:
+/// everything is checked, and processing is nontransitional.
+///
+/// # Example
+///
+/// ```
+/// use icu::normalizer::uts46::Uts46Options;
+///
+/// let mut options = Uts46Options::default();
+/// options.use_std3_ascii_rules = false;
+/// options.verify_dns_length = false;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Uts46Options {
+    /// `UseSTD3ASCIIRules`: whether the only ASCII characters allowed in labels are
+    /// lowercase letters, digits, and the hyphen-minus.
+    pub use_std3_ascii_rules: bool,
+    /// `CheckHyphens`: whether labels may not start or end with a hyphen-minus, or
+    /// have hyphen-minuses in both their third and fourth position.
+    pub check_hyphens: bool,
+    /// `CheckBidi`: whether labels must satisfy the Bidi rule of
+    /// [RFC 5893](https://www.rfc-editor.org/rfc/rfc5893#section-2) in domain names
+    /// that contain right-to-left characters.
+    pub check_bidi: bool,
+    /// `CheckJoiners`: whether ZERO WIDTH JOINER and ZERO WIDTH NON-JOINER must satisfy
+    /// the CONTEXTJ rules of [RFC 5892](https://www.rfc-editor.org/rfc/rfc5892#appendix-A.1).
+    pub check_joiners: bool,
+    /// `Transitional_Processing`: whether the deviation characters are mapped the way
+    /// IDNA2003 did, e.g. "ß" to "ss".
+    ///
+    /// Transitional processing is deprecated.
+    pub transitional_processing: bool,
+    /// `VerifyDnsLength`: whether [`to_ascii`](Uts46ProcessorBorrowed::to_ascii) checks
+    /// that the domain name and its labels are not empty or longer than DNS allows.
+    pub verify_dns_length: bool,
+}
+
+impl Default for Uts46Options {
+    fn default() -> Self {
+        Self {
+            use_std3_ascii_rules: true,
+            check_hyphens: true,
+            check_bidi: true,
+            check_joiners: true,
+            transitional_processing: false,
+            verify_dns_length: true,
+        }
+    }
+}
+
+impl Uts46Options {
+    /// The options that the [WHATWG URL Standard](https://url.spec.whatwg.org/#idna) uses
+    /// for the host of a URL, with `beStrict` set to false.
+    pub const fn whatwg_url() -> Self {
+        Self {
+            use_std3_ascii_rules: false,
+            check_hyphens: false,
+            check_bidi: true,
+            check_joiners: true,
+            transitional_processing: false,
+            verify_dns_length: false,
+        }
+    }
+}
+
+/// The validity criterion that a label fails.
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Uts46ErrorKind {
+    /// The label starts with `xn--` but is not ASCII, is not valid Punycode, or
+    /// decodes to an empty or all-ASCII label.
+    #[displaydoc("Invalid Punycode")]
+    Punycode,
+    /// The label contains a disallowed character, a character that is not in NFC or
+    /// that would be mapped, a deviation character in transitional processing, or
+    /// ASCII that is not allowed by [`Uts46Options::use_std3_ascii_rules`].
+    #[displaydoc("Disallowed character")]
+    DisallowedCharacter,
+    /// With [`Uts46Options::check_hyphens`], the label starts or ends with a hyphen-minus,
+    /// or has hyphen-minuses in its third and fourth position. Without it, the label
+    /// decodes to a label that starts with `xn--`.
+    #[displaydoc("Invalid hyphens")]
+    Hyphens,
+    /// The label starts with a combining mark.
+    #[displaydoc("Leading combining mark")]
+    LeadingCombiningMark,
+    /// A ZERO WIDTH JOINER or ZERO WIDTH NON-JOINER is not in a context that allows it.
+    #[displaydoc("Invalid joiner context")]
+    Joiners,
+    /// The label doesn't satisfy the Bidi rule.
+    #[displaydoc("Invalid bidirectional text")]
+    Bidi,
+    /// The label is empty or longer than 63 bytes in ASCII form.
+    #[displaydoc("Invalid label length")]
+    LabelLength,
+    /// The domain name is empty or longer than 253 bytes in ASCII form.
+    #[displaydoc("Invalid domain name length")]
+    DomainLength,
+}
+
+impl core::error::Error for Uts46ErrorKind {}
+
+/// An error found by UTS 46 processing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Uts46Error {
+    /// The index of the label that the error is in, or `None` for
+    /// [`Uts46ErrorKind::DomainLength`].
+    ///
+    /// Labels are counted in the mapped domain name, starting from zero.
+    pub label: Option<usize>,
+    /// What is wrong with the label.
+    pub kind: Uts46ErrorKind,
+}
+
+impl core::fmt::Display for Uts46Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.label {
+            Some(label) => write!(f, "{} in label {label}", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl core::error::Error for Uts46Error {}
+
+/// A label after the Convert/Validate step.
+struct Label {
+    text: String,
+    /// Whether the label still needs to be checked against the validity criteria,
+    /// and whether transitionally.
+    validate: Option<bool>,
+}
+
+/// A borrowed version of a processor for the `ToASCII` and `ToUnicode` operations of
+/// [UTS #46: Unicode IDNA Compatibility Processing](https://www.unicode.org/reports/tr46/).
+///
+/// Every entry point returns all the errors that were found, along with the index
+/// of the label each was found in.
+///
+/// ✨ *Enabled with the `icu_properties` Cargo feature.*
+///
+/// # Example
+///
+/// ```
+/// use icu::normalizer::uts46::Uts46ErrorKind;
+/// use icu::normalizer::uts46::Uts46Options;
+/// use icu::normalizer::uts46::Uts46ProcessorBorrowed;
+///
+/// let uts46 = Uts46ProcessorBorrowed::new();
+/// let options = Uts46Options::default();
+///
+/// assert_eq!(
+///     uts46.to_ascii("Bücher.Example", options).unwrap(),
+///     "xn--bcher-kva.example"
+/// );
+///
+/// let (unicode, errors) = uts46.to_unicode("xn--bcher-kva.example", options);
+/// assert_eq!(unicode, "bücher.example");
+/// assert!(errors.is_empty());
+///
+/// let errors = uts46.to_ascii("example.-bücher", options).unwrap_err();
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].label, Some(1));
+/// assert_eq!(errors[0].kind, Uts46ErrorKind::Hyphens);
+/// ```
+#[derive(Debug)]
+pub struct Uts46ProcessorBorrowed<'a> {
+    mapper: Uts46MapperBorrowed<'a>,
+    general_category: CodePointMapDataBorrowed<'a, GeneralCategory>,
+    bidi_class: CodePointMapDataBorrowed<'a, BidiClass>,
+    joining_type: CodePointMapDataBorrowed<'a, JoiningType>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for Uts46ProcessorBorrowed<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Uts46ProcessorBorrowed<'static> {
+    /// Cheaply converts a [`Uts46ProcessorBorrowed<'static>`] into a [`Uts46Processor`].
+    ///
+    /// Note: Due to branching and indirection, using [`Uts46Processor`] might inhibit some
+    /// compile-time optimizations that are possible with [`Uts46ProcessorBorrowed`].
+    pub const fn static_to_owned(self) -> Uts46Processor {
+        Uts46Processor {
+            mapper: self.mapper.static_to_owned(),
+            general_category: self.general_category.static_to_owned(),
+            bidi_class: self.bidi_class.static_to_owned(),
+            joining_type: self.joining_type.static_to_owned(),
+        }
+    }
+
+    /// Constructor using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Uts46ProcessorBorrowed {
+            mapper: Uts46MapperBorrowed::new(),
+            general_category: CodePointMapData::<GeneralCategory>::new(),
+            bidi_class: CodePointMapData::<BidiClass>::new(),
+            joining_type: CodePointMapData::<JoiningType>::new(),
+        }
+    }
+}
+
+impl Uts46ProcessorBorrowed<'_> {
+    /// Performs `ToASCII` on a domain name.
+    ///
+    /// Returns the domain name with every label that is not ASCII converted to Punycode,
+    /// or all the errors that were found.
+    pub fn to_ascii(&self, domain: &str, options: Uts46Options) -> Result<String, Vec<Uts46Error>> {
+        self.to_ascii_impl(domain.chars(), options)
+    }
+
+    /// Performs `ToASCII` on a domain name in potentially-invalid UTF-16.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER,
+    /// which is disallowed.
+    ///
+    /// See [`to_ascii`](Self::to_ascii).
+    pub fn to_ascii_utf16(
+        &self,
+        domain: &[u16],
+        options: Uts46Options,
+    ) -> Result<Vec<u16>, Vec<Uts46Error>> {
+        self.to_ascii_impl(chars_utf16(domain), options)
+            .map(|ascii| ascii.encode_utf16().collect())
+    }
+
+    /// Performs `ToUnicode` on a domain name.
+    ///
+    /// Returns the domain name with every label mapped and converted from Punycode,
+    /// together with all the errors that were found. The domain name is returned even
+    /// if there are errors so that it can be displayed; a label that fails to convert
+    /// from Punycode is left as is.
+    pub fn to_unicode(&self, domain: &str, options: Uts46Options) -> (String, Vec<Uts46Error>) {
+        let mut errors = Vec::new();
+        let labels = self.process(domain.chars(), options, &mut errors);
+        (join(labels.iter().map(|label| label.text.as_str())), errors)
+    }
+
+    /// Performs `ToUnicode` on a domain name in potentially-invalid UTF-16.
+    ///
+    /// Unpaired surrogates are mapped to the REPLACEMENT CHARACTER,
+    /// which is disallowed.
+    ///
+    /// See [`to_unicode`](Self::to_unicode).
+    pub fn to_unicode_utf16(
+        &self,
+        domain: &[u16],
+        options: Uts46Options,
+    ) -> (Vec<u16>, Vec<Uts46Error>) {
+        let mut errors = Vec::new();
+        let labels = self.process(chars_utf16(domain), options, &mut errors);
+        let unicode = join(labels.iter().map(|label| label.text.as_str()));
+        (unicode.encode_utf16().collect(), errors)
+    }
+
+    fn to_ascii_impl(
+        &self,
+        domain: impl Iterator<Item = char>,
+        options: Uts46Options,
+    ) -> Result<String, Vec<Uts46Error>> {
+        let mut errors = Vec::new();
+        let labels = self.process(domain, options, &mut errors);
+        let mut ascii_labels = Vec::with_capacity(labels.len());
+        for (i, label) in labels.into_iter().enumerate() {
+            if label.text.is_ascii() {
+                ascii_labels.push(label.text);
+            } else if let Some(encoded) = punycode::encode(&label.text) {
+                ascii_labels.push(alloc::format!("xn--{encoded}"));
+            } else {
+                errors.push(Uts46Error {
+                    label: Some(i),
+                    kind: Uts46ErrorKind::Punycode,
+                });
+                ascii_labels.push(label.text);
+            }
+        }
+        if options.verify_dns_length {
+            // The root label is the empty label after a trailing dot.
+            let labels = match ascii_labels.split_last() {
+                Some((root, rest)) if root.is_empty() && !rest.is_empty() => rest,
+                _ => ascii_labels.as_slice(),
+            };
+            for (i, label) in labels.iter().enumerate() {
+                if label.is_empty() || label.len() > MAX_LABEL_LENGTH {
+                    errors.push(Uts46Error {
+                        label: Some(i),
+                        kind: Uts46ErrorKind::LabelLength,
+                    });
+                }
+            }
+            let length =
+                labels.iter().map(String::len).sum::<usize>() + labels.len().saturating_sub(1);
+            if length == 0 || length > MAX_DOMAIN_LENGTH {
+                errors.push(Uts46Error {
+                    label: None,
+                    kind: Uts46ErrorKind::DomainLength,
+                });
+            }
+        }
+        if errors.is_empty() {
+            Ok(join(ascii_labels.iter().map(String::as_str)))
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// The Map, Normalize, Break, and Convert/Validate steps of the
+    /// Processing section of UTS 46.
+    fn process(
+        &self,
+        domain: impl Iterator<Item = char>,
+        options: Uts46Options,
+        errors: &mut Vec<Uts46Error>,
+    ) -> Vec<Label> {
+        let mut mapped: String = self.mapper.map_normalize(domain).collect();
+        // Like ICU4C, map the deviation characters after the mapping, which maps
+        // e.g. ẞ to ß, and normalize again.
+        if options.transitional_processing && mapped.chars().any(is_deviation) {
+            mapped = self
+                .mapper
+                .map_normalize(mapped.chars().flat_map(map_transitional))
+                .collect();
+        }
+        let mut labels: Vec<Label> = mapped
+            .split('.')
+            .enumerate()
+            .map(|(i, label)| {
+                let Some(encoded) = label.strip_prefix("xn--") else {
+                    return Label {
+                        text: String::from(label),
+                        validate: Some(options.transitional_processing),
+                    };
+                };
+                let mut error = |kind| {
+                    errors.push(Uts46Error {
+                        label: Some(i),
+                        kind,
+                    })
+                };
+                match punycode::decode(encoded).filter(|_| label.is_ascii()) {
+                    Some(decoded) => {
+                        if decoded.is_ascii() {
+                            error(Uts46ErrorKind::Punycode);
+                        }
+                        Label {
+                            text: decoded,
+                            validate: Some(false),
+                        }
+                    }
+                    None => {
+                        error(Uts46ErrorKind::Punycode);
+                        Label {
+                            text: String::from(label),
+                            validate: None,
+                        }
+                    }
+                }
+            })
+            .collect();
+        let is_bidi_domain = options.check_bidi
+            && labels.iter().any(|label| {
+                label.text.chars().any(|c| {
+                    matches!(
+                        self.bidi_class.get(c),
+                        BidiClass::RightToLeft | BidiClass::ArabicLetter | BidiClass::ArabicNumber
+                    )
+                })
+            });
+        for (i, label) in labels.iter_mut().enumerate() {
+            if let Some(transitional) = label.validate.take() {
+                self.validate(&label.text, transitional, is_bidi_domain, options, |kind| {
+                    errors.push(Uts46Error {
+                        label: Some(i),
+                        kind,
+                    })
+                });
+            }
+        }
+        labels
+    }
+
+    /// Checks a label against the validity criteria of UTS 46 and reports
+    /// each criterion that is not satisfied.
+    fn validate(
+        &self,
+        label: &str,
+        transitional: bool,
+        is_bidi_domain: bool,
+        options: Uts46Options,
+        mut error: impl FnMut(Uts46ErrorKind),
+    ) {
+        if self
+            .mapper
+            .normalize_validate(label.chars())
+            .ne(label.chars())
+            || label.contains(char::REPLACEMENT_CHARACTER)
+            || (transitional && label.chars().any(is_deviation))
+            || (options.use_std3_ascii_rules
+                && label
+                    .bytes()
+                    .any(|b| b.is_ascii() && !matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'-')))
+        {
+            error(Uts46ErrorKind::DisallowedCharacter);
+        }
+        if options.check_hyphens {
+            if label.get(2..4) == Some("--") || label.starts_with('-') || label.ends_with('-') {
+                error(Uts46ErrorKind::Hyphens);
+            }
+        } else if label.starts_with("xn--") {
+            error(Uts46ErrorKind::Hyphens);
+        }
+        if label
+            .chars()
+            .next()
+            .is_some_and(|c| GeneralCategoryGroup::Mark.contains(self.general_category.get(c)))
+        {
+            error(Uts46ErrorKind::LeadingCombiningMark);
+        }
+        if options.check_joiners && !self.has_valid_joiners(label) {
+            error(Uts46ErrorKind::Joiners);
+        }
+        if is_bidi_domain && !self.satisfies_bidi_rule(label) {
+            error(Uts46ErrorKind::Bidi);
+        }
+    }
+
+    /// The CONTEXTJ rules of RFC 5892, Appendix A.1 and A.2.
+    fn has_valid_joiners(&self, label: &str) -> bool {
+        label.char_indices().all(|(i, c)| {
+            if c != ZWNJ && c != ZWJ {
+                return true;
+            }
+            let (before, after) = label.split_at(i);
+            if before
+                .chars()
+                .next_back()
+                .is_some_and(|c| self.mapper.is_virama(c))
+            {
+                return true;
+            }
+            if c == ZWJ {
+                return false;
+            }
+            // (Joining_Type:{L,D})(Joining_Type:T)*\u200C(Joining_Type:T)*(Joining_Type:{R,D})
+            matches!(
+                self.first_non_transparent(before.chars().rev()),
+                Some(JoiningType::LeftJoining | JoiningType::DualJoining)
+            ) && matches!(
+                self.first_non_transparent(after.chars().skip(1)),
+                Some(JoiningType::RightJoining | JoiningType::DualJoining)
+            )
+        })
+    }
+
+    /// The joining type of the first character that is not transparent.
+    fn first_non_transparent(&self, chars: impl Iterator<Item = char>) -> Option<JoiningType> {
+        chars
+            .map(|c| self.joining_type.get(c))
+            .find(|&joining_type| joining_type != JoiningType::Transparent)
+    }
+
+    /// The Bidi rule of RFC 5893, Section 2.
+    fn satisfies_bidi_rule(&self, label: &str) -> bool {
+        use BidiClass as B;
+        let mut classes = label.chars().map(|c| self.bidi_class.get(c));
+        let Some(first) = classes.next() else {
+            return true;
+        };
+        // The class of the last character that is not NSM.
+        let mut last = first;
+        match first {
+            // Rule 1 and rule 2
+            B::RightToLeft | B::ArabicLetter => {
+                let mut has_en = false;
+                let mut has_an = false;
+                for class in classes {
+                    match class {
+                        B::RightToLeft
+                        | B::ArabicLetter
+                        | B::EuropeanSeparator
+                        | B::CommonSeparator
+                        | B::EuropeanTerminator
+                        | B::OtherNeutral
+                        | B::BoundaryNeutral => {}
+                        B::EuropeanNumber => has_en = true,
+                        B::ArabicNumber => has_an = true,
+                        B::NonspacingMark => continue,
+                        _ => return false,
+                    }
+                    last = class;
+                }
+                // Rule 3 and rule 4
+                matches!(
+                    last,
+                    B::RightToLeft | B::ArabicLetter | B::EuropeanNumber | B::ArabicNumber
+                ) && !(has_en && has_an)
+            }
+            // Rule 1 and rule 5
+            B::LeftToRight => {
+                for class in classes {
+                    match class {
+                        B::LeftToRight
+                        | B::EuropeanNumber
+                        | B::EuropeanSeparator
+                        | B::CommonSeparator
+                        | B::EuropeanTerminator
+                        | B::OtherNeutral
+                        | B::BoundaryNeutral => {}
+                        B::NonspacingMark => continue,
+                        _ => return false,
+                    }
+                    last = class;
+                }
+                // Rule 6
+                matches!(last, B::LeftToRight | B::EuropeanNumber)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A processor for the `ToASCII` and `ToUnicode` operations of
+/// [UTS #46: Unicode IDNA Compatibility Processing](https://www.unicode.org/reports/tr46/).
+///
+/// See [`Uts46ProcessorBorrowed`].
+///
+/// ✨ *Enabled with the `icu_properties` Cargo feature.*
+#[derive(Debug)]
+pub struct Uts46Processor {
+    mapper: Uts46Mapper,
+    general_category: CodePointMapData<GeneralCategory>,
+    bidi_class: CodePointMapData<BidiClass>,
+    joining_type: CodePointMapData<JoiningType>,
+}
+
+impl Uts46Processor {
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> Uts46ProcessorBorrowed<'_> {
+        Uts46ProcessorBorrowed {
+            mapper: self.mapper.as_borrowed(),
+            general_category: self.general_category.as_borrowed(),
+            bidi_class: self.bidi_class.as_borrowed(),
+            joining_type: self.joining_type.as_borrowed(),
+        }
+    }
+
+    /// Constructor using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[expect(clippy::new_ret_no_self)]
+    pub const fn new() -> Uts46ProcessorBorrowed<'static> {
+        Uts46ProcessorBorrowed::new()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerUts46DataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkdTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + DataProvider<PropertyEnumGeneralCategoryV1>
+            + DataProvider<PropertyEnumBidiClassV1>
+            + DataProvider<PropertyEnumJoiningTypeV1>
+            + ?Sized,
+    {
+        Ok(Uts46Processor {
+            mapper: Uts46Mapper::try_new(provider)?,
+            general_category: CodePointMapData::try_new_unstable(provider)?,
+            bidi_class: CodePointMapData::try_new_unstable(provider)?,
+            joining_type: CodePointMapData::try_new_unstable(provider)?,
+        })
+    }
+}
+
+/// The characters of potentially-invalid UTF-16, with unpaired
+/// surrogates mapped to the REPLACEMENT CHARACTER.
+fn chars_utf16(text: &[u16]) -> impl Iterator<Item = char> + '_ {
+    char::decode_utf16(text.iter().copied()).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+}
+
+fn join<'a>(labels: impl Iterator<Item = &'a str>) -> String {
+    let mut domain = String::new();
+    for (i, label) in labels.enumerate() {
+        if i != 0 {
+            domain.push('.');
+        }
+        domain.push_str(label);
+    }
+    domain
+}
+
+/// The deviation characters, whose status depends on `Transitional_Processing`.
+fn is_deviation(c: char) -> bool {
+    matches!(c, 'ß' | 'ς' | ZWNJ | ZWJ)
+}
+
+/// Maps the deviation characters the way transitional processing does.
+fn map_transitional(c: char) -> impl Iterator<Item = char> {
+    let (first, second) = match c {
+        'ß' => (Some('s'), Some('s')),
+        'ς' => (Some('σ'), None),
+        ZWNJ | ZWJ => (None, None),
+        c => (Some(c), None),
+    };
+    first.into_iter().chain(second)
+}
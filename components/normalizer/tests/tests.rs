@@ -6,6 +6,10 @@ use icu_normalizer::ComposingNormalizer;
 use icu_normalizer::ComposingNormalizerBorrowed;
 use icu_normalizer::DecomposingNormalizer;
 use icu_normalizer::DecomposingNormalizerBorrowed;
+use icu_normalizer::FcdNormalizer;
+use icu_normalizer::FcdNormalizerBorrowed;
+use icu_normalizer::NfkcCasefoldNormalizer;
+use icu_normalizer::NfkcCasefoldNormalizerBorrowed;
 use icu_normalizer::properties::CanonicalCombiningClassMap;
 use icu_normalizer::properties::CanonicalCombiningClassMapBorrowed;
 use icu_normalizer::properties::CanonicalComposition;
@@ -15,6 +19,8 @@ use icu_normalizer::properties::CanonicalDecompositionBorrowed;
 use icu_normalizer::properties::Decomposed;
 use icu_normalizer::uts46::Uts46Mapper;
 use icu_normalizer::uts46::Uts46MapperBorrowed;
+#[cfg(feature = "icu_properties")]
+use icu_normalizer::uts46::{Uts46ErrorKind, Uts46Options, Uts46ProcessorBorrowed};
 
 #[test]
 fn test_nfd_basic() {
@@ -180,6 +186,210 @@ fn test_nfkc_owned() {
     assert_eq!(normalizer.normalize("\u{0345}"), "\u{0345}"); // Iota subscript
 }
 
+#[test]
+fn test_fcd_basic() {
+    let normalizer = FcdNormalizerBorrowed::new();
+    assert!(normalizer.is_normalized("ä"));
+    assert!(normalizer.is_normalized("a\u{0308}"));
+    assert!(normalizer.is_normalized("\u{1EA1}\u{0302}")); // ậ decomposes in order
+    assert!(!normalizer.is_normalized("\u{00E2}\u{0323}")); // ậ does not
+    assert_eq!(
+        normalizer.normalize("\u{00E2}\u{0323}"),
+        "a\u{0323}\u{0302}"
+    );
+    assert_eq!(
+        normalizer.normalize("x\u{00E2}\u{0323}y\u{00E2}"),
+        "xa\u{0323}\u{0302}y\u{00E2}"
+    );
+    assert_eq!(
+        normalizer.normalize("\u{0344}\u{0323}"),
+        "\u{0323}\u{0308}\u{0301}"
+    );
+    assert_eq!(normalizer.normalize("\u{212B}"), "\u{212B}"); // FCD is not unique
+    assert_eq!(normalizer.normalize("\u{AC00}\u{11A8}"), "\u{AC00}\u{11A8}");
+
+    let utf8 = "\u{00E2}\u{0323}".as_bytes();
+    assert!(!normalizer.is_normalized_utf8(utf8));
+    assert_eq!(normalizer.normalize_utf8(utf8), "a\u{0323}\u{0302}");
+    let utf16: Vec<u16> = "\u{00E2}\u{0323}".encode_utf16().collect();
+    assert!(!normalizer.is_normalized_utf16(&utf16));
+    assert_eq!(
+        normalizer.normalize_utf16(&utf16),
+        [0x61u16, 0x0323, 0x0302].as_slice()
+    );
+}
+
+#[test]
+fn test_fcd_owned() {
+    let owned = FcdNormalizer::try_new_unstable(&icu_normalizer::provider::Baked).unwrap();
+    let normalizer = owned.as_borrowed();
+    assert!(normalizer.is_normalized("a\u{0308}"));
+    assert_eq!(
+        normalizer.normalize("\u{00E2}\u{0323}"),
+        "a\u{0323}\u{0302}"
+    );
+}
+
+#[test]
+fn test_fcc_basic() {
+    let normalizer = ComposingNormalizerBorrowed::new_fcc();
+    assert_eq!(normalizer.normalize("a\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("e\u{0323}\u{0302}"), "ệ");
+    assert_eq!(normalizer.normalize("𝅗𝅥"), "𝅗\u{1D165}"); // Composition exclusion
+    assert_eq!(normalizer.normalize("\u{2126}"), "Ω"); // ohm sign
+    assert_eq!(normalizer.normalize("ﬁ"), "ﬁ"); // ligature unchanged
+    assert_eq!(normalizer.normalize("\u{1100}\u{1161}\u{11A8}"), "\u{AC01}");
+
+    // Unlike NFC, FCC does not compose across the intervening U+0334.
+    assert_eq!(
+        normalizer.normalize("a\u{0334}\u{0301}"),
+        "a\u{0334}\u{0301}"
+    );
+    assert_eq!(
+        ComposingNormalizerBorrowed::new_nfc().normalize("a\u{0334}\u{0301}"),
+        "á\u{0334}"
+    );
+    assert!(normalizer.is_normalized("a\u{0334}\u{0301}"));
+    assert!(!normalizer.is_normalized("á\u{0334}"));
+    assert_eq!(normalizer.normalize("\u{00E2}\u{0323}"), "\u{1EAD}");
+
+    let utf16: Vec<u16> = "a\u{0334}\u{0301}".encode_utf16().collect();
+    assert!(normalizer.is_normalized_utf16(&utf16));
+    assert_eq!(
+        normalizer.normalize_utf8("á\u{0334}".as_bytes()),
+        "a\u{0334}\u{0301}"
+    );
+}
+
+#[test]
+fn test_fcc_owned() {
+    let owned =
+        ComposingNormalizer::try_new_fcc_unstable(&icu_normalizer::provider::Baked).unwrap();
+    let normalizer = owned.as_borrowed();
+    assert_eq!(normalizer.normalize("a\u{0308}"), "ä");
+    assert_eq!(
+        normalizer.normalize("a\u{0334}\u{0301}"),
+        "a\u{0334}\u{0301}"
+    );
+}
+
+#[test]
+fn test_nfkc_casefold_basic() {
+    let normalizer = NfkcCasefoldNormalizerBorrowed::new_nfkc_casefold();
+    assert_eq!(normalizer.normalize("Abc"), "abc");
+    assert_eq!(normalizer.normalize("A\u{0308}"), "ä");
+    assert_eq!(normalizer.normalize("ﬁ"), "fi"); // ligature
+    assert_eq!(normalizer.normalize("Ⓐ"), "a"); // compatibility mapping
+    assert_eq!(normalizer.normalize("ß"), "ss");
+    assert_eq!(normalizer.normalize("\u{1E9E}"), "ss");
+    assert_eq!(normalizer.normalize("A\u{00AD}B\u{200B}c"), "abc"); // default ignorables
+    assert_eq!(normalizer.normalize("\u{2126}"), "ω"); // ohm sign
+    assert_eq!(normalizer.normalize("\u{212B}"), "å"); // angstrom sign
+    assert_eq!(normalizer.normalize("\u{0130}"), "i\u{0307}");
+    assert_eq!(normalizer.normalize("\u{1F88}"), "\u{1F00}ι");
+    assert_eq!(normalizer.normalize("ﾍﾟ"), "ペ"); // half-width
+    assert_eq!(normalizer.normalize("\u{FDFA}").chars().count(), 18);
+    assert!(normalizer.is_normalized("strasse"));
+    assert!(!normalizer.is_normalized("Strasse"));
+    assert!(!normalizer.is_normalized("a\u{0308}"));
+
+    let utf8 = "Stra\u{00DF}e".as_bytes();
+    assert_eq!(normalizer.normalize_utf8(utf8), "strasse");
+    let utf16: Vec<u16> = "Stra\u{00DF}e".encode_utf16().collect();
+    let expected: Vec<u16> = "strasse".encode_utf16().collect();
+    assert_eq!(normalizer.normalize_utf16(&utf16), expected.as_slice());
+}
+
+#[test]
+fn test_nfkc_simple_casefold_basic() {
+    let normalizer = NfkcCasefoldNormalizerBorrowed::new_nfkc_simple_casefold();
+    assert_eq!(normalizer.normalize("Abc"), "abc");
+    assert_eq!(normalizer.normalize("ﬁ"), "fi");
+    assert_eq!(normalizer.normalize("ß"), "ß");
+    assert_eq!(normalizer.normalize("\u{1E9E}"), "ß");
+    assert_eq!(normalizer.normalize("A\u{00AD}B"), "ab");
+    assert_eq!(normalizer.normalize("\u{0130}"), "\u{0130}");
+    assert_eq!(normalizer.normalize("\u{1F88}"), "\u{1F80}");
+    assert_eq!(normalizer.normalize("\u{0345}"), "ι");
+}
+
+#[test]
+fn test_nfkc_casefold_owned() {
+    let owned =
+        NfkcCasefoldNormalizer::try_new_nfkc_casefold_unstable(&icu_normalizer::provider::Baked)
+            .unwrap();
+    let normalizer = owned.as_borrowed();
+    assert_eq!(normalizer.normalize("Stra\u{00DF}e"), "strasse");
+
+    let owned = NfkcCasefoldNormalizer::try_new_nfkc_simple_casefold_unstable(
+        &icu_normalizer::provider::Baked,
+    )
+    .unwrap();
+    let normalizer = owned.as_borrowed();
+    assert_eq!(normalizer.normalize("Stra\u{00DF}e"), "stra\u{00DF}e");
+}
+
+#[test]
+fn test_streaming_utf8() {
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+    let nfkc = ComposingNormalizerBorrowed::new_nfkc();
+    let nfkd = DecomposingNormalizerBorrowed::new_nfkd();
+
+    let text = "Ame\u{0301}lie \u{1100}\u{1161}\u{11A8} \u{00E2}\u{0323} ﬁ \u{1D15E}".as_bytes();
+    for chunk_len in 1..=text.len() {
+        let mut streaming = nfc.streaming_utf8(String::new());
+        let mut streaming_nfd = nfd.streaming_utf8(String::new());
+        let mut streaming_nfkc = nfkc.streaming_utf8(String::new());
+        let mut streaming_nfkd = nfkd.streaming_utf8(String::new());
+        for chunk in text.chunks(chunk_len) {
+            streaming.push(chunk).unwrap();
+            streaming_nfd.push(chunk).unwrap();
+            streaming_nfkc.push(chunk).unwrap();
+            streaming_nfkd.push(chunk).unwrap();
+        }
+        assert_eq!(streaming.finish().unwrap(), nfc.normalize_utf8(text));
+        assert_eq!(streaming_nfd.finish().unwrap(), nfd.normalize_utf8(text));
+        assert_eq!(streaming_nfkc.finish().unwrap(), nfkc.normalize_utf8(text));
+        assert_eq!(streaming_nfkd.finish().unwrap(), nfkd.normalize_utf8(text));
+    }
+
+    // Only the pending combining sequence is held back.
+    let mut streaming = nfc.streaming_utf8(String::new());
+    streaming.push(b"abc e").unwrap();
+    streaming.push(b"\xCC\x81\xCC").unwrap();
+    streaming.push(b"\xA3").unwrap();
+    streaming.push(b"x").unwrap();
+    // An incomplete sequence at the end of the input
+    streaming.push(b"\xE2\x82").unwrap();
+    assert_eq!(streaming.finish().unwrap(), "abc \u{1EB9}\u{0301}x\u{FFFD}");
+}
+
+#[test]
+fn test_streaming_utf16() {
+    let nfc = ComposingNormalizerBorrowed::new_nfc();
+    let nfd = DecomposingNormalizerBorrowed::new_nfd();
+
+    let text: Vec<u16> = "Ame\u{0301}lie \u{1100}\u{1161}\u{11A8} \u{00E2}\u{0323} \u{1D15E}"
+        .encode_utf16()
+        .collect();
+    for chunk_len in 1..=text.len() {
+        let mut streaming = nfc.streaming_utf16(Vec::<u16>::new());
+        let mut streaming_nfd = nfd.streaming_utf16(Vec::<u16>::new());
+        for chunk in text.chunks(chunk_len) {
+            streaming.push(chunk).unwrap();
+            streaming_nfd.push(chunk).unwrap();
+        }
+        assert_eq!(streaming.finish().unwrap(), *nfc.normalize_utf16(&text));
+        assert_eq!(streaming_nfd.finish().unwrap(), *nfd.normalize_utf16(&text));
+    }
+
+    // A high surrogate at the end of the input
+    let mut streaming = nfc.streaming_utf16(Vec::<u16>::new());
+    streaming.push(&[0x0061, 0xD834]).unwrap();
+    assert_eq!(streaming.finish().unwrap(), [0x0061, 0xFFFD]);
+}
+
 #[test]
 fn test_uts46_map_normalize() {
     let mapper = Uts46MapperBorrowed::new();
@@ -454,6 +664,302 @@ fn test_uts46_normalize_validate() {
     );
 }
 
+#[cfg(feature = "icu_properties")]
+fn uts46_errors(
+    label: Option<usize>,
+    kinds: &[Uts46ErrorKind],
+) -> Vec<(Option<usize>, Uts46ErrorKind)> {
+    kinds.iter().map(|&kind| (label, kind)).collect()
+}
+
+#[cfg(feature = "icu_properties")]
+fn uts46_to_ascii(
+    domain: &str,
+    options: Uts46Options,
+) -> Result<String, Vec<(Option<usize>, Uts46ErrorKind)>> {
+    Uts46ProcessorBorrowed::new()
+        .to_ascii(domain, options)
+        .map_err(|errors| errors.iter().map(|e| (e.label, e.kind)).collect())
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_to_ascii() {
+    let options = Uts46Options::default();
+    assert_eq!(
+        uts46_to_ascii("Bücher.Example", options).unwrap(),
+        "xn--bcher-kva.example"
+    );
+    assert_eq!(
+        uts46_to_ascii("MÜNCHEN.de", options).unwrap(),
+        "xn--mnchen-3ya.de"
+    );
+    assert_eq!(
+        uts46_to_ascii("example.com", options).unwrap(),
+        "example.com"
+    );
+    assert_eq!(
+        uts46_to_ascii("ＥＸＡＭＰＬＥ。com", options).unwrap(),
+        "example.com"
+    );
+    // Samples from RFC 3492, Section 7.1
+    assert_eq!(
+        uts46_to_ascii("他们为什么不说中文", options).unwrap(),
+        "xn--ihqwcrb4cv8a8dqg056pqjye"
+    );
+    assert_eq!(
+        uts46_to_ascii(
+            "\u{0644}\u{064A}\u{0647}\u{0645}\u{0627}\u{0628}\u{062A}\u{0643}\u{0644}\u{0645}\u{0648}\u{0634}\u{0639}\u{0631}\u{0628}\u{064A}\u{061F}",
+            options
+        )
+        .unwrap(),
+        "xn--egbpdaj6bu4bxfgehfvwxn"
+    );
+    assert_eq!(
+        uts46_to_ascii("3年B組金八先生", options).unwrap(),
+        "xn--3b-ww4c5e180e575a65lsy2b"
+    );
+    // Labels that are already Punycode are validated but kept.
+    assert_eq!(
+        uts46_to_ascii("xn--bcher-kva.example", options).unwrap(),
+        "xn--bcher-kva.example"
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_transitional() {
+    let mut options = Uts46Options::default();
+    assert_eq!(uts46_to_ascii("faß.de", options).unwrap(), "xn--fa-hia.de");
+    assert_eq!(uts46_to_ascii("faẞ.de", options).unwrap(), "xn--fa-hia.de");
+    assert_eq!(uts46_to_ascii("βόλος", options).unwrap(), "xn--nxasmm1c");
+    assert_eq!(uts46_to_ascii("βόλοσ", options).unwrap(), "xn--nxasmq6b");
+    options.transitional_processing = true;
+    assert_eq!(uts46_to_ascii("faß.de", options).unwrap(), "fass.de");
+    assert_eq!(uts46_to_ascii("FAẞ.de", options).unwrap(), "fass.de");
+    assert_eq!(uts46_to_ascii("βόλος", options).unwrap(), "xn--nxasmq6b");
+    assert_eq!(uts46_to_ascii("a\u{200D}b", options).unwrap(), "ab");
+    // Punycode labels are validated nontransitionally.
+    assert_eq!(
+        uts46_to_ascii("xn--fa-hia.de", options).unwrap(),
+        "xn--fa-hia.de"
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_to_unicode() {
+    let uts46 = Uts46ProcessorBorrowed::new();
+    let options = Uts46Options::default();
+    assert_eq!(
+        uts46.to_unicode("xn--bcher-kva.Example", options),
+        (String::from("bücher.example"), Vec::new())
+    );
+    assert_eq!(
+        uts46.to_unicode("xn--fa-hia.de.", options),
+        (String::from("faß.de."), Vec::new())
+    );
+    // Lengths are not verified.
+    assert_eq!(uts46.to_unicode("", options), (String::new(), Vec::new()));
+    // The domain name is returned along with the errors.
+    let (unicode, errors) = uts46.to_unicode("a.xn--a!.-b", options);
+    assert_eq!(unicode, "a.xn--a!.-b");
+    assert_eq!(
+        errors.iter().map(|e| (e.label, e.kind)).collect::<Vec<_>>(),
+        [
+            (Some(1), Uts46ErrorKind::Punycode),
+            (Some(2), Uts46ErrorKind::Hyphens)
+        ]
+    );
+    assert_eq!(errors[0].to_string(), "Invalid Punycode in label 1");
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_errors() {
+    use Uts46ErrorKind::*;
+    let options = Uts46Options::default();
+    // Punycode
+    assert_eq!(
+        uts46_to_ascii("xn--ab-.de", options),
+        Err(uts46_errors(Some(0), &[Punycode]))
+    );
+    assert_eq!(
+        uts46_to_ascii("de.xn--", options),
+        Err(uts46_errors(Some(1), &[Punycode]))
+    );
+    assert_eq!(
+        uts46_to_ascii("xn--ü.de", options),
+        Err(uts46_errors(Some(0), &[Punycode]))
+    );
+    assert_eq!(
+        uts46_to_ascii("xn--a!b.de", options),
+        Err(uts46_errors(Some(0), &[Punycode]))
+    );
+    // Disallowed characters
+    assert_eq!(
+        uts46_to_ascii("a\u{0080}b", options),
+        Err(uts46_errors(Some(0), &[DisallowedCharacter]))
+    );
+    assert_eq!(
+        uts46_to_ascii("de.a_b", options),
+        Err(uts46_errors(Some(1), &[DisallowedCharacter]))
+    );
+    // Decoded labels must be in NFC and must not contain mapped characters.
+    assert_eq!(
+        uts46_to_ascii("xn--a-ecp.de", options),
+        Err(uts46_errors(Some(0), &[DisallowedCharacter]))
+    );
+    // Hyphens
+    assert_eq!(
+        uts46_to_ascii("ab--c.de", options),
+        Err(uts46_errors(Some(0), &[Hyphens]))
+    );
+    assert_eq!(
+        uts46_to_ascii("a-.de", options),
+        Err(uts46_errors(Some(0), &[Hyphens]))
+    );
+    assert_eq!(
+        uts46_to_ascii("de.-a", options),
+        Err(uts46_errors(Some(1), &[Hyphens]))
+    );
+    // Leading combining mark
+    assert_eq!(
+        uts46_to_ascii("\u{0301}a", options),
+        Err(uts46_errors(Some(0), &[LeadingCombiningMark]))
+    );
+    // Joiners
+    assert_eq!(
+        uts46_to_ascii("a\u{200D}b", options),
+        Err(uts46_errors(Some(0), &[Joiners]))
+    );
+    assert_eq!(
+        uts46_to_ascii("a\u{200C}b", options),
+        Err(uts46_errors(Some(0), &[Joiners]))
+    );
+    assert!(uts46_to_ascii("\u{0915}\u{094D}\u{200D}\u{0937}", options).is_ok());
+    assert!(uts46_to_ascii("\u{0915}\u{094D}\u{200C}\u{0937}", options).is_ok());
+    assert!(uts46_to_ascii("\u{0628}\u{064E}\u{200C}\u{0628}", options).is_ok());
+    assert_eq!(
+        uts46_to_ascii("\u{0628}\u{200C}\u{0627}\u{200C}\u{0628}", options),
+        Err(uts46_errors(Some(0), &[Joiners]))
+    );
+    // Bidi
+    assert!(uts46_to_ascii("0a.b", options).is_ok());
+    assert_eq!(
+        uts46_to_ascii("0a.\u{05D0}", options),
+        Err(uts46_errors(Some(0), &[Bidi]))
+    );
+    assert!(uts46_to_ascii("a0.\u{05D0}1", options).is_ok());
+    assert_eq!(
+        uts46_to_ascii("a.1\u{05D0}", options),
+        Err(uts46_errors(Some(1), &[Bidi]))
+    );
+    assert_eq!(
+        uts46_to_ascii("\u{05D0}a", options),
+        Err(uts46_errors(Some(0), &[Bidi]))
+    );
+    assert_eq!(
+        uts46_to_ascii("\u{05D0}1\u{0661}", options),
+        Err(uts46_errors(Some(0), &[Bidi]))
+    );
+    // Several errors in one label
+    assert_eq!(
+        uts46_to_ascii("-\u{200D}-", options),
+        Err(uts46_errors(Some(0), &[Hyphens, Joiners]))
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_dns_length() {
+    use Uts46ErrorKind::*;
+    let options = Uts46Options::default();
+    let label = "a".repeat(63);
+    assert!(uts46_to_ascii(&label, options).is_ok());
+    assert_eq!(
+        uts46_to_ascii(&format!("{label}a.de"), options),
+        Err(uts46_errors(Some(0), &[LabelLength]))
+    );
+    assert_eq!(
+        uts46_to_ascii("a..b", options),
+        Err(uts46_errors(Some(1), &[LabelLength]))
+    );
+    // The root label may be empty.
+    assert_eq!(
+        uts46_to_ascii("example.com.", options).unwrap(),
+        "example.com."
+    );
+    let mut errors = uts46_errors(Some(0), &[LabelLength]);
+    errors.extend(uts46_errors(None, &[DomainLength]));
+    assert_eq!(uts46_to_ascii("", options), Err(errors.clone()));
+    assert_eq!(uts46_to_ascii(".", options), Err(errors));
+    let domain = [&label[..62]; 4].join(".");
+    assert_eq!(domain.len(), 251);
+    assert!(uts46_to_ascii(&domain, options).is_ok());
+    assert_eq!(
+        uts46_to_ascii(&format!("{domain}.ab"), options),
+        Err(uts46_errors(None, &[DomainLength]))
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_options() {
+    let options = Uts46Options::whatwg_url();
+    assert_eq!(uts46_to_ascii("a_b.-c-", options).unwrap(), "a_b.-c-");
+    assert_eq!(uts46_to_ascii("", options).unwrap(), "");
+    assert_eq!(
+        uts46_to_ascii("xn--xn---epa", options),
+        Err(uts46_errors(Some(0), &[Uts46ErrorKind::Hyphens]))
+    );
+    let mut options = Uts46Options::default();
+    options.check_joiners = false;
+    assert_eq!(uts46_to_ascii("a\u{200C}b", options).unwrap(), "xn--ab-j1t");
+    options.check_bidi = false;
+    assert_eq!(
+        uts46_to_ascii("0a.\u{05D0}", options).unwrap(),
+        "0a.xn--4db"
+    );
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_utf16() {
+    let uts46 = Uts46ProcessorBorrowed::new();
+    let options = Uts46Options::default();
+    let utf16 = |s: &str| s.encode_utf16().collect::<Vec<u16>>();
+    assert_eq!(
+        uts46
+            .to_ascii_utf16(&utf16("Bücher.Example"), options)
+            .unwrap(),
+        utf16("xn--bcher-kva.example")
+    );
+    assert_eq!(
+        uts46.to_unicode_utf16(&utf16("xn--bcher-kva.Example"), options),
+        (utf16("bücher.example"), Vec::new())
+    );
+    let errors = uts46
+        .to_ascii_utf16(&[0x0061, 0xD800, 0x002E, 0x0062], options)
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].label, Some(0));
+    assert_eq!(errors[0].kind, Uts46ErrorKind::DisallowedCharacter);
+}
+
+#[test]
+#[cfg(feature = "icu_properties")]
+fn test_uts46_processor_owned() {
+    let owned = Uts46ProcessorBorrowed::new().static_to_owned();
+    let uts46 = owned.as_borrowed();
+    assert_eq!(
+        uts46
+            .to_ascii("Bücher.Example", Uts46Options::default())
+            .unwrap(),
+        "xn--bcher-kva.example"
+    );
+}
+
 #[test]
 fn test_nfd_str_to() {
     let normalizer = DecomposingNormalizerBorrowed::new_nfd();
@@ -595,6 +595,18 @@ where
     pub fn get_default(&self) -> (FourBitMetadata, &V) {
         self.as_parts().default
     }
+
+    /// Returns all distinct values, starting with the default ("other") plural variant.
+    pub fn iter(&self) -> impl Iterator<Item = (FourBitMetadata, &V)> {
+        let parts = self.as_parts();
+        core::iter::once(parts.default).chain(
+            parts
+                .specials
+                .into_iter()
+                .flat_map(|specials| specials.iter())
+                .map(|ule| (ule.sized.get().metadata, &ule.variable)),
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
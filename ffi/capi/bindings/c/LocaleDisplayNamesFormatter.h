@@ -27,6 +27,9 @@ icu4x_LocaleDisplayNamesFormatter_create_v1_with_provider_mv1_result icu4x_Local
 
 void icu4x_LocaleDisplayNamesFormatter_of_mv1(const LocaleDisplayNamesFormatter* self, const Locale* locale, DiplomatWrite* write);
 
+typedef struct icu4x_LocaleDisplayNamesFormatter_try_of_mv1_result { bool is_ok;} icu4x_LocaleDisplayNamesFormatter_try_of_mv1_result;
+icu4x_LocaleDisplayNamesFormatter_try_of_mv1_result icu4x_LocaleDisplayNamesFormatter_try_of_mv1(const LocaleDisplayNamesFormatter* self, const Locale* locale, DiplomatWrite* write);
+
 void icu4x_LocaleDisplayNamesFormatter_destroy_mv1(LocaleDisplayNamesFormatter* self);
 
 
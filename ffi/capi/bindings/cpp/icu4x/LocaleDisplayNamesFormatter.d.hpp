@@ -68,6 +68,18 @@ public:
   template<typename W>
   inline void of_write(const icu4x::Locale& locale, W& writeable_output) const;
 
+  /**
+   * 🚧 This API is unstable and may experience breaking changes outside major releases.
+   *
+   * Returns the locale-specific display name of a locale, or nothing if the fallback
+   * is `None` and a subtag has no display name.
+   *
+   * See the [Rust documentation for `try_of`](https://docs.rs/icu/2.3.1/icu/experimental/displaynames/multi/struct.LocaleDisplayNamesFormatter.html#method.try_of) for more information.
+   */
+  inline std::optional<std::string> try_of(const icu4x::Locale& locale) const;
+  template<typename W>
+  inline std::optional<std::monostate> try_of_write(const icu4x::Locale& locale, W& writeable_output) const;
+
     inline const icu4x::capi::LocaleDisplayNamesFormatter* AsFFI() const;
     inline icu4x::capi::LocaleDisplayNamesFormatter* AsFFI();
     inline static const icu4x::LocaleDisplayNamesFormatter* FromFFI(const icu4x::capi::LocaleDisplayNamesFormatter* ptr);
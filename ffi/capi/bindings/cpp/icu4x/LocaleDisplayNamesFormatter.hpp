@@ -30,6 +30,9 @@ namespace capi {
 
     void icu4x_LocaleDisplayNamesFormatter_of_mv1(const icu4x::capi::LocaleDisplayNamesFormatter* self, const icu4x::capi::Locale* locale, icu4x::diplomat::capi::DiplomatWrite* write);
 
+    typedef struct icu4x_LocaleDisplayNamesFormatter_try_of_mv1_result { bool is_ok;} icu4x_LocaleDisplayNamesFormatter_try_of_mv1_result;
+    icu4x_LocaleDisplayNamesFormatter_try_of_mv1_result icu4x_LocaleDisplayNamesFormatter_try_of_mv1(const icu4x::capi::LocaleDisplayNamesFormatter* self, const icu4x::capi::Locale* locale, icu4x::diplomat::capi::DiplomatWrite* write);
+
     void icu4x_LocaleDisplayNamesFormatter_destroy_mv1(LocaleDisplayNamesFormatter* self);
 
     } // extern "C"
@@ -65,6 +68,23 @@ inline void icu4x::LocaleDisplayNamesFormatter::of_write(const icu4x::Locale& lo
         &write);
 }
 
+inline std::optional<std::string> icu4x::LocaleDisplayNamesFormatter::try_of(const icu4x::Locale& locale) const {
+    std::string output;
+    icu4x::diplomat::capi::DiplomatWrite write = icu4x::diplomat::WriteFromString(output);
+    auto result = icu4x::capi::icu4x_LocaleDisplayNamesFormatter_try_of_mv1(this->AsFFI(),
+        locale.AsFFI(),
+        &write);
+    return result.is_ok ? std::optional<std::string>(std::move(output)) : std::nullopt;
+}
+template<typename W>
+inline std::optional<std::monostate> icu4x::LocaleDisplayNamesFormatter::try_of_write(const icu4x::Locale& locale, W& writeable) const {
+    icu4x::diplomat::capi::DiplomatWrite write = icu4x::diplomat::WriteTrait<W>::Construct(writeable);
+    auto result = icu4x::capi::icu4x_LocaleDisplayNamesFormatter_try_of_mv1(this->AsFFI(),
+        locale.AsFFI(),
+        &write);
+    return result.is_ok ? std::optional<std::monostate>() : std::nullopt;
+}
+
 inline const icu4x::capi::LocaleDisplayNamesFormatter* icu4x::LocaleDisplayNamesFormatter::AsFFI() const {
     return reinterpret_cast<const icu4x::capi::LocaleDisplayNamesFormatter*>(this);
 }
@@ -142,6 +142,22 @@ pub mod ffi {
         pub fn of(&self, locale: &Locale, write: &mut DiplomatWrite) {
             let _infallible = self.0.of(&locale.0).write_to(write);
         }
+
+        /// 🚧 This API is unstable and may experience breaking changes outside major releases.
+        ///
+        /// Returns the locale-specific display name of a locale, or nothing if the fallback
+        /// is `None` and a subtag has no display name.
+        #[diplomat::rust_link(
+            icu::experimental::displaynames::multi::LocaleDisplayNamesFormatter::try_of,
+            FnInStruct
+        )]
+        // Unstable, do not generate in demo:
+        #[diplomat::attr(demo_gen, disable)]
+        pub fn try_of(&self, locale: &Locale, write: &mut DiplomatWrite) -> Option<()> {
+            self.0.try_of(&locale.0).map(|name| {
+                let _infallible = name.write_to(write);
+            })
+        }
     }
 
     impl RegionDisplayNames {
@@ -198,7 +214,7 @@ pub mod ffi {
         ///
         /// Returns the locale specific display name of a region.
         /// Note that the function returns an empty string in case the display name for a given
-        /// region code is not found.
+        /// region code is not found and the fallback is `None`.
         #[diplomat::rust_link(
             icu::experimental::displaynames::multi::RegionDisplayNames::of,
             FnInStruct
@@ -213,7 +229,7 @@ pub mod ffi {
             let _infallible = self
                 .0
                 .of(icu_locale_core::subtags::Region::try_from_utf8(region)?)
-                .unwrap_or("")
+                .unwrap_or_default()
                 .write_to(write);
             Ok(())
         }
@@ -74,6 +74,21 @@ final class LocaleDisplayNamesFormatter implements ffi.Finalizable {
     return write.finalize();
   }
 
+  /// 🚧 This API is unstable and may experience breaking changes outside major releases.
+  ///
+  /// Returns the locale-specific display name of a locale, or nothing if the fallback
+  /// is `None` and a subtag has no display name.
+  ///
+  /// See the [Rust documentation for `try_of`](https://docs.rs/icu/2.3.1/icu/experimental/displaynames/multi/struct.LocaleDisplayNamesFormatter.html#method.try_of) for more information.
+  String? tryOf(Locale locale) {
+    final write = _Write();
+    final result = _icu4x_LocaleDisplayNamesFormatter_try_of_mv1(_ffi, locale._ffi, write._ffi);
+    if (!result.isOk) {
+      return null;
+    }
+    return write.finalize();
+  }
+
 }
 
 // ignore: experimental_member_use
@@ -100,4 +115,10 @@ external _ResultOpaqueInt32 _icu4x_LocaleDisplayNamesFormatter_create_v1_with_pr
 // ignore: non_constant_identifier_names
 external void _icu4x_LocaleDisplayNamesFormatter_of_mv1(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Opaque> locale, ffi.Pointer<ffi.Opaque> write);
 
+// ignore: experimental_member_use
+@meta.RecordUse()
+@ffi.Native<_ResultVoidVoid Function(ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>, ffi.Pointer<ffi.Opaque>)>(isLeaf: true, symbol: 'icu4x_LocaleDisplayNamesFormatter_try_of_mv1')
+// ignore: non_constant_identifier_names
+external _ResultVoidVoid _icu4x_LocaleDisplayNamesFormatter_try_of_mv1(ffi.Pointer<ffi.Opaque> self, ffi.Pointer<ffi.Opaque> locale, ffi.Pointer<ffi.Opaque> write);
+
 // dart format on
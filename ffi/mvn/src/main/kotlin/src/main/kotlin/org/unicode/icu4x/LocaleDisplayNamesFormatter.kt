@@ -10,6 +10,7 @@ internal interface LocaleDisplayNamesFormatterLib: Library {
     fun icu4x_LocaleDisplayNamesFormatter_create_v1_mv1(locale: Pointer, options: DisplayNamesOptionsNative): ResultPointerInt
     fun icu4x_LocaleDisplayNamesFormatter_create_v1_with_provider_mv1(provider: Pointer, locale: Pointer, options: DisplayNamesOptionsNative): ResultPointerInt
     fun icu4x_LocaleDisplayNamesFormatter_of_mv1(handle: Pointer, locale: Pointer, write: Pointer): Unit
+    fun icu4x_LocaleDisplayNamesFormatter_try_of_mv1(handle: Pointer, locale: Pointer, write: Pointer): OptionUnit
 }
 /** 🚧 This API is unstable and may experience breaking changes outside major releases.
 *
@@ -99,5 +100,23 @@ class LocaleDisplayNamesFormatter internal constructor (
         val returnString = DW.writeToString(write)
         return returnString
     }
+    
+    /** 🚧 This API is unstable and may experience breaking changes outside major releases.
+    *
+    *Returns the locale-specific display name of a locale, or nothing if the fallback
+    *is `None` and a subtag has no display name.
+    *
+    *See the [Rust documentation for `try_of`](https://docs.rs/icu/2.3.1/icu/experimental/displaynames/multi/struct.LocaleDisplayNamesFormatter.html#method.try_of) for more information.
+    */
+    fun tryOf(locale: Locale): String? {
+        val write = DW.lib.diplomat_buffer_write_create(0)
+        val returnVal = lib.icu4x_LocaleDisplayNamesFormatter_try_of_mv1(handle, locale.handle, write);
+        
+        returnVal.option() ?: return null
+
+        val returnString = DW.writeToString(write)
+        return returnString
+                                
+    }
 
 }
\ No newline at end of file
@@ -37,6 +37,16 @@ export class LocaleDisplayNamesFormatter {
      */
     of(locale: Locale): string;
 
+    /**
+     * 🚧 This API is unstable and may experience breaking changes outside major releases.
+     *
+     * Returns the locale-specific display name of a locale, or nothing if the fallback
+     * is `None` and a subtag has no display name.
+     *
+     * See the [Rust documentation for `try_of`](https://docs.rs/icu/2.3.1/icu/experimental/displaynames/multi/struct.LocaleDisplayNamesFormatter.html#method.try_of) for more information.
+     */
+    tryOf(locale: Locale): string | null;
+
     /**
      * 🚧 This API is unstable and may experience breaking changes outside major releases.
      *
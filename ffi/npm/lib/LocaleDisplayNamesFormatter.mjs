@@ -129,6 +129,30 @@ export class LocaleDisplayNamesFormatter {
         }
     }
 
+    /**
+     * 🚧 This API is unstable and may experience breaking changes outside major releases.
+     *
+     * Returns the locale-specific display name of a locale, or nothing if the fallback
+     * is `None` and a subtag has no display name.
+     *
+     * See the [Rust documentation for `try_of`](https://docs.rs/icu/2.3.1/icu/experimental/displaynames/multi/struct.LocaleDisplayNamesFormatter.html#method.try_of) for more information.
+     */
+    tryOf(locale) {
+        const write = new diplomatRuntime.DiplomatWriteBuf(wasm);
+
+
+        const result = wasm.icu4x_LocaleDisplayNamesFormatter_try_of_mv1(this.ffiValue, locale instanceof Locale ? locale.ffiValue : typeError('locale', 'Locale'), write.buffer);
+
+        try {
+            return result === 0 ? null : write.readString8();
+        }
+
+        finally {
+            diplomatRuntime.FUNCTION_PARAM_ALLOC.clean();
+            write.free();
+        }
+    }
+
     /**
      * 🚧 This API is unstable and may experience breaking changes outside major releases.
      *
@@ -33,7 +33,7 @@ export class RegionDisplayNames {
      *
      * Returns the locale specific display name of a region.
      * Note that the function returns an empty string in case the display name for a given
-     * region code is not found.
+     * region code is not found and the fallback is `None`.
      *
      * See the [Rust documentation for `of`](https://docs.rs/icu/2.3.1/icu/experimental/displaynames/multi/struct.RegionDisplayNames.html#method.of) for more information.
      */
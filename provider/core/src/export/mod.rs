@@ -129,12 +129,14 @@ macro_rules! __make_exportable_provider {
     ($provider:ty, [ $($(#[$cfg:meta])? $struct_m:ty),+, ]) => {
         impl $crate::export::ExportableProvider for $provider {
             fn supported_markers(&self) -> alloc::collections::BTreeSet<$crate::DataMarkerInfo> {
-                alloc::collections::BTreeSet::from_iter([
+                // A static, so that providers with many markers don't build a large array on the stack
+                static MARKERS: &[$crate::DataMarkerInfo] = &[
                     $(
                         $(#[$cfg])?
                         <$struct_m>::INFO,
                     )+
-                ])
+                ];
+                MARKERS.iter().copied().collect()
             }
         }
 
@@ -5,6 +5,7 @@ include!("collation_jamo_v1.rs.data");
 include!("collation_metadata_v1.rs.data");
 include!("collation_tailoring_v1.rs.data");
 include!("collation_special_primaries_v1.rs.data");
+include!("collation_script_starts_v1.rs.data");
 include!("collation_root_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
@@ -46,6 +47,7 @@ macro_rules! impl_data_provider {
         impl_collation_metadata_v1!($provider);
         impl_collation_tailoring_v1!($provider);
         impl_collation_special_primaries_v1!($provider);
+        impl_collation_script_starts_v1!($provider);
         impl_collation_root_v1!($provider);
     };
 }
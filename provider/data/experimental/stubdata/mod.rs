@@ -27,6 +27,29 @@ include!("units_names_length_core_v1.rs.data");
 include!("units_names_area_core_v1.rs.data");
 include!("units_essentials_v1.rs.data");
 include!("units_names_volume_outlier_v1.rs.data");
+include!("units_names_consumption_core_v1.rs.data");
+include!("units_names_consumption_extended_v1.rs.data");
+include!("units_names_consumption_outlier_v1.rs.data");
+include!("units_names_digital_core_v1.rs.data");
+include!("units_names_digital_extended_v1.rs.data");
+include!("units_names_digital_outlier_v1.rs.data");
+include!("units_names_energy_core_v1.rs.data");
+include!("units_names_energy_extended_v1.rs.data");
+include!("units_names_energy_outlier_v1.rs.data");
+include!("units_names_power_core_v1.rs.data");
+include!("units_names_power_extended_v1.rs.data");
+include!("units_names_power_outlier_v1.rs.data");
+include!("units_names_pressure_core_v1.rs.data");
+include!("units_names_pressure_extended_v1.rs.data");
+include!("units_names_pressure_outlier_v1.rs.data");
+include!("units_names_speed_core_v1.rs.data");
+include!("units_names_speed_extended_v1.rs.data");
+include!("units_names_speed_outlier_v1.rs.data");
+include!("units_names_temperature_core_v1.rs.data");
+include!("units_names_temperature_extended_v1.rs.data");
+include!("units_names_temperature_outlier_v1.rs.data");
+include!("units_names_other_v1.rs.data");
+include!("units_compound_patterns_v1.rs.data");
 include!("language_display_names_v1.rs.data");
 include!("short_hour_relative_v1.rs.data");
 include!("units_names_mass_extended_v1.rs.data");
@@ -43,6 +66,9 @@ include!("long_hour_relative_v1.rs.data");
 include!("units_names_length_outlier_v1.rs.data");
 include!("short_second_relative_v1.rs.data");
 include!("variant_display_names_v1.rs.data");
+include!("key_display_names_v1.rs.data");
+include!("currency_display_names_v1.rs.data");
+include!("locale_display_names_patterns_v1.rs.data");
 include!("narrow_month_relative_v1.rs.data");
 include!("narrow_day_relative_v1.rs.data");
 include!("transliterator_rules_v1.rs.data");
@@ -55,7 +81,10 @@ include!("narrow_minute_relative_v1.rs.data");
 include!("digital_duration_data_v1.rs.data");
 include!("units_names_duration_core_v1.rs.data");
 include!("currency_fractions_v1.rs.data");
+include!("currency_regions_v1.rs.data");
 include!("units_info_v1.rs.data");
+include!("units_preferences_v1.rs.data");
+include!("units_quantities_v1.rs.data");
 include!("narrow_year_relative_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
@@ -119,6 +148,29 @@ macro_rules! impl_data_provider {
         impl_units_names_area_core_v1!($provider);
         impl_units_essentials_v1!($provider);
         impl_units_names_volume_outlier_v1!($provider);
+        impl_units_names_consumption_core_v1!($provider);
+        impl_units_names_consumption_extended_v1!($provider);
+        impl_units_names_consumption_outlier_v1!($provider);
+        impl_units_names_digital_core_v1!($provider);
+        impl_units_names_digital_extended_v1!($provider);
+        impl_units_names_digital_outlier_v1!($provider);
+        impl_units_names_energy_core_v1!($provider);
+        impl_units_names_energy_extended_v1!($provider);
+        impl_units_names_energy_outlier_v1!($provider);
+        impl_units_names_power_core_v1!($provider);
+        impl_units_names_power_extended_v1!($provider);
+        impl_units_names_power_outlier_v1!($provider);
+        impl_units_names_pressure_core_v1!($provider);
+        impl_units_names_pressure_extended_v1!($provider);
+        impl_units_names_pressure_outlier_v1!($provider);
+        impl_units_names_speed_core_v1!($provider);
+        impl_units_names_speed_extended_v1!($provider);
+        impl_units_names_speed_outlier_v1!($provider);
+        impl_units_names_temperature_core_v1!($provider);
+        impl_units_names_temperature_extended_v1!($provider);
+        impl_units_names_temperature_outlier_v1!($provider);
+        impl_units_names_other_v1!($provider);
+        impl_units_compound_patterns_v1!($provider);
         impl_language_display_names_v1!($provider);
         impl_short_hour_relative_v1!($provider);
         impl_units_names_mass_extended_v1!($provider);
@@ -135,6 +187,9 @@ macro_rules! impl_data_provider {
         impl_units_names_length_outlier_v1!($provider);
         impl_short_second_relative_v1!($provider);
         impl_variant_display_names_v1!($provider);
+        impl_key_display_names_v1!($provider);
+        impl_currency_display_names_v1!($provider);
+        impl_locale_display_names_patterns_v1!($provider);
         impl_narrow_month_relative_v1!($provider);
         impl_narrow_day_relative_v1!($provider);
         impl_transliterator_rules_v1!($provider);
@@ -147,7 +202,10 @@ macro_rules! impl_data_provider {
         impl_digital_duration_data_v1!($provider);
         impl_units_names_duration_core_v1!($provider);
         impl_currency_fractions_v1!($provider);
+        impl_currency_regions_v1!($provider);
         impl_units_info_v1!($provider);
+        impl_units_preferences_v1!($provider);
+        impl_units_quantities_v1!($provider);
         impl_narrow_year_relative_v1!($provider);
     };
 }
@@ -6,6 +6,9 @@ include!("normalizer_nfkd_tables_v1.rs.data");
 include!("normalizer_nfc_v1.rs.data");
 include!("normalizer_nfd_data_v1.rs.data");
 include!("normalizer_uts46_data_v1.rs.data");
+include!("normalizer_nfkc_casefold_data_v1.rs.data");
+include!("normalizer_nfkc_simple_casefold_data_v1.rs.data");
+include!("normalizer_nfkc_casefold_tables_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
 ///
@@ -46,5 +49,8 @@ macro_rules! impl_data_provider {
         impl_normalizer_nfc_v1!($provider);
         impl_normalizer_nfd_data_v1!($provider);
         impl_normalizer_uts46_data_v1!($provider);
+        impl_normalizer_nfkc_casefold_data_v1!($provider);
+        impl_normalizer_nfkc_simple_casefold_data_v1!($provider);
+        impl_normalizer_nfkc_casefold_tables_v1!($provider);
     };
 }
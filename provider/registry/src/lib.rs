@@ -46,6 +46,7 @@ macro_rules! registry(
             icu::collator::provider::CollationMetadataV1: CollationMetadataV1,
             icu::collator::provider::CollationReorderingV1: CollationReorderingV1,
             icu::collator::provider::CollationSpecialPrimariesV1: CollationSpecialPrimariesV1,
+            icu::collator::provider::CollationScriptStartsV1: CollationScriptStartsV1,
             icu::datetime::provider::time_zones::TimezoneNamesLocationsOverrideV1: TimezoneNamesLocationsOverrideV1,
             icu::datetime::provider::time_zones::TimezoneNamesLocationsRootV1: TimezoneNamesLocationsRootV1,
             icu::datetime::provider::time_zones::TimezoneNamesCitiesOverrideV1: TimezoneNamesCitiesOverrideV1,
@@ -80,6 +81,9 @@ macro_rules! registry(
             icu::normalizer::provider::NormalizerNfkdTablesV1: NormalizerNfkdTablesV1,
             icu::normalizer::provider::NormalizerNfdSupplementV1: NormalizerNfdSupplementV1,
             icu::normalizer::provider::NormalizerUts46DataV1: NormalizerUts46DataV1,
+            icu::normalizer::provider::NormalizerNfkcCasefoldDataV1: NormalizerNfkcCasefoldDataV1,
+            icu::normalizer::provider::NormalizerNfkcSimpleCasefoldDataV1: NormalizerNfkcSimpleCasefoldDataV1,
+            icu::normalizer::provider::NormalizerNfkcCasefoldTablesV1: NormalizerNfkcCasefoldTablesV1,
             icu::plurals::provider::PluralsCardinalV1: PluralsCardinalV1,
             icu::plurals::provider::PluralsOrdinalV1: PluralsOrdinalV1,
             icu::properties::provider::PropertyNameLongBidiClassV1: PropertyNameLongBidiClassV1,
@@ -294,6 +298,7 @@ macro_rules! registry(
             icu::experimental::dimension::provider::currency::patterns::CurrencyPatternsDataV1: CurrencyPatternsDataV1,
             icu::experimental::dimension::provider::currency::extended::CurrencyExtendedDataV1: CurrencyExtendedDataV1,
             icu::experimental::dimension::provider::currency::fractions::CurrencyFractionsV1: CurrencyFractionsV1,
+            icu::experimental::dimension::provider::currency::regions::CurrencyRegionsV1: CurrencyRegionsV1,
             icu::experimental::dimension::provider::currency::no_currency::CurrencyPatternsNoCurrencyV1: CurrencyPatternsNoCurrencyV1,
             icu::experimental::dimension::provider::percent::PercentEssentialsV1: PercentEssentialsV1,
             icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesAreaCoreV1: UnitsNamesAreaCoreV1,
@@ -311,6 +316,29 @@ macro_rules! registry(
             icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesVolumeCoreV1: UnitsNamesVolumeCoreV1,
             icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesVolumeExtendedV1: UnitsNamesVolumeExtendedV1,
             icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesVolumeOutlierV1: UnitsNamesVolumeOutlierV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesConsumptionCoreV1: UnitsNamesConsumptionCoreV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesConsumptionExtendedV1: UnitsNamesConsumptionExtendedV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesConsumptionOutlierV1: UnitsNamesConsumptionOutlierV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesDigitalCoreV1: UnitsNamesDigitalCoreV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesDigitalExtendedV1: UnitsNamesDigitalExtendedV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesDigitalOutlierV1: UnitsNamesDigitalOutlierV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesEnergyCoreV1: UnitsNamesEnergyCoreV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesEnergyExtendedV1: UnitsNamesEnergyExtendedV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesEnergyOutlierV1: UnitsNamesEnergyOutlierV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesPowerCoreV1: UnitsNamesPowerCoreV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesPowerExtendedV1: UnitsNamesPowerExtendedV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesPowerOutlierV1: UnitsNamesPowerOutlierV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesPressureCoreV1: UnitsNamesPressureCoreV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesPressureExtendedV1: UnitsNamesPressureExtendedV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesPressureOutlierV1: UnitsNamesPressureOutlierV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesSpeedCoreV1: UnitsNamesSpeedCoreV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesSpeedExtendedV1: UnitsNamesSpeedExtendedV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesSpeedOutlierV1: UnitsNamesSpeedOutlierV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesTemperatureCoreV1: UnitsNamesTemperatureCoreV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesTemperatureExtendedV1: UnitsNamesTemperatureExtendedV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesTemperatureOutlierV1: UnitsNamesTemperatureOutlierV1,
+            icu::experimental::dimension::provider::units::categorized_display_names::UnitsNamesOtherV1: UnitsNamesOtherV1,
+            icu::experimental::dimension::provider::units::compound_patterns::UnitsCompoundPatternsV1: UnitsCompoundPatternsV1,
             icu::experimental::dimension::provider::units::essentials::UnitsEssentialsV1: UnitsEssentialsV1,
             icu::experimental::duration::provider::DigitalDurationDataV1: DigitalDurationDataV1,
             icu::experimental::displaynames::provider::RegionDisplayNamesV1: RegionDisplayNamesV1,
@@ -318,6 +346,9 @@ macro_rules! registry(
             icu::experimental::displaynames::provider::LocaleDisplayNamesV1: LocaleDisplayNamesV1,
             icu::experimental::displaynames::provider::ScriptDisplayNamesV1: ScriptDisplayNamesV1,
             icu::experimental::displaynames::provider::VariantDisplayNamesV1: VariantDisplayNamesV1,
+            icu::experimental::displaynames::provider::KeyDisplayNamesV1: KeyDisplayNamesV1,
+            icu::experimental::displaynames::provider::CurrencyDisplayNamesV1: CurrencyDisplayNamesV1,
+            icu::experimental::displaynames::provider::LocaleDisplayNamesPatternsV1: LocaleDisplayNamesPatternsV1,
             icu::locale::provider::names::LocaleNamesRegionMediumTinyV1: LocaleNamesRegionMediumTinyV1,
             icu::locale::provider::names::LocaleNamesRegionMediumLightV1: LocaleNamesRegionMediumLightV1,
             icu::locale::provider::names::LocaleNamesRegionShortTinyV1: LocaleNamesRegionShortTinyV1,
@@ -365,6 +396,8 @@ macro_rules! registry(
             icu::experimental::personnames::provider::PersonNamesFormatV1: PersonNamesFormatV1,
             icu::experimental::transliterate::provider::TransliteratorRulesV1: TransliteratorRulesV1,
             icu::experimental::units::provider::UnitsInfoV1: UnitsInfoV1,
+            icu::experimental::units::provider::UnitsPreferencesV1: UnitsPreferencesV1,
+            icu::experimental::units::provider::UnitsQuantitiesV1: UnitsQuantitiesV1,
             icu::plurals::provider::PluralsRangesV1: PluralsRangesV1,
             icu::segmenter::provider::SegmenterUnihanRadicalV1: SegmenterUnihanRadicalV1,
             icu::segmenter::provider::SegmenterBreakGraphemeClusterV2: SegmenterBreakGraphemeClusterV2,
@@ -7,6 +7,7 @@
 //! Sample file:
 //! <https://github.com/unicode-cldr/cldr-core/blob/master/supplemental/currencyData.json>
 
+use icu::locale::subtags::Region;
 use serde::Deserialize;
 use std::collections::BTreeMap;
 use tinystr::TinyAsciiStr;
@@ -39,9 +40,27 @@ pub(crate) struct Fractions {
     pub(crate) currencies: BTreeMap<ISOCode, RoundingModes>,
 }
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct RegionCurrency {
+    /// The first day of use, as `YYYY-MM-DD`.
+    #[serde(rename = "_from")]
+    pub(crate) from: Option<String>,
+
+    /// The first day the currency was no longer used, as `YYYY-MM-DD`.
+    #[serde(rename = "_to")]
+    pub(crate) to: Option<String>,
+
+    #[serde(rename = "_tender")]
+    pub(crate) tender: Option<String>,
+}
+
 #[derive(PartialEq, Debug, Deserialize)]
 pub(crate) struct CurrencyData {
     pub(crate) fractions: Fractions,
+
+    /// The currencies of each region. Every map in the list contains a single entry,
+    /// as a currency can appear more than once (e.g. `JOD` in `PS`).
+    pub(crate) region: BTreeMap<Region, Vec<BTreeMap<ISOCode, RegionCurrency>>>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -8,6 +8,7 @@
 //! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-localenames-full/main/en/localeDisplayNames.json>
 
 use serde::Deserialize;
+use std::collections::BTreeMap;
 
 #[derive(PartialEq, Debug, Deserialize)]
 pub(crate) struct LocaleDisplayPattern {
@@ -15,12 +16,20 @@ pub(crate) struct LocaleDisplayPattern {
     pub(crate) locale_pattern: String,
     #[serde(rename = "localeSeparator")]
     pub(crate) locale_separator: String,
+    #[serde(rename = "localeKeyTypePattern")]
+    pub(crate) locale_key_type_pattern: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
 pub(crate) struct LocaleDisplayNames {
     #[serde(rename = "localeDisplayPattern")]
     pub(crate) locale_display_pattern: LocaleDisplayPattern,
+    /// Key display names, keyed by the CLDR key name (e.g. `calendar`).
+    #[serde(default)]
+    pub(crate) keys: BTreeMap<String, String>,
+    /// Type display names, keyed by the CLDR key and type names (e.g. `calendar`, `gregorian`).
+    #[serde(default)]
+    pub(crate) types: BTreeMap<String, BTreeMap<String, String>>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -73,6 +73,16 @@ pub(crate) struct Patterns {
 
     #[serde(rename = "compoundUnitPattern1-count-other")]
     pub(crate) other_compound_unit_pattern1: Option<String>,
+
+    #[serde(rename = "perUnitPattern")]
+    pub(crate) per_unit_pattern: Option<String>,
+
+    #[serde(rename = "gender")]
+    pub(crate) gender: Option<String>,
+
+    /// The remaining patterns, such as the case and gender variants.
+    #[serde(flatten)]
+    pub(crate) inflections: BTreeMap<String, String>,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -13,7 +13,7 @@ use icu::locale::{
     subtags::{language, script},
 };
 use icu_provider::prelude::*;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use zerovec::ZeroVec;
 
 mod collator_serde;
@@ -236,6 +236,100 @@ collation_provider!(
     (CollationTailoringV1, CollationData),
 );
 
+impl DataProvider<CollationScriptStartsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CollationScriptStartsV1>, DataError> {
+        #[cfg(not(any(feature = "use_wasm", feature = "use_icu4c")))]
+        return Err(DataError::custom(
+            "icu_provider_source must be built with use_icu4c or use_wasm to build collation data",
+        )
+        .with_req(CollationScriptStartsV1::INFO, req));
+        #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
+        {
+            use icu::collator::Collator;
+            use icu::properties::props::Script;
+            use icu::properties::{CodePointMapData, PropertyNamesShort};
+
+            self.check_req::<CollationScriptStartsV1>(req)?;
+
+            let collator =
+                Collator::try_new_unstable(self, Default::default(), Default::default())?;
+            let collator = collator.as_borrowed();
+            let scripts = CodePointMapData::<Script>::try_new_unstable(self)?;
+            let names = PropertyNamesShort::<Script>::try_new_unstable(self)?;
+
+            // The primary of `s` if it maps to a single collation element with a primary.
+            let single_primary = |s: &str| {
+                let mut primaries = collator
+                    .collation_elements_str(s)
+                    .map(|(_, ce)| ce.primary())
+                    .filter(|&primary| primary != 0);
+                let primary = primaries.next()?;
+                primaries.next().is_none().then_some(primary)
+            };
+
+            // Without a contraction, U+FDD1 is an unassigned code point.
+            let unassigned = single_primary("\u{FDD1}");
+
+            let mut starts = BTreeMap::new();
+            for script in scripts.as_borrowed().iter_ranges().map(|range| range.value) {
+                if matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+                    || starts.contains_key(&script)
+                {
+                    continue;
+                }
+                // The root collation has a contraction of U+FDD1 with the first
+                // character of each script, which maps to the start of the script.
+                let start = scripts
+                    .as_borrowed()
+                    .iter_ranges_for_value(script)
+                    .flatten()
+                    .filter_map(char::from_u32)
+                    .find_map(|c| {
+                        let primary = single_primary(&format!("\u{FDD1}{c}"))?;
+                        (Some(primary) != single_primary(&c.to_string())
+                            && Some(primary) != unassigned)
+                            .then_some(primary)
+                    })
+                    // Hangul syllables decompose to conjoining jamo, so there is no
+                    // contraction for Hangul, and its group starts with the first jamo.
+                    .or_else(|| (script == Script::Hangul).then(|| single_primary("\u{1100}"))?);
+                if let Some(start) = start {
+                    starts.insert(script, start);
+                }
+            }
+            // Katakana_Or_Hiragana has no characters of its own, but it is a reorder code.
+            if let Some(&start) = starts.get(&Script::Katakana) {
+                starts.insert(Script::KatakanaOrHiragana, start);
+            }
+
+            let starts = starts
+                .into_iter()
+                .filter_map(|(script, start)| {
+                    Some((
+                        names
+                            .as_borrowed()
+                            .get_locale_script(script)?
+                            .to_tinystr()
+                            .to_unvalidated(),
+                        start,
+                    ))
+                })
+                .collect();
+
+            Ok(DataResponse {
+                metadata: Default::default(),
+                payload: DataPayload::from_owned(CollationScriptStarts { starts }),
+            })
+        }
+    }
+}
+
+impl IterableDataProviderCached<CollationScriptStartsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
 impl collator_serde::CollationData {
     fn suffix() -> &'static str {
         "_data"
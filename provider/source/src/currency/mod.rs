@@ -8,6 +8,7 @@ pub(crate) mod extended;
 pub(crate) mod fractions;
 pub(crate) mod no_currency;
 pub(crate) mod patterns;
+pub(crate) mod regions;
 pub(crate) mod symbols;
 
 use std::borrow::Cow;
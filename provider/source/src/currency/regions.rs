@@ -0,0 +1,141 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use crate::cldr_serde;
+use icu::experimental::dimension::provider::currency::regions::*;
+use icu_provider::prelude::*;
+use std::collections::HashSet;
+use zerovec::ZeroVec;
+
+impl DataProvider<CurrencyRegionsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencyRegionsV1>, DataError> {
+        self.check_req::<CurrencyRegionsV1>(req)?;
+
+        let resource: &cldr_serde::currencies::supplemental::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/currencyData.json")?;
+
+        // The root has no currencies.
+        let currencies = match req.id.locale.region {
+            None => Vec::new(),
+            Some(region) => resource
+                .supplemental
+                .currency_data
+                .region
+                .get(&region)
+                .ok_or(DataErrorKind::IdentifierNotFound.into_error())?
+                .iter()
+                .flatten()
+                .map(|(iso_code, currency)| {
+                    Ok(RegionCurrency {
+                        iso_code: iso_code.to_unvalidated(),
+                        from: currency.from.as_deref().map(parse_date).transpose()?,
+                        to: currency.to.as_deref().map(parse_date).transpose()?,
+                        tender: currency.tender.as_deref() != Some("false"),
+                    })
+                })
+                .collect::<Result<Vec<_>, DataError>>()?,
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(CurrencyRegions {
+                currencies: ZeroVec::alloc_from_slice(&currencies),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<CurrencyRegionsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        let resource: &cldr_serde::currencies::supplemental::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/currencyData.json")?;
+
+        Ok(resource
+            .supplemental
+            .currency_data
+            .region
+            .keys()
+            .map(|&region| Some(region))
+            .chain([None])
+            .map(|region| {
+                let mut locale = DataLocale::default();
+                locale.region = region;
+                DataIdentifierCow::from_locale(locale)
+            })
+            .collect())
+    }
+}
+
+/// Parses a `YYYY-MM-DD` date from `<currencyData>`.
+fn parse_date(date: &str) -> Result<CurrencyDate, DataError> {
+    let mut parts = date.splitn(3, '-');
+    let mut next = || {
+        parts
+            .next()
+            .ok_or_else(|| DataError::custom("Invalid currency date").with_display_context(date))
+    };
+    let year = next()?.parse::<i32>();
+    let month = next()?.parse::<u8>();
+    let day = next()?.parse::<u8>();
+    match (year, month, day) {
+        (Ok(year), Ok(month @ 1..=12), Ok(day @ 1..=31)) => Ok(CurrencyDate { year, month, day }),
+        _ => Err(DataError::custom("Invalid currency date").with_display_context(date)),
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::data_locale;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let load = |locale| {
+        let response: DataResponse<CurrencyRegionsV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierCow::from_locale(locale).as_borrowed(),
+                ..Default::default()
+            })
+            .unwrap();
+        response.payload.get().currencies.to_vec()
+    };
+
+    let brazil = load(data_locale!("und-BR"));
+    assert_eq!(brazil.len(), 7);
+    assert_eq!(
+        brazil[0],
+        RegionCurrency {
+            iso_code: tinystr::tinystr!(3, "BRL").to_unvalidated(),
+            from: Some(CurrencyDate {
+                year: 1994,
+                month: 7,
+                day: 1
+            }),
+            to: None,
+            tender: true,
+        }
+    );
+    assert_eq!(
+        brazil[1].to,
+        Some(CurrencyDate {
+            year: 1994,
+            month: 7,
+            day: 1
+        })
+    );
+
+    // Non-tender currencies are included
+    let us = load(data_locale!("und-US"));
+    assert!(
+        us.iter()
+            .any(|c| c.iso_code == tinystr::tinystr!(3, "USN").to_unvalidated() && !c.tender)
+    );
+
+    assert!(load(Default::default()).is_empty());
+}
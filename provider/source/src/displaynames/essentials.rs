@@ -5,6 +5,7 @@
 use crate::IterableDataProviderCached;
 use crate::SourceDataProvider;
 use crate::cldr_serde;
+use icu::experimental::displaynames::provider::*;
 use icu::locale::provider::names::*;
 use icu_pattern::DoublePlaceholderPattern;
 use icu_provider::prelude::*;
@@ -58,6 +59,48 @@ crate::displaynames::impl_displaynames_legacy_iter_v1!(
     "localeDisplayNames.json"
 );
 
+impl DataProvider<LocaleDisplayNamesPatternsV1> for SourceDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<LocaleDisplayNamesPatternsV1>, DataError> {
+        self.check_req::<LocaleDisplayNamesPatternsV1>(req)?;
+
+        let data: &cldr_serde::displaynames::locale_display_pattern::Resource = self
+            .cldr()?
+            .displaynames()
+            .read_and_parse(req.id.locale, "localeDisplayNames.json")?;
+
+        let patterns = &data.main.value.localedisplaynames.locale_display_pattern;
+        let parse = |pattern_str: &str, name: &str| {
+            DoublePlaceholderPattern::try_from_str(pattern_str, Default::default())
+                .map(|pattern| VarZeroCow::from_encodeable(&pattern))
+                .map_err(|e| {
+                    DataError::custom("Failed to parse locale display pattern")
+                        .with_display_context(name)
+                        .with_display_context(&e)
+                })
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(LocaleDisplayNamesPatterns {
+                locale_pattern: parse(&patterns.locale_pattern, "localePattern")?,
+                locale_separator: parse(&patterns.locale_separator, "localeSeparator")?,
+                locale_key_type_pattern: parse(
+                    &patterns.locale_key_type_pattern,
+                    "localeKeyTypePattern",
+                )?,
+            }),
+        })
+    }
+}
+
+crate::displaynames::impl_displaynames_legacy_iter_v1!(
+    LocaleDisplayNamesPatternsV1,
+    "localeDisplayNames.json"
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,4 +133,25 @@ mod tests {
             "A, B"
         );
     }
+
+    #[test]
+    fn test_locale_display_names_patterns() {
+        let provider = SourceDataProvider::new_testing();
+
+        let data: DataPayload<LocaleDisplayNamesPatternsV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&data_locale!("fr")),
+                ..Default::default()
+            })
+            .unwrap()
+            .payload;
+
+        assert_eq!(
+            data.get()
+                .locale_key_type_pattern
+                .interpolate(["A", "B"])
+                .to_string(),
+            "A\u{202f}: B"
+        );
+    }
 }
@@ -0,0 +1,231 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use crate::cldr_serde;
+use icu::experimental::displaynames::provider::*;
+use icu_provider::prelude::*;
+use potential_utf::PotentialUtf8;
+use std::collections::HashSet;
+use tinystr::TinyAsciiStr;
+
+impl DataProvider<KeyDisplayNamesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<KeyDisplayNamesV1>, DataError> {
+        self.check_req::<KeyDisplayNamesV1>(req)?;
+
+        let data: &cldr_serde::displaynames::locale_display_pattern::Resource = self
+            .cldr()?
+            .displaynames()
+            .read_and_parse(req.id.locale, "localeDisplayNames.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(KeyDisplayNames::from(data)),
+        })
+    }
+}
+
+crate::displaynames::impl_displaynames_legacy_iter_v1!(
+    KeyDisplayNamesV1,
+    "localeDisplayNames.json"
+);
+
+impl DataProvider<CurrencyDisplayNamesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CurrencyDisplayNamesV1>, DataError> {
+        self.check_req::<CurrencyDisplayNamesV1>(req)?;
+
+        let data: &cldr_serde::currencies::data::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(req.id.locale, "currencies.json")?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(CurrencyDisplayNames::from(data)),
+        })
+    }
+}
+
+impl IterableDataProviderCached<CurrencyDisplayNamesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        let numbers = self.cldr()?.numbers();
+        Ok(numbers
+            .list_locales()?
+            .filter(|locale| {
+                numbers
+                    .file_exists(locale, "currencies.json")
+                    .unwrap_or_default()
+            })
+            .map(DataIdentifierCow::from_locale)
+            .collect())
+    }
+}
+
+/// Maps a CLDR key name to its BCP-47 Unicode extension key.
+fn bcp47_key(key: &str) -> Option<TinyAsciiStr<2>> {
+    let key = match key {
+        "calendar" => "ca",
+        "collation" => "co",
+        "colAlternate" => "ka",
+        "colBackwards" => "kb",
+        "colCaseFirst" => "kf",
+        "colCaseLevel" => "kc",
+        "colNormalization" => "kk",
+        "colNumeric" => "kn",
+        "colReorder" => "kr",
+        "colStrength" => "ks",
+        "currency" => "cu",
+        "numbers" => "nu",
+        "timezone" => "tz",
+        // Keys such as "hc" and "ms" already use their BCP-47 form, while the
+        // singletons "t" and "x" are not Unicode extension keys.
+        key if key.len() == 2 => key,
+        _ => return None,
+    };
+    TinyAsciiStr::try_from_str(key).ok()
+}
+
+/// Maps a CLDR type name to its BCP-47 Unicode extension type.
+fn bcp47_type<'a>(key: &str, ty: &'a str) -> &'a str {
+    match (key, ty) {
+        ("ca", "gregorian") => "gregory",
+        ("ca", "ethiopic-amete-alem") => "ethioaa",
+        ("co", "dictionary") => "dict",
+        ("co", "gb2312han") => "gb2312",
+        ("co", "phonebook") => "phonebk",
+        ("co", "traditional") => "trad",
+        ("ka", "non-ignorable") => "noignore",
+        ("ks", "primary") => "level1",
+        ("ks", "secondary") => "level2",
+        ("ks", "tertiary") => "level3",
+        ("ks", "quaternary") => "level4",
+        ("ks", "identical") => "identic",
+        ("kb" | "kc" | "kf" | "kk" | "kn", "yes") => "true",
+        ("kb" | "kc" | "kf" | "kk" | "kn", "no") => "false",
+        _ => ty,
+    }
+}
+
+impl From<&cldr_serde::displaynames::locale_display_pattern::Resource>
+    for KeyDisplayNames<'static>
+{
+    fn from(other: &cldr_serde::displaynames::locale_display_pattern::Resource) -> Self {
+        let names = &other.main.value.localedisplaynames;
+        let keys = names
+            .keys
+            .iter()
+            .filter_map(|(key, name)| Some((bcp47_key(key)?.to_unvalidated(), name.as_str())))
+            .collect();
+        let mut types = Vec::new();
+        for (key, key_types) in &names.types {
+            let Some(bcp47_key) = bcp47_key(key) else {
+                continue;
+            };
+            for (ty, name) in key_types {
+                // "core" is not a type, and alt names are not used in locale display names.
+                if ty == "core" || ty.contains("-alt-") {
+                    continue;
+                }
+                types.push((
+                    bcp47_key.to_unvalidated(),
+                    bcp47_type(&bcp47_key, ty),
+                    name.as_str(),
+                ));
+            }
+        }
+        types.sort();
+        Self {
+            keys,
+            types: types
+                .into_iter()
+                .map(|(key, ty, name)| (key, PotentialUtf8::from_str(ty), name))
+                .collect(),
+        }
+    }
+}
+
+impl From<&cldr_serde::currencies::data::Resource> for CurrencyDisplayNames<'static> {
+    fn from(other: &cldr_serde::currencies::data::Resource) -> Self {
+        Self {
+            names: other
+                .main
+                .value
+                .numbers
+                .currencies
+                .iter()
+                .filter_map(|(code, currency)| {
+                    Some((
+                        TinyAsciiStr::<3>::try_from_str(code).ok()?.to_unvalidated(),
+                        currency.display_name.as_deref()?,
+                    ))
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::locale::data_locale;
+
+    #[test]
+    fn test_key_display_names() {
+        let provider = SourceDataProvider::new_testing();
+
+        let data: DataPayload<KeyDisplayNamesV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&data_locale!("en-001")),
+                ..Default::default()
+            })
+            .unwrap()
+            .payload;
+
+        let key = |k: &str| TinyAsciiStr::<2>::try_from_str(k).unwrap().to_unvalidated();
+        assert_eq!(data.get().keys.get(&key("co")), Some("Sort Order"));
+        assert_eq!(
+            data.get().keys.get(&key("hc")),
+            Some("Hour Cycle (12 vs 24)")
+        );
+        assert_eq!(
+            data.get()
+                .types
+                .get_2d(&key("ca"), PotentialUtf8::from_str("gregory")),
+            Some("Gregorian Calendar")
+        );
+        assert_eq!(
+            data.get()
+                .types
+                .get_2d(&key("co"), PotentialUtf8::from_str("phonebk")),
+            Some("Phonebook Sort Order")
+        );
+        assert_eq!(
+            data.get()
+                .types
+                .get_2d(&key("kn"), PotentialUtf8::from_str("true")),
+            Some("Sort Digits Numerically")
+        );
+    }
+
+    #[test]
+    fn test_currency_display_names() {
+        let provider = SourceDataProvider::new_testing();
+
+        let data: DataPayload<CurrencyDisplayNamesV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&data_locale!("fr")),
+                ..Default::default()
+            })
+            .unwrap()
+            .payload;
+
+        assert_eq!(
+            data.get()
+                .names
+                .get(&TinyAsciiStr::try_from_str("USD").unwrap().to_unvalidated()),
+            Some("dollar des États-Unis")
+        );
+    }
+}
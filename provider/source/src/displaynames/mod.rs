@@ -4,6 +4,7 @@
 
 pub(crate) mod coverage_experimental;
 pub(crate) mod essentials;
+pub(crate) mod keys;
 pub(crate) mod language;
 pub(crate) mod region;
 pub(crate) mod script;
@@ -0,0 +1,325 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains provider implementations for the `NFKC_Casefold`
+//! and `NFKC_Simple_Casefold` mappings, which are computed from the UCD
+//! and the canonical normalization data exported from ICU.
+//!
+//! The trie values follow trie-value-format.md in `icu_normalizer`. They refer
+//! to the NFD tables first and to `NormalizerNfkcCasefoldTablesV1` second.
+
+use crate::SourceDataProvider;
+use crate::properties::ucd_helpers;
+use icu::normalizer::provider::*;
+use icu::normalizer::{ComposingNormalizer, DecomposingNormalizer};
+use icu::properties::{CodePointMapData, props::CanonicalCombiningClass};
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use zerovec::ZeroVec;
+
+/// See trie-value-format.md
+const IGNORABLE_MARKER: u32 = 0xFFFFFFFF;
+
+/// See trie-value-format.md
+const NON_ROUND_TRIP_MARKER: u32 = 1 << 30;
+
+/// See trie-value-format.md
+const BACKWARD_COMBINING_MARKER: u32 = 1 << 31;
+
+/// See trie-value-format.md
+const ONLY_NON_STARTERS_IN_TRAIL: u32 = 1 << 4;
+
+/// See trie-value-format.md
+const HIGH_ZEROS_MASK: u32 = 0x3FFF0000;
+
+/// See trie-value-format.md
+const LOW_ZEROS_MASK: u32 = 0xFFE0;
+
+/// The trie values of both mappings for all code points, and the tables
+/// that the complex decompositions refer to in addition to the NFD tables.
+struct CasefoldData {
+    casefold: Vec<u32>,
+    simple_casefold: Vec<u32>,
+    tables: DecompositionTables<'static>,
+}
+
+/// A decomposition that goes in the tables: The index of the mapping,
+/// the code point, and the trie value without the offset.
+type Complex = (usize, usize, u32);
+
+fn find<T: PartialEq>(haystack: &[T], needle: &[T]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+impl SourceDataProvider {
+    fn nfkc_casefold_data(&self) -> Result<CasefoldData, DataError> {
+        let nfd = DecomposingNormalizer::try_new_nfd_unstable(self)?;
+        let nfd = nfd.as_borrowed();
+        let nfkd = DecomposingNormalizer::try_new_nfkd_unstable(self)?;
+        let nfkd = nfkd.as_borrowed();
+        let nfc = ComposingNormalizer::try_new_nfc_unstable(self)?;
+        let nfc = nfc.as_borrowed();
+        let ccc = CodePointMapData::<CanonicalCombiningClass>::try_new_unstable(self)?;
+        let ccc = ccc.as_borrowed();
+
+        let nfd_data: DataPayload<NormalizerNfdDataV1> = self.load(Default::default())?.payload;
+        let nfd_trie = &nfd_data.get().trie;
+        let nfkd_data: DataPayload<NormalizerNfkdDataV1> = self.load(Default::default())?.payload;
+        let nfkd_trie = &nfkd_data.get().trie;
+        let nfd_tables: DataPayload<NormalizerNfdTablesV1> = self.load(Default::default())?.payload;
+        let scalars16 = nfd_tables.get().scalars16.to_vec();
+        let scalars24 = nfd_tables.get().scalars24.to_vec();
+
+        let mut mappings = [BTreeMap::new(), BTreeMap::new()];
+        for line in self
+            .rscd()?
+            .parse_ucd_lines("ucd/DerivedNormalizationProps.txt")?
+        {
+            let Some(line) = line.skip_missing_rule() else {
+                continue;
+            };
+            let mut fields = line.fields();
+            let (Some(range), Some(property)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            let mapping = match property {
+                "NFKC_CF" => &mut mappings[0],
+                "NFKC_SCF" => &mut mappings[1],
+                _ => continue,
+            };
+            let value = ucd_helpers::parse_cps(fields.next().unwrap_or_default());
+            for cp in ucd_helpers::parse_range(range) {
+                mapping.insert(cp, value.clone());
+            }
+        }
+
+        let combines_backwards = |c: char| nfd_trie.get(c) & BACKWARD_COMBINING_MARKER != 0;
+        let is_starter = |c: char| ccc.get(c) == CanonicalCombiningClass::NotReordered;
+
+        let mut values = [vec![0; 0x110000], vec![0; 0x110000]];
+        let mut complex = Vec::<Complex>::new();
+        let mut decompositions = BTreeMap::<(usize, usize), Vec<char>>::new();
+        for (m, mapping) in mappings.iter().enumerate() {
+            for cp in 0..=(char::MAX as u32) {
+                let nfd_value = nfd_trie.get32(cp);
+                let Some(c) = char::from_u32(cp) else {
+                    // Surrogates, which only matter for the UTF-16 slice mode
+                    values[m][cp as usize] = nfd_value;
+                    continue;
+                };
+                let d = match mapping.get(&cp) {
+                    Some(mapped) => nfd.normalize_iter(mapped.chars()).collect::<Vec<_>>(),
+                    None => nfd.normalize_iter([c].into_iter()).collect(),
+                };
+                // U+0345 maps to U+03B9, so at run time, its trie value does not
+                // carry a canonical combining class, and it has to be looked up
+                // as a starter when it occurs in the trail of a mapping. This
+                // rules out the NFD and NFKD trie values, which treat it as a
+                // non-starter.
+                let iota_subscript_in_trail = d.iter().skip(1).any(|&c| c == '\u{0345}');
+                if !iota_subscript_in_trail
+                    && nfd.normalize_iter([c].into_iter()).eq(d.iter().copied())
+                {
+                    values[m][cp as usize] = nfd_value;
+                    continue;
+                }
+                let Some(&first) = d.first() else {
+                    values[m][cp as usize] = IGNORABLE_MARKER;
+                    continue;
+                };
+                let nfkd_value = nfkd_trie.get32(cp);
+                if !iota_subscript_in_trail
+                    && nfkd.normalize_iter([c].into_iter()).eq(d.iter().copied())
+                    && !(nfkd_value & HIGH_ZEROS_MASK != 0 && nfkd_value & LOW_ZEROS_MASK == 0)
+                {
+                    // Same as NFKD, including the special cases such as U+FDFA and the
+                    // halfwidth kana voicing marks, and not referring to the NFKD tables
+                    values[m][cp as usize] = nfkd_value;
+                    continue;
+                }
+                if !is_starter(first) {
+                    return Err(DataError::custom("Mapping starts with a non-starter")
+                        .with_debug_context(&c));
+                }
+                let mut flags = 0;
+                if combines_backwards(first) {
+                    flags |= BACKWARD_COMBINING_MARKER;
+                }
+                if nfc.normalize_iter(d.iter().copied()).ne([c]) {
+                    flags |= NON_ROUND_TRIP_MARKER;
+                }
+                values[m][cp as usize] = match *d.as_slice() {
+                    [first] if (0x20..=0xFFFF).contains(&u32::from(first)) => {
+                        flags | u32::from(first)
+                    }
+                    [first, second]
+                        if c != '\u{212B}'
+                            && (0x20..=0x7FFF).contains(&u32::from(first))
+                            && (0x20..=0x7FFF).contains(&u32::from(second))
+                            && !combines_backwards(first)
+                            && !is_starter(second) =>
+                    {
+                        flags | (u32::from(second) << 15) | u32::from(first)
+                    }
+                    _ => {
+                        let len = if d.iter().all(|&c| u32::from(c) <= 0xFFFF) {
+                            d.len().checked_sub(2)
+                        } else {
+                            d.len().checked_sub(1)
+                        };
+                        let Some(len) = len.filter(|&len| len <= 0b1111) else {
+                            return Err(DataError::custom("Unsupported mapping length")
+                                .with_debug_context(&c));
+                        };
+                        if !iota_subscript_in_trail && d.iter().skip(1).all(|&c| !is_starter(c)) {
+                            flags |= ONLY_NON_STARTERS_IN_TRAIL;
+                        }
+                        complex.push((m, cp as usize, flags | len as u32));
+                        decompositions.insert((m, cp as usize), d);
+                        0
+                    }
+                };
+            }
+        }
+
+        // The complex decompositions that are not in the NFD tables go to the
+        // supplementary tables, but the offsets into the 24-bit supplementary
+        // table depend on the final length of the 16-bit one.
+        let mut supplementary16 = Vec::<u16>::new();
+        let mut supplementary24 = Vec::<char>::new();
+        for d in decompositions.values() {
+            if let Ok(d16) = d
+                .iter()
+                .map(|&c| u16::try_from(u32::from(c)))
+                .collect::<Result<Vec<_>, _>>()
+            {
+                if find(&scalars16, &d16).is_none() && find(&supplementary16, &d16).is_none() {
+                    supplementary16.extend(d16);
+                }
+            } else if find(&scalars24, d).is_none() && find(&supplementary24, d).is_none() {
+                supplementary24.extend(d);
+            }
+        }
+        if scalars16.len() + scalars24.len() + supplementary16.len() + supplementary24.len() > 0xFFF
+        {
+            return Err(DataError::custom(
+                "Tables too large for the trie value format",
+            ));
+        }
+
+        for (m, cp, value) in complex {
+            let d = &decompositions[&(m, cp)];
+            let offset = if let Ok(d16) = d
+                .iter()
+                .map(|&c| u16::try_from(u32::from(c)))
+                .collect::<Result<Vec<_>, _>>()
+            {
+                find(&scalars16, &d16)
+                    .or_else(|| {
+                        find(&supplementary16, &d16).map(|i| scalars16.len() + scalars24.len() + i)
+                    })
+                    .unwrap()
+            } else {
+                find(&scalars24, d)
+                    .map(|i| scalars16.len() + i)
+                    .or_else(|| {
+                        find(&supplementary24, d)
+                            .map(|i| scalars16.len() + scalars24.len() + supplementary16.len() + i)
+                    })
+                    .unwrap()
+            };
+            values[m][cp] = value | ((offset as u32 + 1) << 16);
+        }
+
+        let [casefold, simple_casefold] = values;
+        Ok(CasefoldData {
+            casefold,
+            simple_casefold,
+            tables: DecompositionTables {
+                scalars16: ZeroVec::alloc_from_slice(&supplementary16),
+                scalars24: ZeroVec::alloc_from_slice(&supplementary24),
+            },
+        })
+    }
+
+    #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
+    fn nfkc_casefold_trie(&self, values: &[u32]) -> Result<DecompositionData<'static>, DataError> {
+        let nfd_data: DataPayload<NormalizerNfdDataV1> = self.load(Default::default())?.payload;
+        let mut builder = icu_codepointtrie_builder::CodePointTrieBuilder::new(
+            0,
+            nfd_data.get().trie.get32(u32::MAX),
+            self.trie_type().into(),
+        );
+        for (cp, &value) in values.iter().enumerate() {
+            builder.set_value(cp as u32, value);
+        }
+        // Below the first character that does not map to itself, the normalizer
+        // does not need to look at the trie.
+        let passthrough_cap = values
+            .iter()
+            .position(|&value| value != 0)
+            .unwrap_or(0x300)
+            .min(0x300) as u16;
+        Ok(DecompositionData {
+            trie: builder.build(),
+            passthrough_cap,
+        })
+    }
+}
+
+macro_rules! nfkc_casefold_data_provider {
+    ($marker:ident, $field:ident) => {
+        #[cfg(not(any(feature = "use_wasm", feature = "use_icu4c")))]
+        impl DataProvider<$marker> for SourceDataProvider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                self.check_req::<$marker>(req)?;
+                Err(DataError::custom(concat!(
+                    "The `use_wasm` or `use_icu4c` feature must be enabled to use ",
+                    stringify!($marker)
+                )))
+            }
+        }
+
+        #[cfg(any(feature = "use_wasm", feature = "use_icu4c"))]
+        impl DataProvider<$marker> for SourceDataProvider {
+            fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                self.check_req::<$marker>(req)?;
+                let data = self.nfkc_casefold_data()?;
+                Ok(DataResponse {
+                    metadata: Default::default(),
+                    payload: DataPayload::from_owned(self.nfkc_casefold_trie(&data.$field)?),
+                })
+            }
+        }
+
+        impl crate::IterableDataProviderCached<$marker> for SourceDataProvider {
+            fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+                Ok(HashSet::from_iter([Default::default()]))
+            }
+        }
+    };
+}
+
+nfkc_casefold_data_provider!(NormalizerNfkcCasefoldDataV1, casefold);
+
+nfkc_casefold_data_provider!(NormalizerNfkcSimpleCasefoldDataV1, simple_casefold);
+
+impl DataProvider<NormalizerNfkcCasefoldTablesV1> for SourceDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<NormalizerNfkcCasefoldTablesV1>, DataError> {
+        self.check_req::<NormalizerNfkcCasefoldTablesV1>(req)?;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(self.nfkc_casefold_data()?.tables),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<NormalizerNfkcCasefoldTablesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
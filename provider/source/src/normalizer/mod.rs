@@ -15,6 +15,7 @@ use std::collections::{HashMap, HashSet};
 use std::convert::TryFrom;
 use zerovec::ZeroVec;
 
+mod casefold;
 mod normalizer_serde;
 
 macro_rules! normalization_provider {
@@ -5,13 +5,7 @@
 use crate::SourceDataProvider;
 use crate::cldr_serde;
 use cldr_serde::units::preferences::UnitType;
-use icu::experimental::dimension::provider::units::categorized_display_names::{
-    UnitsNamesAreaCoreV1, UnitsNamesAreaExtendedV1, UnitsNamesAreaOutlierV1,
-    UnitsNamesDurationCoreV1, UnitsNamesDurationExtendedV1, UnitsNamesDurationOutlierV1,
-    UnitsNamesLengthCoreV1, UnitsNamesLengthExtendedV1, UnitsNamesLengthOutlierV1,
-    UnitsNamesMassCoreV1, UnitsNamesMassExtendedV1, UnitsNamesMassOutlierV1,
-    UnitsNamesVolumeCoreV1, UnitsNamesVolumeExtendedV1, UnitsNamesVolumeOutlierV1,
-};
+use icu::experimental::dimension::provider::units::categorized_display_names::*;
 use icu::experimental::dimension::provider::units::display_names::UnitsDisplayNames;
 use icu_provider::DataMarkerAttributes;
 use icu_provider::prelude::*;
@@ -172,6 +166,118 @@ impl_units_display_names_provider!(UnitsNamesVolumeCoreV1, UnitType::Core, "volu
 impl_units_display_names_provider!(UnitsNamesVolumeExtendedV1, UnitType::Extended, "volume");
 impl_units_display_names_provider!(UnitsNamesVolumeOutlierV1, UnitType::Outlier, "volume");
 
+// Consumption
+impl_units_display_names_provider!(UnitsNamesConsumptionCoreV1, UnitType::Core, "consumption");
+impl_units_display_names_provider!(
+    UnitsNamesConsumptionExtendedV1,
+    UnitType::Extended,
+    "consumption"
+);
+impl_units_display_names_provider!(
+    UnitsNamesConsumptionOutlierV1,
+    UnitType::Outlier,
+    "consumption"
+);
+
+// Digital
+impl_units_display_names_provider!(UnitsNamesDigitalCoreV1, UnitType::Core, "digital");
+impl_units_display_names_provider!(UnitsNamesDigitalExtendedV1, UnitType::Extended, "digital");
+impl_units_display_names_provider!(UnitsNamesDigitalOutlierV1, UnitType::Outlier, "digital");
+
+// Energy
+impl_units_display_names_provider!(UnitsNamesEnergyCoreV1, UnitType::Core, "energy");
+impl_units_display_names_provider!(UnitsNamesEnergyExtendedV1, UnitType::Extended, "energy");
+impl_units_display_names_provider!(UnitsNamesEnergyOutlierV1, UnitType::Outlier, "energy");
+
+// Power
+impl_units_display_names_provider!(UnitsNamesPowerCoreV1, UnitType::Core, "power");
+impl_units_display_names_provider!(UnitsNamesPowerExtendedV1, UnitType::Extended, "power");
+impl_units_display_names_provider!(UnitsNamesPowerOutlierV1, UnitType::Outlier, "power");
+
+// Pressure
+impl_units_display_names_provider!(UnitsNamesPressureCoreV1, UnitType::Core, "pressure");
+impl_units_display_names_provider!(UnitsNamesPressureExtendedV1, UnitType::Extended, "pressure");
+impl_units_display_names_provider!(UnitsNamesPressureOutlierV1, UnitType::Outlier, "pressure");
+
+// Speed
+impl_units_display_names_provider!(UnitsNamesSpeedCoreV1, UnitType::Core, "speed");
+impl_units_display_names_provider!(UnitsNamesSpeedExtendedV1, UnitType::Extended, "speed");
+impl_units_display_names_provider!(UnitsNamesSpeedOutlierV1, UnitType::Outlier, "speed");
+
+// Temperature
+impl_units_display_names_provider!(UnitsNamesTemperatureCoreV1, UnitType::Core, "temperature");
+impl_units_display_names_provider!(
+    UnitsNamesTemperatureExtendedV1,
+    UnitType::Extended,
+    "temperature"
+);
+impl_units_display_names_provider!(
+    UnitsNamesTemperatureOutlierV1,
+    UnitType::Outlier,
+    "temperature"
+);
+
+/// The categories whose units are split into core, extended and outlier markers.
+const CATEGORIZED: [&str; 12] = [
+    "area",
+    "consumption",
+    "digital",
+    "duration",
+    "energy",
+    "length",
+    "mass",
+    "power",
+    "pressure",
+    "speed",
+    "temperature",
+    "volume",
+];
+
+impl DataProvider<UnitsNamesOtherV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitsNamesOtherV1>, DataError> {
+        self.check_req::<UnitsNamesOtherV1>(req)?;
+        self.get_display_name_payload::<UnitsNamesOtherV1>(req)
+    }
+}
+
+impl crate::IterableDataProviderCached<UnitsNamesOtherV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        let mut data_locales = HashSet::new();
+        for locale in self.cldr()?.numbers().list_locales()? {
+            let units_format_data: &cldr_serde::units::data::Resource =
+                self.cldr()?.units().read_and_parse(&locale, "units.json")?;
+            let units_format_data = &units_format_data.main.value.units;
+
+            for (length, length_patterns) in [
+                ("long", &units_format_data.long),
+                ("short", &units_format_data.short),
+                ("narrow", &units_format_data.narrow),
+            ] {
+                for (category, units_map) in &length_patterns.categories {
+                    if CATEGORIZED.contains(&category.as_str()) {
+                        continue;
+                    }
+                    for (unit, patterns) in units_map {
+                        if patterns.other.is_none() {
+                            continue;
+                        }
+                        data_locales.insert(DataIdentifierCow::from_owned(
+                            DataMarkerAttributes::try_from_string(format!("{length}-{unit}"))
+                                .map_err(|_| {
+                                    DataError::custom("Failed to parse the attribute")
+                                        .with_debug_context(&unit)
+                                })?,
+                            locale,
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(data_locales)
+    }
+}
+
 #[test]
 fn test_basic() {
     use icu::locale::{data_locale, locale};
@@ -0,0 +1,176 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::SourceDataProvider;
+use crate::cldr_serde;
+
+use icu::experimental::dimension::provider::units::compound_patterns::{
+    UnitsCompoundPatterns, UnitsCompoundPatternsV1,
+};
+use icu::experimental::dimension::provider::units::essentials::CompoundCount;
+use icu::experimental::dimension::provider::units::pattern_key::{PatternKey, PowerValue};
+use icu_provider::DataMarkerAttributes;
+use icu_provider::prelude::*;
+use zerovec::{ZeroMap, ZeroMap2d};
+
+/// Parses the key of a gendered power pattern in the nominative case,
+/// such as `compoundUnitPattern1-gender-feminine-count-one`.
+fn parse_gendered_power_key(key: &str) -> Option<(&str, CompoundCount)> {
+    let (gender, count) = key
+        .strip_prefix("compoundUnitPattern1-gender-")?
+        .split_once("-count-")?;
+    let count = match count {
+        "zero" => CompoundCount::Zero,
+        "one" => CompoundCount::One,
+        "two" => CompoundCount::Two,
+        "few" => CompoundCount::Few,
+        "many" => CompoundCount::Many,
+        "other" => CompoundCount::Other,
+        // The case variants, such as `one-case-genitive`, are not supported yet.
+        _ => return None,
+    };
+    Some((gender, count))
+}
+
+impl DataProvider<UnitsCompoundPatternsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitsCompoundPatternsV1>, DataError> {
+        self.check_req::<UnitsCompoundPatternsV1>(req)?;
+
+        let units_format_data: &cldr_serde::units::data::Resource = self
+            .cldr()?
+            .units()
+            .read_and_parse(req.id.locale, "units.json")?;
+        let units_format_data = &units_format_data.main.value.units;
+        let length_data = match req.id.marker_attributes.as_str() {
+            "long" => &units_format_data.long,
+            "short" => &units_format_data.short,
+            "narrow" => &units_format_data.narrow,
+            _ => return Err(DataError::custom("Failed to get length data")),
+        };
+
+        let per_unit_patterns = length_data
+            .categories
+            .values()
+            .flatten()
+            .filter_map(|(unit, patterns)| {
+                Some((unit.as_str(), patterns.per_unit_pattern.as_deref()?))
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        let mut gendered_powers = BTreeMap::<(&str, PatternKey), &str>::new();
+        for (power, power_value) in [(2, PowerValue::Two), (3, PowerValue::Three)] {
+            let Some(patterns) = length_data.powers.get(&power) else {
+                continue;
+            };
+            for (key, pattern) in &patterns.inflections {
+                if let Some((gender, count)) = parse_gendered_power_key(key) {
+                    gendered_powers.insert(
+                        (
+                            gender,
+                            PatternKey::Power {
+                                power: power_value,
+                                count,
+                            },
+                        ),
+                        pattern,
+                    );
+                }
+            }
+        }
+
+        // The genders are only needed to select the gendered power patterns.
+        // They are only available in the long units data.
+        let genders = if gendered_powers.is_empty() {
+            BTreeMap::new()
+        } else {
+            units_format_data
+                .long
+                .categories
+                .values()
+                .flatten()
+                .filter_map(|(unit, patterns)| Some((unit.as_str(), patterns.gender.as_deref()?)))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let result = UnitsCompoundPatterns {
+            per_unit_patterns: ZeroMap::from_iter(per_unit_patterns),
+            genders: ZeroMap::from_iter(genders),
+            gendered_powers: ZeroMap2d::from_iter(
+                gendered_powers
+                    .into_iter()
+                    .map(|((gender, key), pattern)| (gender, key, pattern)),
+            ),
+        };
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(result),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<UnitsCompoundPatternsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        let units = self.cldr()?.units();
+        let locales = units.list_locales()?;
+        Ok(locales
+            .into_iter()
+            .flat_map(|locale| {
+                [
+                    DataMarkerAttributes::from_str_or_panic("long"),
+                    DataMarkerAttributes::from_str_or_panic("short"),
+                    DataMarkerAttributes::from_str_or_panic("narrow"),
+                ]
+                .into_iter()
+                .map(move |length| DataIdentifierCow::from_borrowed_and_owned(length, locale))
+            })
+            .collect())
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::data_locale;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let load = |length: &str, locale| -> DataPayload<UnitsCompoundPatternsV1> {
+        provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    DataMarkerAttributes::from_str_or_panic(length),
+                    locale,
+                ),
+                ..Default::default()
+            })
+            .unwrap()
+            .payload
+    };
+
+    let en_long = load("long", &data_locale!("en"));
+    assert_eq!(
+        en_long.get().per_unit_patterns.get("hour"),
+        Some("{0} per hour")
+    );
+    assert!(en_long.get().gendered_powers.is_empty());
+    assert!(en_long.get().genders.is_empty());
+
+    let fr_long = load("long", &data_locale!("fr"));
+    assert_eq!(fr_long.get().genders.get("meter"), Some("masculine"));
+    assert_eq!(
+        fr_long.get().gendered_powers.get_2d(
+            "feminine",
+            &PatternKey::Power {
+                power: PowerValue::Two,
+                count: CompoundCount::Other,
+            }
+        ),
+        Some("{0} carrées")
+    );
+
+    let fr_short = load("short", &data_locale!("fr"));
+    assert_eq!(fr_short.get().per_unit_patterns.get("hour"), Some("{0}/h"));
+}
@@ -3,7 +3,9 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 pub(crate) mod categorized_display_name;
+pub(crate) mod compound_patterns;
 pub(crate) mod essentials;
 pub(crate) mod helpers;
 pub(crate) mod ids;
 pub(crate) mod info;
+pub(crate) mod preferences;
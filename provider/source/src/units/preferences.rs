@@ -0,0 +1,195 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use crate::cldr_serde;
+use icu::experimental::units::provider::{
+    UnitsPreferences, UnitsPreferencesV1, UnitsQuantities, UnitsQuantitiesV1,
+};
+use icu::locale::subtags::Region;
+use icu_provider::prelude::*;
+use zerovec::{VarZeroVec, ZeroMap, ZeroVec};
+
+/// The threshold of a preference that has no `geq` attribute, as defined by
+/// <https://www.unicode.org/reports/tr35/tr35-info.html#Unit_Preferences>.
+const DEFAULT_THRESHOLD: f64 = 1.0;
+
+impl SourceDataProvider {
+    fn unit_preferences(
+        &self,
+    ) -> Result<&cldr_serde::units::preferences::CategoryPreferences, DataError> {
+        let resource: &cldr_serde::units::preferences::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/unitPreferenceData.json")?;
+        Ok(&resource.supplemental.unit_preference_data)
+    }
+}
+
+impl DataProvider<UnitsQuantitiesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitsQuantitiesV1>, DataError> {
+        self.check_req::<UnitsQuantitiesV1>(req)?;
+
+        let units_data: &cldr_serde::units::info::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/units.json")?;
+
+        let preferences = self.unit_preferences()?;
+
+        // Only the quantities with unit preferences are needed.
+        let base_units = units_data
+            .supplemental
+            .unit_quantities
+            .quantities
+            .iter()
+            .filter(|(_, quantity)| preferences.contains_key(&quantity.quantity))
+            .map(|(base_unit, quantity)| (quantity.quantity.as_str(), base_unit.as_str()))
+            .collect::<BTreeMap<_, _>>();
+
+        if let Some(quantity) = preferences
+            .keys()
+            .find(|q| !base_units.contains_key(q.as_str()))
+        {
+            return Err(
+                DataError::custom("Quantity without a base unit").with_display_context(quantity)
+            );
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(UnitsQuantities {
+                base_units: base_units.into_iter().collect::<ZeroMap<_, _>>(),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<UnitsQuantitiesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+impl DataProvider<UnitsPreferencesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<UnitsPreferencesV1>, DataError> {
+        self.check_req::<UnitsPreferencesV1>(req)?;
+
+        let (quantity, usage) = req
+            .id
+            .marker_attributes
+            .split_once('/')
+            .ok_or(DataErrorKind::IdentifierNotFound.into_error())?;
+
+        let region = match req.id.locale.region {
+            None => "001".to_string(),
+            Some(region) => region.to_string(),
+        };
+
+        let preferences = self
+            .unit_preferences()?
+            .get(quantity)
+            .and_then(|usages| usages.get(usage))
+            .and_then(|regions| regions.get(&region))
+            .ok_or(DataErrorKind::IdentifierNotFound.into_error())?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(UnitsPreferences {
+                units: VarZeroVec::from(
+                    &preferences
+                        .iter()
+                        .map(|preference| preference.unit.as_str())
+                        .collect::<Vec<_>>(),
+                ),
+                thresholds: preferences
+                    .iter()
+                    .map(|preference| preference.greater_or_equal.unwrap_or(DEFAULT_THRESHOLD))
+                    .collect::<ZeroVec<f64>>(),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<UnitsPreferencesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        let mut ids = HashSet::new();
+        for (quantity, usages) in self.unit_preferences()? {
+            for (usage, regions) in usages {
+                let attributes = format!("{quantity}/{usage}");
+                for region in regions.keys() {
+                    let attributes = DataMarkerAttributes::try_from_string(attributes.clone())
+                        .map_err(|_| {
+                            DataError::custom("Invalid unit preference usage")
+                                .with_display_context(usage)
+                        })?;
+                    let mut locale = DataLocale::default();
+                    if region != "001" {
+                        locale.region = Some(Region::try_from_str(region).map_err(|_| {
+                            DataError::custom("Invalid region").with_display_context(region)
+                        })?);
+                    }
+                    ids.insert(DataIdentifierCow::from_owned(attributes, locale));
+                }
+            }
+        }
+        Ok(ids)
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::data_locale;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let quantities: DataResponse<UnitsQuantitiesV1> = provider.load(Default::default()).unwrap();
+    assert_eq!(
+        quantities.payload.get().base_units.get("length"),
+        Some("meter")
+    );
+    assert_eq!(
+        quantities.payload.get().base_units.get("consumption"),
+        Some("cubic-meter-per-meter")
+    );
+
+    let load = |attributes, locale| {
+        let response: DataResponse<UnitsPreferencesV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    DataMarkerAttributes::from_str_or_panic(attributes),
+                    &locale,
+                ),
+                ..Default::default()
+            })
+            .unwrap();
+        let preferences = response.payload.get();
+        preferences
+            .units
+            .iter()
+            .map(String::from)
+            .zip(preferences.thresholds.iter())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        load("length/road", data_locale!("und")),
+        [
+            ("kilometer".to_string(), 0.9),
+            ("meter".to_string(), 300.0),
+            ("meter".to_string(), 10.0),
+            ("meter".to_string(), 1.0),
+        ]
+    );
+    assert_eq!(
+        load("length/person-height", data_locale!("und-US")),
+        [
+            ("foot-and-inch".to_string(), 3.0),
+            ("inch".to_string(), 1.0)
+        ]
+    );
+}
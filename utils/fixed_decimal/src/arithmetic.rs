@@ -0,0 +1,821 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This file contains exact arithmetic on [`UnsignedDecimal`] and [`Decimal`].
+//!
+//! Addition, subtraction and multiplication are exact: the result holds every digit needed to
+//! represent the mathematical result. Division is exact up to a caller-chosen position, where
+//! the quotient is rounded using the same modes and increments as the rounding functions.
+//!
+//! Addition, subtraction and multiplication are also available as operators. As the result may
+//! not fit in the magnitude range, they return a [`Result`] like the `checked_` functions.
+
+use core::cmp;
+use core::cmp::Ordering;
+use core::ops::{Add, Mul, Sub};
+
+use smallvec::SmallVec;
+
+use crate::{
+    ArithmeticError, Decimal, LimitError, RoundingIncrement, Sign, SignedRoundingMode,
+    UnsignedDecimal, UnsignedRoundingMode,
+};
+
+type Digits = SmallVec<[u8; 16]>;
+
+/// The maximum number of digits that a division computes before rounding.
+///
+/// Long division takes time proportional to the number of quotient digits, so this bounds the
+/// work done for a rounding position far below the magnitude of the quotient.
+const MAX_QUOTIENT_DIGITS: i32 = 1000;
+
+/// Returns the digits of `value` between `low` and `high` (inclusive) in ascending order of
+/// magnitude.
+fn ascending_digits(value: &UnsignedDecimal, low: i16, high: i16) -> Digits {
+    (low..=high).map(|m| value.digit_at(m)).collect()
+}
+
+/// Returns the nonzero significand of `value` as big-endian digits without leading or
+/// trailing zeros.
+fn significand(value: &UnsignedDecimal) -> Digits {
+    if value.is_zero() {
+        return Digits::new();
+    }
+    (value.nonzero_magnitude_end()..=value.nonzero_magnitude_start())
+        .rev()
+        .map(|m| value.digit_at(m))
+        .collect()
+}
+
+/// Compares the numeric values of two unsigned decimals, ignoring leading and trailing zeros.
+fn cmp_abs(a: &UnsignedDecimal, b: &UnsignedDecimal) -> Ordering {
+    match (a.is_zero(), b.is_zero()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        (false, false) => {}
+    }
+    let start = a.nonzero_magnitude_start();
+    match start.cmp(&b.nonzero_magnitude_start()) {
+        Ordering::Equal => {}
+        ord => return ord,
+    }
+    let end = cmp::min(a.nonzero_magnitude_end(), b.nonzero_magnitude_end());
+    (end..=start)
+        .rev()
+        .map(|m| a.digit_at(m).cmp(&b.digit_at(m)))
+        .find(|ord| *ord != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Computes `a + b`, keeping the fraction digits of both operands.
+fn add_abs(a: &UnsignedDecimal, b: &UnsignedDecimal) -> Result<UnsignedDecimal, LimitError> {
+    let mut result = if a.is_zero() {
+        b.clone()
+    } else if b.is_zero() {
+        a.clone()
+    } else {
+        let low = cmp::min(a.nonzero_magnitude_end(), b.nonzero_magnitude_end());
+        let high = cmp::max(a.nonzero_magnitude_start(), b.nonzero_magnitude_start());
+        let mut digits = Digits::new();
+        let mut carry = 0;
+        for m in low..=high {
+            let sum = a.digit_at(m) + b.digit_at(m) + carry;
+            digits.push(sum % 10);
+            carry = sum / 10;
+        }
+        digits.push(carry);
+        UnsignedDecimal::try_from_ascending_digits_at(&digits, low)?
+    };
+    pad_to_operands(&mut result, a, b);
+    Ok(result)
+}
+
+/// Computes `a - b` where `a >= b`, keeping the fraction digits of both operands.
+fn sub_abs(a: &UnsignedDecimal, b: &UnsignedDecimal) -> UnsignedDecimal {
+    debug_assert_ne!(cmp_abs(a, b), Ordering::Less);
+    let mut result = if b.is_zero() {
+        a.clone()
+    } else {
+        let low = cmp::min(a.nonzero_magnitude_end(), b.nonzero_magnitude_end());
+        let high = a.nonzero_magnitude_start();
+        let mut digits = Digits::new();
+        let mut borrow = 0;
+        for m in low..=high {
+            let subtrahend = b.digit_at(m) + borrow;
+            let minuend = a.digit_at(m);
+            if minuend >= subtrahend {
+                digits.push(minuend - subtrahend);
+                borrow = 0;
+            } else {
+                digits.push(minuend + 10 - subtrahend);
+                borrow = 1;
+            }
+        }
+        debug_assert_eq!(borrow, 0);
+        // The difference is not larger than `a`, so its magnitudes are always in range.
+        UnsignedDecimal::try_from_ascending_digits_at(&digits, low).unwrap_or_default()
+    };
+    pad_to_operands(&mut result, a, b);
+    result
+}
+
+/// Computes `a * b`.
+///
+/// The result has as many fraction digits as both operands together.
+fn mul_abs(a: &UnsignedDecimal, b: &UnsignedDecimal) -> Result<UnsignedDecimal, LimitError> {
+    let lower = *a.magnitude_range().start() as i32 + *b.magnitude_range().start() as i32;
+    let lower = i16::try_from(lower).map_err(|_| LimitError)?;
+    let mut result = if a.is_zero() || b.is_zero() {
+        UnsignedDecimal::default()
+    } else {
+        let a_low = a.nonzero_magnitude_end();
+        let b_low = b.nonzero_magnitude_end();
+        let a_digits = ascending_digits(a, a_low, a.nonzero_magnitude_start());
+        let b_digits = ascending_digits(b, b_low, b.nonzero_magnitude_start());
+        let mut accumulator: SmallVec<[u32; 16]> =
+            core::iter::repeat_n(0, a_digits.len() + b_digits.len()).collect();
+        for (i, x) in a_digits.iter().enumerate() {
+            for (j, y) in b_digits.iter().enumerate() {
+                if let Some(slot) = accumulator.get_mut(i + j) {
+                    *slot += (*x as u32) * (*y as u32);
+                }
+            }
+            // Propagate carries eagerly so that the accumulator cannot overflow.
+            let mut carry = 0;
+            for slot in accumulator.iter_mut() {
+                *slot += carry;
+                carry = *slot / 10;
+                *slot %= 10;
+            }
+        }
+        let digits: Digits = accumulator.iter().map(|d| *d as u8).collect();
+        let low = i16::try_from(a_low as i32 + b_low as i32).map_err(|_| LimitError)?;
+        UnsignedDecimal::try_from_ascending_digits_at(&digits, low)?
+    };
+    result.widen_magnitude_range(0, lower);
+    Ok(result)
+}
+
+/// Computes the quotient `dividend / divisor` truncated after the digit at `position - 1`.
+///
+/// If the division is inexact, a nonzero digit is appended at `position - 2`, so that rounding
+/// the result at `position` gives the same answer as rounding the exact quotient.
+fn div_abs(
+    dividend: &UnsignedDecimal,
+    divisor: &UnsignedDecimal,
+    position: i16,
+) -> Result<UnsignedDecimal, ArithmeticError> {
+    if divisor.is_zero() {
+        return Err(ArithmeticError::DivisionByZero);
+    }
+    // The least significant digit of the truncated quotient.
+    let last = position as i32 - 1;
+    if last - 1 < i16::MIN as i32 {
+        return Err(ArithmeticError::Limit);
+    }
+    if dividend.is_zero() {
+        return Ok(UnsignedDecimal::default());
+    }
+    // The quotient is less than 10^(top + 1).
+    let top = dividend.nonzero_magnitude_start() as i32 - divisor.nonzero_magnitude_start() as i32;
+    if last > top {
+        // The truncated quotient is zero, and the division is inexact.
+        // `last - 1` was checked against `i16::MIN` above.
+        return Ok(UnsignedDecimal::try_from_ascending_digits_at(
+            &[1],
+            (last - 1) as i16,
+        )?);
+    }
+    if top - last >= MAX_QUOTIENT_DIGITS {
+        return Err(ArithmeticError::Limit);
+    }
+    // dividend / divisor = (A / B) * 10^shift, where A and B are the integer significands.
+    // The truncated quotient is then floor(A * 10^(shift - last) / B) * 10^last.
+    let shift = dividend.nonzero_magnitude_end() as i32 - divisor.nonzero_magnitude_end() as i32;
+    let scale = shift - last;
+    let mut numerator = significand(dividend);
+    let mut denominator = significand(divisor);
+    if scale >= 0 {
+        numerator.extend(core::iter::repeat_n(0, scale as usize));
+    } else {
+        denominator.extend(core::iter::repeat_n(0, scale.unsigned_abs() as usize));
+    }
+
+    let mut quotient = Digits::new();
+    let mut remainder = Digits::new();
+    for digit in numerator {
+        if !remainder.is_empty() || digit != 0 {
+            remainder.push(digit);
+        }
+        let mut q = 0;
+        while cmp_big_endian(&remainder, &denominator) != Ordering::Less {
+            sub_big_endian(&mut remainder, &denominator);
+            q += 1;
+        }
+        quotient.push(q);
+    }
+
+    let mut ascending: Digits = quotient.into_iter().rev().collect();
+    let mut lowest = last;
+    if !remainder.is_empty() {
+        ascending.insert(0, 1);
+        lowest -= 1;
+    }
+    // `lowest` was checked against `i16::MIN` above.
+    Ok(UnsignedDecimal::try_from_ascending_digits_at(
+        &ascending,
+        lowest as i16,
+    )?)
+}
+
+/// Compares two big-endian integers without leading zeros.
+fn cmp_big_endian(a: &[u8], b: &[u8]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Subtracts `b` from `a` in place, where `a >= b` and both are big-endian integers without
+/// leading zeros. The result has no leading zeros.
+fn sub_big_endian(a: &mut Digits, b: &[u8]) {
+    let mut borrow = 0;
+    let offset = a.len() - b.len();
+    for (i, slot) in a.iter_mut().enumerate().rev() {
+        let subtrahend = i
+            .checked_sub(offset)
+            .and_then(|j| b.get(j))
+            .copied()
+            .unwrap_or(0)
+            + borrow;
+        if *slot >= subtrahend {
+            *slot -= subtrahend;
+            borrow = 0;
+        } else {
+            *slot = *slot + 10 - subtrahend;
+            borrow = 1;
+        }
+    }
+    let leading_zeros = a.iter().take_while(|d| **d == 0).count();
+    a.drain(..leading_zeros);
+}
+
+/// Pads `result` with trailing zeros so that it has as many fraction digits as the more precise
+/// of `a` and `b`.
+fn pad_to_operands(result: &mut UnsignedDecimal, a: &UnsignedDecimal, b: &UnsignedDecimal) {
+    result.widen_magnitude_range(
+        0,
+        cmp::min(*a.magnitude_range().start(), *b.magnitude_range().start()),
+    );
+}
+
+impl UnsignedDecimal {
+    /// Adds another [`UnsignedDecimal`] to this one, returning [`ArithmeticError::Limit`] if the
+    /// result does not fit in the magnitude range of [`UnsignedDecimal`].
+    ///
+    /// The result has as many fraction digits as the more precise operand, so that no precision
+    /// is lost. Leading zeros of the operands are not kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = UnsignedDecimal::from_str("1.50").unwrap();
+    /// let b = UnsignedDecimal::from_str("99.5").unwrap();
+    /// assert_eq!("101.00", a.checked_add(&b).unwrap().to_string());
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        Ok(add_abs(self, other)?)
+    }
+
+    /// Subtracts another [`UnsignedDecimal`] from this one, returning
+    /// [`ArithmeticError::Negative`] if the result would be negative.
+    ///
+    /// The result has as many fraction digits as the more precise operand, so that no precision
+    /// is lost. Leading zeros of the operands are not kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{ArithmeticError, UnsignedDecimal};
+    /// # use std::str::FromStr;
+    ///
+    /// let a = UnsignedDecimal::from_str("10.25").unwrap();
+    /// let b = UnsignedDecimal::from_str("0.5").unwrap();
+    /// assert_eq!("9.75", a.checked_sub(&b).unwrap().to_string());
+    /// assert_eq!(Err(ArithmeticError::Negative), b.checked_sub(&a));
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        if cmp_abs(self, other) == Ordering::Less {
+            Err(ArithmeticError::Negative)
+        } else {
+            Ok(sub_abs(self, other))
+        }
+    }
+
+    /// Multiplies this [`UnsignedDecimal`] by another one, returning [`ArithmeticError::Limit`]
+    /// if the result does not fit in the magnitude range of [`UnsignedDecimal`].
+    ///
+    /// The result has as many fraction digits as both operands together, like in long
+    /// multiplication by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let price = UnsignedDecimal::from_str("19.99").unwrap();
+    /// let quantity = UnsignedDecimal::from(3u32);
+    /// assert_eq!("59.97", price.checked_mul(&quantity).unwrap().to_string());
+    ///
+    /// let rate = UnsignedDecimal::from_str("0.075").unwrap();
+    /// assert_eq!("1.49925", price.checked_mul(&rate).unwrap().to_string());
+    /// ```
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        Ok(mul_abs(self, other)?)
+    }
+
+    /// Divides this [`UnsignedDecimal`] by another one, rounding the quotient at a particular
+    /// digit position and increment using the specified rounding mode.
+    ///
+    /// The result is padded with trailing zeros up to `position`, and is the same as rounding the
+    /// exact (possibly infinite) quotient with [`UnsignedDecimal::round_with_mode_and_increment`].
+    ///
+    /// Returns [`ArithmeticError::Limit`] if the quotient would have more than 1000 digits above
+    /// `position`, or if the result does not fit in the magnitude range of [`UnsignedDecimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{
+    ///     ArithmeticError, RoundingIncrement, UnsignedDecimal, UnsignedRoundingMode,
+    /// };
+    ///
+    /// let ten = UnsignedDecimal::from(10u32);
+    /// let three = UnsignedDecimal::from(3u32);
+    ///
+    /// let quotient = ten.divide_with(
+    ///     &three,
+    ///     -2,
+    ///     UnsignedRoundingMode::HalfEven,
+    ///     RoundingIncrement::MultiplesOf1,
+    /// );
+    /// assert_eq!("3.33", quotient.unwrap().to_string());
+    ///
+    /// let quotient = ten.divide_with(
+    ///     &three,
+    ///     -2,
+    ///     UnsignedRoundingMode::Expand,
+    ///     RoundingIncrement::MultiplesOf5,
+    /// );
+    /// assert_eq!("3.35", quotient.unwrap().to_string());
+    ///
+    /// assert_eq!(
+    ///     Err(ArithmeticError::DivisionByZero),
+    ///     ten.divide_with(
+    ///         &UnsignedDecimal::from(0u32),
+    ///         0,
+    ///         UnsignedRoundingMode::HalfEven,
+    ///         RoundingIncrement::MultiplesOf1,
+    ///     )
+    /// );
+    /// ```
+    pub fn divide_with(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: UnsignedRoundingMode,
+        increment: RoundingIncrement,
+    ) -> Result<Self, ArithmeticError> {
+        let mut quotient = div_abs(self, divisor, position)?;
+        quotient.round_with_mode_and_increment(position, mode, increment);
+        quotient.pad_end(position);
+        Ok(quotient)
+    }
+}
+
+/// Returns the sign of a result whose absolute value is `absolute`.
+fn result_sign(negative: bool, absolute: &UnsignedDecimal) -> Sign {
+    if negative && !absolute.is_zero() {
+        Sign::Negative
+    } else {
+        Sign::None
+    }
+}
+
+impl Decimal {
+    /// Adds another [`Decimal`] to this one, returning [`ArithmeticError::Limit`] if the result
+    /// does not fit in the magnitude range of [`Decimal`].
+    ///
+    /// The result has as many fraction digits as the more precise operand, so that no precision
+    /// is lost. Leading zeros of the operands are not kept. Nonnegative results have
+    /// [`Sign::None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-1.50").unwrap();
+    /// let b = Decimal::from_str("0.5").unwrap();
+    /// assert_eq!("-1.00", a.checked_add(&b).unwrap().to_string());
+    /// ```
+    pub fn checked_add(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        let self_negative = self.sign == Sign::Negative;
+        let other_negative = other.sign == Sign::Negative;
+        if self_negative == other_negative {
+            let absolute = add_abs(&self.absolute, &other.absolute)?;
+            return Ok(Decimal::new(
+                result_sign(self_negative, &absolute),
+                absolute,
+            ));
+        }
+        Ok(match cmp_abs(&self.absolute, &other.absolute) {
+            Ordering::Less => {
+                let absolute = sub_abs(&other.absolute, &self.absolute);
+                Decimal::new(result_sign(other_negative, &absolute), absolute)
+            }
+            _ => {
+                let absolute = sub_abs(&self.absolute, &other.absolute);
+                Decimal::new(result_sign(self_negative, &absolute), absolute)
+            }
+        })
+    }
+
+    /// Subtracts another [`Decimal`] from this one, returning [`ArithmeticError::Limit`] if the
+    /// result does not fit in the magnitude range of [`Decimal`].
+    ///
+    /// The result has as many fraction digits as the more precise operand, so that no precision
+    /// is lost. Leading zeros of the operands are not kept. Nonnegative results have
+    /// [`Sign::None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("100").unwrap();
+    /// let b = Decimal::from_str("100.01").unwrap();
+    /// assert_eq!("-0.01", a.checked_sub(&b).unwrap().to_string());
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        let negated = Decimal::new(
+            match other.sign {
+                Sign::Negative => Sign::None,
+                Sign::None | Sign::Positive => Sign::Negative,
+            },
+            other.absolute.clone(),
+        );
+        self.checked_add(&negated)
+    }
+
+    /// Multiplies this [`Decimal`] by another one, returning [`ArithmeticError::Limit`] if the
+    /// result does not fit in the magnitude range of [`Decimal`].
+    ///
+    /// The result has as many fraction digits as both operands together, like in long
+    /// multiplication by hand. Nonnegative results have [`Sign::None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::Decimal;
+    /// # use std::str::FromStr;
+    ///
+    /// let a = Decimal::from_str("-2.5").unwrap();
+    /// let b = Decimal::from_str("0.40").unwrap();
+    /// assert_eq!("-1.000", a.checked_mul(&b).unwrap().to_string());
+    /// ```
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, ArithmeticError> {
+        let negative = (self.sign == Sign::Negative) != (other.sign == Sign::Negative);
+        let absolute = mul_abs(&self.absolute, &other.absolute)?;
+        Ok(Decimal::new(result_sign(negative, &absolute), absolute))
+    }
+
+    /// Divides this [`Decimal`] by another one, rounding the quotient at a particular digit
+    /// position and increment using the specified rounding mode.
+    ///
+    /// The result is padded with trailing zeros up to `position`, and is the same as rounding the
+    /// exact (possibly infinite) quotient with [`Decimal::round_with_mode_and_increment`].
+    /// Nonnegative results have [`Sign::None`].
+    ///
+    /// Returns [`ArithmeticError::Limit`] if the quotient would have more than 1000 digits above
+    /// `position`, or if the result does not fit in the magnitude range of [`Decimal`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{Decimal, RoundingIncrement, SignedRoundingMode};
+    /// # use std::str::FromStr;
+    ///
+    /// let total = Decimal::from_str("-100").unwrap();
+    /// let parts = Decimal::from(3);
+    ///
+    /// let share = total.divide_with(
+    ///     &parts,
+    ///     -2,
+    ///     SignedRoundingMode::Floor,
+    ///     RoundingIncrement::MultiplesOf1,
+    /// );
+    /// assert_eq!("-33.34", share.unwrap().to_string());
+    ///
+    /// let share = total.divide_with(
+    ///     &parts,
+    ///     -2,
+    ///     SignedRoundingMode::Ceil,
+    ///     RoundingIncrement::MultiplesOf1,
+    /// );
+    /// assert_eq!("-33.33", share.unwrap().to_string());
+    /// ```
+    pub fn divide_with(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: SignedRoundingMode,
+        increment: RoundingIncrement,
+    ) -> Result<Self, ArithmeticError> {
+        let negative = (self.sign == Sign::Negative) != (divisor.sign == Sign::Negative);
+        let absolute = div_abs(&self.absolute, &divisor.absolute, position)?;
+        let mut quotient =
+            Decimal::new(if negative { Sign::Negative } else { Sign::None }, absolute);
+        quotient.round_with_mode_and_increment(position, mode, increment);
+        quotient.absolute.pad_end(position);
+        if quotient.absolute.is_zero() {
+            quotient.sign = Sign::None;
+        }
+        Ok(quotient)
+    }
+}
+
+macro_rules! impl_binary_operator {
+    ($type:ty, $trait:ident, $method:ident, $checked:ident) => {
+        /// Computes the exact result of the operation, like
+        #[doc = concat!("[`", stringify!($type), "::", stringify!($checked), "`].")]
+        impl $trait<&$type> for &$type {
+            type Output = Result<$type, ArithmeticError>;
+            fn $method(self, rhs: &$type) -> Self::Output {
+                self.$checked(rhs)
+            }
+        }
+
+        /// Computes the exact result of the operation, like
+        #[doc = concat!("[`", stringify!($type), "::", stringify!($checked), "`].")]
+        impl $trait<$type> for $type {
+            type Output = Result<$type, ArithmeticError>;
+            fn $method(self, rhs: $type) -> Self::Output {
+                self.$checked(&rhs)
+            }
+        }
+
+        /// Computes the exact result of the operation, like
+        #[doc = concat!("[`", stringify!($type), "::", stringify!($checked), "`].")]
+        impl $trait<&$type> for $type {
+            type Output = Result<$type, ArithmeticError>;
+            fn $method(self, rhs: &$type) -> Self::Output {
+                self.$checked(rhs)
+            }
+        }
+    };
+}
+
+impl_binary_operator!(UnsignedDecimal, Add, add, checked_add);
+impl_binary_operator!(UnsignedDecimal, Sub, sub, checked_sub);
+impl_binary_operator!(UnsignedDecimal, Mul, mul, checked_mul);
+impl_binary_operator!(Decimal, Add, add, checked_add);
+impl_binary_operator!(Decimal, Sub, sub, checked_sub);
+impl_binary_operator!(Decimal, Mul, mul, checked_mul);
+
+#[test]
+fn test_add_sub() {
+    use core::str::FromStr;
+
+    #[derive(Debug)]
+    struct TestCase {
+        pub a: &'static str,
+        pub b: &'static str,
+        pub sum: &'static str,
+        pub difference: &'static str,
+    }
+    let cases = [
+        TestCase {
+            a: "0",
+            b: "0",
+            sum: "0",
+            difference: "0",
+        },
+        TestCase {
+            a: "1",
+            b: "2",
+            sum: "3",
+            difference: "-1",
+        },
+        TestCase {
+            a: "999.99",
+            b: "0.01",
+            sum: "1000.00",
+            difference: "999.98",
+        },
+        TestCase {
+            a: "00.500",
+            b: "-0.5",
+            sum: "0.000",
+            difference: "1.000",
+        },
+        TestCase {
+            a: "-12.5",
+            b: "-0.125",
+            sum: "-12.625",
+            difference: "-12.375",
+        },
+        TestCase {
+            a: "1000",
+            b: "0.001",
+            sum: "1000.001",
+            difference: "999.999",
+        },
+        TestCase {
+            a: "+7",
+            b: "-9",
+            sum: "-2",
+            difference: "16",
+        },
+        TestCase {
+            a: "123456789012345678901234567890",
+            b: "987654321098765432109876543210",
+            sum: "1111111110111111111011111111100",
+            difference: "-864197532086419753208641975320",
+        },
+    ];
+    for cas in &cases {
+        let a = Decimal::from_str(cas.a).unwrap();
+        let b = Decimal::from_str(cas.b).unwrap();
+        assert_eq!(cas.sum, a.checked_add(&b).unwrap().to_string(), "{cas:?}");
+        assert_eq!(cas.sum, (&b + &a).unwrap().to_string(), "{cas:?}");
+        assert_eq!(cas.difference, (&a - &b).unwrap().to_string(), "{cas:?}");
+        let c = ((a.clone() - &b).unwrap() + b).unwrap();
+        assert_eq!(
+            cmp_abs(&a.absolute, &c.absolute),
+            Ordering::Equal,
+            "{cas:?}"
+        );
+    }
+
+    let mut max = UnsignedDecimal::from(9u32);
+    max.multiply_pow10(i16::MAX);
+    assert_eq!(Err(ArithmeticError::Limit), max.checked_add(&max));
+    assert_eq!(Err(ArithmeticError::Limit), &max + &max);
+
+    let one = UnsignedDecimal::from(1u32);
+    assert_eq!(Err(ArithmeticError::Negative), one.checked_sub(&max));
+    assert_eq!(Err(ArithmeticError::Negative), &one - &max);
+    assert_eq!(Ok(UnsignedDecimal::default()), &one - &one);
+}
+
+#[test]
+fn test_mul() {
+    use core::str::FromStr;
+
+    let cases = [
+        ("0", "0", "0"),
+        ("0.0", "12.34", "0.000"),
+        ("3", "4", "12"),
+        ("-3", "4", "-12"),
+        ("-3", "-4", "12"),
+        ("-0.5", "0", "0.0"),
+        ("1.5", "1.5", "2.25"),
+        ("99", "99", "9801"),
+        ("0.001", "0.001", "0.000001"),
+        ("12.50", "8", "100.00"),
+        (
+            "99999999999999999999",
+            "99999999999999999999",
+            "9999999999999999999800000000000000000001",
+        ),
+    ];
+    for (a, b, expected) in cases {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        assert_eq!(
+            expected,
+            a.checked_mul(&b).unwrap().to_string(),
+            "{a} * {b}"
+        );
+        assert_eq!(expected, (&b * &a).unwrap().to_string(), "{b} * {a}");
+    }
+
+    let mut tiny = UnsignedDecimal::from(1u32);
+    tiny.multiply_pow10(i16::MIN);
+    assert_eq!(Err(ArithmeticError::Limit), &tiny * &tiny);
+}
+
+#[test]
+fn test_divide_with() {
+    use core::str::FromStr;
+
+    let cases = [
+        ("1", "3", 0, SignedRoundingMode::Ceil, "1"),
+        ("1", "3", -3, SignedRoundingMode::Floor, "0.333"),
+        ("2", "3", -3, SignedRoundingMode::Floor, "0.666"),
+        (
+            "2",
+            "3",
+            -3,
+            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfTrunc),
+            "0.667",
+        ),
+        ("-1", "8", -2, SignedRoundingMode::HalfCeil, "-0.12"),
+        ("-1", "8", -2, SignedRoundingMode::HalfFloor, "-0.13"),
+        (
+            "1",
+            "8",
+            -2,
+            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven),
+            "0.12",
+        ),
+        (
+            "1.000001",
+            "8",
+            -2,
+            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven),
+            "0.13",
+        ),
+        ("10", "4", -3, SignedRoundingMode::Floor, "2.500"),
+        ("1234.5", "0.05", 0, SignedRoundingMode::Floor, "24690"),
+        ("1234.5", "-0.05", 2, SignedRoundingMode::Ceil, "-24600"),
+        ("0.0001", "3", -2, SignedRoundingMode::Floor, "0.00"),
+        ("-0.0001", "3", -2, SignedRoundingMode::Ceil, "0.00"),
+        ("-0.0001", "3", -2, SignedRoundingMode::Floor, "-0.01"),
+        ("0", "-7", -1, SignedRoundingMode::Floor, "0.0"),
+    ];
+    for (a, b, position, mode, expected) in cases {
+        let a = Decimal::from_str(a).unwrap();
+        let b = Decimal::from_str(b).unwrap();
+        let quotient = a
+            .divide_with(&b, position, mode, RoundingIncrement::MultiplesOf1)
+            .unwrap();
+        assert_eq!(expected, quotient.to_string(), "{a} / {b} at {position}");
+    }
+
+    let increments = [
+        (RoundingIncrement::MultiplesOf1, "1.67"),
+        (RoundingIncrement::MultiplesOf2, "1.66"),
+        (RoundingIncrement::MultiplesOf5, "1.65"),
+        (RoundingIncrement::MultiplesOf25, "1.75"),
+    ];
+    let five = UnsignedDecimal::from(5u32);
+    let three = UnsignedDecimal::from(3u32);
+    for (increment, expected) in increments {
+        let quotient = five
+            .divide_with(&three, -2, UnsignedRoundingMode::HalfEven, increment)
+            .unwrap();
+        assert_eq!(expected, quotient.to_string(), "{increment:?}");
+    }
+
+    assert_eq!(
+        Err(ArithmeticError::DivisionByZero),
+        five.divide_with(
+            &UnsignedDecimal::default(),
+            0,
+            UnsignedRoundingMode::HalfEven,
+            RoundingIncrement::MultiplesOf1
+        )
+    );
+    assert_eq!(
+        Err(ArithmeticError::Limit),
+        five.divide_with(
+            &three,
+            i16::MIN,
+            UnsignedRoundingMode::HalfEven,
+            RoundingIncrement::MultiplesOf1
+        )
+    );
+    assert_eq!(
+        Err(ArithmeticError::Limit),
+        five.divide_with(
+            &three,
+            -1000,
+            UnsignedRoundingMode::HalfEven,
+            RoundingIncrement::MultiplesOf1
+        )
+    );
+
+    // Positions far above the quotient don't require any long division
+    let mut huge = UnsignedDecimal::from(7u32);
+    huge.multiply_pow10(i16::MAX);
+    let mut tiny = UnsignedDecimal::from(3u32);
+    tiny.multiply_pow10(i16::MIN + 2);
+    for mode in [UnsignedRoundingMode::Trunc, UnsignedRoundingMode::Expand] {
+        let quotient = tiny
+            .divide_with(&huge, 0, mode, RoundingIncrement::MultiplesOf1)
+            .unwrap();
+        let expected = if mode == UnsignedRoundingMode::Expand {
+            "1"
+        } else {
+            "0"
+        };
+        assert_eq!(expected, quotient.to_string(), "{mode:?}");
+    }
+}
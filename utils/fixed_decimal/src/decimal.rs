@@ -10,10 +10,10 @@ use core::fmt;
 use core::ops::RangeInclusive;
 use core::str::FromStr;
 
-use crate::{uint_iterator::IntIterator, IncrementLike, NoIncrement};
 #[cfg(feature = "ryu")]
-use crate::{FloatPrecision, LimitError};
-use crate::{ParseError, RoundingIncrement, UnsignedRoundingMode};
+use crate::FloatPrecision;
+use crate::{uint_iterator::IntIterator, IncrementLike, NoIncrement};
+use crate::{LimitError, ParseError, RoundingIncrement, UnsignedRoundingMode};
 
 // UnsignedDecimal assumes usize (digits.len()) is at least as big as a u16
 #[cfg(not(any(
@@ -1821,6 +1821,45 @@ impl UnsignedDecimal {
         self.digits.extend_from_slice(new_digits);
     }
 
+    /// Initialize a [`UnsignedDecimal`] from a slice of digits in ascending order of magnitude,
+    /// the first of which has magnitude `lowest_magnitude`.
+    ///
+    /// Leading and trailing zeros in `ascending` are dropped; the magnitude range is the
+    /// smallest one containing magnitude 0 and all nonzero digits.
+    pub(crate) fn try_from_ascending_digits_at(
+        ascending: &[u8],
+        lowest_magnitude: i16,
+    ) -> Result<Self, LimitError> {
+        let mut result = Self::default();
+        let Some(start) = ascending.iter().position(|d| *d != 0) else {
+            return Ok(result);
+        };
+        // `start` is in range, so there is at least one nonzero digit.
+        let end = ascending.iter().rposition(|d| *d != 0).unwrap_or(start);
+        let bottom =
+            i16::try_from(lowest_magnitude as i32 + start as i32).map_err(|_| LimitError)?;
+        let top = i16::try_from(lowest_magnitude as i32 + end as i32).map_err(|_| LimitError)?;
+        #[expect(clippy::indexing_slicing)] // start <= end < ascending.len()
+        result
+            .digits
+            .extend(ascending[start..=end].iter().rev().copied());
+        result.magnitude = top;
+        result.upper_magnitude = cmp::max(0, top);
+        result.lower_magnitude = cmp::min(0, bottom);
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        Ok(result)
+    }
+
+    /// Widens the magnitude range of this number so that it includes `upper_magnitude` and
+    /// `lower_magnitude`, adding leading or trailing zeros as necessary.
+    pub(crate) fn widen_magnitude_range(&mut self, upper_magnitude: i16, lower_magnitude: i16) {
+        self.upper_magnitude = cmp::max(self.upper_magnitude, upper_magnitude);
+        self.lower_magnitude = cmp::min(self.lower_magnitude, lower_magnitude);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
     /// Assert that the invariants among struct fields are enforced. Returns true if all are okay.
     /// Call this in any method that mutates the struct fields.
     ///
@@ -49,6 +49,7 @@
 //!
 //! [`ICU4X`]: ../icu/index.html
 
+mod arithmetic;
 mod compact;
 mod decimal;
 mod integer;
@@ -130,6 +131,29 @@ pub enum ParseError {
 
 impl core::error::Error for ParseError {}
 
+/// An error involving [`Decimal`] or [`UnsignedDecimal`] arithmetic.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum ArithmeticError {
+    /// See [`LimitError`].
+    #[displaydoc("Magnitude or number of digits exceeded")]
+    Limit,
+    /// The divisor of a division is zero.
+    #[displaydoc("Division by zero")]
+    DivisionByZero,
+    /// The difference of two [`UnsignedDecimal`]s is negative.
+    #[displaydoc("Negative difference of unsigned decimals")]
+    Negative,
+}
+
+impl core::error::Error for ArithmeticError {}
+
+impl From<LimitError> for ArithmeticError {
+    fn from(_: LimitError) -> Self {
+        Self::Limit
+    }
+}
+
 // TODO(#5065): implement these while `WithCompactExponent` and `WithScientificExponent` are implemented.
 // pub type FixedDecimalOrInfinity = WithInfinity<UnsignedDecimal>;
 // pub type DecimalOrInfinity = Signed<FixedDecimalOrInfinity>;
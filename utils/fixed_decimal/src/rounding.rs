@@ -58,7 +58,8 @@ impl IncrementLike for NoIncrement {
 ///
 /// NOTE:
 ///   - `Ceil`, `Floor`, `HalfCeil` and `HalfFloor` are part of the [`SignedRoundingMode`] enum.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum UnsignedRoundingMode {
     Expand,
@@ -72,7 +73,8 @@ pub enum UnsignedRoundingMode {
 ///
 /// NOTE:
 ///   - You can find the comparative table of all the rounding modes in the [`UnsignedRoundingMode`] documentation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum SignedRoundingMode {
     Unsigned(UnsignedRoundingMode),
@@ -120,7 +122,8 @@ pub enum SignedRoundingMode {
 ///     );
 /// }
 /// ```
-#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub enum RoundingIncrement {
     /// Round the least significant digit to any digit (0-9).